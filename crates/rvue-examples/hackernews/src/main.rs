@@ -30,6 +30,17 @@ struct Story {
     time: i64,
 }
 
+impl Story {
+    /// Page the story links to, or its discussion for text posts
+    fn link(&self) -> String {
+        if self.url.is_empty() {
+            format!("https://news.ycombinator.com/item?id={}", self.id)
+        } else {
+            self.url.clone()
+        }
+    }
+}
+
 unsafe impl Trace for Story {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
@@ -276,16 +287,8 @@ fn hacker_news_app() -> ViewStruct {
                     <Text content=create_memo(move || format!("Refresh ({}s)", countdown.get())) font_size=14.0 />
                 </Button>
             </Flex>
-            <Flex
-                styles=ReactiveStyles::new()
-                    .set_height(Height(Size::Pixels(1.0)))
-                    .set_background_color(BackgroundColor(Color::rgb(224, 224, 224)))
-            />
-            <Flex
-                styles=ReactiveStyles::new()
-                    .set_height(Height(Size::Pixels(1.0)))
-                    .set_background_color(BackgroundColor(Color::rgb(255, 255, 255)))
-            />
+            <Divider />
+            <Divider styles=ReactiveStyles::new().set_background_color(BackgroundColor(Color::rgb(255, 255, 255))) />
             <For each=stories key=|s: &Story| s.id view={|s| view! {
                 <Flex
                     styles=ReactiveStyles::new()
@@ -300,7 +303,12 @@ fn hacker_news_app() -> ViewStruct {
                         .set_width(Width(Size::Percent(100.0)))
                         .set_overflow_x(Overflow::Auto)
                 >
-                     <Text content=s.title.clone() font_size=15.0 font_weight="500" />
+                     <Link
+                         content=s.title.clone()
+                         href=s.link()
+                         track_visited=true
+                         on_click=|href: &str| println!("Open {}", href)
+                     />
                      <Text content=format!("Score: {}", s.score) font_size=12.0 color="#666" />
                      <Text content=format!("by {}", s.by) font_size=12.0 color="#666" />
                 </Flex>
@@ -20,53 +20,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a stylesheet with CSS rules for selector-based styling
     let mut stylesheet = Stylesheet::new();
 
-    // Primary button styles (class selector)
-    stylesheet.add_interactive_colors(
-        "button.primary",
-        Color::rgb(0, 123, 255),   // Normal: Blue
-        Color::rgb(0, 86, 179),    // Hover: Darker blue
-        Color::rgb(0, 65, 130),    // Active: Even darker
-        Color::rgb(200, 200, 200), // Disabled: Gray
-    );
-    let mut props = Properties::new();
-    props.insert(BorderRadius(8.0));
-    stylesheet.add_rule("button.primary", props);
-
-    // Secondary button styles
-    stylesheet.add_interactive_colors(
-        "button.secondary",
-        Color::rgb(108, 117, 125), // Normal: Gray
-        Color::rgb(90, 98, 104),   // Hover: Darker gray
-        Color::rgb(70, 78, 84),    // Active: Even darker
-        Color::rgb(200, 200, 200), // Disabled: Gray
-    );
-    let mut props = Properties::new();
-    props.insert(BorderRadius(8.0));
-    stylesheet.add_rule("button.secondary", props);
-
-    // Danger button styles
-    stylesheet.add_interactive_colors(
-        "button.danger",
-        Color::rgb(220, 53, 69),   // Normal: Red
-        Color::rgb(189, 37, 50),   // Hover: Darker red
-        Color::rgb(158, 28, 40),   // Active: Even darker
-        Color::rgb(200, 200, 200), // Disabled: Gray
-    );
-    let mut props = Properties::new();
-    props.insert(BorderRadius(8.0));
-    stylesheet.add_rule("button.danger", props);
-
-    // Success button styles
-    stylesheet.add_interactive_colors(
-        "button.success",
-        Color::rgb(40, 167, 69),   // Normal: Green
-        Color::rgb(30, 126, 52),   // Hover: Darker green
-        Color::rgb(25, 105, 43),   // Active: Even darker
-        Color::rgb(200, 200, 200), // Disabled: Gray
-    );
-    let mut props = Properties::new();
-    props.insert(BorderRadius(8.0));
-    stylesheet.add_rule("button.success", props);
+    // Button styles: hover, active and disabled shades are derived from the base color
+    for (selector, base) in [
+        ("button.primary", Color::rgb(0, 123, 255)),     // Blue
+        ("button.secondary", Color::rgb(108, 117, 125)), // Gray
+        ("button.danger", Color::rgb(220, 53, 69)),      // Red
+        ("button.success", Color::rgb(40, 167, 69)),     // Green
+    ] {
+        stylesheet.add_button_style(selector, base);
+        let mut props = Properties::new();
+        props.insert(BorderRadius(8.0));
+        stylesheet.add_rule(selector, props);
+    }
 
     // Special ID button styles
     stylesheet.add_background_color_with_hover(
@@ -3,8 +3,14 @@
 //! This module provides simple heuristics to classify expressions
 //! as static or reactive for code generation.
 
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, ToTokens};
+use syn::punctuated::Punctuated;
 use syn::visit::Visit;
-use syn::{Expr, ExprCall, ExprMacro, ExprMethodCall};
+use syn::{
+    Block, Expr, ExprCall, ExprClosure, ExprField, ExprLit, ExprMacro, ExprMethodCall, ExprPath,
+    Ident, Lit, LitStr, Local, PatIdent, Stmt, Token,
+};
 
 /// Classification for expressions used in attributes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -119,6 +125,12 @@ fn is_signal_variable(expr: &Expr) -> bool {
 /// Check if an expression is a ViewStruct by looking at its type path
 fn is_view_struct_type(expr: &Expr) -> bool {
     match expr {
+        // `{view}` children arrive wrapped in their braces
+        Expr::Block(block) => {
+            if let [Stmt::Expr(inner, None)] = block.block.stmts.as_slice() {
+                return is_view_struct_type(inner);
+            }
+        }
         Expr::Path(path) => {
             if let Some(segment) = path.path.segments.last() {
                 let name = segment.ident.to_string();
@@ -169,6 +181,10 @@ fn is_view_struct_type(expr: &Expr) -> bool {
             if method_name.contains("component") {
                 return true;
             }
+            // Recognize `.view()` on a reusable `Once` view
+            if method_name == "view" && expr_method.args.is_empty() {
+                return true;
+            }
             // Recognize .run() calls on slot children
             if method_name == "run" {
                 if let Expr::Path(path) = &*expr_method.receiver {
@@ -232,3 +248,227 @@ impl<'ast> Visit<'ast> for ReactiveDetector {
         self.is_reactive = true;
     }
 }
+
+/// An expression that reads signals, rewritten to run inside an effect
+pub struct TrackedExpr {
+    /// Bindings to clone before the effect is created, each a name and the place it clones
+    pub captures: Vec<(Ident, Expr)>,
+    /// The expression, reading its captures in place of the variables and fields they clone
+    pub expr: TokenStream,
+}
+
+/// Find whether an expression reads a signal with `.get()` when it is evaluated
+///
+/// Returns `None` when the expression reads no signal. Otherwise every local variable the
+/// expression uses becomes a capture, or just the field when it is only used through one, as in
+/// `model.count.get()`. Cloning the captures into the effect keeps the originals usable after
+/// the view. Reads inside closures run later and do not count, though what the closures use
+/// does; the arguments of `format!`-like macros count too, including variables named inline in
+/// the format string.
+pub fn tracked_reads(expr: &Expr) -> Option<TrackedExpr> {
+    let mut finder =
+        TrackedReads { reads: false, closure_depth: 0, bound: Vec::new(), places: Vec::new() };
+    finder.visit_expr(expr);
+    if !finder.reads {
+        return None;
+    }
+
+    let mut captures = Vec::new();
+    let mut renames = Vec::new();
+    for place in finder.places {
+        let ident = match &place {
+            Expr::Path(path) => path.path.get_ident().cloned(),
+            _ => None,
+        };
+        let ident = ident.unwrap_or_else(|| {
+            let members: Vec<_> =
+                place_tokens(&place).into_iter().filter(|token| token != ".").collect();
+            format_ident!("__{}", members.join("_"))
+        });
+        if matches!(place, Expr::Field(_)) {
+            renames.push((place_tokens(&place), ident.clone()));
+        }
+        captures.push((ident, place));
+    }
+    // The longest chain wins where one field chain extends another
+    renames.sort_by_key(|(tokens, _)| std::cmp::Reverse(tokens.len()));
+    let expr = rename_places(expr.to_token_stream(), &renames);
+    Some(TrackedExpr { captures, expr })
+}
+
+/// The tokens of a place expression, as strings to compare against
+fn place_tokens(place: &Expr) -> Vec<String> {
+    place.to_token_stream().into_iter().map(|token| token.to_string()).collect()
+}
+
+/// Replace each field chain in `tokens`, including inside macro calls, with its capture
+fn rename_places(tokens: TokenStream, renames: &[(Vec<String>, Ident)]) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut renamed = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        // `other.model.count` is a different place than `model.count`
+        let continues_path = i > 0
+            && matches!(&tokens[i - 1], TokenTree::Punct(punct) if matches!(punct.as_char(), '.' | ':'));
+        let rename = renames.iter().find(|(place, _)| {
+            !continues_path
+                && tokens.len() - i >= place.len()
+                && tokens[i..i + place.len()].iter().zip(place).all(|(token, place)| {
+                    !matches!(token, TokenTree::Group(_)) && token.to_string() == *place
+                })
+        });
+        match (rename, &tokens[i]) {
+            (Some((place, ident)), _) => {
+                renamed.push(TokenTree::Ident(ident.clone()));
+                i += place.len();
+            }
+            (None, TokenTree::Group(group)) => {
+                let mut inner =
+                    Group::new(group.delimiter(), rename_places(group.stream(), renames));
+                inner.set_span(group.span());
+                renamed.push(TokenTree::Group(inner));
+                i += 1;
+            }
+            (None, token) => {
+                renamed.push(token.clone());
+                i += 1;
+            }
+        }
+    }
+    renamed.into_iter().collect()
+}
+
+struct TrackedReads {
+    reads: bool,
+    closure_depth: usize,
+    /// Names bound inside the expression, by closure parameters or `let`
+    bound: Vec<Ident>,
+    /// Variables and field chains on variables the expression uses
+    places: Vec<Expr>,
+}
+
+impl TrackedReads {
+    /// The variable a place is rooted at, if it is a plain local path or field chain on one
+    fn root(place: &Expr) -> Option<&Ident> {
+        match place {
+            Expr::Path(path) if path.qself.is_none() => path.path.get_ident(),
+            Expr::Field(field) => Self::root(&field.base),
+            _ => None,
+        }
+    }
+
+    fn capture(&mut self, place: Expr) {
+        let Some(root) = Self::root(&place) else {
+            return;
+        };
+        let name = root.to_string();
+        let is_local = name.starts_with(|c: char| c.is_lowercase() || c == '_') && name != "self";
+        let captured = self
+            .places
+            .iter()
+            .any(|seen| seen.to_token_stream().to_string() == place.to_token_stream().to_string());
+        if is_local && !self.bound.contains(root) && !captured {
+            self.places.push(place);
+        }
+    }
+
+    /// Capture the variables a format string names inline, as in `"{count}"` or `"{name:>8}"`
+    fn capture_inline_args(&mut self, format: &LitStr) {
+        let format = format.value();
+        let mut rest = format.as_str();
+        while let Some(start) = rest.find('{') {
+            rest = &rest[start + 1..];
+            if let Some(escaped) = rest.strip_prefix('{') {
+                rest = escaped;
+                continue;
+            }
+            let end = rest.find(['}', ':']).unwrap_or(rest.len());
+            if let Ok(ident) = syn::parse_str::<Ident>(&rest[..end]) {
+                self.capture(Expr::Path(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: ident.into(),
+                }));
+            }
+            rest = &rest[end..];
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TrackedReads {
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        if node.method == "get" && node.args.is_empty() && self.closure_depth == 0 {
+            self.reads = true;
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_path(&mut self, node: &'ast ExprPath) {
+        self.capture(Expr::Path(node.clone()));
+    }
+
+    fn visit_expr_field(&mut self, node: &'ast ExprField) {
+        if Self::root(&node.base).is_some() {
+            self.capture(Expr::Field(node.clone()));
+        } else {
+            syn::visit::visit_expr_field(self, node);
+        }
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        // A plain function name is an item, not a variable
+        if !matches!(&*node.func, Expr::Path(_)) {
+            self.visit_expr(&node.func);
+        }
+        for arg in &node.args {
+            self.visit_expr(arg);
+        }
+    }
+
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        let bound = self.bound.len();
+        for input in &node.inputs {
+            self.visit_pat(input);
+        }
+        self.closure_depth += 1;
+        self.visit_expr(&node.body);
+        self.closure_depth -= 1;
+        self.bound.truncate(bound);
+    }
+
+    fn visit_block(&mut self, node: &'ast Block) {
+        let bound = self.bound.len();
+        syn::visit::visit_block(self, node);
+        self.bound.truncate(bound);
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        // The initializer is evaluated before the pattern binds
+        if let Some(init) = &node.init {
+            self.visit_local_init(init);
+        }
+        self.visit_pat(&node.pat);
+    }
+
+    fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+        self.bound.push(node.ident.clone());
+        syn::visit::visit_pat_ident(self, node);
+    }
+
+    fn visit_expr_macro(&mut self, node: &'ast ExprMacro) {
+        let Ok(args) = node.mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        else {
+            return;
+        };
+        if let Some(Expr::Lit(ExprLit { lit: Lit::Str(format), .. })) = args.first() {
+            self.capture_inline_args(format);
+        }
+        for arg in &args {
+            match arg {
+                // A named format argument, `name = value`, only reads its value
+                Expr::Assign(assign) => self.visit_expr(&assign.right),
+                arg => self.visit_expr(arg),
+            }
+        }
+    }
+}
@@ -32,6 +32,10 @@ pub struct RvueElement {
     pub attributes: Vec<RvueAttribute>,
     /// Child nodes
     pub children: Vec<RvueNode>,
+    /// Styled runs of a rich `Text`, parsed from inline markup like `<b>`
+    pub spans: Vec<RvueSpan>,
+    /// Base props from `{..props}`, with explicit attributes overriding its fields
+    pub spread: Option<Expr>,
     /// Span for error reporting
     pub span: Span,
 }
@@ -43,11 +47,20 @@ pub enum WidgetType {
     Button,
     Flex,
     TextInput,
+    TextArea,
     NumberInput,
     Checkbox,
     Radio,
+    ProgressBar,
+    Spinner,
+    Divider,
+    Spacer,
+    Link,
     Show,
     For,
+    Switch,
+    /// Arm of a `Switch`, only valid as its direct child
+    Match,
     /// Custom component (PascalCase function name)
     Custom(String),
 }
@@ -59,6 +72,8 @@ pub enum RvueAttribute {
     Static { name: String, value: String, span: Span },
     /// Dynamic attribute with expression value
     Dynamic { name: String, expr: Expr, span: Span },
+    /// Optional attribute written `attr=maybe?`, applied only when the `Option` is `Some`
+    Optional { name: String, expr: Expr, span: Span },
     /// Event handler (on_click, on_key_down, etc.)
     Event { name: String, handler: Expr, span: Span },
     /// Slot attribute (slot:name or slot)
@@ -66,6 +81,28 @@ pub enum RvueAttribute {
     Slot { name: Option<String>, content: TokenStream, span: Span },
 }
 
+/// Styled run inside a rich `Text`, e.g. `<b>"bold"</b>`
+#[derive(Debug, Clone)]
+pub struct RvueSpan {
+    /// Text of the run
+    pub text: SpanText,
+    /// Set inside `<b>` or `<strong>`
+    pub bold: bool,
+    /// Set inside `<i>` or `<em>`
+    pub italic: bool,
+    /// Color from the innermost `<span color=...>`
+    pub color: Option<TokenStream>,
+}
+
+/// Text of a rich text run
+#[derive(Debug, Clone)]
+pub enum SpanText {
+    /// Literal text
+    Literal(String),
+    /// Block expression, converted with `to_string()` when the view is built
+    Expr(Expr),
+}
+
 /// Text node
 #[derive(Debug, Clone)]
 pub struct RvueText {
@@ -81,6 +118,7 @@ impl RvueAttribute {
         match self {
             RvueAttribute::Static { span, .. } => *span,
             RvueAttribute::Dynamic { span, .. } => *span,
+            RvueAttribute::Optional { span, .. } => *span,
             RvueAttribute::Event { span, .. } => *span,
             RvueAttribute::Slot { span, .. } => *span,
         }
@@ -91,6 +129,7 @@ impl RvueAttribute {
         match self {
             RvueAttribute::Static { name, .. } => name,
             RvueAttribute::Dynamic { name, .. } => name,
+            RvueAttribute::Optional { name, .. } => name,
             RvueAttribute::Event { name, .. } => name,
             RvueAttribute::Slot { .. } => "slot",
         }
@@ -118,11 +157,19 @@ impl WidgetType {
             WidgetType::Button => quote::format_ident!("Button"),
             WidgetType::Flex => quote::format_ident!("Flex"),
             WidgetType::TextInput => quote::format_ident!("TextInput"),
+            WidgetType::TextArea => quote::format_ident!("TextArea"),
             WidgetType::NumberInput => quote::format_ident!("NumberInput"),
             WidgetType::Checkbox => quote::format_ident!("Checkbox"),
             WidgetType::Radio => quote::format_ident!("Radio"),
+            WidgetType::ProgressBar => quote::format_ident!("ProgressBar"),
+            WidgetType::Spinner => quote::format_ident!("Spinner"),
+            WidgetType::Divider => quote::format_ident!("Divider"),
+            WidgetType::Spacer => quote::format_ident!("Spacer"),
+            WidgetType::Link => quote::format_ident!("Link"),
             WidgetType::Show => quote::format_ident!("Show"),
             WidgetType::For => quote::format_ident!("For"),
+            WidgetType::Switch => quote::format_ident!("Switch"),
+            WidgetType::Match => quote::format_ident!("Match"),
             WidgetType::Custom(name) => quote::format_ident!("{}", name),
         }
     }
@@ -18,6 +18,21 @@ pub fn parse_attribute(attr: &NodeAttribute) -> Result<RvueAttribute, AttributeE
     }
 }
 
+/// Parse a `{..props}` spread attribute into its base expression
+pub fn parse_spread(attr: &NodeAttribute) -> Option<Expr> {
+    let NodeAttribute::Block(block) = attr else {
+        return None;
+    };
+    match block.try_block()?.stmts.as_slice() {
+        [syn::Stmt::Expr(Expr::Range(range), None)]
+            if range.start.is_none() && matches!(range.limits, syn::RangeLimits::HalfOpen(_)) =>
+        {
+            range.end.as_deref().cloned()
+        }
+        _ => None,
+    }
+}
+
 /// Parse a keyed attribute
 fn parse_keyed_attribute(attr: &KeyedAttribute) -> Result<RvueAttribute, AttributeError> {
     let name = attr.key.to_string();
@@ -29,17 +44,61 @@ fn parse_keyed_attribute(attr: &KeyedAttribute) -> Result<RvueAttribute, Attribu
 
     if let Some(event_name) = name.strip_prefix("on_") {
         parse_event_attr(attr, event_name, span)
-    } else if name == "on:click" {
-        parse_event_attr(attr, "click", span)
-    } else if name == "on:key_down" {
-        parse_event_attr(attr, "key_down", span)
-    } else if name == "on:pointer_down" {
-        parse_event_attr(attr, "pointer_down", span)
+    } else if let Some(event_name) = name.strip_prefix("on:") {
+        parse_event_attr(attr, resolve_event(event_name, span), span)
     } else {
         parse_prop_attr(attr, &name, span)
     }
 }
 
+/// Events a `view!` element can handle, named after their `Component::on_*` registration
+const EVENTS: &[&str] = &[
+    "click",
+    "click_capture",
+    "pointer_down",
+    "pointer_down_capture",
+    "pointer_up",
+    "pointer_up_capture",
+    "pointer_enter",
+    "pointer_leave",
+    "pointer_move",
+    "key_down",
+    "key_down_capture",
+    "key_up",
+    "input",
+    "change",
+    "focus",
+    "blur",
+    "context_menu",
+    "long_press",
+    "drag_start",
+    "drag",
+    "drag_end",
+    "drag_over",
+    "drop",
+];
+
+/// Map the event in `on:event` to its registration name
+///
+/// Both the DOM spelling (`on:pointerdown`) and the registration name (`on:pointer_down`) are
+/// accepted.
+fn resolve_event(event_name: &str, span: Span) -> &'static str {
+    let wanted = event_name.replace('_', "").to_lowercase();
+    match EVENTS.iter().find(|event| event.replace('_', "") == wanted) {
+        Some(event) => event,
+        None => {
+            let supported: Vec<String> =
+                EVENTS.iter().map(|event| event.replace('_', "")).collect();
+            abort!(
+                span,
+                "Unknown event `on:{}`",
+                event_name;
+                help = "Supported events: {}", supported.join(", ")
+            )
+        }
+    }
+}
+
 /// Parse an event attribute (on_click, on_key_down, etc.)
 fn parse_event_attr(
     attr: &KeyedAttribute,
@@ -59,6 +118,10 @@ fn parse_prop_attr(
     name: &str,
     span: Span,
 ) -> Result<RvueAttribute, AttributeError> {
+    if let Some(expr) = attr.value().and_then(optional_expr) {
+        return Ok(RvueAttribute::Optional { name: name.to_string(), expr, span });
+    }
+
     match attr.value() {
         Some(expr) => match extract_value_from_expr(expr) {
             Value::Static(s) => {
@@ -72,6 +135,18 @@ fn parse_prop_attr(
     }
 }
 
+/// The `Option` in `attr=maybe?` or `attr={maybe?}`
+fn optional_expr(expr: &Expr) -> Option<Expr> {
+    match expr {
+        Expr::Try(try_expr) => Some((*try_expr.expr).clone()),
+        Expr::Block(block) => match block.block.stmts.as_slice() {
+            [syn::Stmt::Expr(inner, None)] => optional_expr(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Parse handler expression from attribute value
 fn parse_handler_expression(expr: &Expr, _span: Span) -> Result<Expr, AttributeError> {
     Ok(expr.clone())
@@ -97,31 +172,90 @@ fn extract_value_from_expr(expr: &Expr) -> Value {
 }
 
 /// Classify a tag name into a widget type
-pub fn classify_widget(tag_name: &str) -> WidgetType {
+pub fn classify_widget(tag_name: &str, span: Span) -> WidgetType {
     match tag_name {
         "Text" => WidgetType::Text,
         "Button" => WidgetType::Button,
         "Flex" => WidgetType::Flex,
         "TextInput" => WidgetType::TextInput,
+        "TextArea" => WidgetType::TextArea,
         "NumberInput" => WidgetType::NumberInput,
         "Checkbox" => WidgetType::Checkbox,
         "Radio" => WidgetType::Radio,
+        "ProgressBar" => WidgetType::ProgressBar,
+        "Spinner" => WidgetType::Spinner,
+        "Divider" => WidgetType::Divider,
+        "Spacer" => WidgetType::Spacer,
+        "Link" => WidgetType::Link,
         "Show" => WidgetType::Show,
         "For" => WidgetType::For,
+        "Switch" => WidgetType::Switch,
+        "Match" => WidgetType::Match,
         _ => {
+            // Any PascalCase tag may name a user component, however close to a builtin it is
             if is_pascal_case(tag_name) {
-                WidgetType::Custom(tag_name.to_string())
-            } else {
+                return WidgetType::Custom(tag_name.to_string());
+            }
+            // A lowercase tag can't be a component, so one edit from a builtin is a typo
+            if let Some(builtin) = BUILTIN_WIDGETS.iter().find(|builtin| {
+                edit_distance(&tag_name.to_lowercase(), &builtin.to_lowercase()) <= 1
+            }) {
                 abort!(
-                    Span::call_site(),
-                    "Unknown widget type: '{}'",
+                    span,
+                    "Unknown widget `{}`",
                     tag_name;
-                    help = "Available built-in widgets: Text, Button, Flex, TextInput, NumberInput, Checkbox, Radio, Show, For\n\
-                            Custom components must be in PascalCase."
+                    help = "did you mean `{}`?", builtin
                 )
             }
+            abort!(
+                span,
+                "Unknown widget type: '{}'",
+                tag_name;
+                help = "Available built-in widgets: {}\n\
+                        Custom components must be in PascalCase.", BUILTIN_WIDGETS.join(", ")
+            )
+        }
+    }
+}
+
+/// Tags of the built-in widgets, as written in `view!`
+const BUILTIN_WIDGETS: &[&str] = &[
+    "Text",
+    "Button",
+    "Flex",
+    "TextInput",
+    "TextArea",
+    "NumberInput",
+    "Checkbox",
+    "Radio",
+    "ProgressBar",
+    "Spinner",
+    "Divider",
+    "Spacer",
+    "Link",
+    "Show",
+    "For",
+    "Switch",
+    "Match",
+];
+
+/// Number of insertions, deletions, substitutions and adjacent swaps turning `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1).min(row[j - 1] + 1).min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
         }
+        rows.push(row);
     }
+    rows[a.len()][b.len()]
 }
 
 /// Check if a string is in PascalCase
@@ -3,10 +3,11 @@
 //! This module handles converting rvue AST into Rust code
 //! that creates widgets using rvue widget system.
 
-use crate::analysis::{classify_expression, ExpressionKind};
+use crate::analysis::{classify_expression, tracked_reads, ExpressionKind, TrackedExpr};
 use crate::ast::{RvueAttribute, RvueElement, RvueNode, RvueText, WidgetType};
 use crate::widgets::generate_event_handlers;
 use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro_error2::abort;
 use quote::{format_ident, quote, quote_spanned, ToTokens};
 use rstml::node::{Node, NodeAttribute, NodeElement, NodeName};
 use syn::spanned::Spanned;
@@ -15,6 +16,11 @@ use syn::{Block, Expr};
 /// Generate view code from AST nodes
 pub fn generate_view_code(nodes: Vec<RvueNode>) -> TokenStream {
     let ctx_ident = format_ident!("ctx");
+    let view_struct = if is_fragment(&nodes) {
+        quote! { rvue::ViewStruct::fragment(root_component) }
+    } else {
+        quote! { rvue::ViewStruct::new(root_component) }
+    };
     let root_component = match nodes.len() {
         0 => generate_empty_component(&ctx_ident),
         1 => generate_node_code(&nodes[0], &ctx_ident),
@@ -69,11 +75,19 @@ pub fn generate_view_code(nodes: Vec<RvueNode>) -> TokenStream {
                 unsafe { *ptr = *#ctx_ident.id_counter; }
             }
 
-            rvue::ViewStruct::new(root_component)
+            #view_struct
         }
     }
 }
 
+/// Whether the top-level nodes form a fragment with several roots
+fn is_fragment(nodes: &[RvueNode]) -> bool {
+    match nodes {
+        [RvueNode::Fragment(children)] => children.len() > 1,
+        nodes => nodes.len() > 1,
+    }
+}
+
 fn generate_empty_component(ctx_ident: &Ident) -> TokenStream {
     quote! {
         {
@@ -104,9 +118,20 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
             let (slot_attrs, normal_attrs): (Vec<_>, _) =
                 el.attributes.iter().partition(|a| a.is_slot());
 
+            if let Some(attr) =
+                normal_attrs.iter().find(|a| matches!(a, RvueAttribute::Optional { .. }))
+            {
+                abort!(
+                    attr.span(),
+                    "Optional attribute `{}` is not supported on component `{}`",
+                    attr.name(), name;
+                    help = "Component props are always set; pass the `Option` itself or spread a base with `{{..props}}`"
+                );
+            }
+
             let props_init = normal_attrs
                 .iter()
-                .filter(|a| !matches!(a, RvueAttribute::Event { .. }))
+                .filter(|a| !matches!(a, RvueAttribute::Event { .. }) && a.name() != "ref")
                 .map(|attr| {
                     let name = format_ident!("{}", attr.name());
                     let PropValue { value, is_reactive } = extract_attr_value(attr);
@@ -117,7 +142,10 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
                     }
                 });
 
+            let spread = el.spread.as_ref().map(|base| quote! { ..#base });
+
             let events_code = generate_event_handlers_for_element(&component_ident, el);
+            let node_ref_code = generate_node_ref(&el.attributes, &component_ident);
 
             let slot_code = if !slot_attrs.is_empty() {
                 generate_slot_injection(&slot_attrs, &component_ident)
@@ -128,7 +156,8 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
             quote! {
                 {
                     let props = #props_struct_name {
-                        #(#props_init),*
+                        #(#props_init,)*
+                        #spread
                     };
 
                     let #component_ident = #ctx_ident.create_component(
@@ -138,12 +167,12 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
 
                     let view = rvue::runtime::with_owner(rvue::Gc::clone(&#component_ident), || #widget_name(props));
 
-                    let inner_comp = rvue::prelude::View::into_component(view);
-                    #component_ident.add_child(inner_comp);
+                    rvue::prelude::View::into_view_struct(view).mount_into(&#component_ident);
 
                     #slot_code
 
                     #events_code
+                    #node_ref_code
 
                     #component_ident
                 }
@@ -172,14 +201,24 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
                 quote! { |_ctx: &mut BuildContext| { let state = rvue::widgets::Flex::new().build(_ctx); rvue::ViewStruct::new(rvue::Gc::clone(state.component())) } }
             } else {
                 let child_code = generate_children_code_for_show(&el.children);
-                quote! { |ctx: &mut BuildContext| { #child_code } }
+                quote! { move |ctx: &mut BuildContext| { #child_code } }
             };
 
+            let transition_call = el.attributes.iter().find_map(|a| match a {
+                RvueAttribute::Static { name, value, .. } if name == "transition" => {
+                    Some(quote! { .transition(#value) })
+                }
+                RvueAttribute::Dynamic { name, expr, .. } if name == "transition" => {
+                    Some(quote! { .transition(#expr) })
+                }
+                _ => None,
+            });
+
             let events_code = generate_event_handlers_for_element(&component_ident, el);
 
             quote! {
                 {
-                    let widget = rvue::widgets::Show::new(#when_value, #children_code);
+                    let widget = rvue::widgets::Show::new(#when_value, #children_code) #transition_call;
                     let state = widget.build(&mut ctx);
                     let #component_ident = Gc::clone(state.component());
 
@@ -189,23 +228,46 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
                 }
             }
         }
+        WidgetType::Switch => {
+            let switch_code = generate_switch_code(el);
+            let events_code = generate_event_handlers_for_element(&component_ident, el);
+
+            quote! {
+                {
+                    let widget = #switch_code;
+                    let state = widget.build(&mut #ctx_ident);
+                    let #component_ident = Gc::clone(state.component());
+
+                    #events_code
+
+                    #component_ident
+                }
+            }
+        }
+        WidgetType::Match => abort_match_outside_switch(el.span),
         _ => {
-            let widget_code =
-                generate_widget_builder_code(&el.widget_type, &el.attributes, el.span);
+            let widget_code = generate_widget_builder_code(el);
             let effects_code =
                 generate_reactive_effects(&el.widget_type, &el.attributes, &component_ident);
             let children_code = generate_children_code(&el.children, &component_ident, ctx_ident);
             let events_code = generate_event_handlers_for_element(&component_ident, el);
+            let aria_label_code = generate_aria_label(&el.attributes, &component_ident);
+            let node_ref_code = generate_node_ref(&el.attributes, &component_ident);
+
+            let optional_code = generate_optional_attrs(&el.attributes);
 
             quote! {
                 {
                     let widget = #widget_code;
+                    #optional_code
                     let state = widget.build(&mut #ctx_ident);
                     let #component_ident = Gc::clone(state.component());
 
                     #children_code
                     #events_code
                     #effects_code
+                    #aria_label_code
+                    #node_ref_code
 
                     #component_ident
                 }
@@ -214,6 +276,58 @@ fn generate_element_code(el: &RvueElement, ctx_ident: &Ident) -> TokenStream {
     }
 }
 
+/// Generate a `Switch` builder with one `Match` arm per child element
+///
+/// Arm contents are built lazily, only when the arm becomes active.
+fn generate_switch_code(el: &RvueElement) -> TokenStream {
+    let arms = el.children.iter().filter_map(|child| match child {
+        RvueNode::Element(arm) if arm.widget_type == WidgetType::Match => {
+            let when_value = arm
+                .find_attr("when")
+                .map(|attr| extract_attr_value(attr).value)
+                .unwrap_or_else(|| quote! { false });
+
+            let children_code = if arm.children.is_empty() {
+                quote! { |ctx: &mut BuildContext| { let state = rvue::widgets::Flex::new().build(ctx); rvue::Gc::clone(state.component()) } }
+            } else {
+                let child_code = generate_children_code_for_show(&arm.children);
+                quote! { move |ctx: &mut BuildContext| { #child_code } }
+            };
+
+            let key_call = arm.find_attr("key").map(|attr| {
+                let key = extract_attr_value(attr).value;
+                quote! { .key(#key) }
+            });
+
+            Some(quote! { rvue::widgets::Match::new(#when_value, #children_code) #key_call })
+        }
+        // Whitespace between arms
+        RvueNode::Text(text) if text.content.trim().is_empty() => None,
+        other => abort!(
+            node_span(other, el.span),
+            "`<Switch>` children must be `<Match>` elements";
+            help = "Wrap the content in `<Match when={...}>`"
+        ),
+    });
+
+    quote! {
+        rvue::widgets::Switch::new(vec![#(#arms),*])
+    }
+}
+
+fn node_span(node: &RvueNode, fallback: Span) -> Span {
+    match node {
+        RvueNode::Element(el) => el.span,
+        RvueNode::Text(text) => text.span,
+        RvueNode::Block(_, span) => *span,
+        RvueNode::Fragment(_) => fallback,
+    }
+}
+
+fn abort_match_outside_switch(span: Span) -> ! {
+    abort!(span, "`<Match>` can only be used as a direct child of `<Switch>`")
+}
+
 /// Generate code to inject slot content into a parent component
 fn generate_slot_injection(slot_attrs: &[&RvueAttribute], component_ident: &Ident) -> TokenStream {
     let slot_injections: Vec<TokenStream> = slot_attrs
@@ -235,8 +349,7 @@ fn generate_slot_injection(slot_attrs: &[&RvueAttribute], component_ident: &Iden
                         rvue::runtime::with_owner(rvue::Gc::clone(&#component_ident), || #content)
                     });
                     let slot_view = #slot_var.run();
-                    let inner_comp = rvue::prelude::View::into_component(slot_view);
-                    #component_ident.add_child(inner_comp);
+                    slot_view.mount_into(&#component_ident);
                 }
             }
         })
@@ -366,6 +479,23 @@ fn generate_children_code(
             }
         }
         RvueNode::Block(expr, span) => {
+            if classify_expression(expr) == ExpressionKind::ViewStruct {
+                // A nested view may be a fragment, whose roots belong directly under the parent
+                return quote_spanned! { *span =>
+                    rvue::prelude::View::into_view_struct(#expr).mount_into(&#parent_id);
+                };
+            }
+            if classify_expression(expr) == ExpressionKind::Static {
+                // Views and fragments held in variables are told apart from text by their type
+                return quote_spanned! { *span =>
+                    {
+                        #[allow(unused_imports)]
+                        use rvue::view::block_child::{TextKind as _, ViewKind as _};
+                        let child = #expr;
+                        (&child).block_child_kind().mount(child, &#parent_id, &mut #ctx_ident);
+                    }
+                };
+            }
             let block_code = generate_block_node_code(expr, *span, ctx_ident);
             quote! {
                 {
@@ -374,18 +504,7 @@ fn generate_children_code(
                 }
             }
         }
-        RvueNode::Fragment(nodes) => {
-            let fragment_children = nodes.iter().map(|node| {
-                let child_code = generate_node_code(node, ctx_ident);
-                quote! {
-                    {
-                        let child = #child_code;
-                        #parent_id.add_child(child);
-                    }
-                }
-            });
-            quote! { #(#fragment_children)* }
-        }
+        RvueNode::Fragment(nodes) => generate_children_code(nodes, parent_id, ctx_ident),
     });
 
     quote! {
@@ -440,12 +559,17 @@ fn generate_children_code_for_show(children: &[RvueNode]) -> TokenStream {
 }
 
 fn generate_element_code_for_show(el: &RvueElement) -> TokenStream {
-    let widget_code = generate_widget_builder_code(&el.widget_type, &el.attributes, el.span);
+    let widget_code = match el.widget_type {
+        WidgetType::Switch => generate_switch_code(el),
+        _ => generate_widget_builder_code(el),
+    };
     let events_code = generate_event_handlers_for_element(&format_ident!("child"), el);
+    let optional_code = generate_optional_attrs(&el.attributes);
 
     quote! {
         {
             let widget = #widget_code;
+            #optional_code
             let state = widget.build(ctx);
             let child = Gc::clone(state.component());
 
@@ -505,6 +629,12 @@ fn generate_event_handlers_for_element(component_id: &Ident, el: &RvueElement) -
         .events()
         .iter()
         .filter_map(|a| match a {
+            // Handled by the Link builder, see `generate_widget_builder_code`
+            RvueAttribute::Event { name, .. }
+                if name == "click" && el.widget_type == WidgetType::Link =>
+            {
+                None
+            }
             RvueAttribute::Event { name, handler, .. } => Some((name.as_str(), handler)),
             _ => None,
         })
@@ -517,25 +647,44 @@ fn generate_event_handlers_for_element(component_id: &Ident, el: &RvueElement) -
     }
 }
 
-fn generate_widget_builder_code(
-    widget_type: &WidgetType,
-    attributes: &[RvueAttribute],
-    span: Span,
-) -> TokenStream {
-    let props = WidgetProps::new(attributes);
+fn generate_widget_builder_code(el: &RvueElement) -> TokenStream {
+    let props = WidgetProps::new(&el.attributes);
+    let span = el.span;
 
-    match widget_type {
+    match &el.widget_type {
         WidgetType::Text => {
             let PropValue { value: content_value, is_reactive } =
                 props.value("content", || quote! { "" });
             let widget_ident = Ident::new("Text", span);
+            let selectable_call = props
+                .optional_value("selectable")
+                .map(|v| quote! { .selectable(#v) })
+                .unwrap_or_else(|| quote! {});
 
             let style_call = extract_style_call(&props);
 
-            if is_reactive {
+            if !el.spans.is_empty() {
+                let spans = crate::rich_text::generate_spans_code(&el.spans);
+                quote! {
+                    {
+                        rvue::widgets::#widget_ident::rich(#spans)
+                            #selectable_call
+                            #style_call
+                    }
+                }
+            } else if attr_reads(&el.attributes, "content").is_some() {
+                quote! {
+                    {
+                        rvue::widgets::#widget_ident::new((#content_value).to_string())
+                            #selectable_call
+                            #style_call
+                    }
+                }
+            } else if is_reactive {
                 quote! {
                     {
                         rvue::widgets::#widget_ident::new(#content_value)
+                            #selectable_call
                             #style_call
                     }
                 }
@@ -543,6 +692,7 @@ fn generate_widget_builder_code(
                 quote! {
                     {
                         rvue::widgets::#widget_ident::new(#content_value.to_string())
+                            #selectable_call
                             #style_call
                     }
                 }
@@ -554,6 +704,7 @@ fn generate_widget_builder_code(
             let widget_ident = Ident::new("Button", span);
 
             let style_call = extract_style_call(&props);
+            let submit_call = props.optional_calls(&["submit"]);
 
             let class_call = if class_value.to_string() != quote! { "" }.to_string() {
                 quote! { .class(#class_value) }
@@ -573,6 +724,7 @@ fn generate_widget_builder_code(
                         #style_call
                         #class_call
                         #id_call
+                        #submit_call
                 }
             }
         }
@@ -637,25 +789,59 @@ fn generate_widget_builder_code(
         WidgetType::TextInput => {
             let PropValue { value: value_value, .. } = props.value("value", || quote! { "" });
             let widget_ident = Ident::new("TextInput", span);
+            let error_call = props.optional_calls(&["name", "error"]);
 
             let style_call = extract_style_call(&props);
 
             quote! {
                 {
                     rvue::widgets::#widget_ident::new(#value_value.to_string())
+                        #error_call
                         #style_call
                 }
             }
         }
+        WidgetType::TextArea => {
+            let widget_ident = Ident::new("TextArea", span);
+            let option_calls = props.optional_calls(&["rows", "name", "error"]);
+            let style_call = extract_style_call(&props);
+
+            // `bind:value` takes a `(read, write)` signal pair and keeps both in sync
+            match props.optional_value("bind:value") {
+                Some(signal) => quote! {
+                    {
+                        let (value, set_value) = #signal;
+                        rvue::widgets::#widget_ident::new(value)
+                            .bind(set_value)
+                            #option_calls
+                            #style_call
+                    }
+                },
+                None => {
+                    let PropValue { value: value_value, .. } =
+                        props.value("value", || quote! { "" });
+                    quote! {
+                        {
+                            rvue::widgets::#widget_ident::new(#value_value.to_string())
+                                #option_calls
+                                #style_call
+                        }
+                    }
+                }
+            }
+        }
         WidgetType::NumberInput => {
             let PropValue { value: value_value, .. } = props.value("value", || quote! { 0.0 });
             let widget_ident = Ident::new("NumberInput", span);
+            let option_calls =
+                props.optional_calls(&["min", "max", "step", "steppers", "name", "error"]);
 
             let style_call = extract_style_call(&props);
 
             quote! {
                 {
                     rvue::widgets::#widget_ident::new(#value_value)
+                        #option_calls
                         #style_call
                 }
             }
@@ -664,12 +850,17 @@ fn generate_widget_builder_code(
             let PropValue { value: checked_value, .. } =
                 props.value("checked", || quote! { false });
             let widget_ident = Ident::new("Checkbox", span);
+            let indeterminate_call = props
+                .optional_value("indeterminate")
+                .map(|v| quote! { .indeterminate(#v) })
+                .unwrap_or_else(|| quote! {});
 
             let style_call = extract_style_call(&props);
 
             quote! {
                 {
                     rvue::widgets::#widget_ident::new(#checked_value)
+                        #indeterminate_call
                         #style_call
                 }
             }
@@ -689,6 +880,96 @@ fn generate_widget_builder_code(
                 }
             }
         }
+        WidgetType::ProgressBar => {
+            let PropValue { value: value_value, .. } = props.value("value", || quote! { 0.0 });
+            let widget_ident = Ident::new("ProgressBar", span);
+            let indeterminate_call = props
+                .optional_value("indeterminate")
+                .map(|v| quote! { .indeterminate(#v) })
+                .unwrap_or_else(|| quote! {});
+
+            let style_call = extract_style_call(&props);
+
+            quote! {
+                {
+                    rvue::widgets::#widget_ident::new(#value_value)
+                        #indeterminate_call
+                        #style_call
+                }
+            }
+        }
+        WidgetType::Spinner => {
+            let widget_ident = Ident::new("Spinner", span);
+            let style_call = extract_style_call(&props);
+
+            quote! {
+                {
+                    rvue::widgets::#widget_ident::new()
+                        #style_call
+                }
+            }
+        }
+        WidgetType::Divider => {
+            let widget_ident = Ident::new("Divider", span);
+            let vertical_call = props
+                .optional_value("vertical")
+                .map(|v| quote! { .vertical(#v) })
+                .unwrap_or_else(|| quote! {});
+            let style_call = extract_style_call(&props);
+
+            quote! {
+                {
+                    rvue::widgets::#widget_ident::new()
+                        #vertical_call
+                        #style_call
+                }
+            }
+        }
+        WidgetType::Spacer => {
+            let widget_ident = Ident::new("Spacer", span);
+            let style_call = extract_style_call(&props);
+
+            quote! {
+                {
+                    rvue::widgets::#widget_ident::new()
+                        #style_call
+                }
+            }
+        }
+        WidgetType::Link => {
+            let PropValue { value: content_value, is_reactive } =
+                props.value("content", || quote! { "" });
+            let PropValue { value: href_value, .. } = props.value("href", || quote! { "" });
+            let widget_ident = Ident::new("Link", span);
+            let content_value = if is_reactive {
+                content_value
+            } else {
+                quote! { (#content_value).to_string() }
+            };
+            let track_visited_call = props.optional_calls(&["track_visited"]);
+            // A link's click handler receives its URL, so it goes to the builder rather than
+            // the component
+            let on_click_call = el
+                .attributes
+                .iter()
+                .find_map(|attr| match attr {
+                    RvueAttribute::Event { name, handler, .. } if name == "click" => {
+                        Some(quote! { .on_click(#handler) })
+                    }
+                    _ => None,
+                })
+                .unwrap_or_else(|| quote! {});
+            let style_call = extract_style_call(&props);
+
+            quote! {
+                {
+                    rvue::widgets::#widget_ident::new(#content_value, #href_value)
+                        #track_visited_call
+                        #on_click_call
+                        #style_call
+                }
+            }
+        }
         WidgetType::Show => {
             let PropValue { value: when_value, .. } = props.value("when", || quote! { false });
             let widget_ident = Ident::new("Show", span);
@@ -696,6 +977,13 @@ fn generate_widget_builder_code(
                 rvue::widgets::#widget_ident::new(#when_value, || view! {})
             }
         }
+        WidgetType::Switch => {
+            let widget_ident = Ident::new("Switch", span);
+            quote! {
+                rvue::widgets::#widget_ident::new(Vec::new())
+            }
+        }
+        WidgetType::Match => abort_match_outside_switch(span),
         WidgetType::For => {
             let PropValue { value: items_value, .. } = props.value("each", || quote! { vec![] });
             let PropValue { value: key_fn, .. } = props.value("key", || quote! { |item| item });
@@ -710,16 +998,16 @@ fn generate_widget_builder_code(
         }
         WidgetType::Custom(name) => {
             let widget_name = Ident::new(name, span);
-            let props = attributes
-                .iter()
-                .filter(|a| !matches!(a, RvueAttribute::Event { .. }))
-                .map(|attr| {
-                    let name = format_ident!("{}", attr.name());
-                    let PropValue { value, .. } = extract_attr_value(attr);
-                    quote! {
-                        .#name(#value)
-                    }
-                });
+            let props =
+                el.attributes.iter().filter(|a| !matches!(a, RvueAttribute::Event { .. })).map(
+                    |attr| {
+                        let name = format_ident!("{}", attr.name());
+                        let PropValue { value, .. } = extract_attr_value(attr);
+                        quote! {
+                            .#name(#value)
+                        }
+                    },
+                );
             quote! {
                 #widget_name::new()#(#props)*
             }
@@ -727,15 +1015,100 @@ fn generate_widget_builder_code(
     }
 }
 
+/// Keep attributes that read signals in sync with the component they were built into
+///
+/// Widgets given a signal handle subscribe to it themselves in `build()`. An expression such as
+/// `content={count.get()}` only yields the current value, which is what the widget is built
+/// from, so it is re-run in an effect that writes the result through the matching
+/// `Component::set_*` setter. The variables the expression uses are cloned into the effect, so
+/// they must be `Clone`.
 fn generate_reactive_effects(
-    _widget_type: &WidgetType,
-    _attributes: &[RvueAttribute],
-    _component_ident: &Ident,
+    widget_type: &WidgetType,
+    attributes: &[RvueAttribute],
+    component_ident: &Ident,
 ) -> TokenStream {
-    // Widgets handle reactive content internally in their build() methods.
-    // The effect is created there if the content is reactive.
-    // So we do not need to generate additional effects here.
-    quote! {}
+    let (attr, apply): (&str, fn(&TokenStream) -> TokenStream) = match widget_type {
+        WidgetType::Text => {
+            ("content", |expr| quote! { comp.set_text_content((#expr).to_string()) })
+        }
+        WidgetType::ProgressBar => ("value", |expr| quote! { comp.set_progress_value(#expr) }),
+        _ => return quote! {},
+    };
+    let Some(TrackedExpr { captures, expr }) = attr_reads(attributes, attr) else {
+        return quote! {};
+    };
+    let (names, places): (Vec<_>, Vec<_>) = captures.into_iter().unzip();
+    let apply = apply(&expr);
+
+    quote! {
+        {
+            #(let #names = ::core::clone::Clone::clone(&#places);)*
+            let comp = Gc::clone(&#component_ident);
+            let effect = rvue::effect::create_effect(move || {
+                #apply;
+            });
+            #component_ident.add_effect(effect);
+        }
+    }
+}
+
+/// The expression given to the `name` attribute, rewritten for an effect, if it reads signals
+fn attr_reads(attributes: &[RvueAttribute], name: &str) -> Option<TrackedExpr> {
+    match WidgetProps::new(attributes).find(name)? {
+        RvueAttribute::Dynamic { expr, .. } => tracked_reads(expr),
+        _ => None,
+    }
+}
+
+/// Apply each `attr=maybe?` through the builder method of the same name when it is `Some`
+fn generate_optional_attrs(attributes: &[RvueAttribute]) -> TokenStream {
+    let applies = attributes.iter().filter_map(|attr| match attr {
+        RvueAttribute::Optional { name, expr, span } => {
+            let method = Ident::new(name, *span);
+            Some(quote_spanned! { *span =>
+                let widget = match #expr {
+                    ::core::option::Option::Some(value) => widget.#method(value),
+                    ::core::option::Option::None => widget,
+                };
+            })
+        }
+        _ => None,
+    });
+    quote! { #(#applies)* }
+}
+
+/// Generate the `aria_label` override shared by all built-in widgets
+fn generate_aria_label(attributes: &[RvueAttribute], component_ident: &Ident) -> TokenStream {
+    let Some(PropValue { value, is_reactive }) =
+        attributes.iter().find(|attr| attr.name() == "aria_label").map(extract_attr_value)
+    else {
+        return quote! {};
+    };
+
+    if is_reactive {
+        quote! {
+            {
+                let comp = Gc::clone(&#component_ident);
+                let effect = rvue::effect::create_effect(move || {
+                    comp.set_aria_label(#value);
+                });
+                #component_ident.add_effect(effect);
+            }
+        }
+    } else {
+        quote! { #component_ident.set_aria_label(#value); }
+    }
+}
+
+/// Generate code storing the built component into a `ref={node_ref}` attribute
+fn generate_node_ref(attributes: &[RvueAttribute], component_ident: &Ident) -> TokenStream {
+    match attributes.iter().find(|attr| attr.name() == "ref") {
+        Some(attr) => {
+            let PropValue { value, .. } = extract_attr_value(attr);
+            quote! { rvue::NodeRef::set(&#value, rvue::Gc::clone(&#component_ident)); }
+        }
+        None => quote! {},
+    }
 }
 
 struct PropValue {
@@ -756,18 +1129,29 @@ impl<'a> WidgetProps<'a> {
     where
         F: FnOnce() -> TokenStream,
     {
-        self.attributes
-            .iter()
-            .find(|attr| attr.name() == name)
+        self.find(name)
             .map(extract_attr_value)
             .unwrap_or_else(|| PropValue { value: default(), is_reactive: false })
     }
 
     fn optional_value(&self, name: &str) -> Option<TokenStream> {
+        self.find(name).map(|attr| extract_attr_value(attr).value)
+    }
+
+    /// Builder calls `.name(value)` for each of `names` given as an attribute
+    fn optional_calls(&self, names: &[&str]) -> TokenStream {
+        let calls = names.iter().filter_map(|name| {
+            let method = Ident::new(name, Span::call_site());
+            self.optional_value(name).map(|v| quote! { .#method(#v) })
+        });
+        quote! { #(#calls)* }
+    }
+
+    /// Attribute passed to the widget builder; `attr=maybe?` ones are applied afterwards
+    fn find(&self, name: &str) -> Option<&'a RvueAttribute> {
         self.attributes
             .iter()
-            .find(|attr| attr.name() == name)
-            .map(|attr| extract_attr_value(attr).value)
+            .find(|attr| attr.name() == name && !matches!(attr, RvueAttribute::Optional { .. }))
     }
 }
 
@@ -786,6 +1170,10 @@ fn extract_attr_value(attr: &RvueAttribute) -> PropValue {
             let is_reactive = matches!(classify_expression(expr), ExpressionKind::Reactive);
             PropValue { value: quote! { #expr }, is_reactive }
         }
+        RvueAttribute::Optional { .. } => PropValue {
+            value: quote! { compile_error!("Optional attributes are not supported in this position") },
+            is_reactive: false,
+        },
         RvueAttribute::Event { .. } => PropValue {
             value: quote! { compile_error!("Unexpected event attribute in property position") },
             is_reactive: false,
@@ -842,28 +1230,49 @@ fn convert_element(el_node: &NodeElement) -> Option<RvueNode> {
     }
 
     let tag_name = el_node.name().to_string();
-    let widget_type = crate::attrs::classify_widget(&tag_name);
+    let widget_type = crate::attrs::classify_widget(&tag_name, el_node.name().span());
 
     let mut attributes = vec![];
+    let mut spread = None;
     for attr in el_node.attributes() {
         if let NodeAttribute::Attribute(_k_attr) = attr {
             if let Ok(rvue_attr) = crate::attrs::parse_attribute(attr) {
                 attributes.push(rvue_attr);
             }
+        } else if let Some(base) = crate::attrs::parse_spread(attr) {
+            if widget_type.is_builtin() {
+                abort!(
+                    attr.span(),
+                    "Spread props are not supported on built-in widget `{}`",
+                    tag_name;
+                    help = "Spread props fill the generated Props struct of a #[component]"
+                );
+            }
+            spread = Some(base);
         }
     }
 
-    let children = el_node
-        .children
-        .iter()
-        .filter_map(|n| convert_rstml_to_rvue(n, Some(&widget_type)))
-        .collect();
+    // Inline markup inside Text becomes styled runs of one widget instead of children
+    let (children, spans) = if widget_type == WidgetType::Text
+        && crate::rich_text::has_inline_markup(&el_node.children)
+    {
+        (vec![], crate::rich_text::collect_spans(&el_node.children))
+    } else {
+        let children = el_node
+            .children
+            .iter()
+            .filter_map(|n| convert_rstml_to_rvue(n, Some(&widget_type)))
+            .collect();
+        (children, vec![])
+    };
 
     Some(RvueNode::Element(RvueElement {
         tag_name,
         widget_type,
         attributes,
         children,
+        spans,
+        spread,
         span: el_node.name().span(),
     }))
 }
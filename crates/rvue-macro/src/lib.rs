@@ -5,18 +5,23 @@ mod ast;
 mod attrs;
 mod codegen;
 mod parser;
+mod rich_text;
 #[allow(dead_code)]
 mod widgets;
 
 mod component;
+mod property;
 mod slot;
+mod text;
 
 use codegen::{convert_rstml_to_rvue, generate_view_code};
 use component::component_impl;
 use parser::{parse_global_class, parse_view, strip_global_class};
 use proc_macro::TokenStream;
 use proc_macro_error2::proc_macro_error;
+use property::derive_widget_property_impl;
 use slot::slot_impl;
+use text::text_impl;
 
 /// The `view!` macro provides HTML-like syntax for creating UI components
 ///
@@ -40,17 +45,26 @@ use slot::slot_impl;
 /// - `Button` - Interactive button with label
 /// - `Flex` - Flexbox container with direction, gap, align_items, justify_content
 /// - `TextInput` - Text input field
+/// - `TextArea` - Multi-line text input, with `rows` and two-way `bind:value`
 /// - `NumberInput` - Numeric input field
 /// - `Checkbox` - Boolean checkbox
 /// - `Radio` - Radio button
+/// - `ProgressBar` - Determinate or indeterminate progress bar
+/// - `Spinner` - Rotating loading indicator
+/// - `Divider` - 1px line between sections, with `vertical` for rows
+/// - `Spacer` - Filler taking up the free space of a flex container
+/// - `Link` - Clickable text with `href`, whose `on_click` receives the URL
 /// - `Show` - Conditional rendering
 /// - `For` - List rendering
+/// - `Switch` / `Match` - Multi-branch conditional rendering
 ///
 /// # Attributes
 ///
 /// Static attributes: `attr="value"`
 /// Dynamic attributes: `attr={expression}`
 /// Event handlers: `on_event=handler`
+/// Optional attributes: `attr=maybe?` calls the builder method only when `maybe` is `Some`
+/// Spread props on a component: `<Badge {..base} count=5 />`, with explicit attributes winning
 ///
 /// # Styles
 ///
@@ -80,6 +94,11 @@ use slot::slot_impl;
 /// view! { <Button on_click=|| println!("clicked")>"Click me"</Button> }
 /// ```
 ///
+/// Rich text with inline `<b>`, `<strong>`, `<i>`, `<em>` and `<span color=...>`:
+/// ```ignore
+/// view! { <Text>"normal " <b>"bold"</b> " " <span color="#cc0000">"red"</span></Text> }
+/// ```
+///
 /// Flex container with children:
 /// ```ignore
 /// view! {
@@ -89,6 +108,12 @@ use slot::slot_impl;
 ///     </Flex>
 /// }
 /// ```
+///
+/// Fragment whose roots become siblings wherever the view is placed:
+/// ```ignore
+/// let items = view! { <><Text content="A" /><Text content="B" /></> };
+/// view! { <Flex>{items}</Flex> }
+/// ```
 #[proc_macro_error]
 #[proc_macro]
 pub fn view(input: TokenStream) -> TokenStream {
@@ -115,6 +140,26 @@ pub fn view(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Build a reactive string from a format string and signals
+///
+/// Expands to a `ReadSignal<String>` memo that re-formats whenever one of the signals changes,
+/// so it can be passed straight to `content`. The arguments are signals, not values: each is
+/// cloned and read with `.get()` inside the memo. Named arguments work as in `format!`.
+///
+/// # Basic Usage
+///
+/// ```ignore
+/// let (count, set_count) = create_signal(0);
+/// view! {
+///     <Text content={text!("Count: {}", count)} />
+///     <Text content={text!("{count} of {total}", count = count, total = total)} />
+/// }
+/// ```
+#[proc_macro]
+pub fn text(input: TokenStream) -> TokenStream {
+    text_impl(input.into()).into()
+}
+
 /// The `#[component]` macro marks a function as a component
 ///
 /// Components are functions that return `impl View` and can be used
@@ -194,3 +239,26 @@ pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
 pub fn slot(_attr: TokenStream, item: TokenStream) -> TokenStream {
     slot_impl(_attr.into(), item.into()).into()
 }
+
+/// Derive `rvue::properties::WidgetProperty` for a custom widget property
+///
+/// The default value returned by `static_default` is `Default::default()`, or the expression
+/// given with `#[property(default = ...)]`. It is built once, on first use.
+///
+/// # Basic Usage
+///
+/// ```ignore
+/// use rvue::properties::PropertyMap;
+/// use rvue_macro::WidgetProperty;
+///
+/// #[derive(Clone, Debug, WidgetProperty)]
+/// #[property(default = Rating(3))]
+/// struct Rating(u8);
+///
+/// let map = PropertyMap::with(Rating(5));
+/// assert_eq!(map.get_or_default::<Rating>().0, 5);
+/// ```
+#[proc_macro_derive(WidgetProperty, attributes(property))]
+pub fn derive_widget_property(input: TokenStream) -> TokenStream {
+    derive_widget_property_impl(input.into()).into()
+}
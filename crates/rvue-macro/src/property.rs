@@ -0,0 +1,57 @@
+//! Implementation of `#[derive(WidgetProperty)]`
+//!
+//! The derive implements `rvue::properties::WidgetProperty` by keeping the default value in a
+//! lazily initialized static. The default comes from `#[property(default = expr)]`, or from
+//! `Default::default()` when the attribute is absent.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, DeriveInput, Expr};
+
+pub fn derive_widget_property_impl(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<DeriveInput>(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    // A static inside a generic function is shared by every instantiation
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "WidgetProperty cannot be derived for generic types",
+        )
+        .to_compile_error();
+    }
+
+    let default = match property_default(&input.attrs) {
+        Ok(Some(expr)) => quote! { #expr },
+        Ok(None) => quote! { ::core::default::Default::default() },
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let name = &input.ident;
+    quote! {
+        impl rvue::properties::WidgetProperty for #name {
+            fn static_default() -> &'static Self {
+                static DEFAULT: ::std::sync::OnceLock<#name> = ::std::sync::OnceLock::new();
+                DEFAULT.get_or_init(|| #default)
+            }
+        }
+    }
+}
+
+/// Parse `#[property(default = expr)]`
+fn property_default(attrs: &[Attribute]) -> syn::Result<Option<Expr>> {
+    let mut default = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("property")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                default = Some(meta.value()?.parse::<Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported property attribute, expected `default = ...`"))
+            }
+        })?;
+    }
+    Ok(default)
+}
@@ -0,0 +1,116 @@
+//! Inline markup inside `Text`
+//!
+//! `<Text>"normal " <b>"bold"</b> " more"</Text>` becomes a single rich `Text` built from
+//! styled runs rather than a `Text` with child widgets. Supported inline elements are
+//! `<b>`, `<strong>`, `<i>`, `<em>` and `<span color=...>`, and they may nest.
+
+use crate::ast::{RvueSpan, SpanText};
+use proc_macro2::TokenStream;
+use proc_macro_error2::abort;
+use quote::{quote, ToTokens};
+use rstml::node::{Node, NodeAttribute, NodeElement};
+use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, Lit};
+
+const INLINE_TAGS: &[&str] = &["b", "strong", "i", "em", "span"];
+
+/// Inherited style while walking nested inline elements
+#[derive(Clone, Default)]
+struct InlineStyle {
+    bold: bool,
+    italic: bool,
+    color: Option<TokenStream>,
+}
+
+/// Check whether `Text` children use inline markup and should become styled runs
+pub fn has_inline_markup(children: &[Node]) -> bool {
+    children.iter().any(|child| match child {
+        Node::Element(el) => INLINE_TAGS.contains(&el.name().to_string().as_str()),
+        Node::Fragment(frag) => has_inline_markup(&frag.children),
+        _ => false,
+    })
+}
+
+/// Flatten `Text` children into styled runs, in document order
+pub fn collect_spans(children: &[Node]) -> Vec<RvueSpan> {
+    let mut spans = Vec::new();
+    collect_into(children, &InlineStyle::default(), &mut spans);
+    spans
+}
+
+fn collect_into(children: &[Node], style: &InlineStyle, spans: &mut Vec<RvueSpan>) {
+    for child in children {
+        let text = match child {
+            Node::Text(text) => SpanText::Literal(text.value_string()),
+            Node::RawText(raw) => SpanText::Literal(raw.to_string_best()),
+            Node::Block(block) => match syn::parse2::<Expr>(block.to_token_stream()) {
+                Ok(expr) => SpanText::Expr(expr),
+                Err(_) => continue,
+            },
+            Node::Fragment(frag) => {
+                collect_into(&frag.children, style, spans);
+                continue;
+            }
+            Node::Element(el) => {
+                collect_into(&el.children, &inline_style(el, style), spans);
+                continue;
+            }
+            Node::Comment(_) | Node::Doctype(_) => continue,
+        };
+        spans.push(RvueSpan {
+            text,
+            bold: style.bold,
+            italic: style.italic,
+            color: style.color.clone(),
+        });
+    }
+}
+
+fn inline_style(el: &NodeElement, parent: &InlineStyle) -> InlineStyle {
+    let tag = el.name().to_string();
+    let mut style = parent.clone();
+    match tag.as_str() {
+        "b" | "strong" => style.bold = true,
+        "i" | "em" => style.italic = true,
+        "span" => {
+            if let Some(color) = span_color(el) {
+                style.color = Some(color);
+            }
+        }
+        _ => abort!(
+            el.name().span(),
+            "Unknown inline element `<{}>` in Text",
+            tag;
+            help = "Rich text supports <b>, <strong>, <i>, <em> and <span color=...>"
+        ),
+    }
+    style
+}
+
+/// Color of a `<span>`, from a CSS color string like `"#c00"` or any `Color` expression
+fn span_color(el: &NodeElement) -> Option<TokenStream> {
+    let value = el.attributes().iter().find_map(|attr| match attr {
+        NodeAttribute::Attribute(attr) if attr.key.to_string() == "color" => attr.value(),
+        _ => None,
+    })?;
+
+    if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = value {
+        return Some(quote! { rvue::style::color_from_attr(#lit) });
+    }
+    Some(quote! { #value })
+}
+
+/// Generate the `TextSpan` list passed to `Text::rich`
+pub fn generate_spans_code(spans: &[RvueSpan]) -> TokenStream {
+    let spans = spans.iter().map(|span| {
+        let text = match &span.text {
+            SpanText::Literal(text) => quote! { #text },
+            SpanText::Expr(expr) => quote! { (#expr).to_string() },
+        };
+        let bold = span.bold.then(|| quote! { .bold() });
+        let italic = span.italic.then(|| quote! { .italic() });
+        let color = span.color.as_ref().map(|color| quote! { .color(#color) });
+        quote! { rvue::TextSpan::new(#text) #bold #italic #color }
+    });
+    quote! { [#(#spans),*] }
+}
@@ -0,0 +1,71 @@
+//! Implementation of `text!`
+//!
+//! `text!("Count: {}", count)` expands to a memo of the formatted string. Each argument is a
+//! signal: it is cloned into the memo and read with `.get()`, so the memo tracks it and the
+//! signal stays usable after the macro.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, LitStr, Token};
+
+struct TextInput {
+    format: LitStr,
+    args: Vec<TextArg>,
+}
+
+/// A positional `signal` or named `name = signal` argument
+struct TextArg {
+    name: Option<Ident>,
+    signal: Expr,
+}
+
+impl Parse for TextArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Ok(Self { name: Some(name), signal: input.parse()? })
+        } else {
+            Ok(Self { name: None, signal: input.parse()? })
+        }
+    }
+}
+
+impl Parse for TextInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format = input.parse()?;
+        let args = if input.is_empty() {
+            Vec::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::<TextArg, Token![,]>::parse_terminated(input)?.into_iter().collect()
+        };
+        Ok(Self { format, args })
+    }
+}
+
+pub fn text_impl(input: TokenStream) -> TokenStream {
+    let TextInput { format, args } = match syn::parse2(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let idents: Vec<Ident> = (0..args.len()).map(|i| format_ident!("__text_arg{}", i)).collect();
+    let clones = args.iter().zip(&idents).map(|(arg, ident)| {
+        let signal = &arg.signal;
+        quote! { let #ident = ::core::clone::Clone::clone(&(#signal)); }
+    });
+    let reads = args.iter().zip(&idents).map(|(arg, ident)| match &arg.name {
+        Some(name) => quote! { #name = #ident.get() },
+        None => quote! { #ident.get() },
+    });
+
+    quote! {
+        {
+            #(#clones)*
+            rvue::signal::create_memo(move || ::std::format!(#format, #(#reads),*))
+        }
+    }
+}
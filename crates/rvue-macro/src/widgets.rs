@@ -18,11 +18,19 @@ pub fn generate_widget_code(
         WidgetType::Button => generate_button_widget(id, attributes),
         WidgetType::Flex => generate_flex_widget(id, attributes),
         WidgetType::TextInput => generate_text_input_widget(id, attributes),
+        WidgetType::TextArea => generate_text_area_widget(id, attributes),
         WidgetType::NumberInput => generate_number_input_widget(id, attributes),
         WidgetType::Checkbox => generate_checkbox_widget(id, attributes),
         WidgetType::Radio => generate_radio_widget(id, attributes),
+        WidgetType::ProgressBar => generate_progress_bar_widget(id, attributes),
+        WidgetType::Spinner => generate_spinner_widget(id, attributes),
+        WidgetType::Divider => generate_divider_widget(id, attributes),
+        WidgetType::Spacer => generate_spacer_widget(id, attributes),
+        WidgetType::Link => generate_link_widget(id, attributes),
         WidgetType::Show => generate_show_widget(id, attributes),
         WidgetType::For => generate_for_widget(id, attributes),
+        WidgetType::Switch => generate_switch_widget(id, attributes),
+        WidgetType::Match => generate_match_widget(id, attributes),
         WidgetType::Custom(name) => generate_custom_widget(id, name, attributes),
     }
 }
@@ -52,6 +60,30 @@ fn event_setter(event_name: &str, handler: &Expr) -> TokenStream {
             Some(2) => quote! { on_click(#handler) },
             _ => panic!("Event handler with more than 2 arguments not supported"),
         },
+        "click_capture" => match arg_count {
+            Some(0) => quote! { on_click_capture_0arg(#handler) },
+            Some(1) => quote! { on_click_capture_1arg(#handler) },
+            Some(2) => quote! { on_click_capture(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "pointer_down_capture" => match arg_count {
+            Some(0) => quote! { on_pointer_down_capture_0arg(#handler) },
+            Some(1) => quote! { on_pointer_down_capture_1arg(#handler) },
+            Some(2) => quote! { on_pointer_down_capture(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "pointer_up_capture" => match arg_count {
+            Some(0) => quote! { on_pointer_up_capture_0arg(#handler) },
+            Some(1) => quote! { on_pointer_up_capture_1arg(#handler) },
+            Some(2) => quote! { on_pointer_up_capture(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "key_down_capture" => match arg_count {
+            Some(0) => quote! { on_key_down_capture_0arg(#handler) },
+            Some(1) => quote! { on_key_down_capture_1arg(#handler) },
+            Some(2) => quote! { on_key_down_capture(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
         "input" => match arg_count {
             Some(0) => quote! { on_input_0arg(#handler) },
             Some(1) => quote! { on_input_1arg(#handler) },
@@ -88,6 +120,48 @@ fn event_setter(event_name: &str, handler: &Expr) -> TokenStream {
             Some(2) => quote! { on_blur(#handler) },
             _ => panic!("Event handler with more than 2 arguments not supported"),
         },
+        "context_menu" => match arg_count {
+            Some(0) => quote! { on_context_menu_0arg(#handler) },
+            Some(1) => quote! { on_context_menu_1arg(#handler) },
+            Some(2) => quote! { on_context_menu(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "long_press" => match arg_count {
+            Some(0) => quote! { on_long_press_0arg(#handler) },
+            Some(1) => quote! { on_long_press_1arg(#handler) },
+            Some(2) => quote! { on_long_press(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "drag_start" => match arg_count {
+            Some(0) => quote! { on_drag_start_0arg(#handler) },
+            Some(1) => quote! { on_drag_start_1arg(#handler) },
+            Some(2) => quote! { on_drag_start(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "drag" => match arg_count {
+            Some(0) => quote! { on_drag_0arg(#handler) },
+            Some(1) => quote! { on_drag_1arg(#handler) },
+            Some(2) => quote! { on_drag(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "drag_end" => match arg_count {
+            Some(0) => quote! { on_drag_end_0arg(#handler) },
+            Some(1) => quote! { on_drag_end_1arg(#handler) },
+            Some(2) => quote! { on_drag_end(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "drag_over" => match arg_count {
+            Some(0) => quote! { on_drag_over_0arg(#handler) },
+            Some(1) => quote! { on_drag_over_1arg(#handler) },
+            Some(2) => quote! { on_drag_over(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
+        "drop" => match arg_count {
+            Some(0) => quote! { on_drop_0arg(#handler) },
+            Some(1) => quote! { on_drop_1arg(#handler) },
+            Some(2) => quote! { on_drop(#handler) },
+            _ => panic!("Event handler with more than 2 arguments not supported"),
+        },
         "pointer_down" => match arg_count {
             Some(0) => quote! { on_pointer_down_0arg(#handler) },
             Some(1) => quote! { on_pointer_down_1arg(#handler) },
@@ -152,15 +226,25 @@ fn unwrap_expr(expr: &Expr) -> &Expr {
 
 fn generate_text_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     let content = extract_prop_value(attrs, "content", || quote! { "" });
+    let selectable = attrs
+        .iter()
+        .find(|a| a.name() == "selectable")
+        .map(|a| {
+            let value = extract_attr_value(a);
+            quote! { .selectable(#value) }
+        })
+        .unwrap_or_else(|| quote! {});
 
     quote! {
-        rvue::widgets::Text::new(#content)
+        rvue::widgets::Text::new(#content)#selectable
     }
 }
 
-fn generate_button_widget(_id: u64, _attrs: &[RvueAttribute]) -> TokenStream {
+fn generate_button_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
+    let submit = optional_calls(attrs, &["submit"]);
+
     quote! {
-        rvue::widgets::Button::new()
+        rvue::widgets::Button::new()#submit
     }
 }
 
@@ -227,27 +311,70 @@ fn generate_flex_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     }
 }
 
+/// Builder calls `.name(value)` for each of `names` given as an attribute
+fn optional_calls(attrs: &[RvueAttribute], names: &[&str]) -> TokenStream {
+    let calls = names.iter().filter_map(|name| {
+        let attr = attrs.iter().find(|a| a.name() == *name)?;
+        let method = quote::format_ident!("{}", name);
+        let value = extract_attr_value(attr);
+        Some(quote! { .#method(#value) })
+    });
+    quote! { #(#calls)* }
+}
+
 fn generate_text_input_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     let value = extract_prop_value(attrs, "value", || quote! { "".to_string() });
+    let error = optional_calls(attrs, &["name", "error"]);
 
     quote! {
-        rvue::widgets::TextInput::new(#value)
+        rvue::widgets::TextInput::new(#value)#error
+    }
+}
+
+fn generate_text_area_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
+    let calls = optional_calls(attrs, &["rows", "name", "error"]);
+
+    match attrs.iter().find(|a| a.name() == "bind:value") {
+        Some(binding) => {
+            let signal = extract_attr_value(binding);
+            quote! {
+                {
+                    let (value, set_value) = #signal;
+                    rvue::widgets::TextArea::new(value).bind(set_value)#calls
+                }
+            }
+        }
+        None => {
+            let value = extract_prop_value(attrs, "value", || quote! { "".to_string() });
+            quote! {
+                rvue::widgets::TextArea::new(#value)#calls
+            }
+        }
     }
 }
 
 fn generate_number_input_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     let value = extract_prop_value(attrs, "value", || quote! { 0.0 });
+    let calls = optional_calls(attrs, &["min", "max", "step", "steppers", "name", "error"]);
 
     quote! {
-        rvue::widgets::NumberInput::new(#value)
+        rvue::widgets::NumberInput::new(#value)#calls
     }
 }
 
 fn generate_checkbox_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     let checked = extract_prop_value(attrs, "checked", || quote! { false });
+    let indeterminate = attrs
+        .iter()
+        .find(|a| a.name() == "indeterminate")
+        .map(|a| {
+            let value = extract_attr_value(a);
+            quote! { .indeterminate(#value) }
+        })
+        .unwrap_or_else(|| quote! {});
 
     quote! {
-        rvue::widgets::Checkbox::new(#checked)
+        rvue::widgets::Checkbox::new(#checked)#indeterminate
     }
 }
 
@@ -260,6 +387,59 @@ fn generate_radio_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     }
 }
 
+fn generate_progress_bar_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
+    let value = extract_prop_value(attrs, "value", || quote! { 0.0 });
+    let indeterminate = attrs
+        .iter()
+        .find(|a| a.name() == "indeterminate")
+        .map(|a| {
+            let value = extract_attr_value(a);
+            quote! { .indeterminate(#value) }
+        })
+        .unwrap_or_else(|| quote! {});
+
+    quote! {
+        rvue::widgets::ProgressBar::new(#value)#indeterminate
+    }
+}
+
+fn generate_spinner_widget(_id: u64, _attrs: &[RvueAttribute]) -> TokenStream {
+    quote! {
+        rvue::widgets::Spinner::new()
+    }
+}
+
+fn generate_divider_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
+    let vertical = attrs
+        .iter()
+        .find(|a| a.name() == "vertical")
+        .map(|a| {
+            let value = extract_attr_value(a);
+            quote! { .vertical(#value) }
+        })
+        .unwrap_or_else(|| quote! {});
+
+    quote! {
+        rvue::widgets::Divider::new()#vertical
+    }
+}
+
+fn generate_spacer_widget(_id: u64, _attrs: &[RvueAttribute]) -> TokenStream {
+    quote! {
+        rvue::widgets::Spacer::new()
+    }
+}
+
+fn generate_link_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
+    let content = extract_prop_value(attrs, "content", || quote! { "" });
+    let href = extract_prop_value(attrs, "href", || quote! { "" });
+    let track_visited = optional_calls(attrs, &["track_visited"]);
+
+    quote! {
+        rvue::widgets::Link::new(#content, #href)#track_visited
+    }
+}
+
 fn generate_show_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     let when = extract_prop_value(attrs, "when", || quote! { false });
 
@@ -275,6 +455,27 @@ fn generate_show_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     }
 }
 
+fn generate_switch_widget(_id: u64, _attrs: &[RvueAttribute]) -> TokenStream {
+    quote! {
+        rvue::widgets::Switch::new(Vec::new())
+    }
+}
+
+fn generate_match_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
+    let when = extract_prop_value(attrs, "when", || quote! { false });
+
+    quote! {
+        {
+            use rvue::widget::BuildContext;
+            rvue::widgets::Match::new(#when, |_ctx: &mut BuildContext| {
+                let flex = rvue::widgets::Flex::new();
+                let state = flex.build(_ctx);
+                rvue::Gc::clone(state.component())
+            })
+        }
+    }
+}
+
 fn generate_for_widget(_id: u64, attrs: &[RvueAttribute]) -> TokenStream {
     let items = extract_prop_value(attrs, "each", || quote! { vec![] });
     let key_fn = extract_prop_value(attrs, "key", || quote! { |item| item });
@@ -330,6 +531,9 @@ fn extract_attr_value(attr: &RvueAttribute) -> TokenStream {
     match attr {
         RvueAttribute::Static { value, .. } => quote! { #value },
         RvueAttribute::Dynamic { expr, .. } => quote! { #expr },
+        RvueAttribute::Optional { .. } => {
+            quote! { compile_error!("Optional attributes are not supported in this position") }
+        }
         RvueAttribute::Event { .. } => {
             quote! { compile_error!("Unexpected event attribute in property position") }
         }
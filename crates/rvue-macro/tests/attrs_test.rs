@@ -3,7 +3,10 @@
 //! These tests verify that the macro correctly classifies and handles
 //! different types of attributes: static, dynamic, and event handlers.
 
-use rvue_macro::view;
+#![allow(unused_braces)]
+
+use rvue::prelude::*;
+use rvue_macro::{component, view};
 
 #[test]
 fn test_static_string_attribute() {
@@ -21,6 +24,45 @@ fn test_static_numeric_attribute() {
     let _ = _view;
 }
 
+#[test]
+fn test_number_input_range_attributes() {
+    let view = view! {
+        <NumberInput value=2.0 min=0.0 max=10.0 step=0.5 steppers=true />
+    };
+    let input = &view.root_component;
+    let range = input.number_input_range();
+    assert_eq!((range.min, range.max, range.step), (0.0, 10.0, 0.5));
+    assert!(input.number_input_steppers());
+}
+
+#[test]
+fn test_input_error_attribute() {
+    let view = view! {
+        <TextInput value="" error=Some("Required".to_string()) />
+    };
+    assert_eq!(view.root_component.error_message().as_deref(), Some("Required"));
+}
+
+#[test]
+fn test_submit_button_attribute() {
+    let view = view! {
+        <Button submit=true />
+    };
+    assert!(view.root_component.is_submit_button());
+}
+
+#[test]
+fn test_link_attributes() {
+    let view = view! {
+        <Link content="Story" href="https://example.com" track_visited=true on_click=|_href: &str| {} />
+    };
+    let link = &view.root_component;
+    assert_eq!(link.link_href().as_deref(), Some("https://example.com"));
+    assert_eq!(link.text_content(), "Story");
+    assert!(link.has_class("link"));
+    assert!(link.event_handlers.borrow().get_click().is_some());
+}
+
 #[test]
 fn test_static_boolean_attribute() {
     let _view = view! {
@@ -56,6 +98,42 @@ fn test_dynamic_boolean_attribute() {
     let _ = _view;
 }
 
+#[test]
+fn test_indeterminate_checkbox_attribute() {
+    let (partial, _set_partial) = create_signal(true);
+    #[allow(unused_braces)]
+    let _view = view! {
+        <Checkbox checked=false indeterminate={partial.get()} />
+    };
+    let _ = _view;
+}
+
+#[test]
+fn test_text_area_bind_value_attribute() {
+    let (text, set_text) = create_signal(String::from("notes"));
+    let _view = view! {
+        <TextArea rows=5 bind:value={(text, set_text)} />
+    };
+    let _ = _view;
+}
+
+#[test]
+fn test_selectable_text_attribute() {
+    let _view = view! {
+        <Text content="Copy me" selectable=true />
+    };
+    let _ = _view;
+}
+
+#[test]
+fn test_rich_text_inline_markup() {
+    let name = "world";
+    let _view = view! {
+        <Text>"normal " <b>"bold"</b> " " <em><span color="#cc0000">{name}</span></em></Text>
+    };
+    let _ = _view;
+}
+
 #[test]
 fn test_complex_expression_attribute() {
     let count = 5;
@@ -84,6 +162,82 @@ fn test_mixed_static_dynamic_attributes() {
     let _ = _view;
 }
 
+#[test]
+fn test_aria_label_attribute() {
+    let view = view! {
+        <Button aria_label="Close" />
+    };
+    assert_eq!(view.root_component.aria_label(), Some("Close".to_string()));
+}
+
+#[test]
+fn test_ref_attribute_populates_node_ref() {
+    let input_ref = rvue::NodeRef::new();
+    assert!(input_ref.get().is_none());
+
+    let view = view! {
+        <Flex>
+            <TextInput value="" ref={input_ref.clone()} />
+        </Flex>
+    };
+
+    let input = input_ref.get().expect("ref is set after the view is built");
+    assert!(matches!(input.component_type, rvue::ComponentType::TextInput));
+    assert!(rvue::Gc::ptr_eq(&view.root_component.children.borrow()[0], &input));
+}
+
+#[component]
+fn Badge(label: String, count: i32) -> impl View {
+    view! {
+        <Text content={format!("{label}: {count}")} />
+    }
+}
+
+#[test]
+fn test_spread_props_with_override() {
+    let base = BadgeProps { label: "Inbox".to_string().into_reactive(), count: 1.into_reactive() };
+
+    let view = view! {
+        <Badge {..base} count=5 />
+    };
+
+    let text = view.root_component.children.borrow()[0].clone();
+    assert_eq!(text.text_content(), "Inbox: 5");
+}
+
+#[component]
+fn Line(label: String) -> impl View {
+    view! {
+        <Text content={label} />
+    }
+}
+
+#[test]
+fn test_component_named_like_builtin_is_not_a_typo() {
+    let view = view! {
+        <Line label="Near Link" />
+    };
+
+    let text = view.root_component.children.borrow()[0].clone();
+    assert_eq!(text.text_content(), "Near Link");
+}
+
+#[test]
+fn test_optional_attribute_applies_only_some() {
+    let red = rvue_style::BackgroundColor(rvue_style::Color::rgb(255, 0, 0));
+    let no_color: Option<rvue_style::BackgroundColor> = None;
+    let some_color = Some(red);
+
+    let plain = view! { <Flex background_color=no_color? /> };
+    let colored = view! { <Flex background_color={some_color?} /> };
+
+    let background = |view: &ViewStruct| {
+        view.root_component.widget_styles().and_then(|styles| styles.background_color)
+    };
+    assert_eq!(background(&plain), None);
+    assert_eq!(background(&colored), Some(red));
+}
+
 // Event handler tests are commented out until event handler generation is fixed
 // #[test]
 // fn test_on_click_handler() {
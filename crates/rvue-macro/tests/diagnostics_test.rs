@@ -0,0 +1,19 @@
+//! Compile-time diagnostics of the view! macro
+
+#[test]
+fn test_unknown_tag_suggests_builtin() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/unknown_tag.rs");
+}
+
+#[test]
+fn test_on_event_accepts_known_events() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/on_event_pass.rs");
+}
+
+#[test]
+fn test_on_event_rejects_unknown_event() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/on_event_unknown.rs");
+}
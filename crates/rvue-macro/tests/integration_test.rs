@@ -169,3 +169,41 @@ fn test_nested_reactive_update() {
 
     rvue::signal::__test_clear_signal_subscriptions();
 }
+
+#[test]
+fn test_fragment_roots_mount_as_siblings() {
+    let pair_view = view! {
+        <>
+            <Text content="First" />
+            <Text content="Second" />
+        </>
+    };
+    assert!(pair_view.is_fragment());
+    assert_eq!(pair_view.roots().len(), 2);
+
+    let view = view! {
+        <Flex direction="column">
+            <Text content="Before" />
+            {pair_view}
+        </Flex>
+    };
+
+    let children = view.root_component.children.borrow().clone();
+    let contents: Vec<_> = children.iter().map(|child| child.text_content()).collect();
+    assert_eq!(contents, ["Before", "First", "Second"]);
+    for child in &children[1..] {
+        let parent = child.parent.borrow().clone().expect("fragment root moved to parent");
+        assert!(rvue::Gc::ptr_eq(&parent, &view.root_component));
+    }
+}
+
+#[test]
+fn test_fragment_in_any_variable_mounts_by_type() {
+    let items = view! { <><Text content="A" /><Text content="B" /></> };
+    let label = "C".to_string();
+    let view = view! { <Flex>{items}{label}</Flex> };
+
+    let children = view.root_component.children.borrow().clone();
+    let contents: Vec<_> = children.iter().map(|child| child.text_content()).collect();
+    assert_eq!(contents, ["A", "B", "C"]);
+}
@@ -4,7 +4,7 @@ use rvue::prelude::*;
 use rvue::text::TextContext;
 use rvue::widget::BuildContext;
 use rvue::Gc;
-use rvue_macro::{component, view};
+use rvue_macro::{component, text, view};
 use taffy::TaffyTree;
 
 fn with_build_context<F, R>(f: F) -> R
@@ -293,3 +293,92 @@ fn test_blur_event_handler() {
 
     rvue::signal::__test_clear_signal_subscriptions();
 }
+
+#[test]
+fn test_text_macro_updates_rendered_text() {
+    let (count, set_count) = create_signal(0);
+    let (total, _set_total) = create_signal(3);
+    let view = view! {
+        <Text content={text!("Count: {} of {total}", count, total = total)} />
+    };
+    let root = view.root_component;
+    assert_eq!(root.text_content(), "Count: 0 of 3");
+
+    set_count.set(1);
+    assert_eq!(root.text_content(), "Count: 1 of 3");
+
+    // The macro clones its signals, so they stay usable
+    assert_eq!(count.get(), 1);
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_content_reading_signal_updates_text() {
+    let (count, set_count) = create_signal(0);
+    let view = view! {
+        <Flex>
+            <Text content={count.get()} />
+            <Text content={format!("Count: {}", count.get())} />
+        </Flex>
+    };
+    let root = view.root_component;
+    let plain = root.children.borrow()[0].clone();
+    let formatted = root.children.borrow()[1].clone();
+    assert_eq!(plain.text_content(), "0");
+    assert_eq!(formatted.text_content(), "Count: 0");
+
+    set_count.update(|count| *count += 1);
+    rvue::effect::flush_pending_effects();
+    assert_eq!(plain.text_content(), "1");
+    assert_eq!(formatted.text_content(), "Count: 1");
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_value_reading_signal_updates_progress_bar() {
+    let (progress, set_progress) = create_signal(0.25);
+    let view = view! {
+        <ProgressBar value={progress.get()} />
+    };
+    let bar = view.root_component;
+    assert_eq!(bar.progress_value(), 0.25);
+
+    set_progress.set(0.5);
+    rvue::effect::flush_pending_effects();
+    assert_eq!(bar.progress_value(), 0.5);
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+struct CounterModel {
+    count: ReadSignal<i32>,
+}
+
+#[test]
+fn test_content_reading_signal_keeps_captured_variables_usable() {
+    let (count, set_count) = create_signal(0);
+    let model = CounterModel { count: count.clone() };
+    let name = "Clicks".to_string();
+    let view = view! {
+        <Flex>
+            <Text content={format!("{}: {}", name, count.get())} />
+            <Text content={format!("{name}: {}", model.count.get())} />
+            <Text content={name} />
+        </Flex>
+    };
+    let root = view.root_component;
+    let explicit = root.children.borrow()[0].clone();
+    let inline = root.children.borrow()[1].clone();
+    assert_eq!(explicit.text_content(), "Clicks: 0");
+    assert_eq!(inline.text_content(), "Clicks: 0");
+    assert_eq!(model.count.get(), 0);
+
+    set_count.update(|count| *count += 1);
+    rvue::effect::flush_pending_effects();
+    assert_eq!(explicit.text_content(), "Clicks: 1");
+    assert_eq!(inline.text_content(), "Clicks: 1");
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
@@ -72,6 +72,25 @@ fn test_checkbox_widget() {
     };
 }
 
+#[test]
+fn test_spinner_widget() {
+    let _view = view! {
+        <Spinner />
+    };
+}
+
+#[test]
+fn test_divider_and_spacer_widgets() {
+    let _view = view! {
+        <Flex direction="row">
+            <Text content="Left" />
+            <Spacer />
+            <Divider vertical=true />
+            <Text content="Right" />
+        </Flex>
+    };
+}
+
 #[test]
 fn test_radio_widget() {
     let _view = view! {
@@ -0,0 +1,7 @@
+use rvue_macro::view;
+
+fn main() {
+    let _view = view! {
+        <TextInput value="" on:keyup={move || {}} on:focus={move || {}} on:pointer_down={move || {}} />
+    };
+}
@@ -0,0 +1,9 @@
+use rvue_macro::view;
+
+fn main() {
+    let _view = view! {
+        <Button on:frobnicate={move || {}}>
+            <Text content="Go" />
+        </Button>
+    };
+}
@@ -0,0 +1,7 @@
+use rvue_macro::view;
+
+fn main() {
+    let _view = view! {
+        <text content="Hello" />
+    };
+}
@@ -0,0 +1,16 @@
+use rvue::properties::WidgetProperty;
+use rvue_macro::WidgetProperty;
+
+#[derive(Clone, Default, WidgetProperty)]
+struct Elevation(f32);
+
+#[derive(Clone, WidgetProperty)]
+#[property(default = Accent { hue: 210 })]
+struct Accent {
+    hue: u16,
+}
+
+fn main() {
+    assert_eq!(Elevation::static_default().0, 0.0);
+    assert_eq!(Accent::static_default().hue, 210);
+}
@@ -0,0 +1,43 @@
+//! Tests for `#[derive(WidgetProperty)]`
+
+use rvue::properties::{PropertyMap, WidgetProperty};
+use rvue_macro::WidgetProperty;
+
+#[derive(Clone, Debug, Default, PartialEq, WidgetProperty)]
+struct BadgeCount(u32);
+
+#[derive(Clone, Debug, PartialEq, WidgetProperty)]
+#[property(default = BadgeLabel("new".to_string()))]
+struct BadgeLabel(String);
+
+#[test]
+fn test_derive_compiles() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/widget_property_pass.rs");
+}
+
+#[test]
+fn test_derived_default_uses_default_trait() {
+    assert_eq!(BadgeCount::static_default(), &BadgeCount(0));
+}
+
+#[test]
+fn test_derived_default_uses_property_attribute() {
+    assert_eq!(BadgeLabel::static_default(), &BadgeLabel("new".to_string()));
+    // The default is created once and shared
+    assert!(std::ptr::eq(BadgeLabel::static_default(), BadgeLabel::static_default()));
+}
+
+#[test]
+fn test_derived_property_in_property_map() {
+    let mut map = PropertyMap::new();
+    assert_eq!(map.get_or_default::<BadgeLabel>(), &BadgeLabel("new".to_string()));
+
+    map.insert(BadgeCount(7));
+    map.insert(BadgeLabel("sale".to_string()));
+
+    assert_eq!(map.get::<BadgeCount>(), Some(&BadgeCount(7)));
+    assert_eq!(map.get_or_default::<BadgeLabel>(), &BadgeLabel("sale".to_string()));
+    assert_eq!(map.remove::<BadgeCount>(), Some(BadgeCount(7)));
+    assert_eq!(map.get_or_default::<BadgeCount>(), &BadgeCount(0));
+}
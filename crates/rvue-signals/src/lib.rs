@@ -4,7 +4,7 @@
 //! It handles the core value storage and versioning without subscriber tracking,
 //! which is handled by the parent crates.
 
-use rudo_gc::{Gc, GcCell, GcThreadSafeCell, Trace};
+use rudo_gc::{Gc, GcCell, GcThreadSafeCell, Trace, Weak};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Internal signal data structure containing the value, version tracking, and subscribers.
@@ -24,8 +24,13 @@ pub struct SignalData<T: Clone + 'static> {
     pub value: GcThreadSafeCell<T>,
     /// Monotonically increasing version counter
     pub version: AtomicU64,
+    /// Optional equality check; writes of an equal value are skipped
+    equals: Option<EqualityFn<T>>,
 }
 
+/// Equality check deciding whether a write changes a signal.
+pub type EqualityFn<T> = Box<dyn Fn(&T, &T) -> bool + Send + Sync>;
+
 impl<T: Clone + Trace + 'static> SignalData<T> {
     /// Create a new signal data structure
     pub fn new(value: T) -> Self {
@@ -33,7 +38,68 @@ impl<T: Clone + Trace + 'static> SignalData<T> {
             subscribers: GcCell::new(Vec::new()),
             value: GcThreadSafeCell::new(value),
             version: AtomicU64::new(0),
+            equals: None,
+        }
+    }
+
+    /// Create a signal data structure that ignores writes `eq` considers equal to the current
+    /// value
+    pub fn with_equality<F>(value: T, eq: F) -> Self
+    where
+        F: Fn(&T, &T) -> bool + Send + Sync + 'static,
+    {
+        Self { equals: Some(Box::new(eq)), ..Self::new(value) }
+    }
+
+    fn is_same(&self, old: &T, new: &T) -> bool {
+        self.equals.as_ref().is_some_and(|eq| eq(old, new))
+    }
+
+    /// Store a new value and increment version, returning the old value
+    ///
+    /// Returns `None` without storing anything when the equality check finds the value
+    /// unchanged. Callers that notify subscribers can hold the old value until they are done.
+    pub fn replace(&self, value: T) -> Option<T> {
+        let mut guard = self.value.borrow_mut_simple();
+        if self.is_same(&guard, &value) {
+            return None;
         }
+        let old = std::mem::replace(&mut *guard, value);
+        drop(guard);
+        self.version.fetch_add(1, Ordering::SeqCst);
+        Some(old)
+    }
+
+    /// Modify the value and increment version, returning the old value
+    ///
+    /// Returns `None` without a version change when the equality check finds the modified
+    /// value unchanged.
+    pub fn modify<F>(&self, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut T),
+    {
+        let mut guard = self.value.borrow_mut_simple();
+        let old = (*guard).clone();
+        f(&mut *guard);
+        if self.is_same(&old, &guard) {
+            return None;
+        }
+        drop(guard);
+        self.version.fetch_add(1, Ordering::SeqCst);
+        Some(old)
+    }
+
+    /// Modify the value in place and increment version, returning what `f` returns
+    ///
+    /// Unlike [`modify`](Self::modify) this keeps no copy of the old value, so it also skips the
+    /// equality check. Meant for large values such as lists, where every call changes the value.
+    pub fn modify_in_place<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let result = f(&mut *self.value.borrow_mut_simple());
+        self.version.fetch_add(1, Ordering::SeqCst);
+        result
     }
 
     /// Get the current value
@@ -45,29 +111,19 @@ impl<T: Clone + Trace + 'static> SignalData<T> {
         self.value.borrow().clone()
     }
 
-    /// Set a new value and increment version
+    /// Set a new value and increment version, returning whether the value changed
     #[inline(always)]
-    pub fn set(&self, value: T) {
-        let _old = {
-            let mut guard = self.value.borrow_mut_simple();
-            std::mem::replace(&mut *guard, value)
-        };
-        self.version.fetch_add(1, Ordering::SeqCst);
+    pub fn set(&self, value: T) -> bool {
+        self.replace(value).is_some()
     }
 
-    /// Modify the value and increment version
+    /// Modify the value and increment version, returning whether the value changed
     #[inline(always)]
-    pub fn update<F>(&self, f: F)
+    pub fn update<F>(&self, f: F) -> bool
     where
         F: FnOnce(&mut T),
     {
-        let _old = {
-            let mut guard = self.value.borrow_mut_simple();
-            let old = (*guard).clone();
-            f(&mut *guard);
-            old
-        };
-        self.version.fetch_add(1, Ordering::SeqCst);
+        self.modify(f).is_some()
     }
 
     /// Get the current version
@@ -117,6 +173,11 @@ impl<T: Clone + Trace + 'static> ReadSignal<T> {
         self.data.get()
     }
 
+    /// Create a weak handle that does not keep the signal alive
+    pub fn downgrade(&self) -> WeakReadSignal<T> {
+        WeakReadSignal { data: Gc::downgrade(&self.data) }
+    }
+
     /// Get a reference to the inner data (for advanced use cases)
     #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &Gc<SignalData<T>> {
@@ -162,6 +223,11 @@ impl<T: Clone + Trace + 'static> WriteSignal<T> {
         self.data.update(f);
     }
 
+    /// Create a weak handle that does not keep the signal alive
+    pub fn downgrade(&self) -> WeakWriteSignal<T> {
+        WeakWriteSignal { data: Gc::downgrade(&self.data) }
+    }
+
     /// Get a reference to the inner data (for advanced use cases)
     #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &Gc<SignalData<T>> {
@@ -185,6 +251,49 @@ unsafe impl<T: Clone + Trace + 'static> Trace for WriteSignal<T> {
     }
 }
 
+/// Weak read handle for a signal.
+///
+/// Does not keep the signal alive; [`upgrade`](Self::upgrade) returns `None` once it is gone.
+#[derive(Clone)]
+pub struct WeakReadSignal<T: Clone + Trace + 'static> {
+    data: Weak<SignalData<T>>,
+}
+
+impl<T: Clone + Trace + 'static> WeakReadSignal<T> {
+    /// Get a strong read handle if the signal is still alive
+    pub fn upgrade(&self) -> Option<ReadSignal<T>> {
+        self.data.upgrade().map(|data| ReadSignal { data })
+    }
+}
+
+/// Weak write handle for a signal.
+///
+/// Does not keep the signal alive; writes through it are dropped once the signal is gone.
+#[derive(Clone)]
+pub struct WeakWriteSignal<T: Clone + Trace + 'static> {
+    data: Weak<SignalData<T>>,
+}
+
+impl<T: Clone + Trace + 'static> WeakWriteSignal<T> {
+    /// Get a strong write handle if the signal is still alive
+    pub fn upgrade(&self) -> Option<WriteSignal<T>> {
+        self.data.upgrade().map(|data| WriteSignal { data })
+    }
+
+    /// Set a new value if the signal is still alive, returning whether it was
+    pub fn set(&self, value: T) -> bool {
+        self.upgrade().map(|signal| signal.set(value)).is_some()
+    }
+
+    /// Modify the value if the signal is still alive, returning whether it was
+    pub fn update<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        self.upgrade().map(|signal| signal.update(f)).is_some()
+    }
+}
+
 /// Trait for reading signal values.
 ///
 /// Provides common interface for reading from signal handles.
@@ -219,7 +328,7 @@ impl<T: Clone + Trace + 'static> SignalRead<T> for ReadSignal<T> {
 
 impl<T: Clone + Trace + 'static> SignalWrite<T> for WriteSignal<T> {
     fn set(&self, value: T) {
-        self.data.set(value)
+        self.data.set(value);
     }
 
     fn update<F>(&self, f: F)
@@ -0,0 +1,31 @@
+//! Tests for weak signal handles
+
+use rvue_signals::create_signal;
+
+#[test]
+fn test_weak_handles_upgrade_while_signal_is_alive() {
+    let (read, write) = create_signal(1);
+    let weak_read = read.downgrade();
+    let weak_write = write.downgrade();
+
+    assert!(weak_write.set(2));
+    assert!(weak_write.update(|value| *value += 1));
+    assert_eq!(weak_read.upgrade().map(|signal| signal.get()), Some(3));
+    assert_eq!(read.get(), 3);
+}
+
+#[test]
+fn test_weak_handles_do_not_keep_signal_alive() {
+    let (read, write) = create_signal(String::from("gone"));
+    let weak_read = read.downgrade();
+    let weak_write = write.downgrade();
+
+    drop(read);
+    drop(write);
+    rudo_gc::collect_full();
+
+    assert!(weak_read.upgrade().is_none());
+    assert!(weak_write.upgrade().is_none());
+    assert!(!weak_write.set(String::from("ignored")));
+    assert!(!weak_write.update(|value| value.push('!')));
+}
@@ -25,14 +25,15 @@ pub mod widget;
 
 pub use properties::{
     AlignItems, AlignSelf, BackgroundColor, BorderColor, BorderRadius, BorderStyle, BorderWidth,
-    Color, ComputedStyles, Cursor, Display, FlexBasis, FlexDirection, FlexGrow, FlexShrink,
-    FontFamily, FontSize, FontWeight, Gap, Height, JustifyContent, Margin, Opacity, Overflow,
-    Padding, Size, TextColor, Visibility, Width, ZIndex,
+    Color, ColorParseError, ComputedStyles, Cursor, Display, FlexBasis, FlexDirection, FlexGrow,
+    FlexShrink, FontFamily, FontSize, FontWeight, Gap, Height, JustifyContent, Margin, Opacity,
+    Overflow, Padding, Size, StyleChangeSet, TextColor, TextDecoration, Visibility, WhiteSpace,
+    Width, ZIndex,
 };
 pub use property::{Properties, Property, StyleStore};
 pub use reactive::{
     create_reactive_signal, create_style_effect, on_style_cleanup, ReactiveProperty,
-    ReactiveReadSignal, ReactiveSignal, ReactiveSignalWrite, ReactiveStyles,
+    ReactiveReadSignal, ReactiveSignal, ReactiveSignalWrite, ReactiveStyles, StyleProperty,
 };
 pub use selectors::{ElementState, RvueElement};
 pub use shared::{
@@ -40,6 +41,8 @@ pub use shared::{
     shared_padding, shared_text_color, SharedComputedStyles, SharedStyleBuilder,
     WeakSharedComputedStyles,
 };
-pub use stylesheet::{default_stylesheet, StyleResolver, StyleRule, Stylesheet};
+pub use stylesheet::{
+    default_stylesheet, ButtonShades, StyleResolver, StyleRule, Stylesheet, HIGH_CONTRAST_MIN_RATIO,
+};
 pub use widget::styled::{StyleData, WidgetStyles};
 pub use widget::{StyledWidget, StyledWidgetExt};
@@ -10,6 +10,6 @@ pub struct BackgroundColor(pub Color);
 
 impl Property for BackgroundColor {
     fn initial_value() -> Self {
-        Self(Color(RgbColor { r: 0, g: 0, b: 0 }))
+        Self(Color(RgbColor::rgb(0, 0, 0)))
     }
 }
@@ -31,7 +31,7 @@ pub struct BorderColor(pub Color);
 
 impl Property for BorderColor {
     fn initial_value() -> Self {
-        Self(Color(RgbColor { r: 0, g: 0, b: 0 }))
+        Self(Color(RgbColor::rgb(0, 0, 0)))
     }
 }
 
@@ -2,19 +2,33 @@
 
 use crate::property::Property;
 use rudo_gc::Trace;
+use std::fmt;
+use std::str::FromStr;
 
-/// RGB color.
-#[derive(Clone, Copy, Debug, PartialEq, Default, Trace)]
+/// RGB color with an alpha channel.
+#[derive(Clone, Copy, Debug, PartialEq, Trace)]
 pub struct RgbColor {
     pub r: u8,
     pub g: u8,
     pub b: u8,
+    pub a: u8,
 }
 
 impl RgbColor {
     #[inline]
     pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
-        Self { r, g, b }
+        Self { r, g, b, a: 255 }
+    }
+
+    #[inline]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl Default for RgbColor {
+    fn default() -> Self {
+        Self::rgb(0, 0, 0)
     }
 }
 
@@ -28,21 +42,278 @@ impl Color {
         Self(RgbColor::rgb(r, g, b))
     }
 
+    #[inline]
+    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(RgbColor::rgba(r, g, b, a))
+    }
+
+    /// Build an opaque color from hue in degrees, and saturation and lightness in `0.0..=1.0`.
+    pub fn hsl(h: f32, s: f32, l: f32) -> Self {
+        Self::hsla(h, s, l, 1.0)
+    }
+
+    /// Like [`Color::hsl`], with an alpha in `0.0..=1.0`.
+    pub fn hsla(h: f32, s: f32, l: f32, a: f32) -> Self {
+        let h = h.rem_euclid(360.0);
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match (h / 60.0) as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        let byte = |v: f32| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::rgba(byte(r), byte(g), byte(b), byte_from_unit(a))
+    }
+
+    /// Convert to `(hue, saturation, lightness)`, with hue in `0.0..360.0` and the rest in
+    /// `0.0..=1.0`. Alpha is dropped; read it from `self.0.a`.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let r = self.0.r as f32 / 255.0;
+        let g = self.0.g as f32 / 255.0;
+        let b = self.0.b as f32 / 255.0;
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (h, s, l)
+    }
+
+    /// Lower the HSL lightness by `amount` (`0.0..=1.0`), keeping hue and alpha.
+    pub fn darken(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::hsl(h, s, l - amount).with_alpha(self.0.a)
+    }
+
+    /// Raise the HSL lightness by `amount` (`0.0..=1.0`), keeping hue and alpha.
+    pub fn lighten(&self, amount: f32) -> Self {
+        self.darken(-amount)
+    }
+
+    /// Lower the HSL saturation by `amount` (`0.0..=1.0`), keeping hue and alpha.
+    pub fn desaturate(&self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::hsl(h, s - amount, l).with_alpha(self.0.a)
+    }
+
+    /// WCAG relative luminance, from 0.0 for black to 1.0 for white. Alpha is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        let linear = |channel: u8| {
+            let c = channel as f32 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.0.r) + 0.7152 * linear(self.0.g) + 0.0722 * linear(self.0.b)
+    }
+
+    /// This color painted over an opaque `backdrop`, blended by this color's alpha.
+    pub fn over(&self, backdrop: &Color) -> Color {
+        let alpha = self.0.a as f32 / 255.0;
+        let blend = |top: u8, bottom: u8| {
+            (top as f32 * alpha + bottom as f32 * (1.0 - alpha)).round() as u8
+        };
+        Color::rgb(
+            blend(self.0.r, backdrop.0.r),
+            blend(self.0.g, backdrop.0.g),
+            blend(self.0.b, backdrop.0.b),
+        )
+    }
+
+    /// WCAG contrast ratio against `other`, from 1.0 for equal luminance to 21.0 for black on
+    /// white.
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    #[inline]
+    pub(crate) fn with_alpha(mut self, a: u8) -> Self {
+        self.0.a = a;
+        self
+    }
+
+    /// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`; the `#` is optional.
     pub fn from_hex(hex: &str) -> Option<Self> {
         let hex = hex.trim_start_matches('#');
-        if hex.len() != 6 {
+        if !hex.is_ascii() {
             return None;
         }
-        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        let digit = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|v| v * 17);
+        let pair = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        match hex.len() {
+            3 => Some(Self::rgb(digit(0)?, digit(1)?, digit(2)?)),
+            4 => Some(Self::rgba(digit(0)?, digit(1)?, digit(2)?, digit(3)?)),
+            6 => Some(Self::rgb(pair(0)?, pair(2)?, pair(4)?)),
+            8 => Some(Self::rgba(pair(0)?, pair(2)?, pair(4)?, pair(6)?)),
+            _ => None,
+        }
+    }
+
+    /// Look up a CSS named color, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (r, g, b) = match name.to_ascii_lowercase().as_str() {
+            "transparent" => return Some(Self::rgba(0, 0, 0, 0)),
+            "black" => (0, 0, 0),
+            "white" => (255, 255, 255),
+            "red" => (255, 0, 0),
+            "green" => (0, 128, 0),
+            "lime" => (0, 255, 0),
+            "blue" => (0, 0, 255),
+            "yellow" => (255, 255, 0),
+            "cyan" | "aqua" => (0, 255, 255),
+            "magenta" | "fuchsia" => (255, 0, 255),
+            "gray" | "grey" => (128, 128, 128),
+            "darkgray" | "darkgrey" => (169, 169, 169),
+            "lightgray" | "lightgrey" => (211, 211, 211),
+            "silver" => (192, 192, 192),
+            "maroon" => (128, 0, 0),
+            "olive" => (128, 128, 0),
+            "navy" => (0, 0, 128),
+            "purple" => (128, 0, 128),
+            "teal" => (0, 128, 128),
+            "orange" => (255, 165, 0),
+            "pink" => (255, 192, 203),
+            "brown" => (165, 42, 42),
+            "gold" => (255, 215, 0),
+            "indigo" => (75, 0, 130),
+            "violet" => (238, 130, 238),
+            "coral" => (255, 127, 80),
+            "crimson" => (220, 20, 60),
+            "tomato" => (255, 99, 71),
+            "salmon" => (250, 128, 114),
+            "skyblue" => (135, 206, 235),
+            "steelblue" => (70, 130, 180),
+            "royalblue" => (65, 105, 225),
+            "dodgerblue" => (30, 144, 255),
+            "forestgreen" => (34, 139, 34),
+            "seagreen" => (46, 139, 87),
+            "slategray" | "slategrey" => (112, 128, 144),
+            "whitesmoke" => (245, 245, 245),
+            "beige" => (245, 245, 220),
+            "ivory" => (255, 255, 240),
+            "khaki" => (240, 230, 140),
+            "lavender" => (230, 230, 250),
+            "turquoise" => (64, 224, 208),
+            "tan" => (210, 180, 140),
+            "chocolate" => (210, 105, 30),
+            _ => return None,
+        };
         Some(Self::rgb(r, g, b))
     }
+
+    /// Parse the arguments of `rgb(...)` or `rgba(...)`, comma or space separated.
+    fn from_rgb_function(args: &str) -> Option<Self> {
+        let args = args.replace(['/', ','], " ");
+        let parts: Vec<&str> = args.split_whitespace().collect();
+        let channel = |part: &str| -> Option<u8> {
+            match part.strip_suffix('%') {
+                Some(percent) => unit_to_u8(percent.parse::<f32>().ok()? / 100.0),
+                None => {
+                    let value = part.parse::<f32>().ok()?;
+                    (0.0..=255.0).contains(&value).then(|| value.round() as u8)
+                }
+            }
+        };
+        let alpha = |part: &str| -> Option<u8> {
+            match part.strip_suffix('%') {
+                Some(percent) => unit_to_u8(percent.parse::<f32>().ok()? / 100.0),
+                None => unit_to_u8(part.parse().ok()?),
+            }
+        };
+        match parts.as_slice() {
+            [r, g, b] => Some(Self::rgb(channel(r)?, channel(g)?, channel(b)?)),
+            [r, g, b, a] => Some(Self::rgba(channel(r)?, channel(g)?, channel(b)?, alpha(a)?)),
+            _ => None,
+        }
+    }
+}
+
+/// Scale a `0.0..=1.0` value to a byte
+fn unit_to_u8(value: f32) -> Option<u8> {
+    (0.0..=1.0).contains(&value).then(|| byte_from_unit(value))
+}
+
+/// Scale a `0.0..=1.0` value to a byte, clamping out-of-range values
+fn byte_from_unit(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Error returned when a string is not a recognized color.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorParseError {
+    input: String,
+}
+
+impl ColorParseError {
+    /// The string that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid color `{}`: expected #rgb, #rrggbb, #rrggbbaa, rgb(), rgba() or a CSS color name",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        let lower = trimmed.to_ascii_lowercase();
+        let color = if trimmed.starts_with('#') {
+            Self::from_hex(trimmed)
+        } else if let Some(args) =
+            lower.strip_prefix("rgba(").or_else(|| lower.strip_prefix("rgb("))
+        {
+            args.strip_suffix(')').and_then(Self::from_rgb_function)
+        } else {
+            Self::from_name(trimmed)
+        };
+        color.ok_or_else(|| ColorParseError { input: value.to_string() })
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ColorParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
 }
 
 impl Property for Color {
     fn initial_value() -> Self {
-        Self(RgbColor { r: 0, g: 0, b: 0 })
+        Self(RgbColor::rgb(0, 0, 0))
     }
 }
 
@@ -52,6 +323,6 @@ pub struct TextColor(pub Color);
 
 impl Property for TextColor {
     fn initial_value() -> Self {
-        Self(Color(RgbColor { r: 0, g: 0, b: 0 }))
+        Self(Color(RgbColor::rgb(0, 0, 0)))
     }
 }
@@ -6,7 +6,7 @@ use crate::properties::{
     AlignItems, AlignSelf, BackgroundColor, BorderColor, BorderRadius, BorderStyle, BorderWidth,
     Color, Cursor, Display, FlexBasis, FlexDirection, FlexGrow, FlexShrink, FontFamily, FontSize,
     FontWeight, Gap, Height, JustifyContent, Margin, MaxHeight, MaxWidth, MinHeight, MinWidth,
-    Opacity, Overflow, Padding, TextColor, Visibility, Width, ZIndex,
+    Opacity, Overflow, Padding, TextColor, TextDecoration, Visibility, WhiteSpace, Width, ZIndex,
 };
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -44,6 +44,8 @@ pub struct ComputedStyles {
     pub cursor: Option<Cursor>,
     pub overflow_x: Option<Overflow>,
     pub overflow_y: Option<Overflow>,
+    pub white_space: Option<WhiteSpace>,
+    pub text_decoration: Option<TextDecoration>,
 }
 
 impl ComputedStyles {
@@ -153,6 +155,12 @@ impl ComputedStyles {
         if let Some(oy) = properties.get::<Overflow>() {
             self.overflow_y = Some(*oy);
         }
+        if let Some(ws) = properties.get::<WhiteSpace>() {
+            self.white_space = Some(*ws);
+        }
+        if let Some(td) = properties.get::<TextDecoration>() {
+            self.text_decoration = Some(*td);
+        }
     }
 
     #[inline]
@@ -256,6 +264,94 @@ impl ComputedStyles {
         if let Some(oy) = other.overflow_y.as_ref() {
             self.overflow_y = Some(*oy);
         }
+        if let Some(ws) = other.white_space.as_ref() {
+            self.white_space = Some(*ws);
+        }
+        if let Some(td) = other.text_decoration.as_ref() {
+            self.text_decoration = Some(*td);
+        }
+    }
+}
+
+impl ComputedStyles {
+    /// Classify how `other` differs from `self`, so callers can repaint without relayout when
+    /// only paint properties changed.
+    pub fn diff(&self, other: &ComputedStyles) -> StyleChangeSet {
+        macro_rules! any_changed {
+            ($($field:ident),* $(,)?) => {
+                false $(|| self.$field != other.$field)*
+            };
+        }
+
+        let layout = any_changed!(
+            font_size,
+            font_family,
+            font_weight,
+            padding,
+            margin,
+            width,
+            height,
+            min_width,
+            min_height,
+            max_width,
+            max_height,
+            display,
+            flex_direction,
+            justify_content,
+            align_items,
+            align_self,
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            gap,
+            border_width,
+            overflow_x,
+            overflow_y,
+            white_space,
+        );
+        // Hidden elements keep their layout space, so visibility only needs a repaint
+        let paint = layout
+            || any_changed!(
+                background_color,
+                color,
+                text_color,
+                border_color,
+                border_radius,
+                border_style,
+                opacity,
+                visibility,
+                z_index,
+                cursor,
+                text_decoration,
+            );
+        StyleChangeSet { layout, paint }
+    }
+}
+
+/// Result of [`ComputedStyles::diff`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StyleChangeSet {
+    /// A property that affects size or position changed
+    pub layout: bool,
+    /// Anything changed; a layout change always needs a repaint too
+    pub paint: bool,
+}
+
+impl StyleChangeSet {
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !self.paint
+    }
+
+    #[inline]
+    pub fn needs_layout(&self) -> bool {
+        self.layout
+    }
+
+    /// Whether a repaint is enough, without relayout
+    #[inline]
+    pub fn is_paint_only(&self) -> bool {
+        self.paint && !self.layout
     }
 }
 
@@ -294,5 +390,7 @@ unsafe impl Trace for ComputedStyles {
         self.cursor.trace(visitor);
         self.overflow_x.trace(visitor);
         self.overflow_y.trace(visitor);
+        self.white_space.trace(visitor);
+        self.text_decoration.trace(visitor);
     }
 }
@@ -93,3 +93,63 @@ impl FontWeight {
         }
     }
 }
+
+/// White-space property, controlling line wrapping and the handling of line breaks in text.
+///
+/// Line breaks are replaced with spaces rather than removed, so byte offsets into the text
+/// content stay valid for selection and rich-text spans.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum WhiteSpace {
+    /// Line breaks become spaces; text wraps at the element's width.
+    #[default]
+    Normal,
+    /// Line breaks become spaces; text stays on one line and may overflow.
+    NoWrap,
+    /// Line breaks and spaces are kept as written; text only breaks at line breaks.
+    Pre,
+}
+
+impl WhiteSpace {
+    /// Whether text wraps at the available width.
+    #[inline]
+    pub fn wraps(self) -> bool {
+        matches!(self, Self::Normal)
+    }
+
+    /// Whether line breaks are kept instead of being turned into spaces.
+    #[inline]
+    pub fn preserves_line_breaks(self) -> bool {
+        matches!(self, Self::Pre)
+    }
+}
+
+impl Property for WhiteSpace {
+    fn initial_value() -> Self {
+        Self::Normal
+    }
+}
+
+unsafe impl Trace for WhiteSpace {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Text decoration property, drawn as a line in the text color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TextDecoration {
+    #[default]
+    None,
+    /// A line under the text, as links are styled.
+    Underline,
+    /// A line through the middle of the text.
+    LineThrough,
+}
+
+impl Property for TextDecoration {
+    fn initial_value() -> Self {
+        Self::None
+    }
+}
+
+unsafe impl Trace for TextDecoration {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
@@ -13,9 +13,9 @@ pub mod visibility;
 
 pub use background::BackgroundColor;
 pub use border::{BorderColor, BorderRadius, BorderStyle, BorderWidth};
-pub use color::{Color, TextColor};
-pub use computed_styles::ComputedStyles;
-pub use font::{FontFamily, FontSize, FontWeight};
+pub use color::{Color, ColorParseError, TextColor};
+pub use computed_styles::{ComputedStyles, StyleChangeSet};
+pub use font::{FontFamily, FontSize, FontWeight, TextDecoration, WhiteSpace};
 pub use layout::{
     AlignItems, AlignSelf, Display, FlexBasis, FlexDirection, FlexGrow, FlexShrink, Gap,
     JustifyContent,
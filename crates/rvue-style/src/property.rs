@@ -10,6 +10,7 @@ use rudo_gc::{Trace, Visitor};
 use std::alloc::{self, Layout};
 use std::any::TypeId;
 use std::collections::HashMap;
+use std::fmt;
 
 /// A property that can be styled on a widget.
 ///
@@ -239,8 +240,88 @@ pub trait StyleStore {
     fn z_index(&self) -> Option<&ZIndex>;
     fn gap(&self) -> Option<&Gap>;
     fn size(&self) -> Option<&Size>;
+
+    /// The properties that are set, as `(accessor name, value)` pairs in declaration order.
+    ///
+    /// ```
+    /// use rvue_style::{Opacity, Properties, Size, StyleStore, Width};
+    ///
+    /// let mut props = Properties::with(Opacity(0.5));
+    /// props.insert(Width(Size::pixels(10.0)));
+    /// let names: Vec<_> = props.set_properties().into_iter().map(|(name, _)| name).collect();
+    /// assert_eq!(names, ["width", "opacity"]);
+    /// ```
+    fn set_properties(&self) -> Vec<(&'static str, &dyn fmt::Debug)> {
+        set_properties(self)
+    }
+
+    /// Number of properties that are set.
+    fn property_count(&self) -> usize {
+        self.set_properties().len()
+    }
+
+    /// Accessor names of the properties whose values differ from `other`, including
+    /// properties set in only one of the two stores.
+    fn diff(&self, other: &dyn StyleStore) -> Vec<&'static str> {
+        diff(self, other)
+    }
 }
 
+/// Generate the helpers behind the provided `StyleStore` methods from the accessor list
+macro_rules! style_store_accessors {
+    ($($accessor:ident),* $(,)?) => {
+        fn set_properties<S: StyleStore + ?Sized>(store: &S) -> Vec<(&'static str, &dyn fmt::Debug)> {
+            let mut set = Vec::new();
+            $(
+                if let Some(value) = store.$accessor() {
+                    set.push((stringify!($accessor), value as &dyn fmt::Debug));
+                }
+            )*
+            set
+        }
+
+        fn diff<S: StyleStore + ?Sized>(store: &S, other: &dyn StyleStore) -> Vec<&'static str> {
+            let mut changed = Vec::new();
+            $(
+                if store.$accessor() != other.$accessor() {
+                    changed.push(stringify!($accessor));
+                }
+            )*
+            changed
+        }
+    };
+}
+
+style_store_accessors!(
+    background_color,
+    color,
+    padding,
+    margin,
+    font_size,
+    font_family,
+    font_weight,
+    width,
+    height,
+    display,
+    flex_direction,
+    justify_content,
+    align_items,
+    align_self,
+    flex_grow,
+    flex_shrink,
+    flex_basis,
+    border_color,
+    border_width,
+    border_radius,
+    border_style,
+    opacity,
+    visibility,
+    cursor,
+    z_index,
+    gap,
+    size,
+);
+
 impl StyleStore for Properties {
     fn background_color(&self) -> Option<&BackgroundColor> {
         self.get()
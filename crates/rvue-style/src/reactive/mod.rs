@@ -7,5 +7,5 @@ pub mod style_signal;
 
 pub use style_signal::{
     create_reactive_signal, create_style_effect, on_style_cleanup, ReactiveProperty,
-    ReactiveReadSignal, ReactiveSignal, ReactiveSignalWrite, ReactiveStyles,
+    ReactiveReadSignal, ReactiveSignal, ReactiveSignalWrite, ReactiveStyles, StyleProperty,
 };
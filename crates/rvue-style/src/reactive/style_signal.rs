@@ -6,7 +6,7 @@ use crate::properties::{
     AlignItems, AlignSelf, BackgroundColor, BorderColor, BorderRadius, BorderStyle, BorderWidth,
     Color, ComputedStyles, Cursor, Display, FlexBasis, FlexDirection, FlexGrow, FlexShrink,
     FontFamily, FontSize, FontWeight, Gap, Height, JustifyContent, Margin, Opacity, Overflow,
-    Padding, TextColor, Visibility, Width, ZIndex,
+    Padding, TextColor, TextDecoration, Visibility, WhiteSpace, Width, ZIndex,
 };
 use crate::property::Property;
 use bitflags::bitflags;
@@ -48,6 +48,8 @@ bitflags! {
         const CURSOR = 1 << 26;
         const OVERFLOW_X = 1 << 27;
         const OVERFLOW_Y = 1 << 28;
+        const WHITE_SPACE = 1 << 29;
+        const TEXT_DECORATION = 1 << 30;
     }
 }
 
@@ -299,6 +301,8 @@ pub struct ReactiveStyles {
     pub padding: ReactiveProperty<Padding>,
     pub overflow_x: ReactiveProperty<Overflow>,
     pub overflow_y: ReactiveProperty<Overflow>,
+    pub white_space: ReactiveProperty<WhiteSpace>,
+    pub text_decoration: ReactiveProperty<TextDecoration>,
     flags: StyleFlags,
 }
 
@@ -334,6 +338,8 @@ impl ReactiveStyles {
             padding: ReactiveProperty::Static(Padding::default()),
             overflow_x: ReactiveProperty::Static(Overflow::Visible),
             overflow_y: ReactiveProperty::Static(Overflow::Visible),
+            white_space: ReactiveProperty::Static(WhiteSpace::default()),
+            text_decoration: ReactiveProperty::Static(TextDecoration::default()),
             flags: StyleFlags::empty(),
         }
     }
@@ -518,6 +524,21 @@ impl ReactiveStyles {
         self
     }
 
+    pub fn set_white_space(mut self, value: impl Into<ReactiveProperty<WhiteSpace>>) -> Self {
+        self.white_space = value.into();
+        self.flags |= StyleFlags::WHITE_SPACE;
+        self
+    }
+
+    pub fn set_text_decoration(
+        mut self,
+        value: impl Into<ReactiveProperty<TextDecoration>>,
+    ) -> Self {
+        self.text_decoration = value.into();
+        self.flags |= StyleFlags::TEXT_DECORATION;
+        self
+    }
+
     /// Set overflow_y with a dynamic getter closure
     pub fn set_overflow_y_dynamic(mut self, getter: Rc<dyn Fn() -> Overflow>) -> Self {
         self.overflow_y = ReactiveProperty::with_getter(getter);
@@ -525,6 +546,151 @@ impl ReactiveStyles {
         self
     }
 
+    /// Override this style's properties with every property set in `other`
+    ///
+    /// Properties `other` leaves unset keep their value, so a shared base can be combined
+    /// with per-widget variants.
+    pub fn merge(mut self, other: ReactiveStyles) -> Self {
+        let set = other.flags;
+        if set.contains(StyleFlags::BACKGROUND_COLOR) {
+            self.background_color = other.background_color;
+        }
+        if set.contains(StyleFlags::BORDER_COLOR) {
+            self.border_color = other.border_color;
+        }
+        if set.contains(StyleFlags::BORDER_RADIUS) {
+            self.border_radius = other.border_radius;
+        }
+        if set.contains(StyleFlags::COLOR) {
+            self.color = other.color;
+        }
+        if set.contains(StyleFlags::TEXT_COLOR) {
+            self.text_color = other.text_color;
+        }
+        if set.contains(StyleFlags::CURSOR) {
+            self.cursor = other.cursor;
+        }
+        if set.contains(StyleFlags::DISPLAY) {
+            self.display = other.display;
+        }
+        if set.contains(StyleFlags::OPACITY) {
+            self.opacity = other.opacity;
+        }
+        if set.contains(StyleFlags::VISIBILITY) {
+            self.visibility = other.visibility;
+        }
+        if set.contains(StyleFlags::WIDTH) {
+            self.width = other.width;
+        }
+        if set.contains(StyleFlags::HEIGHT) {
+            self.height = other.height;
+        }
+        if set.contains(StyleFlags::FONT_FAMILY) {
+            self.font_family = other.font_family;
+        }
+        if set.contains(StyleFlags::FONT_SIZE) {
+            self.font_size = other.font_size;
+        }
+        if set.contains(StyleFlags::FONT_WEIGHT) {
+            self.font_weight = other.font_weight;
+        }
+        if set.contains(StyleFlags::Z_INDEX) {
+            self.z_index = other.z_index;
+        }
+        if set.contains(StyleFlags::ALIGN_ITEMS) {
+            self.align_items = other.align_items;
+        }
+        if set.contains(StyleFlags::ALIGN_SELF) {
+            self.align_self = other.align_self;
+        }
+        if set.contains(StyleFlags::BORDER_STYLE) {
+            self.border_style = other.border_style;
+        }
+        if set.contains(StyleFlags::BORDER_WIDTH) {
+            self.border_width = other.border_width;
+        }
+        if set.contains(StyleFlags::FLEX_BASIS) {
+            self.flex_basis = other.flex_basis;
+        }
+        if set.contains(StyleFlags::FLEX_DIRECTION) {
+            self.flex_direction = other.flex_direction;
+        }
+        if set.contains(StyleFlags::FLEX_GROW) {
+            self.flex_grow = other.flex_grow;
+        }
+        if set.contains(StyleFlags::FLEX_SHRINK) {
+            self.flex_shrink = other.flex_shrink;
+        }
+        if set.contains(StyleFlags::GAP) {
+            self.gap = other.gap;
+        }
+        if set.contains(StyleFlags::JUSTIFY_CONTENT) {
+            self.justify_content = other.justify_content;
+        }
+        if set.contains(StyleFlags::MARGIN) {
+            self.margin = other.margin;
+        }
+        if set.contains(StyleFlags::PADDING) {
+            self.padding = other.padding;
+        }
+        if set.contains(StyleFlags::OVERFLOW_X) {
+            self.overflow_x = other.overflow_x;
+        }
+        if set.contains(StyleFlags::OVERFLOW_Y) {
+            self.overflow_y = other.overflow_y;
+        }
+        if set.contains(StyleFlags::WHITE_SPACE) {
+            self.white_space = other.white_space;
+        }
+        if set.contains(StyleFlags::TEXT_DECORATION) {
+            self.text_decoration = other.text_decoration;
+        }
+        self.flags |= set;
+        self
+    }
+
+    /// Set each property in order, later ones overriding earlier ones
+    pub fn extend(self, properties: impl IntoIterator<Item = StyleProperty>) -> Self {
+        properties.into_iter().fold(self, |styles, property| styles.set(property))
+    }
+
+    /// Set a single property
+    pub fn set(self, property: StyleProperty) -> Self {
+        match property {
+            StyleProperty::BackgroundColor(value) => self.set_background_color(value),
+            StyleProperty::BorderColor(value) => self.set_border_color(value),
+            StyleProperty::BorderRadius(value) => self.set_border_radius(value),
+            StyleProperty::Color(value) => self.set_color(value),
+            StyleProperty::TextColor(value) => self.set_text_color(value),
+            StyleProperty::Cursor(value) => self.set_cursor(value),
+            StyleProperty::Display(value) => self.set_display(value),
+            StyleProperty::Opacity(value) => self.set_opacity(value),
+            StyleProperty::Visibility(value) => self.set_visibility(value),
+            StyleProperty::Width(value) => self.set_width(value),
+            StyleProperty::Height(value) => self.set_height(value),
+            StyleProperty::FontFamily(value) => self.set_font_family(value),
+            StyleProperty::FontSize(value) => self.set_font_size(value),
+            StyleProperty::FontWeight(value) => self.set_font_weight(value),
+            StyleProperty::ZIndex(value) => self.set_z_index(value),
+            StyleProperty::AlignItems(value) => self.set_align_items(value),
+            StyleProperty::AlignSelf(value) => self.set_align_self(value),
+            StyleProperty::BorderStyle(value) => self.set_border_style(value),
+            StyleProperty::BorderWidth(value) => self.set_border_width(value),
+            StyleProperty::FlexBasis(value) => self.set_flex_basis(value),
+            StyleProperty::FlexDirection(value) => self.set_flex_direction(value),
+            StyleProperty::FlexGrow(value) => self.set_flex_grow(value),
+            StyleProperty::FlexShrink(value) => self.set_flex_shrink(value),
+            StyleProperty::Gap(value) => self.set_gap(value),
+            StyleProperty::JustifyContent(value) => self.set_justify_content(value),
+            StyleProperty::Margin(value) => self.set_margin(value),
+            StyleProperty::Padding(value) => self.set_padding(value),
+            StyleProperty::OverflowX(value) => self.set_overflow_x(value),
+            StyleProperty::OverflowY(value) => self.set_overflow_y(value),
+            StyleProperty::WhiteSpace(value) => self.set_white_space(value),
+            StyleProperty::TextDecoration(value) => self.set_text_decoration(value),
+        }
+    }
+
     /// Returns true if any set property is reactive or dynamic (needs effect to update).
     /// When false, styles can be computed once at build time without a subscription effect.
     pub fn is_reactive(&self) -> bool {
@@ -558,6 +724,8 @@ impl ReactiveStyles {
             || (flags.contains(StyleFlags::CURSOR) && self.cursor.needs_effect())
             || (flags.contains(StyleFlags::OVERFLOW_X) && self.overflow_x.needs_effect())
             || (flags.contains(StyleFlags::OVERFLOW_Y) && self.overflow_y.needs_effect())
+            || (flags.contains(StyleFlags::WHITE_SPACE) && self.white_space.needs_effect())
+            || (flags.contains(StyleFlags::TEXT_DECORATION) && self.text_decoration.needs_effect())
     }
 
     /// Computes the final computed styles for rendering.
@@ -717,11 +885,107 @@ impl ReactiveStyles {
         } else {
             Overflow::Visible
         });
+        styles.white_space = Some(if flags.contains(StyleFlags::WHITE_SPACE) {
+            self.white_space.get_untracked()
+        } else {
+            WhiteSpace::initial_value()
+        });
+        styles.text_decoration = Some(if flags.contains(StyleFlags::TEXT_DECORATION) {
+            self.text_decoration.get_untracked()
+        } else {
+            TextDecoration::initial_value()
+        });
 
         styles
     }
 }
 
+/// A single style property with its value, for building `ReactiveStyles` from a list
+#[derive(Clone)]
+pub enum StyleProperty {
+    BackgroundColor(ReactiveProperty<BackgroundColor>),
+    BorderColor(ReactiveProperty<BorderColor>),
+    BorderRadius(ReactiveProperty<BorderRadius>),
+    Color(ReactiveProperty<Color>),
+    TextColor(ReactiveProperty<TextColor>),
+    Cursor(ReactiveProperty<Cursor>),
+    Display(ReactiveProperty<Display>),
+    Opacity(ReactiveProperty<Opacity>),
+    Visibility(ReactiveProperty<Visibility>),
+    Width(ReactiveProperty<Width>),
+    Height(ReactiveProperty<Height>),
+    FontFamily(ReactiveProperty<FontFamily>),
+    FontSize(ReactiveProperty<FontSize>),
+    FontWeight(ReactiveProperty<FontWeight>),
+    ZIndex(ReactiveProperty<ZIndex>),
+    AlignItems(ReactiveProperty<AlignItems>),
+    AlignSelf(ReactiveProperty<AlignSelf>),
+    BorderStyle(ReactiveProperty<BorderStyle>),
+    BorderWidth(ReactiveProperty<BorderWidth>),
+    FlexBasis(ReactiveProperty<FlexBasis>),
+    FlexDirection(ReactiveProperty<FlexDirection>),
+    FlexGrow(ReactiveProperty<FlexGrow>),
+    FlexShrink(ReactiveProperty<FlexShrink>),
+    Gap(ReactiveProperty<Gap>),
+    JustifyContent(ReactiveProperty<JustifyContent>),
+    Margin(ReactiveProperty<Margin>),
+    Padding(ReactiveProperty<Padding>),
+    OverflowX(ReactiveProperty<Overflow>),
+    OverflowY(ReactiveProperty<Overflow>),
+    WhiteSpace(ReactiveProperty<WhiteSpace>),
+    TextDecoration(ReactiveProperty<TextDecoration>),
+}
+
+macro_rules! impl_style_property_from {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for StyleProperty {
+                fn from(value: $ty) -> Self {
+                    StyleProperty::$ty(ReactiveProperty::Static(value))
+                }
+            }
+
+            impl From<ReactiveReadSignal<$ty>> for StyleProperty {
+                fn from(signal: ReactiveReadSignal<$ty>) -> Self {
+                    StyleProperty::$ty(ReactiveProperty::Reactive(signal))
+                }
+            }
+        )*
+    };
+}
+
+impl_style_property_from!(
+    BackgroundColor,
+    BorderColor,
+    BorderRadius,
+    Color,
+    TextColor,
+    Cursor,
+    Display,
+    Opacity,
+    Visibility,
+    Width,
+    Height,
+    FontFamily,
+    FontSize,
+    FontWeight,
+    ZIndex,
+    AlignItems,
+    AlignSelf,
+    BorderStyle,
+    BorderWidth,
+    FlexBasis,
+    FlexDirection,
+    FlexGrow,
+    FlexShrink,
+    Gap,
+    JustifyContent,
+    Margin,
+    Padding,
+    WhiteSpace,
+    TextDecoration
+);
+
 impl Default for ReactiveStyles {
     fn default() -> Self {
         Self::new()
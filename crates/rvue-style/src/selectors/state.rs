@@ -20,6 +20,7 @@ bitflags! {
         const TARGET = 1 << 11;
         const FOCUS_WITHIN = 1 << 12;
         const FOCUS_VISIBLE = 1 << 13;
+        const INVALID = 1 << 14;
         const EMPTY = 0;
     }
 }
@@ -42,6 +43,7 @@ impl ElementState {
             "target" => self.intersects(Self::TARGET),
             "focus-within" | "focus_within" => self.intersects(Self::FOCUS_WITHIN),
             "focus-visible" | "focus_visible" => self.intersects(Self::FOCUS_VISIBLE),
+            "invalid" => self.intersects(Self::INVALID),
             _ => false,
         }
     }
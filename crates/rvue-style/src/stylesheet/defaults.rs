@@ -27,6 +27,14 @@ fn add_input_rules(sheet: &mut Stylesheet, selector: &'static str) {
     sheet.add_rule(StyleRule::parse(selector, props));
 }
 
+fn add_invalid_rules(sheet: &mut Stylesheet, selector: &'static str) {
+    let mut props = Properties::new();
+    props.insert(BorderColor(crate::Color::rgb(220, 38, 38)));
+    props.insert(BorderStyle::Solid);
+    props.insert(BorderWidth(1.0));
+    sheet.add_rule(StyleRule::parse(selector, props));
+}
+
 fn add_checkbox_rules(sheet: &mut Stylesheet, selector: &'static str) {
     let mut props = Properties::new();
     props.insert(Width(Size::Pixels(20.0)));
@@ -71,6 +79,10 @@ pub fn default_stylesheet() -> Stylesheet {
     // Input defaults (general input tag)
     add_input_rules(&mut sheet, "input");
 
+    // Inputs with a validation error get a red border
+    add_invalid_rules(&mut sheet, "input:invalid");
+    add_invalid_rules(&mut sheet, "textarea:invalid");
+
     // Checkbox defaults
     add_checkbox_rules(&mut sheet, "checkbox");
 
@@ -80,6 +92,9 @@ pub fn default_stylesheet() -> Stylesheet {
     // Progress bar defaults
     add_size_rule(&mut sheet, "progress", Width(Size::Pixels(200.0)), Height(Size::Pixels(20.0)));
 
+    // Spinner defaults
+    add_size_rule(&mut sheet, "spinner", Width(Size::Pixels(24.0)), Height(Size::Pixels(24.0)));
+
     // Select defaults
     add_size_rule(&mut sheet, "select", Width(Size::Pixels(200.0)), Height(Size::Pixels(35.0)));
 
@@ -118,6 +133,19 @@ mod tests {
         assert!(resolved.height.is_some(), "input should have default height");
     }
 
+    #[test]
+    fn test_default_stylesheet_has_invalid_input_rules() {
+        let sheet = default_stylesheet();
+        let resolver = StyleResolver::new();
+        let valid = resolver.resolve_styles(&RvueElement::new("input"), &sheet);
+        let mut element = RvueElement::new("input");
+        element.state.insert(crate::selectors::ElementState::INVALID);
+        let invalid = resolver.resolve_styles(&element, &sheet);
+
+        assert_eq!(valid.border_color, Some(BorderColor(crate::Color::rgb(180, 180, 180))));
+        assert_eq!(invalid.border_color, Some(BorderColor(crate::Color::rgb(220, 38, 38))));
+    }
+
     #[test]
     fn test_default_stylesheet_is_not_empty() {
         let sheet = default_stylesheet();
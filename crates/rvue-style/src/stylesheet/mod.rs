@@ -6,5 +6,5 @@ pub mod resolver;
 pub mod rule;
 
 pub use defaults::default_stylesheet;
-pub use resolver::StyleResolver;
-pub use rule::{StyleRule, Stylesheet};
+pub use resolver::{StyleResolver, HIGH_CONTRAST_MIN_RATIO};
+pub use rule::{ButtonShades, StyleRule, Stylesheet};
@@ -43,6 +43,7 @@ pub fn parse_stylesheet(css: &str) -> Option<Stylesheet> {
         position += 1;
 
         let mut properties = Properties::new();
+        let mut important = Properties::new();
 
         while position < css.len() {
             while position < css.len() && css[position..].starts_with(|c: char| c.is_whitespace()) {
@@ -93,9 +94,7 @@ pub fn parse_stylesheet(css: &str) -> Option<Stylesheet> {
             }
 
             let value_start = position;
-            while position < css.len()
-                && !css[position..].starts_with(|c: char| c.is_whitespace() || c == ';' || c == '}')
-            {
+            while position < css.len() && !css[position..].starts_with([';', '}']) {
                 position += 1;
             }
             let value = css[value_start..position].trim();
@@ -104,15 +103,29 @@ pub fn parse_stylesheet(css: &str) -> Option<Stylesheet> {
                 position += 1;
             }
 
-            add_property(&mut properties, name, value);
+            match strip_important(value) {
+                Some(value) => add_property(&mut important, name, value),
+                None => add_property(&mut properties, name, value),
+            }
         }
 
-        stylesheet.add_rule(StyleRule::new(selector.to_string(), properties));
+        stylesheet
+            .add_rule(StyleRule::new(selector.to_string(), properties).with_important(important));
     }
 
     Some(stylesheet)
 }
 
+/// Strips a trailing `!important` flag, returning the bare value if it was present.
+fn strip_important(value: &str) -> Option<&str> {
+    let bang = value.rfind('!')?;
+    if value[bang + 1..].trim().eq_ignore_ascii_case("important") {
+        Some(value[..bang].trim_end())
+    } else {
+        None
+    }
+}
+
 fn add_property(properties: &mut Properties, name: &str, value: &str) {
     match name {
         "background-color" | "bg" => {
@@ -150,34 +163,7 @@ fn add_property(properties: &mut Properties, name: &str, value: &str) {
 }
 
 fn parse_color(value: &str) -> Option<Color> {
-    let value = value.trim();
-
-    if value.starts_with('#') {
-        Color::from_hex(value)
-    } else if value.starts_with("rgb") {
-        let value =
-            value.trim_start_matches("rgb(").trim_start_matches("rgba(").trim_end_matches(')');
-        let parts: Vec<&str> = value.split(',').collect();
-        if parts.len() >= 3 {
-            let r = parts[0].trim().parse().ok()?;
-            let g = parts[1].trim().parse().ok()?;
-            let b = parts[2].trim().parse().ok()?;
-            Some(Color::rgb(r, g, b))
-        } else {
-            None
-        }
-    } else {
-        match value.to_lowercase().as_str() {
-            "red" => Some(Color::rgb(255, 0, 0)),
-            "green" => Some(Color::rgb(0, 128, 0)),
-            "blue" => Some(Color::rgb(0, 0, 255)),
-            "white" => Some(Color::rgb(255, 255, 255)),
-            "black" => Some(Color::rgb(0, 0, 0)),
-            "orange" => Some(Color::rgb(255, 165, 0)),
-            "transparent" => Some(Color::rgb(0, 0, 0)),
-            _ => None,
-        }
-    }
+    value.parse().ok()
 }
 
 fn parse_length(value: &str) -> Option<f32> {
@@ -257,4 +243,29 @@ mod tests {
         let stylesheet = parse_stylesheet(css).unwrap();
         assert!(!stylesheet.is_empty());
     }
+
+    #[test]
+    fn test_parse_important_declarations() {
+        let css = r#"
+            .primary {
+                width: 100px !important;
+                height: 40px;
+            }
+        "#;
+
+        let stylesheet = parse_stylesheet(css).unwrap();
+        let rule = stylesheet.rules().next().unwrap();
+
+        assert_eq!(rule.important.get::<Width>(), Some(&Width(Size::Pixels(100.0))));
+        assert!(rule.properties.get::<Width>().is_none());
+        assert_eq!(rule.properties.get::<Height>(), Some(&Height(Size::Pixels(40.0))));
+    }
+
+    #[test]
+    fn test_strip_important() {
+        assert_eq!(strip_important("red !important"), Some("red"));
+        assert_eq!(strip_important("red!important"), Some("red"));
+        assert_eq!(strip_important("red ! IMPORTANT"), Some("red"));
+        assert_eq!(strip_important("red"), None);
+    }
 }
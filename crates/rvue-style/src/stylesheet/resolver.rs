@@ -1,9 +1,15 @@
 //! Style resolver for matching styles to elements.
 
-use crate::properties::ComputedStyles;
+use crate::properties::{BackgroundColor, Color, ComputedStyles, TextColor};
 use crate::property::Properties;
 use crate::selectors::RvueElement;
-use crate::stylesheet::rule::Stylesheet;
+use crate::stylesheet::rule::{StyleRule, Stylesheet};
+
+/// Contrast ratio between text and background that high-contrast mode enforces (WCAG AAA).
+pub const HIGH_CONTRAST_MIN_RATIO: f32 = 7.0;
+
+/// Lightness step used while searching for a color with enough contrast.
+const CONTRAST_LIGHTNESS_STEP: f32 = 0.02;
 
 /// Resolves matched styles for an element from a stylesheet.
 #[derive(Debug, Default)]
@@ -16,17 +22,72 @@ impl StyleResolver {
     }
 
     /// Resolves styles for an element from a stylesheet.
+    ///
+    /// Matching rules are applied in cascade order: ascending specificity, with source
+    /// order breaking ties. `!important` declarations are applied after all normal ones
+    /// using the same ordering, so they win regardless of selector specificity.
     pub fn resolve_styles(&self, element: &RvueElement, stylesheet: &Stylesheet) -> ComputedStyles {
-        let mut result = ComputedStyles::default();
+        let (mut result, important) = self.resolve_cascade(element, stylesheet);
+        result.merge_with_computed(&important);
+        result
+    }
 
-        for rule in stylesheet.rules() {
-            let matches = self.matches_selector(element, &rule.selector);
-            if matches {
-                result.merge(&rule.properties);
+    /// Resolves normal and `!important` declarations as two separate layers.
+    ///
+    /// Each layer is built in the same cascade order as [`resolve_styles`](Self::resolve_styles),
+    /// which merges the important layer over the normal one. Keeping them apart lets callers
+    /// slot other styles, such as inline ones, in between.
+    pub fn resolve_cascade(
+        &self,
+        element: &RvueElement,
+        stylesheet: &Stylesheet,
+    ) -> (ComputedStyles, ComputedStyles) {
+        let mut normal = ComputedStyles::default();
+        let mut important = ComputedStyles::default();
+
+        let mut matched: Vec<&StyleRule> = stylesheet
+            .rules()
+            .filter(|rule| self.matches_selector(element, &rule.selector))
+            .collect();
+
+        // `sort_by_key` is stable, so equal specificity keeps source order.
+        matched.sort_by_key(|rule| rule.specificity);
+
+        for rule in &matched {
+            normal.merge(&rule.properties);
+            if !rule.important.is_empty() {
+                important.merge(&rule.important);
             }
         }
 
-        result
+        (normal, important)
+    }
+
+    /// Post-processes resolved styles for high-contrast mode.
+    ///
+    /// `backdrop` is the opaque color the element is painted over: the nearest opaque ancestor
+    /// background, or the window. The text color (black when unset, like the renderer) is pushed
+    /// darker or lighter until it reaches [`HIGH_CONTRAST_MIN_RATIO`] against what shows
+    /// behind it, which is the element's background composited over the backdrop. If even black
+    /// or white falls short, an opaque background of the element's own is pushed the other way.
+    pub fn apply_high_contrast(&self, styles: &mut ComputedStyles, backdrop: Color) {
+        let own_background = styles.background_color.map(|BackgroundColor(color)| color);
+        let background = own_background.map_or(backdrop, |color| color.over(&backdrop));
+        let text = styles.text_color.map_or(Color::rgb(0, 0, 0), |TextColor(color)| color);
+        if text.over(&background).contrast_ratio(&background) >= HIGH_CONTRAST_MIN_RATIO {
+            return;
+        }
+
+        // Black and white contrast equally with a background of this luminance
+        let darker_text = background.relative_luminance() > 0.179;
+        let text = push_for_contrast(text.with_alpha(255), &background, darker_text);
+        styles.text_color = Some(TextColor(text));
+
+        // Only the element's own opaque background is its to change
+        if own_background.is_some_and(|color| color.0.a == 255) {
+            let background = push_for_contrast(background, &text, !darker_text);
+            styles.background_color = Some(BackgroundColor(background));
+        }
     }
 
     /// Resolves styles using raw selector and properties.
@@ -46,7 +107,7 @@ impl StyleResolver {
     }
 
     /// Checks if an element matches a CSS selector.
-    fn matches_selector(&self, element: &RvueElement, selector: &str) -> bool {
+    pub fn matches_selector(&self, element: &RvueElement, selector: &str) -> bool {
         let selector = selector.trim();
 
         if selector.is_empty() {
@@ -184,12 +245,28 @@ impl StyleResolver {
     }
 }
 
+/// Move `color`'s lightness towards black or white until it contrasts enough with `against`.
+fn push_for_contrast(color: Color, against: &Color, darker: bool) -> Color {
+    if color.contrast_ratio(against) >= HIGH_CONTRAST_MIN_RATIO {
+        return color;
+    }
+    let (h, s, mut l) = color.to_hsl();
+    loop {
+        let candidate = Color::hsl(h, s, l).with_alpha(color.0.a);
+        let at_limit = if darker { l <= 0.0 } else { l >= 1.0 };
+        if at_limit || candidate.contrast_ratio(against) >= HIGH_CONTRAST_MIN_RATIO {
+            return candidate;
+        }
+        let step = if darker { -CONTRAST_LIGHTNESS_STEP } else { CONTRAST_LIGHTNESS_STEP };
+        l = (l + step).clamp(0.0, 1.0);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::properties::BackgroundColor;
     use crate::property::Properties;
-    use crate::stylesheet::rule::StyleRule;
 
     #[test]
     fn test_matches_tag_selector() {
@@ -291,4 +368,135 @@ mod tests {
         let resolved_no_class = resolver.resolve_styles(&element_no_class, &stylesheet);
         assert!(resolved_no_class.background_color.is_none());
     }
+
+    fn background(r: u8, g: u8, b: u8) -> Properties {
+        let mut props = Properties::new();
+        props.insert(BackgroundColor(crate::properties::Color::rgb(r, g, b)));
+        props
+    }
+
+    #[test]
+    fn test_id_rule_beats_class_rule() {
+        let resolver = StyleResolver::new();
+        let mut stylesheet = Stylesheet::new();
+
+        // The id rule comes first in source order but must still win on specificity.
+        stylesheet.add_rule(StyleRule::new("#submit".to_string(), background(0, 0, 255)));
+        stylesheet.add_rule(StyleRule::new(".primary".to_string(), background(255, 0, 0)));
+
+        let element = RvueElement::new("button").with_class("primary").with_id("submit");
+        let resolved = resolver.resolve_styles(&element, &stylesheet);
+
+        assert_eq!(
+            resolved.background_color,
+            Some(BackgroundColor(crate::properties::Color::rgb(0, 0, 255)))
+        );
+    }
+
+    #[test]
+    fn test_equal_specificity_uses_source_order() {
+        let resolver = StyleResolver::new();
+        let mut stylesheet = Stylesheet::new();
+
+        stylesheet.add_rule(StyleRule::new(".a".to_string(), background(255, 0, 0)));
+        stylesheet.add_rule(StyleRule::new(".b".to_string(), background(0, 128, 0)));
+
+        let element = RvueElement::new("button").with_class("a").with_class("b");
+        let resolved = resolver.resolve_styles(&element, &stylesheet);
+
+        assert_eq!(
+            resolved.background_color,
+            Some(BackgroundColor(crate::properties::Color::rgb(0, 128, 0)))
+        );
+    }
+
+    #[test]
+    fn test_important_class_rule_beats_id_rule() {
+        let resolver = StyleResolver::new();
+        let mut stylesheet = Stylesheet::new();
+
+        stylesheet.add_rule(
+            StyleRule::new(".primary".to_string(), Properties::new())
+                .with_important(background(255, 0, 0)),
+        );
+        stylesheet.add_rule(StyleRule::new("#submit".to_string(), background(0, 0, 255)));
+
+        let element = RvueElement::new("button").with_class("primary").with_id("submit");
+        let resolved = resolver.resolve_styles(&element, &stylesheet);
+
+        assert_eq!(
+            resolved.background_color,
+            Some(BackgroundColor(crate::properties::Color::rgb(255, 0, 0)))
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_raises_low_contrast_text() {
+        use crate::properties::{Color, TextColor};
+
+        let resolver = StyleResolver::new();
+        let mut styles = ComputedStyles {
+            background_color: Some(BackgroundColor(Color::rgb(119, 119, 119))),
+            text_color: Some(TextColor(Color::rgb(136, 136, 136))),
+            ..Default::default()
+        };
+        let before = Color::rgb(136, 136, 136).contrast_ratio(&Color::rgb(119, 119, 119));
+        assert!(before < 1.5);
+
+        resolver.apply_high_contrast(&mut styles, Color::rgb(255, 255, 255));
+
+        let text = styles.text_color.unwrap().0;
+        let background = styles.background_color.unwrap().0;
+        assert!(text.contrast_ratio(&background) >= HIGH_CONTRAST_MIN_RATIO);
+    }
+
+    #[test]
+    fn test_high_contrast_keeps_readable_colors() {
+        use crate::properties::{Color, TextColor};
+
+        let resolver = StyleResolver::new();
+        let original = ComputedStyles {
+            background_color: Some(BackgroundColor(Color::rgb(255, 255, 255))),
+            text_color: Some(TextColor(Color::rgb(20, 20, 20))),
+            ..Default::default()
+        };
+        let mut styles = original.clone();
+
+        resolver.apply_high_contrast(&mut styles, Color::rgb(255, 255, 255));
+
+        assert_eq!(styles, original);
+    }
+
+    #[test]
+    fn test_high_contrast_sees_through_transparent_background() {
+        use crate::properties::Color;
+
+        let resolver = StyleResolver::new();
+        let original = ComputedStyles {
+            background_color: Some(BackgroundColor(Color::rgba(0, 0, 0, 0))),
+            ..Default::default()
+        };
+        let mut styles = original.clone();
+
+        // Default black text over a transparent background on a white window is readable
+        resolver.apply_high_contrast(&mut styles, Color::rgb(255, 255, 255));
+
+        assert_eq!(styles, original);
+    }
+
+    #[test]
+    fn test_high_contrast_adjusts_text_against_ancestor_background() {
+        use crate::properties::{Color, TextColor};
+
+        let resolver = StyleResolver::new();
+        let backdrop = Color::rgb(30, 30, 60);
+        let mut styles = ComputedStyles::default();
+
+        resolver.apply_high_contrast(&mut styles, backdrop);
+
+        let text = styles.text_color.map(|TextColor(color)| color).unwrap();
+        assert!(text.contrast_ratio(&backdrop) >= HIGH_CONTRAST_MIN_RATIO);
+        // The ancestor's background is not the element's to change
+        assert_eq!(styles.background_color, None);
+    }
 }
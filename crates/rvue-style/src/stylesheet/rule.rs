@@ -1,5 +1,6 @@
 //! Style rules and stylesheet structures.
 
+use crate::properties::{BackgroundColor, Color};
 use crate::property::Properties;
 use std::cmp::Ordering;
 
@@ -9,13 +10,22 @@ pub struct StyleRule {
     pub selector: String,
     pub specificity: Specificity,
     pub properties: Properties,
+    /// Declarations marked `!important`, applied after every normal declaration.
+    pub important: Properties,
 }
 
 impl StyleRule {
     #[inline]
     pub fn new(selector: String, properties: Properties) -> Self {
         let specificity = Specificity::from_selector(&selector);
-        Self { selector, specificity, properties }
+        Self { selector, specificity, properties, important: Properties::new() }
+    }
+
+    /// Sets the `!important` declarations for this rule.
+    #[inline]
+    pub fn with_important(mut self, important: Properties) -> Self {
+        self.important = important;
+        self
     }
 
     #[inline]
@@ -163,6 +173,61 @@ impl Stylesheet {
     pub fn merge(&mut self, other: &Stylesheet) {
         self.rules.extend(other.rules.iter().cloned());
     }
+
+    /// Add background colors for `selector` and its `:hover`, `:active` and `:disabled`
+    /// states, deriving the state colors from `base` with the default [`ButtonShades`].
+    pub fn add_button_style(&mut self, selector: &str, base: Color) {
+        self.add_button_style_with(selector, base, ButtonShades::default());
+    }
+
+    /// Like [`Stylesheet::add_button_style`], with custom derivation factors.
+    pub fn add_button_style_with(&mut self, selector: &str, base: Color, shades: ButtonShades) {
+        let states = [
+            (String::new(), base),
+            (":hover".to_string(), shades.hover(base)),
+            (":active".to_string(), shades.active(base)),
+            (":disabled".to_string(), shades.disabled(base)),
+        ];
+        for (state, color) in states {
+            let mut props = Properties::new();
+            props.insert(BackgroundColor(color));
+            self.add_rule(StyleRule::new(format!("{selector}{state}"), props));
+        }
+    }
+}
+
+/// How [`Stylesheet::add_button_style`] derives state colors from a base color.
+///
+/// Amounts are in HSL units (`0.0..=1.0`): hover and active lower the lightness, disabled
+/// lowers the saturation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ButtonShades {
+    pub hover_darken: f32,
+    pub active_darken: f32,
+    pub disabled_desaturate: f32,
+}
+
+impl ButtonShades {
+    #[inline]
+    pub fn hover(&self, base: Color) -> Color {
+        base.darken(self.hover_darken)
+    }
+
+    #[inline]
+    pub fn active(&self, base: Color) -> Color {
+        base.darken(self.active_darken)
+    }
+
+    #[inline]
+    pub fn disabled(&self, base: Color) -> Color {
+        base.desaturate(self.disabled_desaturate)
+    }
+}
+
+impl Default for ButtonShades {
+    fn default() -> Self {
+        Self { hover_darken: 0.08, active_darken: 0.16, disabled_desaturate: 0.6 }
+    }
 }
 
 unsafe impl rudo_gc::Trace for Stylesheet {
@@ -0,0 +1,79 @@
+//! Tests for classifying ComputedStyles changes as paint-only or layout-affecting.
+
+use rvue_style::{
+    properties::{
+        BackgroundColor, Color, Padding, Size, TextColor, TextDecoration, Visibility, WhiteSpace,
+        Width,
+    },
+    ComputedStyles, StyleChangeSet,
+};
+
+fn base() -> ComputedStyles {
+    ComputedStyles {
+        background_color: Some(BackgroundColor(Color::rgb(255, 255, 255))),
+        text_color: Some(TextColor(Color::rgb(0, 0, 0))),
+        width: Some(Width(Size::Pixels(100.0))),
+        ..ComputedStyles::default()
+    }
+}
+
+#[test]
+fn test_identical_styles_report_no_change() {
+    let change = base().diff(&base());
+    assert!(change.is_empty());
+    assert_eq!(change, StyleChangeSet::default());
+}
+
+#[test]
+fn test_text_color_change_is_paint_only() {
+    let mut other = base();
+    other.text_color = Some(TextColor(Color::rgb(255, 0, 0)));
+
+    let change = base().diff(&other);
+    assert!(change.is_paint_only());
+    assert!(!change.needs_layout());
+}
+
+#[test]
+fn test_width_change_affects_layout() {
+    let mut other = base();
+    other.width = Some(Width(Size::Pixels(120.0)));
+
+    let change = base().diff(&other);
+    assert!(change.needs_layout());
+    assert!(change.paint, "a relayout is always repainted");
+    assert!(!change.is_paint_only());
+}
+
+#[test]
+fn test_text_decoration_change_is_paint_only() {
+    let mut other = base();
+    other.text_decoration = Some(TextDecoration::Underline);
+
+    let change = base().diff(&other);
+    assert!(change.is_paint_only());
+}
+
+#[test]
+fn test_white_space_change_affects_layout() {
+    let mut other = base();
+    other.white_space = Some(WhiteSpace::NoWrap);
+
+    let change = base().diff(&other);
+    assert!(change.needs_layout());
+}
+
+#[test]
+fn test_setting_or_clearing_a_property_counts_as_change() {
+    let mut other = base();
+    other.padding = Some(Padding(8.0));
+    assert!(base().diff(&other).needs_layout());
+
+    let mut other = base();
+    other.background_color = None;
+    assert!(base().diff(&other).is_paint_only());
+
+    let mut other = base();
+    other.visibility = Some(Visibility::Hidden);
+    assert!(base().diff(&other).is_paint_only());
+}
@@ -4,7 +4,7 @@ use rvue_style::{
     BackgroundColor, Color, Display, FontFamily, FontSize, Height, Margin, Padding, Properties,
     Size, TextColor, Width,
 };
-use rvue_style::{StyledWidget, StyledWidgetExt, WidgetStyles};
+use rvue_style::{Opacity, StyleStore, StyledWidget, StyledWidgetExt, WidgetStyles};
 
 #[test]
 fn test_properties_new() {
@@ -75,6 +75,131 @@ fn test_color_from_hex() {
     assert_eq!(c.0.b, 0);
 }
 
+#[test]
+fn test_color_parses_short_hex() {
+    assert_eq!("#f80".parse::<Color>(), Ok(Color::rgb(255, 136, 0)));
+    assert_eq!("#F80C".parse::<Color>(), Ok(Color::rgba(255, 136, 0, 204)));
+}
+
+#[test]
+fn test_color_parses_long_hex_with_alpha() {
+    assert_eq!("#336699".parse::<Color>(), Ok(Color::rgb(51, 102, 153)));
+    assert_eq!("#33669980".parse::<Color>(), Ok(Color::rgba(51, 102, 153, 128)));
+}
+
+#[test]
+fn test_color_parses_rgb_functions() {
+    assert_eq!("rgb(10, 20, 30)".parse::<Color>(), Ok(Color::rgb(10, 20, 30)));
+    assert_eq!("RGB(100%, 0%, 50%)".parse::<Color>(), Ok(Color::rgb(255, 0, 128)));
+    assert_eq!("rgba(10, 20, 30, 0.5)".parse::<Color>(), Ok(Color::rgba(10, 20, 30, 128)));
+    assert_eq!("rgb(10 20 30 / 25%)".parse::<Color>(), Ok(Color::rgba(10, 20, 30, 64)));
+}
+
+#[test]
+fn test_color_parses_named_colors() {
+    assert_eq!(Color::try_from("Orange"), Ok(Color::rgb(255, 165, 0)));
+    assert_eq!(Color::try_from("grey"), Ok(Color::rgb(128, 128, 128)));
+    assert_eq!(Color::try_from("transparent"), Ok(Color::rgba(0, 0, 0, 0)));
+}
+
+#[test]
+fn test_color_parse_errors_name_the_input() {
+    for input in ["#12", "#ggg", "rgb(1, 2)", "rgb(300, 0, 0)", "rgba(0, 0, 0, 2)", "blurple"] {
+        let err = input.parse::<Color>().unwrap_err();
+        assert_eq!(err.input(), input);
+        assert!(err.to_string().contains(input));
+    }
+}
+
+#[test]
+fn test_color_from_known_hsl_values() {
+    assert_eq!(Color::hsl(0.0, 1.0, 0.5), Color::rgb(255, 0, 0));
+    assert_eq!(Color::hsl(120.0, 1.0, 0.25), Color::rgb(0, 128, 0));
+    assert_eq!(Color::hsl(240.0, 1.0, 0.5), Color::rgb(0, 0, 255));
+    assert_eq!(Color::hsl(60.0, 1.0, 0.5), Color::rgb(255, 255, 0));
+    assert_eq!(Color::hsl(0.0, 0.0, 0.5), Color::rgb(128, 128, 128));
+    assert_eq!(Color::hsl(-120.0, 1.0, 0.5), Color::hsl(240.0, 1.0, 0.5));
+    assert_eq!(Color::hsla(0.0, 1.0, 0.5, 0.5), Color::rgba(255, 0, 0, 128));
+}
+
+#[test]
+fn test_color_to_hsl_known_values() {
+    let (h, s, l) = Color::rgb(255, 0, 0).to_hsl();
+    assert_eq!((h, s, l), (0.0, 1.0, 0.5));
+
+    let (h, s, l) = Color::rgb(51, 102, 153).to_hsl();
+    assert!((h - 210.0).abs() < 0.01);
+    assert!((s - 0.5).abs() < 0.01);
+    assert!((l - 0.4).abs() < 0.01);
+
+    assert_eq!(Color::rgb(255, 255, 255).to_hsl(), (0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_color_hsl_round_trip() {
+    for r in (0..=255).step_by(15) {
+        for g in (0..=255).step_by(17) {
+            for b in (0..=255).step_by(51) {
+                let color = Color::rgb(r as u8, g as u8, b as u8);
+                let (h, s, l) = color.to_hsl();
+                let back = Color::hsl(h, s, l);
+                for (before, after) in
+                    [(color.0.r, back.0.r), (color.0.g, back.0.g), (color.0.b, back.0.b)]
+                {
+                    assert!(
+                        before.abs_diff(after) <= 1,
+                        "{color:?} -> {:?} -> {back:?}",
+                        (h, s, l)
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_style_store_iterates_set_properties() {
+    let mut props = Properties::new();
+    assert_eq!(props.property_count(), 0);
+    assert!(props.set_properties().is_empty());
+
+    props.insert(Opacity(0.5));
+    props.insert(BackgroundColor(Color::rgb(1, 2, 3)));
+    props.insert(Width(Size::pixels(40.0)));
+
+    assert_eq!(props.property_count(), 3);
+    let set: Vec<(&str, String)> = props
+        .set_properties()
+        .into_iter()
+        .map(|(name, value)| (name, format!("{value:?}")))
+        .collect();
+    assert_eq!(set[0].0, "background_color");
+    assert_eq!(set[1].0, "width");
+    assert_eq!(set[2], ("opacity", "Opacity(0.5)".to_string()));
+}
+
+#[test]
+fn test_style_store_diff_reports_changed_properties() {
+    let mut before = Properties::new();
+    before.insert(BackgroundColor(Color::rgb(255, 0, 0)));
+    before.insert(Width(Size::pixels(100.0)));
+    before.insert(Opacity(1.0));
+
+    let mut after = before.clone();
+    after.insert(BackgroundColor(Color::rgb(0, 0, 255)));
+    after.remove::<Opacity>();
+    after.insert(FontSize(14.0));
+
+    assert_eq!(before.diff(&after), ["background_color", "font_size", "opacity"]);
+    assert_eq!(after.diff(&before), before.diff(&after));
+    assert!(before.diff(&before.clone()).is_empty());
+
+    // Stores of different kinds compare by value
+    let data = rvue_style::StyleData::from(after.clone());
+    assert!(after.diff(&data).is_empty());
+    assert_eq!(data.property_count(), after.property_count());
+}
+
 #[test]
 fn test_size_variants() {
     let auto = Size::auto();
@@ -2,8 +2,8 @@ use rvue_style::{
     create_reactive_signal, AlignItems, AlignSelf, BackgroundColor, BorderColor, BorderRadius,
     BorderStyle, BorderWidth, Color, ComputedStyles, Cursor, Display, FlexBasis, FlexDirection,
     FlexGrow, FlexShrink, FontFamily, FontSize, FontWeight, Gap, Height, JustifyContent, Margin,
-    Opacity, Padding, ReactiveProperty, ReactiveSignal, ReactiveSignalWrite, ReactiveStyles, Size,
-    TextColor, Visibility, Width, ZIndex,
+    Opacity, Overflow, Padding, ReactiveProperty, ReactiveSignal, ReactiveSignalWrite,
+    ReactiveStyles, Size, StyleProperty, TextColor, Visibility, Width, ZIndex,
 };
 
 #[test]
@@ -448,3 +448,45 @@ fn test_reactive_styles_to_computed() {
     assert_eq!(computed.width, Some(Width(Size::Pixels(100.0))));
     assert_eq!(computed.height, Some(Height(Size::Pixels(50.0))));
 }
+
+#[test]
+fn test_merge_overrides_only_properties_set_in_other() {
+    let base = ReactiveStyles::new()
+        .set_background_color(BackgroundColor(Color::rgb(240, 240, 240)))
+        .set_border_color(BorderColor(Color::rgb(0, 0, 0)))
+        .set_opacity(Opacity(0.5));
+    let danger = ReactiveStyles::new().set_border_color(BorderColor(Color::rgb(220, 0, 0)));
+
+    let computed = base.merge(danger).compute();
+
+    assert_eq!(computed.background_color, Some(BackgroundColor(Color::rgb(240, 240, 240))));
+    assert_eq!(computed.border_color, Some(BorderColor(Color::rgb(220, 0, 0))));
+    assert_eq!(computed.opacity, Some(Opacity(0.5)));
+}
+
+#[test]
+fn test_merge_keeps_reactive_overrides() {
+    let (width, set_width) = create_reactive_signal(Width(Size::Pixels(10.0)));
+    let merged = ReactiveStyles::new()
+        .set_width(Width(Size::Pixels(1.0)))
+        .merge(ReactiveStyles::new().set_width(width));
+
+    assert!(merged.is_reactive());
+    set_width.set(Width(Size::Pixels(20.0)));
+    assert_eq!(merged.compute().width, Some(Width(Size::Pixels(20.0))));
+}
+
+#[test]
+fn test_extend_applies_properties_in_order() {
+    let styles = ReactiveStyles::new().set_gap(Gap(4.0)).extend([
+        StyleProperty::from(BackgroundColor(Color::rgb(1, 2, 3))),
+        Gap(8.0).into(),
+        StyleProperty::OverflowY(Overflow::Auto.into()),
+        Gap(12.0).into(),
+    ]);
+
+    let computed = styles.compute();
+    assert_eq!(computed.background_color, Some(BackgroundColor(Color::rgb(1, 2, 3))));
+    assert_eq!(computed.gap, Some(Gap(12.0)));
+    assert_eq!(computed.overflow_y, Some(Overflow::Auto));
+}
@@ -120,6 +120,10 @@ fn test_extended_state_matching() {
 
     state.insert(ElementState::FOCUS_VISIBLE);
     assert!(state.matches_pseudo_class("focus-visible"));
+
+    assert!(!state.matches_pseudo_class("invalid"));
+    state.insert(ElementState::INVALID);
+    assert!(state.matches_pseudo_class("invalid"));
 }
 
 #[test]
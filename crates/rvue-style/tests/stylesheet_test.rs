@@ -1,6 +1,12 @@
 //! Stylesheet parsing tests.
 
 use rvue_style::stylesheet::parser::parse_stylesheet;
+use rvue_style::{BackgroundColor, ButtonShades, Color, Stylesheet};
+
+fn background_for(stylesheet: &Stylesheet, selector: &str) -> Color {
+    let rule = stylesheet.rules().find(|rule| rule.selector == selector).unwrap();
+    rule.properties.get::<BackgroundColor>().unwrap().0
+}
 
 #[test]
 fn test_parse_empty_stylesheet() {
@@ -161,3 +167,39 @@ fn test_parse_complex_selectors() {
     let stylesheet = result.unwrap();
     assert_eq!(stylesheet.len(), 2);
 }
+
+#[test]
+fn test_button_style_derives_state_colors() {
+    let base = Color::rgb(0, 123, 255);
+    let mut stylesheet = Stylesheet::new();
+    stylesheet.add_button_style("button.primary", base);
+
+    assert_eq!(stylesheet.len(), 4);
+    assert_eq!(background_for(&stylesheet, "button.primary"), base);
+
+    let (base_h, base_s, base_l) = base.to_hsl();
+    let (hover_h, hover_s, hover_l) = background_for(&stylesheet, "button.primary:hover").to_hsl();
+    assert!((base_l - hover_l - 0.08).abs() < 0.01, "hover is 8% darker");
+    assert!((hover_h - base_h).abs() < 1.0);
+    assert!((hover_s - base_s).abs() < 0.02);
+
+    let (_, _, active_l) = background_for(&stylesheet, "button.primary:active").to_hsl();
+    assert!((base_l - active_l - 0.16).abs() < 0.01, "active is darker still");
+
+    let (_, disabled_s, disabled_l) =
+        background_for(&stylesheet, "button.primary:disabled").to_hsl();
+    assert!((base_s - disabled_s - 0.6).abs() < 0.01);
+    assert!((disabled_l - base_l).abs() < 0.01);
+}
+
+#[test]
+fn test_button_style_factors_are_tunable() {
+    let base = Color::hsl(0.0, 0.8, 0.5);
+    let shades = ButtonShades { hover_darken: 0.2, active_darken: 0.3, disabled_desaturate: 1.0 };
+    let mut stylesheet = Stylesheet::new();
+    stylesheet.add_button_style_with(".danger", base, shades);
+
+    assert_eq!(background_for(&stylesheet, ".danger:hover"), Color::hsl(0.0, 0.8, 0.3));
+    assert_eq!(background_for(&stylesheet, ".danger:active"), Color::hsl(0.0, 0.8, 0.2));
+    assert_eq!(background_for(&stylesheet, ".danger:disabled"), Color::hsl(0.0, 0.0, 0.5));
+}
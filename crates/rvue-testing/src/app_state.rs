@@ -0,0 +1,249 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Windowless application state used by the test harness to run rvue's event passes.
+
+use std::any::Any;
+use std::cell::{RefCell, RefMut};
+
+use rudo_gc::Gc;
+use rvue::app::AppStateLike;
+use rvue::component::Component;
+use rvue::event::context::EventContextOps;
+use rvue::event::drag::DragState;
+use rvue::event::handler::{LongPressState, ScrollDragState};
+use rvue::event::shortcut::{take_pending_shortcuts, ShortcutRegistry};
+use rvue::style::Stylesheet;
+use vello::kurbo::Point;
+
+/// Event-dispatch state for a harness, mirroring the app state without a window or renderer.
+pub(crate) struct HeadlessAppState {
+    pub root: Gc<Component>,
+    pub focused: Option<Gc<Component>>,
+    pub pending_focus: Option<Gc<Component>>,
+    pub pointer_capture: RefCell<Option<Gc<Component>>>,
+    pub last_pointer_pos: Option<Point>,
+    pub hovered_component: Option<Gc<Component>>,
+    pub active_path: Vec<Gc<Component>>,
+    pub hovered_path: Vec<Gc<Component>>,
+    pub focused_path: Vec<Gc<Component>>,
+    pub needs_pointer_pass_update: bool,
+    pub scroll_drag_state: Option<ScrollDragState>,
+    pub long_press_state: Option<LongPressState>,
+    pub drag_state: Option<DragState>,
+    pub drag_payload: Option<Box<dyn Any>>,
+    pub copy_buffer: Option<String>,
+    pub is_ime_active: bool,
+    pub shortcuts: ShortcutRegistry,
+    pub stylesheet: Option<Stylesheet>,
+}
+
+impl HeadlessAppState {
+    pub fn new(root: Gc<Component>) -> Self {
+        Self {
+            root,
+            focused: None,
+            pending_focus: None,
+            pointer_capture: RefCell::new(None),
+            last_pointer_pos: None,
+            hovered_component: None,
+            active_path: Vec::new(),
+            hovered_path: Vec::new(),
+            focused_path: Vec::new(),
+            needs_pointer_pass_update: false,
+            scroll_drag_state: None,
+            long_press_state: None,
+            drag_state: None,
+            drag_payload: None,
+            copy_buffer: None,
+            is_ime_active: false,
+            // Shortcuts registered while building the view, as the app adopts them on start
+            shortcuts: take_pending_shortcuts(),
+            stylesheet: None,
+        }
+    }
+}
+
+impl AppStateLike for HeadlessAppState {
+    fn root_component(&self) -> Gc<Component> {
+        Gc::clone(&self.root)
+    }
+
+    fn pointer_capture(&self) -> Option<Gc<Component>> {
+        self.pointer_capture.borrow().clone()
+    }
+
+    fn pointer_capture_mut(&mut self) -> RefMut<'_, Option<Gc<Component>>> {
+        self.pointer_capture.borrow_mut()
+    }
+
+    fn last_pointer_pos(&self) -> Option<Point> {
+        self.last_pointer_pos
+    }
+
+    fn hovered_component(&self) -> Option<Gc<Component>> {
+        self.hovered_component.clone()
+    }
+
+    fn focused(&self) -> Option<Gc<Component>> {
+        self.focused.clone()
+    }
+
+    fn focused_mut(&mut self) -> &mut Option<Gc<Component>> {
+        &mut self.focused
+    }
+
+    fn fallback(&self) -> Option<Gc<Component>> {
+        None
+    }
+
+    fn pending_focus(&mut self) -> &mut Option<Gc<Component>> {
+        &mut self.pending_focus
+    }
+
+    fn active_path(&mut self) -> &mut Vec<Gc<Component>> {
+        &mut self.active_path
+    }
+
+    fn hovered_path(&mut self) -> &mut Vec<Gc<Component>> {
+        &mut self.hovered_path
+    }
+
+    fn focused_path(&mut self) -> &mut Vec<Gc<Component>> {
+        &mut self.focused_path
+    }
+
+    fn set_active_path(&mut self, path: Vec<Gc<Component>>) {
+        self.active_path = path;
+    }
+
+    fn set_hovered_path(&mut self, path: Vec<Gc<Component>>) {
+        self.hovered_path = path;
+    }
+
+    fn set_focused_path(&mut self, path: Vec<Gc<Component>>) {
+        self.focused_path = path;
+    }
+
+    fn set_needs_pointer_pass_update(&mut self, value: bool) {
+        self.needs_pointer_pass_update = value;
+    }
+
+    fn needs_pointer_pass_update(&self) -> bool {
+        self.needs_pointer_pass_update
+    }
+
+    fn set_focused(&mut self, focused: Option<Gc<Component>>) {
+        self.focused = focused;
+    }
+
+    fn set_needs_cursor_blink_update(&mut self) {}
+
+    fn clear_pointer_capture(&mut self) {
+        *self.pointer_capture.borrow_mut() = None;
+    }
+
+    fn scroll_drag_state(&self) -> Option<ScrollDragState> {
+        self.scroll_drag_state
+    }
+
+    fn set_scroll_drag_state(&mut self, state: Option<ScrollDragState>) {
+        self.scroll_drag_state = state;
+    }
+
+    fn long_press_state(&self) -> Option<LongPressState> {
+        self.long_press_state.clone()
+    }
+
+    fn set_long_press_state(&mut self, state: Option<LongPressState>) {
+        self.long_press_state = state;
+    }
+
+    fn drag_state(&self) -> Option<DragState> {
+        self.drag_state
+    }
+
+    fn set_drag_state(&mut self, state: Option<DragState>) {
+        self.drag_state = state;
+    }
+
+    fn enable_ime(&mut self) {
+        self.is_ime_active = true;
+    }
+
+    fn disable_ime(&mut self) {
+        self.is_ime_active = false;
+    }
+
+    fn update_ime_cursor_area(&mut self) {}
+
+    fn shortcuts(&self) -> &ShortcutRegistry {
+        &self.shortcuts
+    }
+
+    fn shortcuts_mut(&mut self) -> &mut ShortcutRegistry {
+        &mut self.shortcuts
+    }
+
+    fn stylesheet(&self) -> Option<&Stylesheet> {
+        self.stylesheet.as_ref()
+    }
+}
+
+impl EventContextOps for HeadlessAppState {
+    fn request_paint(&mut self) {}
+
+    fn request_layout(&mut self) {
+        self.root.mark_dirty();
+    }
+
+    fn capture_pointer(&mut self, component: Gc<Component>) {
+        *self.pointer_capture.borrow_mut() = Some(component);
+    }
+
+    fn release_pointer(&mut self) {
+        *self.pointer_capture.borrow_mut() = None;
+    }
+
+    fn request_focus(&mut self) {}
+
+    fn resign_focus(&mut self) {
+        self.focused = None;
+    }
+
+    fn set_handled(&mut self) {}
+
+    fn is_handled(&self) -> bool {
+        false
+    }
+
+    fn target(&self) -> Gc<Component> {
+        Gc::clone(&self.root)
+    }
+
+    fn has_pointer_capture(&self) -> bool {
+        false
+    }
+
+    fn set_pending_focus(&mut self, component: Gc<Component>) {
+        self.pending_focus = Some(component);
+    }
+
+    fn set_needs_cursor_blink_update(&mut self) {}
+
+    fn drag_payload(&self) -> Option<&dyn Any> {
+        self.drag_payload.as_deref()
+    }
+
+    fn set_drag_payload(&mut self, payload: Option<Box<dyn Any>>) {
+        self.drag_payload = payload;
+    }
+
+    fn copied_text(&self) -> Option<&str> {
+        self.copy_buffer.as_deref()
+    }
+
+    fn set_copied_text(&mut self, text: String) {
+        self.copy_buffer = Some(text);
+    }
+}
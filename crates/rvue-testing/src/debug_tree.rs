@@ -0,0 +1,101 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Text serialization of a component tree for snapshot assertions.
+
+use std::fmt::Write;
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+
+/// Serialize `root` and its descendants, one component per line, children indented by two spaces.
+///
+/// Each line holds the component type, `#element-id`, sorted `.classes`, key properties and the
+/// laid-out rect in window coordinates. Component ids and pointers are left out so the output
+/// only changes when the tree does.
+pub(crate) fn dump_tree(root: &Gc<Component>) -> String {
+    let mut out = String::new();
+    dump_component(root, 0, (0.0, 0.0), &mut out);
+    out
+}
+
+fn dump_component(component: &Gc<Component>, depth: usize, offset: (f64, f64), out: &mut String) {
+    let _ =
+        write!(out, "{:indent$}{}", "", type_name(&component.component_type), indent = depth * 2);
+
+    if let Some(id) = component.element_id.borrow().as_ref() {
+        let _ = write!(out, " #{}", id);
+    }
+
+    let mut classes = component.classes();
+    classes.sort();
+    for class in &classes {
+        let _ = write!(out, " .{}", class);
+    }
+
+    for (name, value) in key_props(component) {
+        let _ = write!(out, " {}={}", name, value);
+    }
+
+    let layout = component.layout_node().and_then(|node| node.layout_result);
+    let origin = match layout {
+        Some(layout) => {
+            let x = offset.0 + layout.location.x as f64;
+            let y = offset.1 + layout.location.y as f64;
+            let _ = write!(out, " [{}, {}, {}x{}]", x, y, layout.size.width, layout.size.height);
+            (x, y)
+        }
+        None => offset,
+    };
+    out.push('\n');
+
+    for child in component.children.borrow().iter() {
+        dump_component(child, depth + 1, origin, out);
+    }
+}
+
+fn type_name(component_type: &ComponentType) -> String {
+    match component_type {
+        ComponentType::Custom(name) => name.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn key_props(component: &Component) -> Vec<(&'static str, String)> {
+    let mut props = match &component.component_type {
+        ComponentType::Text => vec![("text", format!("{:?}", component.text_content()))],
+        ComponentType::TextInput | ComponentType::TextArea => {
+            vec![("value", format!("{:?}", component.text_input_value()))]
+        }
+        ComponentType::NumberInput => vec![("value", component.number_input_value().to_string())],
+        ComponentType::Checkbox => vec![("checked", component.checkbox_checked().to_string())],
+        ComponentType::ProgressBar if component.progress_indeterminate() => {
+            vec![("indeterminate", "true".to_string())]
+        }
+        ComponentType::ProgressBar => vec![("value", component.progress_value().to_string())],
+        ComponentType::Radio => vec![
+            ("value", format!("{:?}", component.radio_value())),
+            ("checked", component.radio_checked().to_string()),
+        ],
+        ComponentType::Show => vec![("when", component.show_when().to_string())],
+        ComponentType::For => vec![("items", component.for_item_count().to_string())],
+        ComponentType::Switch => match component.switch_active_arm() {
+            Some(index) => vec![("arm", index.to_string())],
+            None => vec![("arm", "none".to_string())],
+        },
+        ComponentType::Suspense => vec![("pending", component.suspense_pending().to_string())],
+        _ => Vec::new(),
+    };
+
+    if let Some(label) = component.aria_label() {
+        props.push(("aria-label", format!("{:?}", label)));
+    }
+    if component.is_disabled() {
+        props.push(("disabled", "true".to_string()));
+    }
+    if let Some(message) = component.error_message() {
+        props.push(("error", format!("{:?}", message)));
+    }
+
+    props
+}
@@ -5,6 +5,7 @@
 
 use rudo_gc::Gc;
 use rvue::component::Component;
+use rvue::event::HandlerInvocation;
 use std::collections::HashMap;
 
 /// A recorded event from the widget lifecycle.
@@ -30,6 +31,8 @@ pub enum RecordedEvent {
     LayoutPerformed,
     /// Widget was rendered
     Rendered,
+    /// An event handler on the widget ran, with the event being dispatched
+    HandlerInvoked(HandlerInvocation),
     /// Custom event
     Custom(String),
 }
@@ -79,6 +82,7 @@ impl WidgetRecorder {
 #[derive(Default)]
 pub struct EventRecorder {
     widget_records: HashMap<u32, WidgetRecorder>,
+    handler_log: Vec<HandlerInvocation>,
 }
 
 impl EventRecorder {
@@ -102,6 +106,25 @@ impl EventRecorder {
         self.widget_records.get(&widget_id).map(|r| r.has_event(&predicate)).unwrap_or(false)
     }
 
+    /// Record a handler invocation, both in the ordered log and on the widget it ran on.
+    pub fn record_handler(&mut self, invocation: HandlerInvocation) {
+        self.record(
+            invocation.component_id as u32,
+            RecordedEvent::HandlerInvoked(invocation.clone()),
+        );
+        self.handler_log.push(invocation);
+    }
+
+    /// Get every recorded handler invocation across the tree, in the order they ran.
+    pub fn handler_log(&self) -> &[HandlerInvocation] {
+        &self.handler_log
+    }
+
+    /// Take (and clear) the ordered handler log.
+    pub fn take_handler_log(&mut self) -> Vec<HandlerInvocation> {
+        std::mem::take(&mut self.handler_log)
+    }
+
     /// Get all widget IDs that have recorded events.
     pub fn recorded_widget_ids(&self) -> Vec<u32> {
         self.widget_records.keys().cloned().collect()
@@ -110,6 +133,7 @@ impl EventRecorder {
     /// Clear all recorded events.
     pub fn clear(&mut self) {
         self.widget_records.clear();
+        self.handler_log.clear();
     }
 }
 
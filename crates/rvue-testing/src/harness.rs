@@ -3,19 +3,36 @@
 
 //! Test harness for headless testing of rvue applications.
 
+use std::cell::RefCell;
 use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 use rudo_gc::Gc;
-use rvue::component::{Component, ComponentId};
+use rvue::async_runtime::clock::enable_virtual_clock;
+use rvue::component::{Component, ComponentId, ComponentType};
+use rvue::event::dispatch::{check_long_press, run_pointer_event_pass, run_text_event_pass};
+use rvue::event::hit_test::{hit_test_with_stylesheet, window_to_local};
+use rvue::event::observer::{set_dispatch_observer, HandlerInvocation};
 use rvue::event::types::{
-    PointerButtonEvent, PointerEvent, PointerInfo, PointerScrollEvent, ScrollDelta,
+    ImeEvent, KeyState, KeyboardEvent, PointerButtonEvent, PointerEvent, PointerMoveEvent,
+    PointerScrollEvent, ScrollDelta, TextEvent,
 };
+use rvue::event::update::{run_update_focus_pass, run_update_pointer_pass};
+use rvue::style::{resolve_styles_for_component, Stylesheet};
 use rvue_style::properties::Overflow;
-use vello::kurbo::Size;
+use rvue_style::ComputedStyles;
+use vello::kurbo::{Point, Rect, Size, Vec2};
+use winit::keyboard::{NativeKeyCode, PhysicalKey};
 
+use crate::app_state::HeadlessAppState;
+use crate::debug_tree::dump_tree;
 use crate::event_recorder::{EventRecorder, RecordedEvent};
 use crate::snapshot::{SnapshotManager, SnapshotOptions};
 
+/// Time between the frames run by [`TestHarness::advance`], about 60 frames per second.
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
 /// Parameters for creating a test harness.
 #[derive(Debug, Clone)]
 pub struct TestHarnessParams {
@@ -31,6 +48,7 @@ impl Default for TestHarnessParams {
             background_color: vello::peniko::Color::from_rgb8(0x29, 0x29, 0x29),
             snapshot_options: SnapshotOptions {
                 tolerance: 16,
+                max_diff_percent: 0.0,
                 padding: 0,
                 background_color: [0x29, 0x29, 0x29, 0xFF],
             },
@@ -41,9 +59,14 @@ impl Default for TestHarnessParams {
 /// A headless test harness for rvue applications.
 pub struct TestHarness {
     root_component: Gc<Component>,
+    app_state: HeadlessAppState,
     recorder: EventRecorder,
+    /// Handler invocations reported by dispatch, moved into `recorder` after each event
+    dispatch_log: Rc<RefCell<Vec<HandlerInvocation>>>,
     snapshot_manager: SnapshotManager,
     window_size: Size,
+    /// Time of the last frame run by `advance`
+    frame_time: Instant,
 }
 
 /// Layout information for a widget.
@@ -62,15 +85,27 @@ impl TestHarness {
     /// Create a test harness with custom parameters.
     pub fn create_with_params(widget: Gc<Component>, params: TestHarnessParams) -> Self {
         rudo_gc::test_util::reset();
+        // Timers spawned from here on follow `advance_time` instead of the wall clock
+        enable_virtual_clock();
 
         let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
         let snapshots_dir = PathBuf::from(manifest_dir).join("tests/snapshots");
 
+        let dispatch_log = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&dispatch_log);
+        set_dispatch_observer(Some(Rc::new(move |invocation: &HandlerInvocation| {
+            log.borrow_mut().push(invocation.clone());
+        })));
+
         Self {
+            app_state: HeadlessAppState::new(Gc::clone(&widget)),
             root_component: widget,
             recorder: EventRecorder::default(),
-            snapshot_manager: SnapshotManager::new(snapshots_dir),
+            dispatch_log,
+            snapshot_manager: SnapshotManager::new(snapshots_dir)
+                .with_options(params.snapshot_options),
             window_size: params.window_size,
+            frame_time: Instant::now(),
         }
     }
 
@@ -79,48 +114,54 @@ impl TestHarness {
         &self.root_component
     }
 
+    /// Resolve styles against `stylesheet` for layout and [`Self::get_computed_styles`].
+    pub fn set_stylesheet(&mut self, stylesheet: Stylesheet) {
+        self.app_state.stylesheet = Some(stylesheet);
+        self.root_component.mark_dirty();
+    }
+
+    /// Resize the headless window and lay the tree out again at the new size.
+    pub fn set_window_size(&mut self, size: Size) {
+        self.window_size = size;
+        self.root_component.mark_dirty();
+        self.compute_layout();
+    }
+
     // === Widget Lookup ===
 
     /// Find a widget by its tag (stored in element_id).
     pub fn get_widget_by_tag(&self, tag: &str) -> Option<Gc<Component>> {
-        self.find_widget_by_tag(&self.root_component, tag)
+        self.get_widget_by_id(tag)
     }
 
-    fn find_widget_by_tag(&self, component: &Gc<Component>, tag: &str) -> Option<Gc<Component>> {
-        let element_id = component.element_id.borrow();
-        if element_id.as_deref() == Some(tag) {
-            return Some(Gc::clone(component));
-        }
-        for child in component.children.borrow().iter() {
-            if let Some(found) = self.find_widget_by_tag(child, tag) {
-                return Some(found);
-            }
-        }
-        None
+    /// Find the first widget, in tree order, whose element id is `id`.
+    pub fn get_widget_by_id(&self, id: &str) -> Option<Gc<Component>> {
+        self.find_widget(|component| component.element_id.borrow().as_deref() == Some(id))
     }
 
-    /// Find a widget by its ID.
-    pub fn get_widget_by_id(&self, id: ComponentId) -> Option<Gc<Component>> {
-        if self.root_component.id == id {
-            return Some(Gc::clone(&self.root_component));
-        }
-        self.find_widget_by_id(&self.root_component, id)
+    /// Find the first text widget, in tree order, whose content is exactly `text`.
+    pub fn get_widget_by_text(&self, text: &str) -> Option<Gc<Component>> {
+        self.find_widget(|component| {
+            component.component_type == ComponentType::Text && component.text_content() == text
+        })
     }
 
-    fn find_widget_by_id(
-        &self,
-        component: &Gc<Component>,
-        id: ComponentId,
-    ) -> Option<Gc<Component>> {
-        if component.id == id {
-            return Some(Gc::clone(component));
-        }
-        for child in component.children.borrow().iter() {
-            if let Some(found) = self.find_widget_by_id(child, id) {
-                return Some(found);
-            }
-        }
-        None
+    /// Find all widgets carrying the class `class`, in tree order.
+    pub fn get_widgets_by_class(&self, class: &str) -> Vec<Gc<Component>> {
+        let mut found = Vec::new();
+        collect_widgets(&self.root_component, &|c| c.has_class(class), false, &mut found);
+        found
+    }
+
+    /// Find a widget by its component ID.
+    pub fn get_widget_by_component_id(&self, id: ComponentId) -> Option<Gc<Component>> {
+        self.find_widget(|component| component.id == id)
+    }
+
+    fn find_widget(&self, predicate: impl Fn(&Gc<Component>) -> bool) -> Option<Gc<Component>> {
+        let mut found = Vec::new();
+        collect_widgets(&self.root_component, &predicate, true, &mut found);
+        found.pop()
     }
 
     // === Event Recording ===
@@ -135,71 +176,100 @@ impl TestHarness {
         self.recorder.get_records(widget.id as u32)
     }
 
-    // === Mouse Events ===
+    /// Get every event handler that ran across the tree, in order, with the dispatched event.
+    pub fn handler_records(&self) -> &[HandlerInvocation] {
+        self.recorder.handler_log()
+    }
 
-    /// Simulate a mouse move to a widget's position.
-    pub fn mouse_move_to(&mut self, _widget: Gc<Component>) {
-        self.process_pointer_event(PointerEvent::Enter(PointerInfo {
-            position: vello::kurbo::Point::new(100.0, 100.0),
-        }));
+    /// Take (and clear) the ordered handler log.
+    pub fn take_handler_records(&mut self) -> Vec<HandlerInvocation> {
+        self.recorder.take_handler_log()
     }
 
-    /// Simulate a mouse click on a widget.
-    pub fn mouse_click_on(&mut self, _widget: Gc<Component>) {
-        let pos = vello::kurbo::Point::new(100.0, 100.0);
-        self.process_pointer_event(PointerEvent::Down(PointerButtonEvent {
-            button: rvue::event::types::PointerButton::Primary,
-            position: pos,
-            click_count: 1,
+    // === Mouse Events ===
+
+    /// Simulate a mouse move to the center of a widget.
+    pub fn mouse_move_to(&mut self, widget: Gc<Component>) {
+        let position = self.widget_center(&widget);
+        self.dispatch_pointer_event(PointerEvent::Move(PointerMoveEvent {
+            position,
+            delta: Vec2::ZERO,
             modifiers: rvue::event::types::Modifiers::default(),
         }));
-        self.process_pointer_event(PointerEvent::Up(PointerButtonEvent {
-            button: rvue::event::types::PointerButton::Primary,
-            position: pos,
-            click_count: 1,
+    }
+
+    /// Simulate a mouse click on the center of a widget.
+    pub fn mouse_click_on(&mut self, widget: Gc<Component>) {
+        self.mouse_move_to(widget);
+        self.mouse_button_press(rvue::event::types::PointerButton::Primary);
+        self.mouse_button_release(rvue::event::types::PointerButton::Primary);
+    }
+
+    /// Simulate a mouse move to a position in window coordinates.
+    pub fn mouse_move_at(&mut self, position: Point) {
+        let delta = position - self.pointer_position();
+        self.dispatch_pointer_event(PointerEvent::Move(PointerMoveEvent {
+            position,
+            delta,
             modifiers: rvue::event::types::Modifiers::default(),
         }));
     }
 
-    /// Simulate a mouse double click on a widget.
-    pub fn mouse_double_click_on(&mut self, _widget: Gc<Component>) {
-        let pos = vello::kurbo::Point::new(100.0, 100.0);
-        for _ in 0..2 {
-            self.process_pointer_event(PointerEvent::Down(PointerButtonEvent {
-                button: rvue::event::types::PointerButton::Primary,
-                position: pos,
-                click_count: 2,
-                modifiers: rvue::event::types::Modifiers::default(),
-            }));
-            self.process_pointer_event(PointerEvent::Up(PointerButtonEvent {
-                button: rvue::event::types::PointerButton::Primary,
-                position: pos,
-                click_count: 2,
-                modifiers: rvue::event::types::Modifiers::default(),
-            }));
+    /// Simulate a mouse click at a position in window coordinates.
+    pub fn mouse_click_at(&mut self, position: Point) {
+        self.mouse_move_at(position);
+        self.mouse_button_press(rvue::event::types::PointerButton::Primary);
+        self.mouse_button_release(rvue::event::types::PointerButton::Primary);
+    }
+
+    /// Simulate a mouse double click on the center of a widget.
+    pub fn mouse_double_click_on(&mut self, widget: Gc<Component>) {
+        self.mouse_move_to(widget);
+        for click_count in 1..=2 {
+            let event = self.button_event(rvue::event::types::PointerButton::Primary, click_count);
+            self.dispatch_pointer_event(PointerEvent::Down(event.clone()));
+            self.dispatch_pointer_event(PointerEvent::Up(event));
         }
     }
 
-    /// Simulate a mouse button press.
+    /// Simulate a mouse button press at the current pointer position.
     pub fn mouse_button_press(&mut self, button: rvue::event::types::PointerButton) {
-        let pos = vello::kurbo::Point::new(100.0, 100.0);
-        self.process_pointer_event(PointerEvent::Down(PointerButtonEvent {
-            button,
-            position: pos,
-            click_count: 1,
-            modifiers: rvue::event::types::Modifiers::default(),
-        }));
+        let event = self.button_event(button, 1);
+        self.dispatch_pointer_event(PointerEvent::Down(event));
     }
 
-    /// Simulate a mouse button release.
+    /// Simulate a mouse button release at the current pointer position.
     pub fn mouse_button_release(&mut self, button: rvue::event::types::PointerButton) {
-        let pos = vello::kurbo::Point::new(100.0, 100.0);
-        self.process_pointer_event(PointerEvent::Up(PointerButtonEvent {
+        let event = self.button_event(button, 1);
+        self.dispatch_pointer_event(PointerEvent::Up(event));
+    }
+
+    fn button_event(
+        &self,
+        button: rvue::event::types::PointerButton,
+        click_count: u32,
+    ) -> PointerButtonEvent {
+        PointerButtonEvent {
             button,
-            position: pos,
-            click_count: 1,
+            position: self.pointer_position(),
+            click_count,
             modifiers: rvue::event::types::Modifiers::default(),
-        }));
+        }
+    }
+
+    /// Where the pointer is, or where the harness has always pressed before it first moved.
+    fn pointer_position(&self) -> Point {
+        self.app_state.last_pointer_pos.unwrap_or(Point::new(100.0, 100.0))
+    }
+
+    /// Window position of the center of `widget`, following scroll offsets.
+    fn widget_center(&mut self, widget: &Gc<Component>) -> Point {
+        self.ensure_layout(widget);
+        let Some(info) = self.get_layout_info(widget) else {
+            return self.pointer_position();
+        };
+        let local = window_to_local(widget, Point::ZERO);
+        Point::new(-local.x + info.size.0 / 2.0, -local.y + info.size.1 / 2.0)
     }
 
     /// Simulate a mouse drag from one widget to another.
@@ -209,6 +279,11 @@ impl TestHarness {
         self.mouse_button_release(rvue::event::types::PointerButton::Primary);
     }
 
+    /// Get the widget holding the pointer capture, e.g. the one pressed or being dragged.
+    pub fn pointer_capture(&self) -> Option<Gc<Component>> {
+        self.app_state.pointer_capture.borrow().clone()
+    }
+
     // === Scroll Events ===
 
     /// Simulate a scroll wheel event at a widget.
@@ -247,13 +322,27 @@ impl TestHarness {
             target.set_scroll_state(new_state);
         }
 
-        self.process_pointer_event(PointerEvent::Scroll(PointerScrollEvent {
+        self.record_pointer_event(PointerEvent::Scroll(PointerScrollEvent {
             delta,
             position: pos,
             modifiers: rvue::event::types::Modifiers::default(),
         }));
     }
 
+    /// Simulate turning the mouse wheel over the center of a widget.
+    ///
+    /// Unlike [`Self::scroll_wheel_at`], the event runs through dispatch, so it reaches
+    /// whatever widget is under the pointer.
+    pub fn mouse_wheel_over(&mut self, widget: Gc<Component>, delta: ScrollDelta) {
+        self.mouse_move_to(widget);
+        let position = self.pointer_position();
+        self.dispatch_pointer_event(PointerEvent::Scroll(PointerScrollEvent {
+            delta,
+            position,
+            modifiers: rvue::event::types::Modifiers::default(),
+        }));
+    }
+
     /// Find scroll container for a widget by traversing the tree.
     fn find_scroll_container_for(&self, widget: &Gc<Component>) -> Option<Gc<Component>> {
         self.find_scroll_container_recursive(&self.root_component, widget)
@@ -315,15 +404,131 @@ impl TestHarness {
 
     // === Focus Events ===
 
-    /// Set focus to a specific widget.
-    pub fn focus_on(&mut self, _widget: Option<Gc<Component>>) {}
+    /// Set focus to a specific widget, or clear it with `None`.
+    pub fn focus_on(&mut self, widget: Option<Gc<Component>>) {
+        match widget {
+            Some(widget) => self.app_state.pending_focus = Some(widget),
+            None => self.blur_focus(),
+        }
+        run_update_focus_pass(&mut self.app_state);
+    }
 
     /// Clear focus.
-    pub fn blur_focus(&mut self) {}
+    pub fn blur_focus(&mut self) {
+        if let Some(focused) = self.app_state.focused.clone() {
+            focused.blur();
+        }
+        run_update_focus_pass(&mut self.app_state);
+    }
+
+    /// Get the currently focused widget.
+    pub fn focused_widget(&self) -> Option<Gc<Component>> {
+        self.app_state.focused.clone()
+    }
+
+    // === Keyboard Events ===
+
+    /// Focus a widget and type `text` into it, one key press per character.
+    pub fn type_text(&mut self, widget: Gc<Component>, text: &str) {
+        self.focus_on(Some(widget));
+        for ch in text.chars() {
+            self.key_combo(Modifiers::default(), Key::Character(ch.to_string().into()));
+        }
+    }
+
+    /// Focus a widget and press and release a key, e.g. `Key::Named(NamedKey::Backspace)`.
+    pub fn press_key(&mut self, widget: Gc<Component>, key: Key) {
+        self.focus_on(Some(widget));
+        self.key_combo(Modifiers::default(), key);
+    }
+
+    /// Press and release a key with modifiers held, sent to the focused widget.
+    pub fn key_combo(&mut self, modifiers: Modifiers, key: Key) {
+        self.process_text_event(TextEvent::Keyboard(key_event(
+            key.clone(),
+            KeyState::Down,
+            modifiers,
+        )));
+        self.process_text_event(TextEvent::Keyboard(key_event(key, KeyState::Up, modifiers)));
+    }
+
+    /// Commit composed text to the focused widget, as an input method would.
+    pub fn ime_commit(&mut self, text: &str) {
+        self.process_text_event(TextEvent::Ime(ImeEvent::Commit(text.to_string())));
+    }
+
+    /// Paste `text` into the focused widget, as the platform's paste command would.
+    pub fn paste_text(&mut self, text: &str) {
+        self.process_text_event(TextEvent::Paste(text.to_string()));
+    }
+
+    /// Get the text most recently copied with Ctrl+C into the app's copy buffer.
+    pub fn copied_text(&self) -> Option<String> {
+        self.app_state.copy_buffer.clone()
+    }
+
+    // === Time ===
+
+    /// Advance the virtual clock by `duration`, firing due `spawn_interval` and
+    /// `spawn_debounced` timers in order, then settle the UI queue and effects.
+    pub fn advance_time(&mut self, duration: Duration) {
+        rvue::headless::advance_time(duration);
+    }
+
+    /// Run one frame, [`FRAME_INTERVAL`] after the previous one, as the app loop does.
+    ///
+    /// Animations such as Show transitions and spinners step forward by exactly one frame,
+    /// focus requested with `Component::focus` moves, a press held long enough fires its long
+    /// press, and layout is recomputed. Async timers follow `advance_time` instead.
+    pub fn advance(&mut self) {
+        self.frame_time += FRAME_INTERVAL;
+        self.root_component.update_at(self.frame_time);
+        run_update_focus_pass(&mut self.app_state);
+        check_long_press(&mut self.app_state, self.frame_time);
+        if self.app_state.needs_pointer_pass_update {
+            run_update_pointer_pass(&mut self.app_state);
+            self.app_state.needs_pointer_pass_update = false;
+        }
+        self.flush_dispatch_log();
+        self.compute_layout();
+    }
 
     // === Process Events ===
 
-    fn process_pointer_event(&mut self, event: PointerEvent) {
+    /// Move the pointer to the event's position and run the event through dispatch.
+    fn dispatch_pointer_event(&mut self, event: PointerEvent) {
+        self.record_pointer_event(event.clone());
+        if let Some(position) = event.position() {
+            self.app_state.last_pointer_pos = Some(position);
+            self.app_state.hovered_component = hit_test_with_stylesheet(
+                &self.root_component,
+                position,
+                self.app_state.stylesheet.as_ref(),
+            );
+        }
+        run_pointer_event_pass(&mut self.app_state, &event);
+        // Time presses on the frame clock, so a long press takes the same frames every run
+        if let (PointerEvent::Down(_), Some(long_press)) =
+            (&event, self.app_state.long_press_state.as_mut())
+        {
+            long_press.pressed_at = self.frame_time;
+        }
+        // Apply hover and active changes, as the app does after each event
+        if self.app_state.needs_pointer_pass_update {
+            run_update_pointer_pass(&mut self.app_state);
+            self.app_state.needs_pointer_pass_update = false;
+        }
+        self.flush_dispatch_log();
+    }
+
+    fn flush_dispatch_log(&mut self) {
+        for invocation in self.dispatch_log.borrow_mut().drain(..) {
+            self.recorder.record_handler(invocation);
+        }
+    }
+
+    /// Log a pointer event on the root widget, without dispatching it.
+    fn record_pointer_event(&mut self, event: PointerEvent) {
         self.recorder.record(
             self.root_component.id as u32,
             RecordedEvent::PointerEvent(crate::PointerRecord {
@@ -342,6 +547,13 @@ impl TestHarness {
         );
     }
 
+    fn process_text_event(&mut self, event: TextEvent) {
+        run_text_event_pass(&mut self.app_state, &event);
+        // Apply focus moved by Tab or by handlers
+        run_update_focus_pass(&mut self.app_state);
+        self.flush_dispatch_log();
+    }
+
     // === Snapshot Testing ===
 
     /// Assert that the current render matches an existing snapshot.
@@ -446,11 +658,25 @@ impl TestHarness {
         let size =
             vello::kurbo::Size { width: self.window_size.width, height: self.window_size.height };
 
-        rvue::component::compute_layout_for_testing(&self.root_component, size);
+        rvue::component::compute_layout_for_testing_with_stylesheet(
+            &self.root_component,
+            size,
+            self.app_state.stylesheet.as_ref(),
+        );
     }
 
     // === Debug Methods ===
 
+    /// Dump the component tree as stable, indented text for snapshot assertions.
+    ///
+    /// Each line holds the component type, `#element-id`, sorted `.classes`, key properties
+    /// and the rect in window coordinates. Layout is computed first if needed.
+    pub fn debug_tree(&mut self) -> String {
+        let root = Gc::clone(&self.root_component);
+        self.ensure_layout(&root);
+        dump_tree(&root)
+    }
+
     /// Debug print the complete scroll state for a widget.
     pub fn debug_scroll_state(&self, widget: &Gc<Component>, label: &str) {
         let state = widget.scroll_state();
@@ -508,6 +734,50 @@ impl TestHarness {
         })
     }
 
+    /// Get a widget's border box in window coordinates, ignoring scroll offsets.
+    ///
+    /// Layout is computed first if the widget has no layout yet or the tree is dirty.
+    pub fn get_layout(&mut self, widget: &Gc<Component>) -> Option<Rect> {
+        self.ensure_layout(widget);
+
+        let origin = Self::window_origin(&self.root_component, widget, (0.0, 0.0))?;
+        let info = self.get_layout_info(widget)?;
+        Some(Rect::from_origin_size(origin, info.size))
+    }
+
+    fn ensure_layout(&mut self, widget: &Gc<Component>) {
+        let has_layout = widget.layout_node().is_some_and(|node| node.layout_result.is_some());
+        if !has_layout || self.root_component.is_dirty() {
+            self.compute_layout();
+        }
+    }
+
+    /// Sum layout locations along the path from `component` down to `target`.
+    fn window_origin(
+        component: &Gc<Component>,
+        target: &Gc<Component>,
+        offset: (f64, f64),
+    ) -> Option<(f64, f64)> {
+        let (x, y) = component.layout_position().unwrap_or((0.0, 0.0));
+        let origin = (offset.0 + x, offset.1 + y);
+        if Gc::ptr_eq(component, target) {
+            return Some(origin);
+        }
+        component
+            .children
+            .borrow()
+            .iter()
+            .find_map(|child| Self::window_origin(child, target, origin))
+    }
+
+    /// Get a widget's resolved styles: inline styles merged over the harness stylesheet.
+    pub fn get_computed_styles(&self, widget: &Gc<Component>) -> ComputedStyles {
+        match &self.app_state.stylesheet {
+            Some(sheet) => resolve_styles_for_component(widget, sheet),
+            None => widget.widget_styles().unwrap_or_default(),
+        }
+    }
+
     /// Get all layout info for a widget and its children.
     pub fn get_layout_info_tree(&self, widget: &Gc<Component>) -> Vec<(String, LayoutInfo)> {
         self._get_layout_info_recursive(widget)
@@ -533,5 +803,43 @@ impl TestHarness {
     }
 }
 
+impl Drop for TestHarness {
+    fn drop(&mut self) {
+        set_dispatch_observer(None);
+    }
+}
+
+/// Pre-order walk of the live tree under `component`, stopping at the first match if `first_only`.
+fn collect_widgets(
+    component: &Gc<Component>,
+    predicate: &dyn Fn(&Gc<Component>) -> bool,
+    first_only: bool,
+    found: &mut Vec<Gc<Component>>,
+) {
+    if predicate(component) {
+        found.push(Gc::clone(component));
+        if first_only {
+            return;
+        }
+    }
+    for child in component.children.borrow().iter() {
+        if first_only && !found.is_empty() {
+            return;
+        }
+        collect_widgets(child, predicate, first_only, found);
+    }
+}
+
+fn key_event(key: Key, state: KeyState, modifiers: Modifiers) -> KeyboardEvent {
+    KeyboardEvent {
+        key,
+        code: PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+        state,
+        modifiers,
+        repeat: false,
+    }
+}
+
 // Re-export types for easier use
-pub use rvue::event::types::PointerButton;
+pub use rvue::event::types::{Modifiers, PointerButton};
+pub use winit::keyboard::{Key, NamedKey};
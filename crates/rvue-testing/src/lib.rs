@@ -8,8 +8,8 @@
 //!
 //! - [`TestHarness`]: A headless environment for testing rvue applications
 //! - Event simulation: Mouse, keyboard, scroll, and focus events
-//! - Snapshot testing: Render and compare screenshots
-//! - Event recording: Track widget lifecycle events
+//! - Snapshot testing: Render and compare screenshots, or dump the tree as text
+//! - Event recording: Track widget lifecycle events and the order handlers ran in
 //!
 //! # Example
 //!
@@ -32,6 +32,8 @@
 //! }
 //! ```
 
+mod app_state;
+mod debug_tree;
 mod event_recorder;
 mod harness;
 mod snapshot;
@@ -41,6 +43,8 @@ mod test_widget;
 mod macros;
 
 pub use event_recorder::{EventRecorder, PointerEventType, PointerRecord, RecordedEvent};
-pub use harness::{PointerButton, TestHarness, TestHarnessParams};
-pub use snapshot::{SnapshotError, SnapshotManager, SnapshotOptions};
+pub use harness::{
+    Key, Modifiers, NamedKey, PointerButton, TestHarness, TestHarnessParams, FRAME_INTERVAL,
+};
+pub use snapshot::{compare_images, ImageDiff, SnapshotError, SnapshotManager, SnapshotOptions};
 pub use test_widget::TestWidgetBuilder;
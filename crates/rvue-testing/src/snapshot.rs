@@ -16,6 +16,15 @@ pub enum SnapshotError {
     #[error("Snapshot mismatch: {0}")]
     Mismatch(PathBuf),
 
+    #[error(
+        "Snapshot differs from {path}: {:.2}% of pixels over tolerance \
+         (max channel delta {}, mean channel delta {:.2})",
+        .diff.differing_percent(),
+        .diff.max_channel_delta,
+        .diff.mean_channel_delta
+    )]
+    ExceedsTolerance { path: PathBuf, diff: ImageDiff },
+
     #[error("Failed to save snapshot: {0}")]
     SaveError(#[from] std::io::Error),
 
@@ -29,15 +38,109 @@ pub enum SnapshotError {
 /// Options for snapshot testing.
 #[derive(Debug, Clone)]
 pub struct SnapshotOptions {
+    /// Largest per-channel difference at which a pixel still counts as matching.
     pub tolerance: u8,
+    /// Percentage (0-100) of pixels allowed to exceed `tolerance` before the snapshot fails.
+    pub max_diff_percent: f64,
     pub padding: u32,
     pub background_color: [u8; 4],
 }
 
 impl Default for SnapshotOptions {
     fn default() -> Self {
-        Self { tolerance: 16, padding: 0, background_color: [0x29, 0x29, 0x29, 0xFF] }
+        Self {
+            tolerance: 16,
+            max_diff_percent: 0.0,
+            padding: 0,
+            background_color: [0x29, 0x29, 0x29, 0xFF],
+        }
+    }
+}
+
+/// Statistics from comparing two images of the same size.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageDiff {
+    /// Largest absolute difference of any channel of any pixel.
+    pub max_channel_delta: u8,
+    /// Mean absolute channel difference over all channels of all pixels.
+    pub mean_channel_delta: f64,
+    /// Number of pixels with a channel difference above the tolerance.
+    pub differing_pixels: u64,
+    pub total_pixels: u64,
+}
+
+impl ImageDiff {
+    /// Percentage (0-100) of pixels above the tolerance.
+    pub fn differing_percent(&self) -> f64 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+        self.differing_pixels as f64 * 100.0 / self.total_pixels as f64
+    }
+}
+
+/// Compare two images pixel by pixel, counting pixels with a channel delta above `tolerance`.
+///
+/// Returns `None` if the images have different dimensions.
+pub fn compare_images(
+    expected: &DynamicImage,
+    actual: &DynamicImage,
+    tolerance: u8,
+) -> Option<ImageDiff> {
+    let expected = expected.to_rgba8();
+    let actual = actual.to_rgba8();
+
+    if expected.dimensions() != actual.dimensions() {
+        return None;
     }
+
+    let mut max_channel_delta = 0u8;
+    let mut channel_delta_sum = 0u64;
+    let mut differing_pixels = 0u64;
+
+    for (e, a) in expected.pixels().zip(actual.pixels()) {
+        let pixel_delta = pixel_delta(e, a);
+        max_channel_delta = max_channel_delta.max(pixel_delta);
+        channel_delta_sum += (0..4).map(|c| e[c].abs_diff(a[c]) as u64).sum::<u64>();
+        if pixel_delta > tolerance {
+            differing_pixels += 1;
+        }
+    }
+
+    let total_pixels = expected.width() as u64 * expected.height() as u64;
+    let mean_channel_delta =
+        if total_pixels == 0 { 0.0 } else { channel_delta_sum as f64 / (total_pixels * 4) as f64 };
+
+    Some(ImageDiff { max_channel_delta, mean_channel_delta, differing_pixels, total_pixels })
+}
+
+fn pixel_delta(e: &Rgba<u8>, a: &Rgba<u8>) -> u8 {
+    (0..4).map(|c| e[c].abs_diff(a[c])).max().unwrap_or(0)
+}
+
+/// Build an image highlighting pixels that differ by more than `tolerance`.
+///
+/// Pixels over the tolerance are red, pixels that differ within the tolerance are yellow, and
+/// matching pixels are a faded copy of `expected`.
+fn diff_image(
+    expected: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    actual: &ImageBuffer<Rgba<u8>, Vec<u8>>,
+    tolerance: u8,
+) -> DynamicImage {
+    let (width, height) = expected.dimensions();
+    let mut diff = ImageBuffer::new(width, height);
+
+    for (x, y, pixel) in diff.enumerate_pixels_mut() {
+        let e = expected.get_pixel(x, y);
+        let a = actual.get_pixel(x, y);
+        *pixel = match pixel_delta(e, a) {
+            0 => Rgba([e[0] / 4 + 96, e[1] / 4 + 96, e[2] / 4 + 96, 255]),
+            delta if delta <= tolerance => Rgba([255, 255, 0, 255]),
+            _ => Rgba([255, 0, 0, 255]),
+        };
+    }
+
+    DynamicImage::ImageRgba8(diff)
 }
 
 /// Snapshot manager for handling render snapshots.
@@ -95,6 +198,10 @@ impl SnapshotManager {
         image::open(&path).map_err(SnapshotError::ImageError)
     }
 
+    /// Compare `actual` against the golden image `name`.
+    ///
+    /// On failure the actual image is written next to the golden as `<name>.new.png` and a
+    /// diff highlighting the changed pixels as `<name>.diff.png`.
     pub fn compare(&self, name: &str, actual: &DynamicImage) -> Result<(), SnapshotError> {
         let expected = match self.load(name) {
             Ok(img) => img,
@@ -105,53 +212,21 @@ impl SnapshotManager {
             Err(e) => return Err(e),
         };
 
-        let expected: ImageBuffer<Rgba<u8>, Vec<u8>> = expected.to_rgba8();
-        let actual_rgba: ImageBuffer<Rgba<u8>, Vec<u8>> = actual.to_rgba8();
-
-        if expected.dimensions() != actual_rgba.dimensions() {
+        let Some(diff) = compare_images(&expected, actual, self.options.tolerance) else {
             self.save_new(name, actual)?;
             return Err(SnapshotError::Mismatch(self.snapshot_path(name)));
-        }
+        };
 
-        let (width, height) = expected.dimensions();
-        let tolerance = self.options.tolerance as i32;
-
-        for y in 0..height {
-            for x in 0..width {
-                let expected_pixel = expected.get_pixel(x, y);
-                let actual_pixel = actual_rgba.get_pixel(x, y);
-
-                let diff_r = (expected_pixel[0] as i32 - actual_pixel[0] as i32).abs();
-                let diff_g = (expected_pixel[1] as i32 - actual_pixel[1] as i32).abs();
-                let diff_b = (expected_pixel[2] as i32 - actual_pixel[2] as i32).abs();
-                let diff_a = (expected_pixel[3] as i32 - actual_pixel[3] as i32).abs();
-
-                if diff_r > tolerance
-                    || diff_g > tolerance
-                    || diff_b > tolerance
-                    || diff_a > tolerance
-                {
-                    let mut diff = ImageBuffer::new(width, height);
-                    for y in 0..height {
-                        for x in 0..width {
-                            let e = expected.get_pixel(x, y);
-                            let a = actual_rgba.get_pixel(x, y);
-                            let pixel = if e == a {
-                                Rgba([128u8, 128u8, 128u8, 128u8])
-                            } else {
-                                Rgba([255u8, 0u8, 0u8, 255u8])
-                            };
-                            diff.put_pixel(x, y, pixel);
-                        }
-                    }
-                    let diff_image = DynamicImage::ImageRgba8(diff);
-                    diff_image.save(self.diff_path(name)).map_err(std::io::Error::other)?;
-                    return Err(SnapshotError::Mismatch(self.snapshot_path(name)));
-                }
-            }
+        if diff.differing_percent() > self.options.max_diff_percent {
+            let image =
+                diff_image(&expected.to_rgba8(), &actual.to_rgba8(), self.options.tolerance);
+            image.save(self.diff_path(name)).map_err(std::io::Error::other)?;
+            self.save_new(name, actual)?;
+            return Err(SnapshotError::ExceedsTolerance { path: self.snapshot_path(name), diff });
         }
 
         let _ = std::fs::remove_file(self.diff_path(name));
+        let _ = std::fs::remove_file(self.new_snapshot_path(name));
         Ok(())
     }
 
@@ -169,21 +244,5 @@ pub fn generate_diff(expected: &DynamicImage, actual: &DynamicImage) -> Option<D
         return None;
     }
 
-    let (width, height) = expected.dimensions();
-    let mut diff = ImageBuffer::new(width, height);
-
-    for y in 0..height {
-        for x in 0..width {
-            let e = expected.get_pixel(x, y);
-            let a = actual.get_pixel(x, y);
-            let pixel = if e == a {
-                Rgba([128u8, 128u8, 128u8, 128u8])
-            } else {
-                Rgba([255u8, 0u8, 0u8, 255u8])
-            };
-            diff.put_pixel(x, y, pixel);
-        }
-    }
-
-    Some(DynamicImage::ImageRgba8(diff))
+    Some(diff_image(&expected, &actual, 0))
 }
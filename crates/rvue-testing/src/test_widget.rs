@@ -165,8 +165,15 @@ impl TestWidgetBuilder {
             component.set_widget_styles(styles);
         }
 
+        if self.scroll_offset_x != 0.0 || self.scroll_offset_y != 0.0 {
+            let mut scroll_state = component.scroll_state();
+            scroll_state.scroll_offset_x = self.scroll_offset_x;
+            scroll_state.scroll_offset_y = self.scroll_offset_y;
+            component.set_scroll_state(scroll_state);
+        }
+
         for child in self.children {
-            component.add_child(Gc::clone(&child));
+            component.append_child(child);
         }
 
         component
@@ -191,8 +198,15 @@ impl TestWidgetBuilder {
             component.set_widget_styles(styles);
         }
 
+        if self.scroll_offset_x != 0.0 || self.scroll_offset_y != 0.0 {
+            let mut scroll_state = component.scroll_state();
+            scroll_state.scroll_offset_x = self.scroll_offset_x;
+            scroll_state.scroll_offset_y = self.scroll_offset_y;
+            component.set_scroll_state(scroll_state);
+        }
+
         for child in self.children {
-            component.add_child(Gc::clone(&child));
+            component.append_child(child);
         }
 
         component
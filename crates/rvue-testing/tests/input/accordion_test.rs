@@ -0,0 +1,104 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for expanding and collapsing accordions.
+
+use rudo_gc::Gc;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::widgets::accordion::AccordionState;
+use rvue::widgets::Transition;
+use rvue::{Accordion, AccordionGroup, TaffyTree, Text};
+use rvue_testing::{TestHarness, TestWidgetBuilder, FRAME_INTERVAL};
+
+/// Accordions built from `accordions` inside a 300x300 root.
+fn mount(accordions: Vec<Accordion>) -> (TestHarness, Vec<AccordionState>) {
+    let root = TestWidgetBuilder::new().with_size(300.0, 300.0).build();
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let states: Vec<_> = accordions
+        .into_iter()
+        .map(|accordion| {
+            let state = accordion.build(&mut ctx);
+            state.mount(Some(Gc::clone(&root)));
+            state
+        })
+        .collect();
+
+    (TestHarness::create(root), states)
+}
+
+fn section(title: &'static str) -> Accordion {
+    Accordion::new(title, move |ctx| {
+        Gc::clone(Text::new(format!("{title} body")).build(ctx).component())
+    })
+}
+
+/// Test that clicking the header expands the body and clicking again collapses it.
+#[test]
+fn test_header_click_toggles_body() {
+    let (mut harness, states) = mount(vec![section("Details")]);
+    let accordion = &states[0];
+    assert!(!accordion.is_open());
+    assert!(!accordion.body().show_content_visible());
+
+    harness.mouse_click_on(Gc::clone(accordion.header()));
+    assert!(accordion.is_open());
+    assert!(accordion.body().show_content_visible());
+    assert!(accordion.component().has_class("open"));
+
+    harness.mouse_click_on(Gc::clone(accordion.header()));
+    assert!(!accordion.is_open());
+    assert!(!accordion.body().show_content_visible());
+    assert!(!accordion.component().has_class("open"));
+}
+
+/// Test that opening one accordion of a group closes the one that was open.
+#[test]
+fn test_exclusive_group_keeps_one_open() {
+    let group = AccordionGroup::new();
+    let (mut harness, states) = mount(vec![
+        section("First").group(&group).open(true),
+        section("Second").group(&group),
+        section("Third"),
+    ]);
+    assert!(states[0].is_open());
+    assert!(!states[1].is_open());
+
+    // The ungrouped accordion opens without affecting the group
+    harness.mouse_click_on(Gc::clone(states[2].header()));
+    assert!(states[0].is_open());
+    assert!(states[2].is_open());
+
+    harness.mouse_click_on(Gc::clone(states[1].header()));
+    assert!(!states[0].is_open());
+    assert!(states[1].is_open());
+    assert!(states[2].is_open());
+
+    // Closing the open member leaves the whole group closed
+    harness.mouse_click_on(Gc::clone(states[1].header()));
+    assert!(!states[0].is_open());
+    assert!(!states[1].is_open());
+}
+
+/// Test that an animated body stays visible until its collapse transition finishes.
+#[test]
+fn test_animated_body_collapses_over_transition() {
+    let (mut harness, states) = mount(vec![section("Details").open(true).animated(true)]);
+    let accordion = &states[0];
+    let duration = Transition::DEFAULT_DURATION;
+
+    harness.mouse_click_on(Gc::clone(accordion.header()));
+    assert!(!accordion.is_open());
+    harness.advance();
+    assert!(accordion.body().show_content_visible());
+
+    let frames = duration.as_millis().div_ceil(FRAME_INTERVAL.as_millis()) + 1;
+    for _ in 0..frames {
+        harness.advance();
+    }
+    assert!(!accordion.body().show_content_visible());
+}
@@ -0,0 +1,87 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for activating focused controls from the keyboard.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue_testing::{Key, NamedKey, TestHarness, TestWidgetBuilder};
+
+fn mount(control: &Gc<Component>) -> TestHarness {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    root.add_child(Gc::clone(control));
+    control.set_parent(Some(Gc::clone(&root)));
+    TestHarness::create(root)
+}
+
+/// Test that Space and Enter both press a focused button.
+#[test]
+fn test_space_and_enter_click_focused_button() {
+    let button = Component::with_global_id(ComponentType::Button, PropertyMap::new());
+    let clicks = Rc::new(Cell::new(0));
+    let clicks_clone = Rc::clone(&clicks);
+    button.on_click_0arg(move || clicks_clone.set(clicks_clone.get() + 1));
+    let mut harness = mount(&button);
+
+    harness.press_key(Gc::clone(&button), Key::Named(NamedKey::Space));
+    assert_eq!(clicks.get(), 1);
+
+    harness.press_key(Gc::clone(&button), Key::Named(NamedKey::Enter));
+    assert_eq!(clicks.get(), 2);
+
+    // Other keys do not activate the button
+    harness.press_key(Gc::clone(&button), Key::Character("a".into()));
+    assert_eq!(clicks.get(), 2);
+}
+
+/// Test that Space toggles a focused checkbox and reports the new state.
+#[test]
+fn test_space_toggles_focused_checkbox() {
+    let checkbox = Component::with_global_id(ComponentType::Checkbox, PropertyMap::new());
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let changes_clone = Rc::clone(&changes);
+    checkbox.on_change_1arg(move |e| changes_clone.borrow_mut().push(e.checked));
+    let mut harness = mount(&checkbox);
+
+    harness.press_key(Gc::clone(&checkbox), Key::Named(NamedKey::Space));
+    assert!(checkbox.checkbox_checked());
+
+    harness.press_key(Gc::clone(&checkbox), Key::Named(NamedKey::Space));
+    assert!(!checkbox.checkbox_checked());
+    assert_eq!(*changes.borrow(), vec![true, false]);
+
+    // Enter is reserved for buttons
+    harness.press_key(Gc::clone(&checkbox), Key::Named(NamedKey::Enter));
+    assert!(!checkbox.checkbox_checked());
+}
+
+/// Test that Space resolves a mixed checkbox to checked.
+#[test]
+fn test_space_resolves_indeterminate_checkbox() {
+    let checkbox = Component::with_global_id(ComponentType::Checkbox, PropertyMap::new());
+    checkbox.set_checkbox_indeterminate(true);
+    let mut harness = mount(&checkbox);
+
+    harness.press_key(Gc::clone(&checkbox), Key::Named(NamedKey::Space));
+    assert!(!checkbox.checkbox_indeterminate());
+    assert!(checkbox.checkbox_checked());
+}
+
+/// Test that Space selects a focused radio without unselecting it again.
+#[test]
+fn test_space_selects_focused_radio() {
+    let radio = Component::with_global_id(ComponentType::Radio, PropertyMap::new());
+    let changes = Rc::new(Cell::new(0));
+    let changes_clone = Rc::clone(&changes);
+    radio.on_change_0arg(move || changes_clone.set(changes_clone.get() + 1));
+    let mut harness = mount(&radio);
+
+    harness.press_key(Gc::clone(&radio), Key::Named(NamedKey::Space));
+    harness.press_key(Gc::clone(&radio), Key::Named(NamedKey::Space));
+    assert!(radio.radio_checked());
+    assert_eq!(changes.get(), 1);
+}
@@ -0,0 +1,90 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for dragging a component onto a drop target.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue_testing::{PointerButton, TestHarness, TestWidgetBuilder};
+use vello::kurbo::Point;
+
+/// Test that the drop target receives the payload set when the drag started.
+#[test]
+fn test_drop_target_receives_drag_payload() {
+    let source = TestWidgetBuilder::new().with_size(100.0, 40.0).build();
+    let target = TestWidgetBuilder::new().with_size(100.0, 100.0).build();
+    let root = TestWidgetBuilder::new()
+        .with_size(400.0, 300.0)
+        .with_child(Gc::clone(&source))
+        .with_child(Gc::clone(&target))
+        .build();
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let events_clone = Rc::clone(&events);
+    source.on_drag_start(move |_, ctx| {
+        ctx.set_drag_payload(String::from("card-7"));
+        events_clone.borrow_mut().push("start".to_string());
+    });
+    let events_clone = Rc::clone(&events);
+    source.on_drag_end_0arg(move || events_clone.borrow_mut().push("end".to_string()));
+    let events_clone = Rc::clone(&events);
+    target.on_drag_over_0arg(move || events_clone.borrow_mut().push("over".to_string()));
+    let events_clone = Rc::clone(&events);
+    target.on_drop(move |e, ctx| {
+        let payload = ctx.drag_payload::<String>().cloned().unwrap_or_default();
+        events_clone.borrow_mut().push(format!("drop {} at {}", payload, e.position.x));
+    });
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+
+    harness.mouse_move_at(Point::new(20.0, 20.0));
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_move_at(Point::new(150.0, 50.0));
+    assert!(harness.pointer_capture().is_some_and(|c| Gc::ptr_eq(&c, &source)));
+    harness.mouse_button_release(PointerButton::Primary);
+
+    assert_eq!(*events.borrow(), ["start", "over", "drop card-7 at 150", "end"]);
+    assert!(harness.pointer_capture().is_none());
+
+    // The drag is over, so moving on reaches nobody's drag handlers
+    harness.mouse_move_at(Point::new(160.0, 60.0));
+    assert_eq!(events.borrow().len(), 4);
+}
+
+/// Test that a clickable inside a drag source keeps its click until the drag actually starts.
+#[test]
+fn test_nested_clickable_clicks_unless_dragged() {
+    let button = TestWidgetBuilder::new().with_size(60.0, 30.0).build();
+    let source =
+        TestWidgetBuilder::new().with_size(200.0, 100.0).with_child(Gc::clone(&button)).build();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&source)).build();
+
+    let clicks = Rc::new(Cell::new(0));
+    let clicks_clone = Rc::clone(&clicks);
+    button.on_click_0arg(move || clicks_clone.set(clicks_clone.get() + 1));
+    let drags = Rc::new(Cell::new(0));
+    let drags_clone = Rc::clone(&drags);
+    source.on_drag_start_0arg(move || drags_clone.set(drags_clone.get() + 1));
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+
+    // A press and release without moving clicks the button
+    harness.mouse_click_at(Point::new(20.0, 15.0));
+    assert_eq!(clicks.get(), 1);
+    assert_eq!(drags.get(), 0);
+
+    // Moving past the threshold drags the source instead
+    harness.mouse_move_at(Point::new(20.0, 15.0));
+    harness.mouse_button_press(PointerButton::Primary);
+    assert!(harness.pointer_capture().is_some_and(|c| Gc::ptr_eq(&c, &button)));
+    harness.mouse_move_at(Point::new(120.0, 80.0));
+    assert_eq!(drags.get(), 1);
+    assert!(harness.pointer_capture().is_some_and(|c| Gc::ptr_eq(&c, &source)));
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(clicks.get(), 1);
+}
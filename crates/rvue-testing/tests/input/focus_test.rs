@@ -0,0 +1,58 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for moving focus from code.
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::NodeRef;
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+fn input_in_root() -> (TestHarness, Gc<Component>) {
+    let input = Component::with_global_id(ComponentType::TextInput, PropertyMap::new());
+    input.init_text_editor("");
+    let root =
+        TestWidgetBuilder::new().with_size(200.0, 100.0).with_child(Gc::clone(&input)).build();
+    (TestHarness::create(root), input)
+}
+
+fn is_focused(harness: &TestHarness, component: &Gc<Component>) -> bool {
+    harness.focused_widget().is_some_and(|focused| Gc::ptr_eq(&focused, component))
+}
+
+/// Test that the component held by a `NodeRef` can be focused.
+#[test]
+fn test_node_ref_focuses_referenced_input() {
+    let (mut harness, input) = input_in_root();
+    let input_ref = NodeRef::new();
+    input_ref.set(Gc::clone(&input));
+
+    harness.focus_on(input_ref.get());
+
+    assert!(is_focused(&harness, &input));
+    assert!(*input.is_focused.borrow());
+}
+
+/// Test that `focus` and `blur` take effect on the next frame, and only for focusable components.
+#[test]
+fn test_focus_method_focuses_input_on_next_update_pass() {
+    let (mut harness, input) = input_in_root();
+    let root = Gc::clone(harness.root());
+
+    // Focusing a non-focusable component is a no-op
+    root.focus();
+    harness.advance();
+    assert!(harness.focused_widget().is_none());
+
+    input.focus();
+    assert!(harness.focused_widget().is_none());
+    harness.advance();
+    assert!(is_focused(&harness, &input));
+    assert!(*input.is_focused.borrow());
+
+    input.blur();
+    harness.advance();
+    assert!(harness.focused_widget().is_none());
+    assert!(!*input.is_focused.borrow());
+}
@@ -0,0 +1,96 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for submitting forms and collecting their field values.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue::prelude::*;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::widgets::FormValues;
+use rvue::{Button, Flex, Form, TaffyTree, Text, TextInput};
+use rvue_style::FlexDirection;
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+use winit::keyboard::{Key, NamedKey};
+
+/// A form holding `name` and `email` fields, the latter failing validation while `email_error`
+/// is set, and a submit button; returns the values each submit received.
+fn mount(email_error: ReadSignal<Option<String>>) -> (TestHarness, Rc<RefCell<Vec<FormValues>>>) {
+    let root = TestWidgetBuilder::new().with_size(300.0, 300.0).build();
+    let submits = Rc::new(RefCell::new(Vec::new()));
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let submits_clone = Rc::clone(&submits);
+    let form = Form::new(move |ctx| {
+        let fields = Flex::new().direction(FlexDirection::Column).build(ctx);
+        let parent = Some(Gc::clone(fields.component()));
+        TextInput::new(String::new()).name("name").build(ctx).mount(parent.clone());
+        TextInput::new(String::new())
+            .name("email")
+            .error(email_error.clone())
+            .build(ctx)
+            .mount(parent.clone());
+        let button = Button::new().id("submit").submit(true).build(ctx);
+        Text::new("Send").build(ctx).mount(Some(Gc::clone(button.component())));
+        button.mount(parent);
+        Gc::clone(fields.component())
+    })
+    .on_submit(move |values| submits_clone.borrow_mut().push(values.clone()))
+    .build(&mut ctx);
+    form.mount(Some(Gc::clone(&root)));
+
+    (TestHarness::create(root), submits)
+}
+
+/// The name and email fields of a form built by [`mount`].
+fn inputs(harness: &TestHarness) -> (Gc<Component>, Gc<Component>) {
+    let inputs = harness.root().query_selector_all("input");
+    (Gc::clone(&inputs[0]), Gc::clone(&inputs[1]))
+}
+
+/// Test that Enter in a field and the submit button both submit the values of every field.
+#[test]
+fn test_submit_collects_field_values() {
+    let (email_error, _) = create_signal(None::<String>);
+    let (mut harness, submits) = mount(email_error);
+    let (name, email) = inputs(&harness);
+
+    harness.type_text(Gc::clone(&name), "Ada");
+    harness.type_text(Gc::clone(&email), "ada@example.com");
+    harness.press_key(Gc::clone(&email), Key::Named(NamedKey::Enter));
+
+    let expected = FormValues::from([
+        ("email".to_string(), "ada@example.com".to_string()),
+        ("name".to_string(), "Ada".to_string()),
+    ]);
+    assert_eq!(*submits.borrow(), vec![expected.clone()]);
+
+    let button = harness.get_widget_by_id("submit").unwrap();
+    harness.mouse_click_on(button);
+    assert_eq!(*submits.borrow(), vec![expected.clone(), expected]);
+}
+
+/// Test that a field with a validation error blocks submitting until it is cleared.
+#[test]
+fn test_invalid_field_blocks_submit() {
+    let (email_error, set_email_error) = create_signal(Some("Required".to_string()));
+    let (mut harness, submits) = mount(email_error);
+    let (name, email) = inputs(&harness);
+
+    harness.type_text(Gc::clone(&name), "Ada");
+    harness.press_key(Gc::clone(&name), Key::Named(NamedKey::Enter));
+    harness.mouse_click_on(harness.get_widget_by_id("submit").unwrap());
+    assert!(submits.borrow().is_empty());
+
+    set_email_error.set(None);
+    harness.press_key(Gc::clone(&email), Key::Named(NamedKey::Enter));
+    assert_eq!(submits.borrow().len(), 1);
+    assert_eq!(submits.borrow()[0]["name"], "Ada");
+    assert_eq!(submits.borrow()[0]["email"], "");
+}
@@ -0,0 +1,76 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for handlers keeping what they capture alive across garbage collections.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use rudo_gc::{Gc, Weak};
+use rvue::component::Component;
+use rvue::prelude::*;
+use rvue::GcPacer;
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+/// A button whose click handler holds the only handles to a counter signal and to a marker
+/// component; returns the button, the count the handler last read and a weak handle to the
+/// marker.
+///
+/// The weak handle is boxed so the test's stack, which the collector scans conservatively, does
+/// not hold the marker's address.
+#[inline(never)]
+fn counting_button() -> (Gc<Component>, Rc<Cell<i32>>, Box<Weak<Component>>) {
+    let button = TestWidgetBuilder::new().with_size(100.0, 40.0).build();
+    let marker = TestWidgetBuilder::new().build();
+    let weak = Box::new(Gc::downgrade(&marker));
+    let (count, set_count) = create_signal(0);
+    let seen = Rc::new(Cell::new(0));
+    let seen_clone = Rc::clone(&seen);
+    button.on_click_0arg(move || {
+        set_count.update(|count| *count += 1);
+        seen_clone.set(count.get());
+        marker.mark_paint_dirty();
+    });
+    (button, seen, weak)
+}
+
+/// Overwrite the stack below the caller so stale copies of Gc pointers do not act as roots.
+#[inline(never)]
+fn scrub_stack() {
+    std::hint::black_box([0usize; 32 * 1024]);
+}
+
+/// Run a collection the way the app does between frames.
+#[inline(never)]
+fn collect_while_idle() {
+    scrub_stack();
+    rudo_gc::set_gc_enabled(false);
+    rudo_gc::set_collect_condition(|_| false);
+    let mut pacer = GcPacer::new().with_growth_threshold(0);
+    assert!(pacer.collect_if_idle(None));
+}
+
+/// Allocate signals that would reuse the memory of anything the collection freed.
+#[inline(never)]
+fn churn_heap() {
+    for value in 0..10_000 {
+        let _ = create_signal(value);
+    }
+}
+
+/// Test that a signal and a component reachable only from a click handler survive a collection.
+#[test]
+fn test_handler_captured_signal_survives_collection() {
+    let (button, seen, marker) = counting_button();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&button)).build();
+    let mut harness = TestHarness::create(root);
+
+    collect_while_idle();
+    churn_heap();
+    assert!(marker.upgrade().is_some());
+
+    harness.mouse_click_on(Gc::clone(&button));
+    harness.mouse_click_on(button);
+    assert_eq!(seen.get(), 2);
+}
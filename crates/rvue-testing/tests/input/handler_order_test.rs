@@ -0,0 +1,106 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for recording which handlers ran during dispatch, and in what order.
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::event::{DispatchPhase, DispatchedEvent, PointerEvent};
+use rvue::properties::PropertyMap;
+use rvue_style::properties::{Height, Size, Width};
+use rvue_style::ComputedStyles;
+use rvue_testing::{Key, NamedKey, RecordedEvent, TestHarness, TestWidgetBuilder};
+
+/// Root > panel > button, each listening for clicks, with the button in the panel's top-left.
+fn nested_view() -> (Gc<Component>, Gc<Component>, Gc<Component>) {
+    let button = Component::with_global_id(ComponentType::Button, PropertyMap::new());
+    button.set_widget_styles(ComputedStyles {
+        width: Some(Width(Size::Pixels(40.0))),
+        height: Some(Height(Size::Pixels(20.0))),
+        ..Default::default()
+    });
+    let panel = TestWidgetBuilder::new().with_tag("panel").with_size(100.0, 50.0).build();
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    panel.add_child(Gc::clone(&button));
+    button.set_parent(Some(Gc::clone(&panel)));
+    root.add_child(Gc::clone(&panel));
+    panel.set_parent(Some(Gc::clone(&root)));
+
+    button.on_click_0arg(|| {});
+    panel.on_click_0arg(|| {});
+    root.on_click_0arg(|| {});
+    root.on_click_capture_0arg(|| {});
+    (root, panel, button)
+}
+
+/// Test that a click is recorded capturing down at the root, then bubbling from the button up.
+#[test]
+fn test_click_records_bubbling_path() {
+    let (root, panel, button) = nested_view();
+    let mut harness = TestHarness::create(Gc::clone(&root));
+
+    harness.mouse_click_on(Gc::clone(&button));
+
+    let clicks: Vec<_> = harness
+        .take_handler_records()
+        .into_iter()
+        .filter(|record| record.handler.starts_with("click"))
+        .map(|record| (record.component_id, record.handler, record.phase))
+        .collect();
+    assert_eq!(
+        clicks,
+        vec![
+            (root.id, "click_capture", DispatchPhase::Capture),
+            (button.id, "click", DispatchPhase::Bubble),
+            (panel.id, "click", DispatchPhase::Bubble),
+            (root.id, "click", DispatchPhase::Bubble),
+        ]
+    );
+    assert!(harness.handler_records().is_empty());
+}
+
+/// Test that each record carries the dispatched event and is also filed under its widget.
+#[test]
+fn test_handler_records_snapshot_the_event() {
+    let (root, panel, button) = nested_view();
+    let mut harness = TestHarness::create(Gc::clone(&root));
+    let center = harness.get_layout(&panel).unwrap().center();
+
+    harness.mouse_click_on(Gc::clone(&panel));
+
+    let record = harness
+        .handler_records()
+        .iter()
+        .find(|record| record.component_id == panel.id && record.handler == "click")
+        .cloned()
+        .unwrap();
+    let DispatchedEvent::Pointer(PointerEvent::Up(up)) = &record.event else {
+        panic!("click should carry the pointer release, got {:?}", record.event);
+    };
+    assert_eq!(up.position, center);
+
+    let panel_records = harness.take_records(Gc::clone(&panel));
+    assert!(panel_records.iter().any(|event| matches!(
+        event,
+        RecordedEvent::HandlerInvoked(invocation) if invocation == &record
+    )));
+    assert!(harness.get_records(Gc::clone(&button)).is_empty());
+}
+
+/// Test that keyboard activation is recorded with the key that triggered it.
+#[test]
+fn test_keyboard_click_is_recorded() {
+    let (root, _panel, button) = nested_view();
+    let mut harness = TestHarness::create(Gc::clone(&root));
+
+    harness.press_key(Gc::clone(&button), Key::Named(NamedKey::Enter));
+
+    let records = harness.take_handler_records();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].component_id, button.id);
+    assert_eq!(records[0].handler, "click");
+    let DispatchedEvent::Keyboard(key) = &records[0].event else {
+        panic!("keyboard click should carry the key event, got {:?}", records[0].event);
+    };
+    assert_eq!(key.key, Key::Named(NamedKey::Enter));
+}
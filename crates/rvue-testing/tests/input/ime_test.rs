@@ -0,0 +1,44 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for placing the input method's candidate window at the caret.
+
+use rudo_gc::Gc;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::TextInput;
+use rvue::{Scene, TaffyTree};
+use rvue_style::{ReactiveStyles, Size, Width};
+use rvue_testing::TestWidgetBuilder;
+
+/// Test that the IME area of a horizontally scrolled input moves left by the scroll offset.
+#[test]
+fn test_ime_area_follows_horizontal_scroll() {
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let input = TextInput::new("abcdefghijklmnopqrstuvwxyz0123456789".to_string())
+        .styles(ReactiveStyles::new().set_width(Width(Size::Pixels(100.0))))
+        .build(&mut ctx);
+    let input = Gc::clone(input.component());
+
+    let root = TestWidgetBuilder::new().with_size(300.0, 100.0).build();
+    root.add_child(Gc::clone(&input));
+    input.set_parent(Some(Gc::clone(&root)));
+    *input.is_focused.borrow_mut_gen_only() = true;
+    input.text_editor().unwrap().editor().move_to(10);
+
+    let mut scene = Scene::new();
+    scene.add_fragment(root);
+    scene.update();
+    let (unscrolled_x, _, _, _) = input.ime_area().unwrap();
+    assert!(unscrolled_x > 40.0 && unscrolled_x < 100.0);
+
+    input.set_text_input_scroll_offset(40.0);
+    input.mark_dirty();
+    scene.update();
+    let (scrolled_x, _, _, _) = input.ime_area().unwrap();
+    assert_eq!(input.text_input_scroll_offset(), 40.0);
+    assert!((scrolled_x - (unscrolled_x - 40.0)).abs() < 0.01);
+}
@@ -0,0 +1,176 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for typing into text inputs through the harness.
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue_testing::{Key, Modifiers, NamedKey, TestHarness, TestWidgetBuilder};
+
+fn text_input(tag: &str) -> Gc<Component> {
+    let input = Component::with_global_id(ComponentType::TextInput, PropertyMap::new());
+    input.init_text_editor("");
+    *input.element_id.borrow_mut_gen_only() = Some(tag.to_string());
+    input
+}
+
+fn input_value(input: &Gc<Component>) -> String {
+    input.text_editor().unwrap().editor().content()
+}
+
+/// Test that typed characters and Backspace edit the focused input.
+#[test]
+fn test_type_text_then_backspace() {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.add_child(Gc::clone(&input));
+    input.set_parent(Some(Gc::clone(&root)));
+
+    let mut harness = TestHarness::create(root);
+    let input = harness.get_widget_by_tag("input").unwrap();
+
+    harness.type_text(input.clone(), "hello");
+    assert_eq!(input_value(&input), "hello");
+    assert!(harness.focused_widget().is_some_and(|f| Gc::ptr_eq(&f, &input)));
+
+    harness.press_key(input.clone(), Key::Named(NamedKey::Backspace));
+    assert_eq!(input_value(&input), "hell");
+}
+
+/// Test that arrow keys move the caret before inserting.
+#[test]
+fn test_arrow_keys_move_caret() {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.add_child(Gc::clone(&input));
+    input.set_parent(Some(Gc::clone(&root)));
+
+    let mut harness = TestHarness::create(root);
+    let input = harness.get_widget_by_tag("input").unwrap();
+
+    harness.type_text(input.clone(), "ac");
+    harness.press_key(input.clone(), Key::Named(NamedKey::ArrowLeft));
+    harness.type_text(input.clone(), "b");
+    assert_eq!(input_value(&input), "abc");
+
+    // Ctrl-modified characters are not inserted
+    harness.key_combo(Modifiers { ctrl: true, ..Default::default() }, Key::Character("x".into()));
+    assert_eq!(input_value(&input), "abc");
+}
+
+/// Test that Ctrl+Z undoes a burst of typing at once and Ctrl+Shift+Z redoes it.
+#[test]
+fn test_undo_and_redo_typing() {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.add_child(Gc::clone(&input));
+    input.set_parent(Some(Gc::clone(&root)));
+
+    let mut harness = TestHarness::create(root);
+    let input = harness.get_widget_by_tag("input").unwrap();
+    let ctrl = Modifiers { ctrl: true, ..Default::default() };
+    let ctrl_shift = Modifiers { ctrl: true, shift: true, ..Default::default() };
+
+    harness.type_text(input.clone(), "abc");
+    harness.key_combo(ctrl, Key::Character("z".into()));
+    assert_eq!(input_value(&input), "");
+    assert_eq!(input.text_input_value(), "");
+
+    harness.key_combo(ctrl_shift, Key::Character("Z".into()));
+    assert_eq!(input_value(&input), "abc");
+    assert_eq!(input.text_editor().unwrap().editor().selection().cursor(), 3);
+
+    // Moving the cursor starts a new undo step
+    harness.press_key(input.clone(), Key::Named(NamedKey::ArrowLeft));
+    harness.type_text(input.clone(), "X");
+    assert_eq!(input_value(&input), "abXc");
+    harness.key_combo(ctrl, Key::Character("z".into()));
+    assert_eq!(input_value(&input), "abc");
+    assert_eq!(input.text_editor().unwrap().editor().selection().cursor(), 2);
+}
+
+/// Test that pasting multi-line text into a single-line input drops the line breaks.
+#[test]
+fn test_paste_strips_newlines_in_single_line_input() {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.add_child(Gc::clone(&input));
+    input.set_parent(Some(Gc::clone(&root)));
+    let area = Component::with_global_id(ComponentType::TextArea, PropertyMap::new());
+    area.init_text_editor("");
+    *area.element_id.borrow_mut_gen_only() = Some("area".to_string());
+    root.add_child(Gc::clone(&area));
+    area.set_parent(Some(Gc::clone(&root)));
+
+    let mut harness = TestHarness::create(root);
+    harness.focus_on(Some(Gc::clone(&input)));
+    harness.paste_text("a\nb");
+    assert_eq!(input_value(&input), "ab");
+    assert_eq!(input.text_input_value(), "ab");
+
+    harness.paste_text("\r\nc");
+    assert_eq!(input_value(&input), "abc");
+
+    // Text areas keep the line breaks
+    harness.focus_on(Some(Gc::clone(&area)));
+    harness.paste_text("a\nb");
+    assert_eq!(input_value(&area), "a\nb");
+}
+
+/// Test that a registered shortcut takes its keys before the focused input sees them.
+#[test]
+fn test_shortcut_fires_before_focused_input() {
+    let saves = std::rc::Rc::new(std::cell::Cell::new(0));
+    let saves_clone = std::rc::Rc::clone(&saves);
+    rvue::prelude::register_shortcut("Ctrl+S", move |_| saves_clone.set(saves_clone.get() + 1));
+
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.append_child(Gc::clone(&input));
+    let mut harness = TestHarness::create(root);
+    harness.focus_on(Some(Gc::clone(&input)));
+
+    harness.key_combo(Modifiers { ctrl: true, ..Default::default() }, Key::Character("s".into()));
+    assert_eq!(saves.get(), 1);
+    assert_eq!(input_value(&input), "");
+
+    harness.type_text(Gc::clone(&input), "s");
+    assert_eq!(saves.get(), 1);
+    assert_eq!(input_value(&input), "s");
+}
+
+/// Test that a shortcut registered after startup fires on the next key event.
+#[test]
+fn test_shortcut_registered_after_startup_fires() {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.append_child(Gc::clone(&input));
+    let mut harness = TestHarness::create(root);
+    harness.focus_on(Some(Gc::clone(&input)));
+
+    let opens = std::rc::Rc::new(std::cell::Cell::new(0));
+    let opens_clone = std::rc::Rc::clone(&opens);
+    rvue::prelude::register_shortcut("Ctrl+O", move |_| opens_clone.set(opens_clone.get() + 1));
+
+    harness.key_combo(Modifiers { ctrl: true, ..Default::default() }, Key::Character("o".into()));
+    assert_eq!(opens.get(), 1);
+    assert_eq!(input_value(&input), "");
+}
+
+/// Test that `prevent_default` in a key handler stops the key from being typed.
+#[test]
+fn test_prevent_default_skips_text_insertion() {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(200.0, 100.0).build();
+    let input = text_input("input");
+    root.append_child(Gc::clone(&input));
+    input.on_key_down(|e, ctx| {
+        if e.key == Key::Character("s".into()) {
+            ctx.prevent_default();
+        }
+    });
+    let mut harness = TestHarness::create(root);
+
+    harness.type_text(Gc::clone(&input), "s");
+    assert_eq!(input_value(&input), "");
+}
@@ -0,0 +1,64 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for clicking and hovering links.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::{Link, TaffyTree};
+use rvue_style::{Cursor, TextDecoration};
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+/// A tracked link to `href` beside an empty corner to move the mouse to; returns the URLs its
+/// clicks reported.
+fn mount(href: &str) -> (TestHarness, Rc<RefCell<Vec<String>>>) {
+    let root = TestWidgetBuilder::new().with_size(300.0, 100.0).build();
+    let clicks = Rc::new(RefCell::new(Vec::new()));
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let clicks_clone = Rc::clone(&clicks);
+    let link = Link::new("Read more", href)
+        .track_visited(true)
+        .on_click(move |href| clicks_clone.borrow_mut().push(href.to_string()))
+        .build(&mut ctx);
+    link.mount(Some(Gc::clone(&root)));
+
+    (TestHarness::create(root), clicks)
+}
+
+/// Test that clicking a link hands its URL to `on_click` and marks it visited.
+#[test]
+fn test_link_click_fires_with_href() {
+    let (mut harness, clicks) = mount("https://example.com/story");
+    let link = harness.get_widgets_by_class("link").remove(0);
+    assert!(!link.has_class("visited"));
+
+    harness.mouse_click_on(Gc::clone(&link));
+
+    assert_eq!(*clicks.borrow(), vec!["https://example.com/story".to_string()]);
+    assert!(link.has_class("visited"));
+}
+
+/// Test that a link shows the pointer cursor and is underlined only while hovered.
+#[test]
+fn test_link_hover_toggles_underline() {
+    let (mut harness, _) = mount("https://example.com");
+    let link = harness.get_widgets_by_class("link").remove(0);
+    let decoration =
+        |link: &Gc<rvue::Component>| link.widget_styles().and_then(|styles| styles.text_decoration);
+    assert_eq!(link.widget_styles().and_then(|styles| styles.cursor), Some(Cursor::Pointer));
+    assert_ne!(decoration(&link), Some(TextDecoration::Underline));
+
+    harness.mouse_move_to(Gc::clone(&link));
+    assert_eq!(decoration(&link), Some(TextDecoration::Underline));
+
+    harness.mouse_move_to(Gc::clone(harness.root()));
+    assert_eq!(decoration(&link), Some(TextDecoration::None));
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for holding the pointer down for a long press.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue::event::handler::LONG_PRESS_THRESHOLD;
+use rvue_testing::{PointerButton, TestHarness, TestWidgetBuilder, FRAME_INTERVAL};
+use vello::kurbo::Point;
+
+/// A button with click and long press handlers; returns their counts.
+fn long_pressable() -> (TestHarness, Rc<Cell<u32>>, Rc<Cell<u32>>) {
+    let button = TestWidgetBuilder::new().with_size(100.0, 40.0).build();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&button)).build();
+
+    let clicks = Rc::new(Cell::new(0));
+    let clicks_clone = Rc::clone(&clicks);
+    button.on_click_0arg(move || clicks_clone.set(clicks_clone.get() + 1));
+    let presses = Rc::new(Cell::new(0));
+    let presses_clone = Rc::clone(&presses);
+    button.on_long_press_0arg(move || presses_clone.set(presses_clone.get() + 1));
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+    (harness, presses, clicks)
+}
+
+/// Run frames for at least `duration`.
+fn hold(harness: &mut TestHarness, duration: std::time::Duration) {
+    for _ in 0..duration.as_millis().div_ceil(FRAME_INTERVAL.as_millis()) {
+        harness.advance();
+    }
+}
+
+/// Test that holding past the threshold fires the long press once, and no click.
+#[test]
+fn test_long_press_fires_after_threshold() {
+    let (mut harness, presses, clicks) = long_pressable();
+
+    harness.mouse_move_at(Point::new(20.0, 20.0));
+    harness.mouse_button_press(PointerButton::Primary);
+    hold(&mut harness, LONG_PRESS_THRESHOLD / 2);
+    assert_eq!(presses.get(), 0);
+
+    hold(&mut harness, LONG_PRESS_THRESHOLD / 2);
+    assert_eq!(presses.get(), 1);
+
+    // The long press consumes the gesture
+    harness.mouse_button_release(PointerButton::Primary);
+    hold(&mut harness, LONG_PRESS_THRESHOLD);
+    assert_eq!(clicks.get(), 0);
+    assert_eq!(presses.get(), 1);
+}
+
+/// Test that moving beyond the slop cancels the long press, while jitter does not.
+#[test]
+fn test_long_press_cancelled_by_movement() {
+    let (mut harness, presses, _clicks) = long_pressable();
+
+    harness.mouse_move_at(Point::new(20.0, 20.0));
+    harness.mouse_button_press(PointerButton::Primary);
+
+    // Jitter within the slop keeps the gesture alive
+    harness.mouse_move_at(Point::new(22.0, 21.0));
+    hold(&mut harness, LONG_PRESS_THRESHOLD / 2);
+
+    harness.mouse_move_at(Point::new(40.0, 20.0));
+    hold(&mut harness, LONG_PRESS_THRESHOLD * 2);
+    assert_eq!(presses.get(), 0);
+}
+
+/// Test that jitter within the slop still lets the long press fire.
+#[test]
+fn test_long_press_survives_jitter() {
+    let (mut harness, presses, _clicks) = long_pressable();
+
+    harness.mouse_move_at(Point::new(20.0, 20.0));
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_move_at(Point::new(22.0, 21.0));
+    hold(&mut harness, LONG_PRESS_THRESHOLD);
+    assert_eq!(presses.get(), 1);
+}
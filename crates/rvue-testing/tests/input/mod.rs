@@ -0,0 +1,44 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for simulated pointer, keyboard and text input.
+
+#![allow(unused_imports)]
+
+mod accordion_test;
+mod activation_test;
+mod drag_test;
+mod focus_test;
+mod form_test;
+mod gc_test;
+mod handler_order_test;
+mod ime_test;
+mod keyboard_test;
+mod link_test;
+mod long_press_test;
+mod number_input_test;
+mod pointer_test;
+mod selection_test;
+mod tabs_test;
+mod text_area_test;
+mod text_input_scroll_test;
+mod validation_test;
+
+pub use accordion_test::*;
+pub use activation_test::*;
+pub use drag_test::*;
+pub use focus_test::*;
+pub use form_test::*;
+pub use gc_test::*;
+pub use handler_order_test::*;
+pub use ime_test::*;
+pub use keyboard_test::*;
+pub use link_test::*;
+pub use long_press_test::*;
+pub use number_input_test::*;
+pub use pointer_test::*;
+pub use selection_test::*;
+pub use tabs_test::*;
+pub use text_area_test::*;
+pub use text_input_scroll_test::*;
+pub use validation_test::*;
@@ -0,0 +1,93 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for stepping number inputs with the stepper buttons and the mouse wheel.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue::component::Component;
+use rvue::event::types::ScrollDelta;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::{NumberInput, TaffyTree};
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+use vello::kurbo::Point;
+
+/// A number input inside a 200x100 root, and the values its `on_input` handler received.
+fn mount(input: NumberInput) -> (TestHarness, Gc<Component>, Rc<RefCell<Vec<f64>>>) {
+    let root = TestWidgetBuilder::new().with_size(200.0, 100.0).build();
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = input.build(&mut ctx);
+    state.mount(Some(Gc::clone(&root)));
+    let input = Gc::clone(state.component());
+
+    let inputs = Rc::new(RefCell::new(Vec::new()));
+    let inputs_clone = Rc::clone(&inputs);
+    input.on_input_1arg(move |e| inputs_clone.borrow_mut().push(e.number_value));
+
+    (TestHarness::create(root), input, inputs)
+}
+
+/// Test that clicking the up stepper adds one step and the down stepper takes it away.
+#[test]
+fn test_stepper_click_steps_value() {
+    let (mut harness, input, inputs) = mount(NumberInput::new(2.0).step(0.5).steppers(true));
+    let field = harness.get_layout(&input).unwrap();
+
+    harness.mouse_click_at(Point::new(field.x1 - 4.0, field.y0 + 4.0));
+    assert_eq!(input.number_input_value(), 2.5);
+
+    harness.mouse_click_at(Point::new(field.x1 - 4.0, field.y1 - 4.0));
+    assert_eq!(input.number_input_value(), 2.0);
+    assert_eq!(*inputs.borrow(), vec![2.5, 2.0]);
+
+    // Clicking the text area of the field does not step
+    harness.mouse_click_at(Point::new(field.x0 + 4.0, field.y0 + 4.0));
+    assert_eq!(input.number_input_value(), 2.0);
+    assert_eq!(inputs.borrow().len(), 2);
+}
+
+/// Test that the stepper area only takes clicks when the steppers are shown.
+#[test]
+fn test_stepper_area_ignored_without_steppers() {
+    let (mut harness, input, inputs) = mount(NumberInput::new(2.0));
+    let field = harness.get_layout(&input).unwrap();
+
+    harness.mouse_click_at(Point::new(field.x1 - 4.0, field.y0 + 4.0));
+    assert_eq!(input.number_input_value(), 2.0);
+    assert!(inputs.borrow().is_empty());
+}
+
+/// Test that scrolling up over the field increments it and scrolling down decrements it.
+#[test]
+fn test_wheel_over_field_steps_value() {
+    let (mut harness, input, inputs) = mount(NumberInput::new(10.0).step(5.0));
+
+    harness.mouse_wheel_over(Gc::clone(&input), ScrollDelta::Line(1.0));
+    assert_eq!(input.number_input_value(), 15.0);
+
+    harness.mouse_wheel_over(Gc::clone(&input), ScrollDelta::Line(-1.0));
+    assert_eq!(input.number_input_value(), 10.0);
+    assert_eq!(*inputs.borrow(), vec![15.0, 10.0]);
+}
+
+/// Test that stepping stops at the bounds without reporting unchanged values.
+#[test]
+fn test_stepping_clamps_to_bounds() {
+    let (mut harness, input, inputs) =
+        mount(NumberInput::new(8.0).min(0.0).max(10.0).step(3.0).steppers(true));
+    let field = harness.get_layout(&input).unwrap();
+
+    harness.mouse_click_at(Point::new(field.x1 - 4.0, field.y0 + 4.0));
+    assert_eq!(input.number_input_value(), 10.0);
+
+    harness.mouse_wheel_over(Gc::clone(&input), ScrollDelta::Line(1.0));
+    assert_eq!(input.number_input_value(), 10.0);
+    assert_eq!(*inputs.borrow(), vec![10.0]);
+}
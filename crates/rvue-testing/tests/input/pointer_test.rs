@@ -0,0 +1,245 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for clicking, hovering and right-clicking with the pointer.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue_style::properties::{FlexDirection, Height, Overflow, Size, Width};
+use rvue_style::ComputedStyles;
+use rvue_testing::{PointerButton, TestHarness, TestWidgetBuilder};
+use vello::kurbo::Point;
+
+/// A 100x40 clickable in the top-left of a 400x300 root that takes no pointer input; returns
+/// the clickable and its click count.
+fn clickable() -> (TestHarness, Gc<Component>, Rc<Cell<u32>>) {
+    let button = TestWidgetBuilder::new().with_size(100.0, 40.0).build();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&button)).build();
+
+    let clicks = Rc::new(Cell::new(0));
+    let clicks_clone = Rc::clone(&clicks);
+    button.on_click_0arg(move || clicks_clone.set(clicks_clone.get() + 1));
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+    (harness, button, clicks)
+}
+
+/// Test that a release over the pressed component clicks it.
+#[test]
+fn test_click_fires_when_released_over_pressed_component() {
+    let (mut harness, button, clicks) = clickable();
+    let inside = Point::new(20.0, 20.0);
+
+    harness.mouse_move_at(inside);
+    harness.mouse_button_press(PointerButton::Primary);
+    assert!(*button.is_active.borrow());
+
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(clicks.get(), 1);
+    assert!(!*button.is_active.borrow());
+}
+
+/// Test that hover ends when the pointer moves off onto nothing.
+#[test]
+fn test_hover_ends_when_pointer_moves_onto_empty_space() {
+    let (mut harness, button, _clicks) = clickable();
+
+    harness.mouse_move_at(Point::new(20.0, 20.0));
+    assert!(*button.is_hovered.borrow());
+
+    // The root takes no pointer input, so nothing is under the pointer here
+    harness.mouse_move_at(Point::new(300.0, 200.0));
+    assert!(!*button.is_hovered.borrow());
+}
+
+/// Test that dragging out keeps the capture but only a release over the component clicks.
+#[test]
+fn test_click_suppressed_when_released_outside() {
+    let (mut harness, button, clicks) = clickable();
+    let inside = Point::new(20.0, 20.0);
+    let outside = Point::new(300.0, 200.0);
+
+    harness.mouse_move_at(inside);
+    harness.mouse_button_press(PointerButton::Primary);
+
+    // Dragging out keeps the capture but drops the active state
+    harness.mouse_move_at(outside);
+    assert!(harness.pointer_capture().is_some());
+    assert!(!*button.is_active.borrow());
+
+    // Dragging back in re-activates the button
+    harness.mouse_move_at(inside);
+    assert!(*button.is_active.borrow());
+
+    harness.mouse_move_at(outside);
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(clicks.get(), 0);
+    assert!(!*button.is_active.borrow());
+    assert!(harness.pointer_capture().is_none());
+}
+
+/// Test that clicking a mixed checkbox resolves it to checked.
+#[test]
+fn test_click_resolves_indeterminate_checkbox_to_checked() {
+    let checkbox = Component::with_global_id(ComponentType::Checkbox, PropertyMap::new());
+    checkbox.set_checkbox_indeterminate(true);
+    checkbox.set_widget_styles(ComputedStyles {
+        width: Some(Width(Size::Pixels(20.0))),
+        height: Some(Height(Size::Pixels(20.0))),
+        ..Default::default()
+    });
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&checkbox)).build();
+    let mut harness = TestHarness::create(root);
+    assert!(!checkbox.checkbox_checked());
+
+    harness.mouse_click_on(Gc::clone(&checkbox));
+
+    assert!(!checkbox.checkbox_indeterminate());
+    assert!(checkbox.checkbox_checked());
+}
+
+/// A fixed-size column scrolled down by `offset_y`.
+fn scroll_column(width: f64, height: f64, offset_y: f64) -> TestWidgetBuilder {
+    TestWidgetBuilder::new()
+        .with_size(width, height)
+        .with_flex_direction(FlexDirection::Column)
+        .with_overflow_y(Overflow::Scroll)
+        .with_scroll_offset(0.0, offset_y)
+}
+
+fn spacer(width: f64, height: f64) -> Gc<Component> {
+    TestWidgetBuilder::new().with_size(width, height).build()
+}
+
+/// Test that `local_position` subtracts every ancestor's offset and scroll.
+#[test]
+fn test_local_position_accounts_for_nesting_and_scroll() {
+    let target = spacer(100.0, 100.0);
+    let inner = scroll_column(150.0, 100.0, 10.0)
+        .with_child(spacer(100.0, 40.0))
+        .with_child(Gc::clone(&target));
+    let outer =
+        scroll_column(200.0, 120.0, 30.0).with_child(spacer(100.0, 50.0)).with_child(inner.build());
+    let root = scroll_column(400.0, 300.0, 0.0)
+        .with_child(spacer(400.0, 20.0))
+        .with_child(outer.build())
+        .build();
+
+    let local = Rc::new(Cell::new(None));
+    let local_clone = Rc::clone(&local);
+    target.on_click(move |e, ctx| local_clone.set(Some(ctx.local_position(e.position))));
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+
+    // Target top in the window: 20 + 50 - 30 (outer scroll) + 40 - 10 (inner scroll) = 70
+    harness.mouse_click_at(Point::new(25.0, 90.0));
+
+    assert_eq!(local.get(), Some(Point::new(25.0, 20.0)));
+}
+
+/// Test that a right click, and only a right click, opens the context menu at the pointer.
+#[test]
+fn test_right_click_fires_context_menu_at_pointer() {
+    let area = TestWidgetBuilder::new().with_size(200.0, 100.0).build();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&area)).build();
+
+    let opened_at = Rc::new(Cell::new(None));
+    let opened_at_clone = Rc::clone(&opened_at);
+    area.on_context_menu_1arg(move |e| opened_at_clone.set(Some(e.position)));
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+
+    let position = Point::new(50.0, 40.0);
+    harness.mouse_move_at(position);
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_button_release(PointerButton::Primary);
+    assert_eq!(opened_at.get(), None);
+
+    harness.mouse_button_press(PointerButton::Secondary);
+    assert_eq!(opened_at.get(), Some(position));
+}
+
+/// An inner clickable inside an outer clickable; returns the inner and outer click counts.
+fn nested_clickables(inner_stops: bool) -> (TestHarness, Rc<Cell<u32>>, Rc<Cell<u32>>) {
+    let inner = TestWidgetBuilder::new().with_size(100.0, 40.0).build();
+    let outer =
+        TestWidgetBuilder::new().with_size(200.0, 100.0).with_child(Gc::clone(&inner)).build();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&outer)).build();
+
+    let outer_clicks = Rc::new(Cell::new(0));
+    let outer_clicks_clone = Rc::clone(&outer_clicks);
+    outer.on_click_0arg(move || outer_clicks_clone.set(outer_clicks_clone.get() + 1));
+
+    let inner_clicks = Rc::new(Cell::new(0));
+    let inner_clicks_clone = Rc::clone(&inner_clicks);
+    inner.on_click(move |_, ctx| {
+        inner_clicks_clone.set(inner_clicks_clone.get() + 1);
+        if inner_stops {
+            ctx.stop_propagation();
+        }
+    });
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+    (harness, inner_clicks, outer_clicks)
+}
+
+/// Test that a click bubbles from the inner clickable to the outer one.
+#[test]
+fn test_click_bubbles_to_outer_handler() {
+    let (mut harness, inner_clicks, outer_clicks) = nested_clickables(false);
+
+    harness.mouse_click_at(Point::new(20.0, 20.0));
+    assert_eq!(inner_clicks.get(), 1);
+    assert_eq!(outer_clicks.get(), 1);
+}
+
+/// Test that `stop_propagation` keeps the click from the outer handler.
+#[test]
+fn test_stop_propagation_prevents_outer_click() {
+    let (mut harness, inner_clicks, outer_clicks) = nested_clickables(true);
+
+    harness.mouse_click_at(Point::new(20.0, 20.0));
+    assert_eq!(inner_clicks.get(), 1);
+    assert_eq!(outer_clicks.get(), 0);
+
+    // Clicking the outer component directly still reaches its handler
+    harness.mouse_click_at(Point::new(150.0, 60.0));
+    assert_eq!(inner_clicks.get(), 1);
+    assert_eq!(outer_clicks.get(), 1);
+}
+
+/// Test that an ancestor's capture handler runs before the target's and its own bubble handlers.
+#[test]
+fn test_capture_handler_runs_before_target_bubble_handler() {
+    let inner = TestWidgetBuilder::new().with_size(100.0, 40.0).build();
+    let outer =
+        TestWidgetBuilder::new().with_size(200.0, 100.0).with_child(Gc::clone(&inner)).build();
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&outer)).build();
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let log_clone = Rc::clone(&log);
+    outer.on_click_capture_0arg(move || log_clone.borrow_mut().push("outer capture"));
+    let log_clone = Rc::clone(&log);
+    outer.on_click_0arg(move || log_clone.borrow_mut().push("outer bubble"));
+    let log_clone = Rc::clone(&log);
+    inner.on_click_0arg(move || log_clone.borrow_mut().push("inner"));
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+
+    harness.mouse_click_at(Point::new(20.0, 20.0));
+    assert_eq!(*log.borrow(), vec!["outer capture", "inner", "outer bubble"]);
+}
@@ -0,0 +1,45 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for selecting and copying selectable text.
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::{PropertyMap, TextContent};
+use rvue::text::ParleyLayoutWrapper;
+use rvue_testing::{Key, Modifiers, PointerButton, TestHarness, TestWidgetBuilder};
+use vello::kurbo::Point;
+
+/// Test that dragging across selectable text selects it and Ctrl+C copies the selection.
+#[test]
+fn test_drag_selects_text_and_ctrl_c_copies_it() {
+    let text = Component::with_global_id(
+        ComponentType::Text,
+        PropertyMap::with(TextContent("Hello world".to_string())),
+    );
+    text.set_text_selectable(true);
+    let root =
+        TestWidgetBuilder::new().with_size(400.0, 300.0).with_child(Gc::clone(&text)).build();
+
+    let mut harness = TestHarness::create(root);
+    harness.compute_layout();
+
+    // Drag from the start of "world" to past the end of the text
+    let (start_x, end_x, mid_y) = {
+        let user_data = text.user_data.borrow();
+        let ParleyLayoutWrapper(layout) = user_data.as_ref().unwrap().downcast_ref().unwrap();
+        let start = parley::Cursor::from_byte_index(layout, 6, parley::Affinity::Downstream)
+            .geometry(layout, 1.0);
+        (start.x0 + 1.0, layout.width() as f64 + 5.0, layout.height() as f64 / 2.0)
+    };
+    harness.mouse_move_at(Point::new(start_x, mid_y));
+    harness.mouse_button_press(PointerButton::Primary);
+    harness.mouse_move_at(Point::new(end_x, mid_y));
+    harness.mouse_button_release(PointerButton::Primary);
+
+    assert_eq!(text.text_selection(), Some((6, 11)));
+    assert_eq!(text.selected_text().as_deref(), Some("world"));
+
+    harness.key_combo(Modifiers { ctrl: true, ..Default::default() }, Key::Character("c".into()));
+    assert_eq!(harness.copied_text().as_deref(), Some("world"));
+}
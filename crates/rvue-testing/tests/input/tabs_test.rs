@@ -0,0 +1,73 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for switching tabs by clicking and with the keyboard.
+
+use rudo_gc::Gc;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::{create_signal, ReadSignal, SignalRead, Tab, Tabs, TaffyTree, Text};
+use rvue_testing::{Key, NamedKey, TestHarness, TestWidgetBuilder};
+
+/// Tabs with three text panels inside a 300x200 root, and the active-index signal.
+fn mount_tabs() -> (TestHarness, ReadSignal<usize>) {
+    let root = TestWidgetBuilder::new().with_size(300.0, 200.0).build();
+    let (active, set_active) = create_signal(0usize);
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let tabs = ["First", "Second", "Third"].into_iter().fold(
+        Tabs::new(active.clone(), set_active),
+        |tabs, name| {
+            tabs.tab(Tab::new(name, move |ctx| {
+                Gc::clone(Text::new(format!("{name} panel")).build(ctx).component())
+            }))
+        },
+    );
+    tabs.build(&mut ctx).mount(Some(Gc::clone(&root)));
+
+    (TestHarness::create(root), active)
+}
+
+/// Test that clicking the second tab shows its panel in place of the first.
+#[test]
+fn test_clicking_tab_shows_its_panel() {
+    let (mut harness, active) = mount_tabs();
+    assert!(harness.get_widget_by_text("First panel").is_some());
+    assert!(harness.get_widget_by_text("Second panel").is_none());
+
+    let tabs = harness.get_widgets_by_class("tab");
+    assert_eq!(tabs.len(), 3);
+    assert!(tabs[0].has_class("active"));
+
+    harness.mouse_click_on(Gc::clone(&tabs[1]));
+
+    assert_eq!(active.get(), 1);
+    assert!(harness.get_widget_by_text("Second panel").is_some());
+    assert!(harness.get_widget_by_text("First panel").is_none());
+    assert!(tabs[1].has_class("active"));
+    assert!(!tabs[0].has_class("active"));
+}
+
+/// Test that the arrow keys move between tabs, wrapping at the ends, and carry focus along.
+#[test]
+fn test_arrow_keys_move_between_tabs() {
+    let (mut harness, active) = mount_tabs();
+    let tabs = harness.get_widgets_by_class("tab");
+
+    harness.press_key(Gc::clone(&tabs[0]), Key::Named(NamedKey::ArrowLeft));
+    assert_eq!(active.get(), 2);
+    assert!(harness.get_widget_by_text("Third panel").is_some());
+    assert!(harness.focused_widget().is_some_and(|f| Gc::ptr_eq(&f, &tabs[2])));
+
+    harness.press_key(Gc::clone(&tabs[2]), Key::Named(NamedKey::ArrowRight));
+    assert_eq!(active.get(), 0);
+    assert!(harness.get_widget_by_text("First panel").is_some());
+
+    harness.press_key(Gc::clone(&tabs[0]), Key::Named(NamedKey::End));
+    assert_eq!(active.get(), 2);
+    harness.press_key(Gc::clone(&tabs[2]), Key::Named(NamedKey::Home));
+    assert_eq!(active.get(), 0);
+}
@@ -0,0 +1,127 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for multi-line editing in text areas.
+
+use rudo_gc::Gc;
+use rvue::component::Component;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::text_area::text_area_layout;
+use rvue::widgets::TextArea;
+use rvue::{create_signal, SignalRead};
+use rvue_testing::{Key, NamedKey, TestHarness, TestWidgetBuilder};
+
+fn build_text_area(text_area: TextArea) -> Gc<Component> {
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    Gc::clone(text_area.build(&mut ctx).component())
+}
+
+fn mount(text_area: &Gc<Component>) -> TestHarness {
+    let root = TestWidgetBuilder::new().with_tag("root").with_size(300.0, 200.0).build();
+    root.add_child(Gc::clone(text_area));
+    text_area.set_parent(Some(Gc::clone(&root)));
+    TestHarness::create(root)
+}
+
+/// Test that Enter inserts a newline and the text lays out on two lines.
+#[test]
+fn test_enter_starts_a_new_line() {
+    let text_area = build_text_area(TextArea::new("").rows(5));
+    let mut harness = mount(&text_area);
+
+    harness.type_text(Gc::clone(&text_area), "first");
+    harness.press_key(Gc::clone(&text_area), Key::Named(NamedKey::Enter));
+    harness.type_text(Gc::clone(&text_area), "second");
+
+    let value = text_area.text_input_value();
+    assert_eq!(value, "first\nsecond");
+
+    let layout = text_area_layout(&value, 14.0, Some(200.0), &mut TextContext::new());
+    assert_eq!(layout.lines().count(), 2);
+}
+
+/// Test that Up and Down move between lines instead of jumping to the ends.
+#[test]
+fn test_arrow_keys_move_between_lines() {
+    let text_area = build_text_area(TextArea::new("abc\ndef"));
+    let mut harness = mount(&text_area);
+
+    harness.focus_on(Some(Gc::clone(&text_area)));
+    let editor = text_area.text_editor().unwrap();
+    editor.editor().move_to(5);
+
+    harness.press_key(Gc::clone(&text_area), Key::Named(NamedKey::ArrowUp));
+    assert_eq!(editor.editor().selection().cursor(), 1);
+
+    harness.press_key(Gc::clone(&text_area), Key::Named(NamedKey::ArrowDown));
+    assert_eq!(editor.editor().selection().cursor(), 5);
+}
+
+/// Test that long lines wrap to the given width.
+#[test]
+fn test_long_lines_wrap_to_width() {
+    let text = "wrap this sentence across several short lines";
+    let mut text_context = TextContext::new();
+
+    let unwrapped = text_area_layout(text, 14.0, None, &mut text_context);
+    let wrapped = text_area_layout(text, 14.0, Some(60.0), &mut text_context);
+    assert_eq!(unwrapped.lines().count(), 1);
+    assert!(wrapped.lines().count() > 1);
+}
+
+/// Test that a bound signal follows edits without moving the cursor.
+#[test]
+fn test_bound_signal_receives_edits() {
+    let (value, set_value) = create_signal(String::from("hi"));
+    let text_area = build_text_area(TextArea::new(value.clone()).bind(set_value));
+    let mut harness = mount(&text_area);
+
+    let editor = text_area.text_editor().unwrap();
+    harness.focus_on(Some(Gc::clone(&text_area)));
+    editor.editor().move_to_end();
+    harness.type_text(Gc::clone(&text_area), "!\nyo");
+
+    assert_eq!(value.get(), "hi!\nyo");
+    assert_eq!(editor.editor().selection().cursor(), 6);
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+/// Test that the binding survives other state written to the component's user data.
+#[test]
+fn test_bound_signal_survives_scroll_state() {
+    let (value, set_value) = create_signal(String::new());
+    let text_area = build_text_area(TextArea::new("").bind(set_value));
+    let mut harness = mount(&text_area);
+
+    text_area.set_scroll_state(rvue::FlexScrollState::default());
+    harness.type_text(Gc::clone(&text_area), "ok");
+
+    assert_eq!(value.get(), "ok");
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+/// Test that an overflowing text area scrolls to keep the cursor in view.
+#[test]
+fn test_overflow_scrolls_to_cursor() {
+    let text_area = build_text_area(TextArea::new("1\n2\n3\n4\n5\n6").rows(2));
+    let root = TestWidgetBuilder::new().with_size(300.0, 200.0).build();
+    root.add_child(Gc::clone(&text_area));
+    text_area.set_parent(Some(Gc::clone(&root)));
+    *text_area.is_focused.borrow_mut_gen_only() = true;
+
+    let editor = text_area.text_editor().unwrap();
+    editor.editor().move_to_end();
+    let mut scene = rvue::Scene::new();
+    scene.add_fragment(root);
+    scene.update();
+    assert!(text_area.text_area_scroll_offset() > 0.0);
+
+    editor.editor().move_to_start();
+    text_area.mark_dirty();
+    scene.update();
+    assert_eq!(text_area.text_area_scroll_offset(), 0.0);
+}
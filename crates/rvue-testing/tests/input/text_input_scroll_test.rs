@@ -0,0 +1,61 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for scrolling single-line inputs to keep the caret in view.
+
+use rudo_gc::Gc;
+use rvue::component::Component;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::TextInput;
+use rvue::{Scene, TaffyTree};
+use rvue_style::{ReactiveStyles, Size, Width};
+use rvue_testing::{Key, NamedKey, TestHarness, TestWidgetBuilder};
+
+/// A 100px wide input inside a wider root, and the scene drawing it.
+fn mount() -> (TestHarness, Scene, Gc<Component>) {
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let input = TextInput::new(String::new())
+        .styles(ReactiveStyles::new().set_width(Width(Size::Pixels(100.0))))
+        .build(&mut ctx);
+    let input = Gc::clone(input.component());
+
+    let root = TestWidgetBuilder::new().with_size(300.0, 100.0).build();
+    root.add_child(Gc::clone(&input));
+    input.set_parent(Some(Gc::clone(&root)));
+
+    let mut scene = Scene::new();
+    scene.add_fragment(Gc::clone(&root));
+    (TestHarness::create(root), scene, input)
+}
+
+/// Caret x within the input's box, from the IME area placed at the caret.
+fn visible_caret_x(input: &Gc<Component>) -> f64 {
+    let (x, _, width, _) = input.ime_area().unwrap();
+    x + width / 2.0
+}
+
+/// Test that typing past the width scrolls the text so the caret stays visible.
+#[test]
+fn test_typing_past_width_scrolls_to_caret() {
+    let (mut harness, mut scene, input) = mount();
+
+    harness.type_text(Gc::clone(&input), "abc");
+    scene.update();
+    assert_eq!(input.text_input_scroll_offset(), 0.0);
+
+    harness.type_text(Gc::clone(&input), "defghijklmnopqrstuvwxyz0123456789");
+    scene.update();
+    assert!(input.text_input_scroll_offset() > 0.0);
+    let caret_x = visible_caret_x(&input);
+    assert!((0.0..=100.0).contains(&caret_x), "caret at {caret_x} is outside the field");
+
+    // Going back to the start scrolls the other way
+    harness.press_key(Gc::clone(&input), Key::Named(NamedKey::Home));
+    scene.update();
+    assert_eq!(input.text_input_scroll_offset(), 0.0);
+    assert!((0.0..=100.0).contains(&visible_caret_x(&input)));
+}
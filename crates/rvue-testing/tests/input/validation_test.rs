@@ -0,0 +1,48 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for showing validation errors on inputs.
+
+use rudo_gc::Gc;
+use rvue::prelude::*;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::{Stylesheet, TaffyTree, TextInput};
+use rvue_style::{BorderColor, Color};
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+/// Test that an error marks the input invalid with a red border, and clearing it reverts.
+#[test]
+fn test_error_shows_message_and_invalid_style() {
+    let root = TestWidgetBuilder::new().with_size(300.0, 200.0).build();
+    let (error, set_error) = create_signal(None::<String>);
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = TextInput::new("abc".to_string()).error(error).build(&mut ctx);
+    state.mount(Some(Gc::clone(&root)));
+    let input = Gc::clone(state.component());
+
+    let mut harness = TestHarness::create(root);
+    harness.set_stylesheet(Stylesheet::with_defaults());
+    let valid_border = Some(BorderColor(Color::rgb(180, 180, 180)));
+    assert!(!input.is_invalid());
+    assert_eq!(harness.get_computed_styles(&input).border_color, valid_border);
+
+    set_error.set(Some("Required".to_string()));
+    assert_eq!(input.error_message().as_deref(), Some("Required"));
+    assert!(input.is_invalid());
+    assert_eq!(
+        harness.get_computed_styles(&input).border_color,
+        Some(BorderColor(Color::rgb(220, 38, 38)))
+    );
+    assert!(harness.debug_tree().contains("Required"));
+
+    set_error.set(None);
+    assert_eq!(input.error_message(), None);
+    assert!(!input.is_invalid());
+    assert_eq!(harness.get_computed_styles(&input).border_color, valid_border);
+    assert!(!harness.debug_tree().contains("Required"));
+}
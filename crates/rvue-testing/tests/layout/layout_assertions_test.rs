@@ -0,0 +1,88 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for querying computed layout and styles through the harness.
+
+use rvue_style::properties::{FlexDirection, Size, Width};
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+use std::cell::RefCell;
+use std::rc::Rc;
+use vello::kurbo::{self, Rect};
+
+/// Test that children of a fixed-size row are placed at the expected window coordinates.
+#[test]
+fn test_get_layout_reports_child_positions() {
+    let first = TestWidgetBuilder::new().with_tag("first").with_size(100.0, 50.0).build();
+    let second = TestWidgetBuilder::new().with_tag("second").with_size(80.0, 40.0).build();
+    let column = TestWidgetBuilder::new()
+        .with_tag("column")
+        .with_size(120.0, 100.0)
+        .with_flex_direction(FlexDirection::Column)
+        .with_child(second)
+        .build();
+    let root = TestWidgetBuilder::new()
+        .with_tag("root")
+        .with_size(300.0, 100.0)
+        .with_flex_direction(FlexDirection::Row)
+        .with_children(vec![first, column])
+        .build();
+
+    let mut harness = TestHarness::create(root);
+    let first = harness.get_widget_by_tag("first").unwrap();
+    let column = harness.get_widget_by_tag("column").unwrap();
+    let second = harness.get_widget_by_tag("second").unwrap();
+
+    assert_eq!(harness.get_layout(&first), Some(Rect::new(0.0, 0.0, 100.0, 50.0)));
+    assert_eq!(harness.get_layout(&column), Some(Rect::new(100.0, 0.0, 220.0, 100.0)));
+    assert_eq!(harness.get_layout(&second), Some(Rect::new(100.0, 0.0, 180.0, 40.0)));
+}
+
+/// Test that computed styles expose the inline size of a widget.
+#[test]
+fn test_get_computed_styles_reads_inline_styles() {
+    let child = TestWidgetBuilder::new().with_tag("child").with_size(100.0, 20.0).build();
+    let root = TestWidgetBuilder::new().with_size(300.0, 100.0).with_child(child).build();
+
+    let harness = TestHarness::create(root);
+    let child = harness.get_widget_by_tag("child").unwrap();
+
+    let styles = harness.get_computed_styles(&child);
+    assert_eq!(styles.width, Some(Width(Size::Pixels(100.0))));
+}
+
+/// Test that resizing the window fires `on_resize` on a child whose size follows the window.
+#[test]
+fn test_on_resize_fires_when_window_resize_changes_child_size() {
+    let child = TestWidgetBuilder::new()
+        .with_tag("child")
+        .with_styles(rvue_style::ComputedStyles {
+            width: Some(Width(Size::Percent(50.0))),
+            ..Default::default()
+        })
+        .with_height(20.0)
+        .build();
+    let root = TestWidgetBuilder::new()
+        .with_styles(rvue_style::ComputedStyles {
+            width: Some(Width(Size::Percent(100.0))),
+            ..Default::default()
+        })
+        .with_child(child)
+        .build();
+
+    let mut harness = TestHarness::create(root);
+    let child = harness.get_widget_by_tag("child").unwrap();
+    let sizes = Rc::new(RefCell::new(Vec::new()));
+    let seen = Rc::clone(&sizes);
+    child.on_resize(move |size| seen.borrow_mut().push(size));
+
+    harness.compute_layout();
+    assert_eq!(*sizes.borrow(), vec![kurbo::Size::new(400.0, 20.0)]);
+
+    // Laying out again at the same size reports nothing new
+    harness.compute_layout();
+    assert_eq!(sizes.borrow().len(), 1);
+
+    harness.set_window_size(kurbo::Size::new(600.0, 600.0));
+    assert_eq!(harness.get_layout(&child), Some(Rect::new(0.0, 0.0, 300.0, 20.0)));
+    assert_eq!(*sizes.borrow(), vec![kurbo::Size::new(400.0, 20.0), kurbo::Size::new(300.0, 20.0)]);
+}
@@ -0,0 +1,10 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for layout and style assertions.
+
+#![allow(unused_imports)]
+
+mod layout_assertions_test;
+
+pub use layout_assertions_test::*;
@@ -5,6 +5,16 @@
 
 #![allow(unused_imports)]
 
+mod input;
+mod layout;
+mod query;
 mod scroll;
+mod snapshot;
+mod time;
 
+pub use input::*;
+pub use layout::*;
+pub use query::*;
 pub use scroll::*;
+pub use snapshot::*;
+pub use time::*;
@@ -0,0 +1,10 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for looking widgets up in the live tree.
+
+#![allow(unused_imports)]
+
+mod widget_query_test;
+
+pub use widget_query_test::*;
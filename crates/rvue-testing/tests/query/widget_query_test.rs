@@ -0,0 +1,87 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for finding widgets by text, class and element id.
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::{PropertyMap, TextContent};
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+fn text(content: &str) -> Gc<Component> {
+    Component::with_global_id(
+        ComponentType::Text,
+        PropertyMap::with(TextContent(content.to_string())),
+    )
+}
+
+fn build_view() -> Gc<Component> {
+    let save = text("Save");
+    let cancel = text("Cancel");
+    let toolbar = TestWidgetBuilder::new()
+        .with_tag("toolbar")
+        .with_size(200.0, 40.0)
+        .with_children(vec![save, cancel])
+        .build();
+    toolbar.add_class("bar");
+    let footer = TestWidgetBuilder::new().with_tag("footer").with_size(200.0, 20.0).build();
+    footer.add_class("bar");
+    let root = TestWidgetBuilder::new()
+        .with_tag("root")
+        .with_size(200.0, 100.0)
+        .with_children(vec![toolbar, footer])
+        .build();
+    root.add_class("app");
+    root
+}
+
+/// Test that a text widget is found by its exact content.
+#[test]
+fn test_get_widget_by_text() {
+    let harness = TestHarness::create(build_view());
+
+    let cancel = harness.get_widget_by_text("Cancel").unwrap();
+    assert_eq!(cancel.component_type, ComponentType::Text);
+    assert_eq!(cancel.text_content(), "Cancel");
+    assert!(harness.get_widget_by_text("Canc").is_none());
+}
+
+/// Test that class lookup returns every match in tree order, including the root.
+#[test]
+fn test_get_widgets_by_class() {
+    let harness = TestHarness::create(build_view());
+
+    let bars = harness.get_widgets_by_class("bar");
+    let ids: Vec<_> = bars.iter().map(|c| c.element_id.borrow().clone()).collect();
+    assert_eq!(ids, vec![Some("toolbar".to_string()), Some("footer".to_string())]);
+
+    let apps = harness.get_widgets_by_class("app");
+    assert_eq!(apps.len(), 1);
+    assert!(Gc::ptr_eq(&apps[0], harness.root()));
+
+    assert!(harness.get_widgets_by_class("missing").is_empty());
+}
+
+/// Test that lookups by element id and component id reach the same widget.
+#[test]
+fn test_get_widget_by_id() {
+    let harness = TestHarness::create(build_view());
+
+    let footer = harness.get_widget_by_id("footer").unwrap();
+    assert!(footer.has_class("bar"));
+    let by_component_id = harness.get_widget_by_component_id(footer.id).unwrap();
+    assert!(Gc::ptr_eq(&footer, &by_component_id));
+    assert!(harness.get_widget_by_id("header").is_none());
+}
+
+/// Test that lookups see widgets added after the harness was created.
+#[test]
+fn test_queries_search_the_live_tree() {
+    let harness = TestHarness::create(build_view());
+    assert!(harness.get_widget_by_text("Delete").is_none());
+
+    let toolbar = harness.get_widget_by_id("toolbar").unwrap();
+    toolbar.add_child(text("Delete"));
+
+    assert!(harness.get_widget_by_text("Delete").is_some());
+}
@@ -124,3 +124,46 @@ fn test_horizontal_scroll() {
 
     assert!(offset.0 > 0.0, "Horizontal scroll should increase X offset, got {}", offset.0);
 }
+
+/// Test that dragging the scrollbar thumb keeps it under the cursor.
+#[test]
+fn test_scrollbar_thumb_drag_keeps_thumb_under_cursor() {
+    use rudo_gc::Gc;
+    use rvue::widgets::scroll_bar::ScrollBar;
+    use rvue_style::properties::FlexDirection;
+    use rvue_testing::PointerButton;
+    use vello::kurbo::Point;
+
+    let root = TestWidgetBuilder::new()
+        .with_size(100.0, 100.0)
+        .with_flex_direction(FlexDirection::Column)
+        .with_overflow_y(Overflow::Scroll)
+        .with_child(TestWidgetBuilder::new().with_size(80.0, 400.0).build())
+        .build();
+    let mut harness = TestHarness::create(Gc::clone(&root));
+    harness.compute_layout();
+
+    let scroll_height = root.scroll_state().scroll_height as f64;
+    assert!(scroll_height > 0.0);
+    let thumb_at = |offset: f64| {
+        ScrollBar::vertical_thumb_rect(0.0, 0.0, 100.0, 100.0, offset, scroll_height).unwrap()
+    };
+
+    // Grab the thumb, then drag it down by 30 pixels
+    let start = thumb_at(0.0).center();
+    harness.mouse_move_at(start);
+    harness.mouse_button_press(PointerButton::Primary);
+    let end = Point::new(start.x, start.y + 30.0);
+    harness.mouse_move_at(end);
+
+    // Content moves by the drag distance scaled by content / container
+    let offset = root.scroll_state().scroll_offset_y as f64;
+    let content_height = 100.0 + scroll_height;
+    assert!((offset - 30.0 * content_height / 100.0).abs() < 0.01);
+    assert!((thumb_at(offset).center().y - end.y).abs() < 0.01);
+
+    // Once released, moving the pointer no longer scrolls
+    harness.mouse_button_release(PointerButton::Primary);
+    harness.mouse_move_at(Point::new(start.x, start.y + 60.0));
+    assert_eq!(root.scroll_state().scroll_offset_y as f64, offset);
+}
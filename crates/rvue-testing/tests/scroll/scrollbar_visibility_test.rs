@@ -5,6 +5,12 @@
 
 #![allow(clippy::map_flatten)]
 
+use std::time::{Duration, Instant};
+
+use rvue::widgets::scroll_bar::{
+    auto_scrollbar_visible, default_scrollbar, expire_scrollbar_activity, thumb_length_for,
+    ScrollBar,
+};
 use rvue_style::properties::Overflow;
 use rvue_testing::{TestHarness, TestWidgetBuilder};
 
@@ -152,3 +158,45 @@ fn test_scroll_state_updates() {
         new_offset.1
     );
 }
+
+/// Test that the thumb never gets shorter than the minimum length for very tall content.
+#[test]
+fn test_thumb_length_clamped_to_minimum_for_tall_content() {
+    let min = default_scrollbar::THUMB_MIN_LENGTH;
+    assert_eq!(thumb_length_for(200.0, 1_000_000.0), min);
+    assert_eq!(thumb_length_for(200.0, 200.0), 100.0);
+
+    let thumb = ScrollBar::vertical_thumb_rect(0.0, 0.0, 200.0, 200.0, 0.0, 1_000_000.0).unwrap();
+    assert_eq!(thumb.height(), min - 2.0 * default_scrollbar::TRACK_MARGIN);
+
+    // A track shorter than the minimum is filled instead of overflowed
+    assert_eq!(thumb_length_for(12.0, 1_000_000.0), 12.0);
+}
+
+/// Test that an Auto scrollbar hides once scrolling has been idle for the delay.
+#[test]
+fn test_auto_scrollbar_hides_after_idle_delay() {
+    let delay = default_scrollbar::AUTO_HIDE_DELAY;
+    let scrolled_at = Instant::now();
+
+    assert!(!auto_scrollbar_visible(false, None, scrolled_at));
+    assert!(auto_scrollbar_visible(true, None, scrolled_at));
+    assert!(auto_scrollbar_visible(false, Some(scrolled_at), scrolled_at + delay / 2));
+    assert!(!auto_scrollbar_visible(false, Some(scrolled_at), scrolled_at + delay));
+
+    let container = TestWidgetBuilder::new()
+        .with_tag("container")
+        .with_size(200.0, 200.0)
+        .with_overflow(Overflow::Auto)
+        .build();
+    container.mark_scroll_activity();
+    let scrolled_at = container.scroll_activity().unwrap();
+
+    // Still waiting to hide, then hidden and repainted
+    assert!(expire_scrollbar_activity(&container, scrolled_at + delay / 2));
+    assert!(container.scroll_activity().is_some());
+    container.clear_dirty();
+    assert!(!expire_scrollbar_activity(&container, scrolled_at + delay + Duration::from_millis(1)));
+    assert!(container.scroll_activity().is_none());
+    assert!(container.is_dirty());
+}
@@ -0,0 +1,44 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the text dump of the component tree.
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue_style::properties::{Height, Size, Width};
+use rvue_style::ComputedStyles;
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+/// Test that the dump lists types, ids, sorted classes, key props and rects.
+#[test]
+fn test_debug_tree_dump() {
+    let button = Component::with_global_id(ComponentType::Button, PropertyMap::new());
+    button.set_widget_styles(ComputedStyles {
+        width: Some(Width(Size::Pixels(30.0))),
+        height: Some(Height(Size::Pixels(20.0))),
+        ..Default::default()
+    });
+    button.add_class("primary");
+    button.add_class("large");
+    button.set_aria_label("Close");
+
+    let left = TestWidgetBuilder::new().with_tag("left").with_size(50.0, 50.0).build();
+    let root = TestWidgetBuilder::new()
+        .with_tag("root")
+        .with_size(200.0, 50.0)
+        .with_children(vec![left, Gc::clone(&button)])
+        .build();
+    root.add_class("toolbar");
+
+    let mut harness = TestHarness::create(root);
+    let expected = "\
+Flex #root .toolbar [0, 0, 200x50]
+  Flex #left [0, 0, 50x50]
+  Button .large .primary aria-label=\"Close\" [50, 0, 30x20]
+";
+    assert_eq!(harness.debug_tree(), expected);
+
+    // The dump is stable across calls
+    assert_eq!(harness.debug_tree(), expected);
+}
@@ -0,0 +1,12 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for snapshot output.
+
+#![allow(unused_imports)]
+
+mod debug_tree_test;
+mod pixel_diff_test;
+
+pub use debug_tree_test::*;
+pub use pixel_diff_test::*;
@@ -0,0 +1,70 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for tolerant image snapshot comparison.
+
+use image::{DynamicImage, ImageBuffer, Rgba};
+use rvue_testing::{compare_images, SnapshotError, SnapshotManager, SnapshotOptions};
+
+fn solid_image(color: [u8; 4]) -> ImageBuffer<Rgba<u8>, Vec<u8>> {
+    ImageBuffer::from_pixel(20, 10, Rgba(color))
+}
+
+/// Test that small per-pixel and per-area differences stay within tolerance.
+#[test]
+fn test_near_identical_images_pass() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = SnapshotManager::new(dir.path().to_path_buf()).with_options(SnapshotOptions {
+        tolerance: 4,
+        max_diff_percent: 1.0,
+        ..Default::default()
+    });
+    let golden = solid_image([100, 100, 100, 255]);
+    manager.bless("button", &DynamicImage::ImageRgba8(golden)).unwrap();
+
+    // Every pixel is off by less than the per-pixel tolerance
+    let shifted = DynamicImage::ImageRgba8(solid_image([103, 98, 100, 255]));
+    manager.compare("button", &shifted).unwrap();
+
+    // One pixel of 200 (0.5%) is far off, below the allowed percentage
+    let mut speck = solid_image([100, 100, 100, 255]);
+    speck.put_pixel(3, 3, Rgba([255, 0, 0, 255]));
+    manager.compare("button", &DynamicImage::ImageRgba8(speck)).unwrap();
+
+    assert!(!dir.path().join("button.diff.png").exists());
+}
+
+/// Test that a change beyond tolerance fails and writes the diff and actual images.
+#[test]
+fn test_image_beyond_tolerance_fails_with_diff() {
+    let dir = tempfile::tempdir().unwrap();
+    let manager = SnapshotManager::new(dir.path().to_path_buf()).with_options(SnapshotOptions {
+        tolerance: 4,
+        max_diff_percent: 1.0,
+        ..Default::default()
+    });
+    let golden = DynamicImage::ImageRgba8(solid_image([100, 100, 100, 255]));
+    manager.bless("button", &golden).unwrap();
+
+    // A 5x2 block (5% of the pixels) changes color
+    let mut changed = solid_image([100, 100, 100, 255]);
+    for x in 0..5 {
+        for y in 0..2 {
+            changed.put_pixel(x, y, Rgba([100, 160, 100, 255]));
+        }
+    }
+    let changed = DynamicImage::ImageRgba8(changed);
+
+    let diff = compare_images(&golden, &changed, 4).unwrap();
+    assert_eq!(diff.max_channel_delta, 60);
+    assert_eq!(diff.differing_pixels, 10);
+    assert!((diff.differing_percent() - 5.0).abs() < f64::EPSILON);
+    assert!((diff.mean_channel_delta - 0.75).abs() < 1e-9);
+
+    match manager.compare("button", &changed) {
+        Err(SnapshotError::ExceedsTolerance { diff, .. }) => assert_eq!(diff.differing_pixels, 10),
+        other => panic!("expected a tolerance failure, got {:?}", other),
+    }
+    assert!(dir.path().join("button.diff.png").exists());
+    assert!(dir.path().join("button.new.png").exists());
+}
@@ -0,0 +1,38 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for advancing async timers with the harness's virtual clock.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rvue::async_runtime::spawn_interval;
+use rvue_testing::{TestHarness, TestWidgetBuilder};
+
+/// Test that an interval fires once per elapsed period, without waiting on the wall clock.
+#[test]
+fn test_interval_fires_once_per_advanced_period() {
+    let root = TestWidgetBuilder::new().with_size(100.0, 100.0).build();
+    let mut harness = TestHarness::create(root);
+
+    let period = Duration::from_secs(60);
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let ticks_clone = Arc::clone(&ticks);
+    let handle = spawn_interval(period, move || {
+        ticks_clone.fetch_add(1, Ordering::SeqCst);
+        async {}
+    });
+
+    // The first tick fires immediately
+    harness.advance_time(Duration::ZERO);
+    assert_eq!(ticks.load(Ordering::SeqCst), 1);
+
+    harness.advance_time(period * 5);
+    assert_eq!(ticks.load(Ordering::SeqCst), 6);
+
+    harness.advance_time(period / 2);
+    assert_eq!(ticks.load(Ordering::SeqCst), 6);
+
+    handle.stop();
+}
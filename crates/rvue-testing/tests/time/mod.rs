@@ -0,0 +1,14 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for virtual time control.
+
+#![allow(unused_imports)]
+
+mod advance_time_test;
+mod spinner_test;
+mod tooltip_test;
+
+pub use advance_time_test::*;
+pub use spinner_test::*;
+pub use tooltip_test::*;
@@ -0,0 +1,84 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the frame-driven rotation of spinners.
+
+use std::f64::consts::TAU;
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::{PropertyMap, ShowCondition};
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::widgets::spinner::SPINNER_PERIOD;
+use rvue::{Spinner, TaffyTree};
+use rvue_testing::{TestHarness, TestWidgetBuilder, FRAME_INTERVAL};
+
+/// A spinner inside a visible Show, inside a 200x100 root.
+fn mount_spinner() -> (TestHarness, Gc<Component>, Gc<Component>) {
+    let root = TestWidgetBuilder::new().with_size(200.0, 100.0).build();
+    let show =
+        Component::with_global_id(ComponentType::Show, PropertyMap::with(ShowCondition(true)));
+    root.add_child(Gc::clone(&show));
+    show.set_parent(Some(Gc::clone(&root)));
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = Spinner::new().build(&mut ctx);
+    state.mount(Some(Gc::clone(&show)));
+    let spinner = Gc::clone(state.component());
+
+    (TestHarness::create(root), show, spinner)
+}
+
+/// Test that each frame turns the arc by the time since the previous frame.
+#[test]
+fn test_spinner_angle_increases_every_frame() {
+    let (mut harness, _show, spinner) = mount_spinner();
+    let step = TAU * FRAME_INTERVAL.as_secs_f64() / SPINNER_PERIOD.as_secs_f64();
+
+    // The first frame only starts the clock
+    harness.advance();
+    let mut previous = spinner.spinner_angle();
+    assert_eq!(previous, 0.0);
+
+    for _ in 0..5 {
+        harness.advance();
+        let angle = spinner.spinner_angle();
+        assert!(angle > previous, "angle went from {previous} to {angle}");
+        assert!((angle - previous - step).abs() < 1e-9);
+        previous = angle;
+    }
+}
+
+/// Test that an unmounted spinner neither turns nor asks for repaints, and resumes on mount.
+#[test]
+fn test_unmounted_spinner_stops_animating() {
+    let (mut harness, show, spinner) = mount_spinner();
+    for _ in 0..3 {
+        harness.advance();
+    }
+    assert!(spinner.spinner_angle() > 0.0);
+
+    // Hiding the Show unmounts the spinner on the next frame
+    show.set_show_when(false);
+    harness.advance();
+    let stopped = spinner.spinner_angle();
+    harness.root().clear_dirty();
+
+    for _ in 0..5 {
+        harness.advance();
+    }
+    assert_eq!(spinner.spinner_angle(), stopped);
+    assert!(!spinner.is_dirty());
+    assert!(!harness.root().is_dirty());
+
+    // Time spent hidden does not jump the arc forward once it is shown again
+    show.set_show_when(true);
+    harness.advance();
+    assert_eq!(spinner.spinner_angle(), stopped);
+    harness.advance();
+    assert!(spinner.spinner_angle() > stopped);
+}
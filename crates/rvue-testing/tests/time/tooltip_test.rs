@@ -0,0 +1,114 @@
+// Copyright 2025 the Rvue Authors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for showing tooltips after a hover delay.
+
+use std::time::Duration;
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::text::TextContext;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::{TaffyTree, Tooltip};
+use rvue_style::properties::{Height, Size, Width};
+use rvue_style::ComputedStyles;
+use rvue_testing::{TestHarness, TestWidgetBuilder, FRAME_INTERVAL};
+use vello::kurbo::Rect;
+
+const DELAY: Duration = Duration::from_millis(300);
+
+/// A 200x100 column with a 40x20 button anchor at `(x, y)` carrying a tooltip.
+fn mount_tooltip(x: f64, y: f64) -> (TestHarness, Gc<Component>, Gc<Component>) {
+    let anchor = Component::with_global_id(ComponentType::Button, PropertyMap::new());
+    anchor.set_widget_styles(ComputedStyles {
+        width: Some(Width(Size::Pixels(40.0))),
+        height: Some(Height(Size::Pixels(20.0))),
+        ..Default::default()
+    });
+    let column = TestWidgetBuilder::new()
+        .with_size(200.0, 100.0)
+        .with_flex_direction(rvue_style::properties::FlexDirection::Column)
+        .build();
+    let row = TestWidgetBuilder::new().with_size(200.0, 20.0).build();
+    let spacer = TestWidgetBuilder::new().with_size(x, 20.0).build();
+    let top = TestWidgetBuilder::new().with_size(200.0, y).build();
+    for (parent, child) in [(&row, &spacer), (&row, &anchor), (&column, &top), (&column, &row)] {
+        parent.add_child(Gc::clone(child));
+        child.set_parent(Some(Gc::clone(parent)));
+    }
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = Tooltip::new("Save the file").delay(DELAY).build(&mut ctx);
+    state.mount(Some(Gc::clone(&anchor)));
+    let tooltip = Gc::clone(state.component());
+
+    (TestHarness::create(column), anchor, tooltip)
+}
+
+/// Run frames for at least `duration`.
+fn run_frames(harness: &mut TestHarness, duration: Duration) {
+    for _ in 0..duration.as_millis().div_ceil(FRAME_INTERVAL.as_millis()) {
+        harness.advance();
+    }
+}
+
+/// Test that the tooltip shows only after the delay while hovered, and hides on leave.
+#[test]
+fn test_tooltip_shows_after_hover_delay_and_hides_on_leave() {
+    let (mut harness, anchor, tooltip) = mount_tooltip(20.0, 10.0);
+    let root = Gc::clone(harness.root());
+
+    harness.mouse_move_to(Gc::clone(&anchor));
+    assert!(*anchor.is_hovered.borrow());
+    run_frames(&mut harness, DELAY / 2);
+    assert!(!tooltip.tooltip_open());
+
+    run_frames(&mut harness, DELAY);
+    assert!(tooltip.tooltip_open());
+    let bubble = harness.get_layout(&tooltip).unwrap();
+    // Below the anchor, left-aligned with it
+    assert_eq!((bubble.x0, bubble.y0), (20.0, 34.0));
+
+    harness.mouse_move_to(root);
+    assert!(!tooltip.tooltip_open());
+    run_frames(&mut harness, DELAY * 2);
+    assert!(!tooltip.tooltip_open());
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+/// Test that leaving before the delay cancels the pending show.
+#[test]
+fn test_tooltip_quick_pass_does_not_show() {
+    let (mut harness, anchor, tooltip) = mount_tooltip(20.0, 10.0);
+    let root = Gc::clone(harness.root());
+
+    harness.mouse_move_to(Gc::clone(&anchor));
+    run_frames(&mut harness, DELAY / 2);
+    harness.mouse_move_to(root);
+    run_frames(&mut harness, DELAY * 2);
+    assert!(!tooltip.tooltip_open());
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+/// Test that a tooltip near the bottom-right corner flips above and shifts left to stay inside.
+#[test]
+fn test_tooltip_flips_to_stay_on_screen() {
+    let (mut harness, anchor, tooltip) = mount_tooltip(150.0, 75.0);
+
+    harness.mouse_move_to(Gc::clone(&anchor));
+    run_frames(&mut harness, DELAY * 2);
+    assert!(tooltip.tooltip_open());
+
+    let bubble = harness.get_layout(&tooltip).unwrap();
+    let window = Rect::new(0.0, 0.0, 200.0, 100.0);
+    assert_eq!(bubble.intersect(window), bubble, "tooltip {bubble:?} should stay inside");
+    assert_eq!(bubble.y1, 75.0 - 4.0, "tooltip should sit above the anchor");
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
@@ -0,0 +1,178 @@
+//! Accessibility tree export via AccessKit
+//!
+//! Builds an AccessKit [`TreeUpdate`] from the component tree so platform
+//! screen readers can inspect the UI.
+
+use crate::component::{Component, ComponentType};
+use accesskit::{Action, Invalid, Node, NodeId, Rect, Role, Toggled, Tree, TreeUpdate};
+use rudo_gc::Gc;
+use vello::kurbo::Point;
+
+/// Map a component type to its AccessKit role
+pub fn role_for(component_type: &ComponentType) -> Role {
+    match component_type {
+        ComponentType::Text => Role::Label,
+        ComponentType::Button => Role::Button,
+        ComponentType::TextInput => Role::TextInput,
+        ComponentType::TextArea => Role::MultilineTextInput,
+        ComponentType::NumberInput => Role::SpinButton,
+        ComponentType::Checkbox => Role::CheckBox,
+        ComponentType::Radio => Role::RadioButton,
+        ComponentType::ContextMenu => Role::Menu,
+        ComponentType::Tooltip => Role::Tooltip,
+        ComponentType::ProgressBar | ComponentType::Spinner => Role::ProgressIndicator,
+        ComponentType::Canvas => Role::Canvas,
+        ComponentType::Show
+        | ComponentType::For
+        | ComponentType::Switch
+        | ComponentType::Suspense
+        | ComponentType::Flex
+        | ComponentType::Custom(_) => Role::GenericContainer,
+    }
+}
+
+/// Build a full accessibility tree rooted at `root`.
+///
+/// Show, For, Switch and Suspense are transparent, so their children are attached to the nearest
+/// ancestor node. `focused` becomes the tree focus, falling back to the root.
+pub fn build_tree_update(root: &Gc<Component>, focused: Option<&Gc<Component>>) -> TreeUpdate {
+    let mut nodes = Vec::new();
+    let root_node = build_node(root, Point::ZERO, &mut nodes);
+    nodes.push((node_id(root), root_node));
+
+    let focus = focused.map(node_id).unwrap_or_else(|| node_id(root));
+    let mut tree = Tree::new(node_id(root));
+    tree.toolkit_name = Some("rvue".to_string());
+    tree.toolkit_version = Some(env!("CARGO_PKG_VERSION").to_string());
+
+    TreeUpdate { nodes, tree: Some(tree), focus }
+}
+
+/// Get the AccessKit node id for a component
+pub fn node_id(component: &Gc<Component>) -> NodeId {
+    NodeId(component.id)
+}
+
+fn build_node(
+    component: &Gc<Component>,
+    parent_origin: Point,
+    nodes: &mut Vec<(NodeId, Node)>,
+) -> Node {
+    let mut node = Node::new(role_for(&component.component_type));
+    let layout = component.layout_node.borrow().as_ref().and_then(|n| n.layout_result);
+
+    let origin = match layout {
+        Some(layout) => {
+            let origin = Point::new(
+                parent_origin.x + layout.location.x as f64,
+                parent_origin.y + layout.location.y as f64,
+            );
+            node.set_bounds(Rect::new(
+                origin.x,
+                origin.y,
+                origin.x + layout.size.width as f64,
+                origin.y + layout.size.height as f64,
+            ));
+            origin
+        }
+        None => parent_origin,
+    };
+
+    if let Some(name) = component.accessible_name() {
+        node.set_label(name);
+    }
+
+    match component.component_type {
+        ComponentType::Checkbox => node.set_toggled(if component.checkbox_indeterminate() {
+            Toggled::Mixed
+        } else {
+            Toggled::from(component.checkbox_checked())
+        }),
+        ComponentType::Radio => node.set_toggled(Toggled::from(component.radio_checked())),
+        ComponentType::TextInput | ComponentType::TextArea => {
+            let value = match component.text_editor() {
+                Some(editor) => editor.editor().content(),
+                None => component.text_input_value(),
+            };
+            node.set_value(value);
+        }
+        ComponentType::NumberInput => node.set_numeric_value(component.number_input_value()),
+        ComponentType::ProgressBar if !component.progress_indeterminate() => {
+            node.set_min_numeric_value(0.0);
+            node.set_max_numeric_value(1.0);
+            node.set_numeric_value(component.progress_value());
+        }
+        _ => {}
+    }
+
+    if let Some(href) = component.link_href() {
+        node.set_role(Role::Link);
+        node.set_url(href);
+    }
+
+    if let Some(message) = component.error_message() {
+        node.set_invalid(Invalid::True);
+        node.set_description(message);
+    }
+
+    if component.is_disabled() {
+        node.set_disabled();
+    } else {
+        if component.accepts_focus() {
+            node.add_action(Action::Focus);
+        }
+        if component.event_handlers.borrow().get_click().is_some()
+            || matches!(component.component_type, ComponentType::Button)
+        {
+            node.add_action(Action::Click);
+        }
+    }
+
+    for child in accessible_children(component) {
+        let child_node = build_node(&child, origin, nodes);
+        node.push_child(node_id(&child));
+        nodes.push((node_id(&child), child_node));
+    }
+
+    node
+}
+
+/// Children that get their own node, looking through transparent control-flow components
+fn accessible_children(component: &Gc<Component>) -> Vec<Gc<Component>> {
+    let mut children = Vec::new();
+    for child in component.children.borrow().iter() {
+        match child.component_type {
+            ComponentType::Show if !child.show_content_visible() => {}
+            ComponentType::Show | ComponentType::For | ComponentType::Switch => {
+                children.extend(accessible_children(child));
+            }
+            ComponentType::Suspense => children.extend(child.suspense_visible_child()),
+            _ => children.push(Gc::clone(child)),
+        }
+    }
+    children
+}
+
+/// Activation handler that lets the adapter use a placeholder until the first frame's tree is sent
+pub(crate) struct DeferredActivationHandler;
+
+impl accesskit::ActivationHandler for DeferredActivationHandler {
+    fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+        None
+    }
+}
+
+/// Action handler for assistive technology requests, which are not routed to widgets yet
+pub(crate) struct IgnoredActionHandler;
+
+impl accesskit::ActionHandler for IgnoredActionHandler {
+    fn do_action(&mut self, request: accesskit::ActionRequest) {
+        log::debug!("Ignoring accessibility action {:?} on {:?}", request.action, request.target);
+    }
+}
+
+pub(crate) struct NoopDeactivationHandler;
+
+impl accesskit::DeactivationHandler for NoopDeactivationHandler {
+    fn deactivate_accessibility(&mut self) {}
+}
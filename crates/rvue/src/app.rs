@@ -10,27 +10,37 @@ pub enum RvueUserEvent {
 #[derive(Debug, Clone)]
 pub enum RvueUserEvent {}
 
+use crate::accessibility::{
+    build_tree_update, DeferredActivationHandler, IgnoredActionHandler, NoopDeactivationHandler,
+};
 use crate::component::{Component, ComponentLifecycle};
 use crate::event::context::EventContextOps;
+use crate::event::cursor::{cursor_icon_for, CursorState};
 use crate::event::dispatch::{
-    run_pointer_event_pass, run_text_event_pass, update_cursor_blink_states,
+    check_long_press, run_pointer_event_pass, run_text_event_pass, update_cursor_blink_states,
 };
-use crate::event::handler::ScrollDragState;
-use crate::event::hit_test::hit_test;
+use crate::event::drag::DragState;
+use crate::event::handler::{LongPressState, ScrollDragState};
+use crate::event::hit_test::hit_test_with_stylesheet;
+use crate::event::shortcut::{take_pending_shortcuts, ShortcutRegistry};
 use crate::event::types::{
     map_scroll_delta, KeyState as RvueKeyState, KeyboardEvent as RvueKeyboardEvent,
     Modifiers as RvueModifiers, PointerButtonEvent, PointerEvent, PointerMoveEvent,
 };
 use crate::event::update::{run_update_focus_pass, run_update_pointer_pass};
 use crate::event::winit_translator::{get_pointer_event_position, WinitTranslator};
+use crate::gc::GcPacer;
 use crate::render::Scene as RvueScene;
 use crate::style::Stylesheet;
 use crate::vello_util::{CreateSurfaceError, RenderContext, RenderSurface};
 use crate::view::ViewStruct;
+use crate::widgets::scroll_bar::expire_scrollbar_activity;
 use rudo_gc::{Gc, GcCell};
-use std::cell::RefMut;
+use std::any::Any;
+use std::cell::{Cell, RefMut};
 use std::io::Write;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use vello::kurbo::Affine;
 use vello::kurbo::{Point, Vec2};
 use vello::peniko::Color;
@@ -38,11 +48,12 @@ use vello::{AaConfig, AaSupport, Renderer, RendererOptions};
 use wgpu::Color as WgpuColor;
 use wgpu::SurfaceTexture;
 use winit::application::ApplicationHandler;
+use winit::dpi::LogicalSize;
 use winit::dpi::PhysicalSize;
 use winit::event::{ElementState, WindowEvent};
-use winit::event_loop::{ActiveEventLoop, EventLoop};
+use winit::event_loop::{ActiveEventLoop, ControlFlow, EventLoop};
 use winit::keyboard::ModifiersState;
-use winit::window::{Window, WindowId};
+use winit::window::{Window, WindowAttributes, WindowId};
 
 pub trait AppStateLike {
     fn root_component(&self) -> Gc<Component>;
@@ -67,9 +78,17 @@ pub trait AppStateLike {
     fn clear_pointer_capture(&mut self);
     fn scroll_drag_state(&self) -> Option<ScrollDragState>;
     fn set_scroll_drag_state(&mut self, state: Option<ScrollDragState>);
+    fn long_press_state(&self) -> Option<LongPressState>;
+    fn set_long_press_state(&mut self, state: Option<LongPressState>);
+    fn drag_state(&self) -> Option<DragState>;
+    fn set_drag_state(&mut self, state: Option<DragState>);
     fn enable_ime(&mut self);
     fn disable_ime(&mut self);
     fn update_ime_cursor_area(&mut self);
+    fn shortcuts(&self) -> &ShortcutRegistry;
+    fn shortcuts_mut(&mut self) -> &mut ShortcutRegistry;
+    /// The stylesheet the view is painted with, so hit tests see the same styles
+    fn stylesheet(&self) -> Option<&Stylesheet>;
 }
 
 pub struct FocusState {
@@ -78,6 +97,244 @@ pub struct FocusState {
     pub pending_focus: Option<Gc<Component>>,
 }
 
+/// Frame rate used to pace pending animations in [`RenderMode::Reactive`].
+const ANIMATION_FPS: u32 = 60;
+
+thread_local! {
+    /// Render mode requested with [`set_render_mode`], applied before the event loop next waits.
+    static PENDING_RENDER_MODE: Cell<Option<RenderMode>> = const { Cell::new(None) };
+    /// Reduced-motion preference set with [`set_reduced_motion`], which wins over the system one.
+    static REDUCED_MOTION_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+    /// Reduced-motion preference the system reported at launch, when asked to read it.
+    static SYSTEM_REDUCED_MOTION: Cell<bool> = const { Cell::new(false) };
+    /// High-contrast preference set with [`set_high_contrast`], which wins over the system one.
+    static HIGH_CONTRAST_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+    /// High-contrast preference the system reported at launch, when asked to read it.
+    static SYSTEM_HIGH_CONTRAST: Cell<bool> = const { Cell::new(false) };
+}
+
+/// When the event loop produces frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// Redraw only when something changes or an animation is pending, and sleep otherwise.
+    #[default]
+    Reactive,
+    /// Redraw continuously, capped at the given frames per second.
+    Continuous(u32),
+}
+
+impl RenderMode {
+    /// Time between frames, or `None` when frames are only drawn on demand.
+    pub fn frame_interval(&self) -> Option<Duration> {
+        match *self {
+            RenderMode::Reactive => None,
+            RenderMode::Continuous(fps) => Some(interval_for_fps(fps)),
+        }
+    }
+}
+
+fn interval_for_fps(fps: u32) -> Duration {
+    Duration::from_secs_f64(1.0 / fps.max(1) as f64)
+}
+
+/// Switch the running application between reactive and continuous rendering.
+///
+/// Can also be called while the view is being built to pick the initial mode.
+pub fn set_render_mode(mode: RenderMode) {
+    PENDING_RENDER_MODE.with(|pending| pending.set(Some(mode)));
+}
+
+/// Override whether animations are reduced, or pass `None` to follow the system setting.
+///
+/// The system setting is only read when the window is configured with
+/// [`WindowConfig::with_system_preferences`]; otherwise it counts as off.
+///
+/// While reduced motion is on, transitions jump straight to their end state.
+pub fn set_reduced_motion(reduced: Option<bool>) {
+    REDUCED_MOTION_OVERRIDE.with(|cell| cell.set(reduced));
+}
+
+/// Whether animations should be skipped, from [`set_reduced_motion`] or the system setting.
+pub fn prefers_reduced_motion() -> bool {
+    REDUCED_MOTION_OVERRIDE.with(Cell::get).unwrap_or_else(|| SYSTEM_REDUCED_MOTION.with(Cell::get))
+}
+
+/// Override whether styles are adjusted for high contrast, or pass `None` to follow the system
+/// setting.
+///
+/// The system setting is only read when the window is configured with
+/// [`WindowConfig::with_system_preferences`]; otherwise it counts as off.
+///
+/// While high contrast is on, resolved text and background colors are pushed apart until they
+/// reach [`rvue_style::HIGH_CONTRAST_MIN_RATIO`].
+pub fn set_high_contrast(enabled: Option<bool>) {
+    HIGH_CONTRAST_OVERRIDE.with(|cell| cell.set(enabled));
+}
+
+/// Whether styles are adjusted for high contrast, from [`set_high_contrast`] or the system
+/// setting.
+pub fn prefers_high_contrast() -> bool {
+    HIGH_CONTRAST_OVERRIDE.with(Cell::get).unwrap_or_else(|| SYSTEM_HIGH_CONTRAST.with(Cell::get))
+}
+
+/// Read the system accessibility preferences into the values used without an override.
+///
+/// Only the GNOME settings are read; elsewhere both preferences stay off.
+fn load_system_preferences() {
+    let animations = gnome_setting("org.gnome.desktop.interface", "enable-animations");
+    let high_contrast = gnome_setting("org.gnome.desktop.a11y.interface", "high-contrast");
+    SYSTEM_REDUCED_MOTION.with(|cell| cell.set(animations == Some(false)));
+    SYSTEM_HIGH_CONTRAST.with(|cell| cell.set(high_contrast == Some(true)));
+}
+
+/// Read a boolean GNOME setting, or `None` when it isn't available.
+fn gnome_setting(schema: &str, key: &str) -> Option<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        let output =
+            std::process::Command::new("gsettings").args(["get", schema, key]).output().ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (schema, key);
+        None
+    }
+}
+
+/// Decide how the event loop should wait after handling events.
+///
+/// Returns `Poll` when a frame is due now, `WaitUntil` the next frame when one is coming but
+/// not yet due, and `Wait` when nothing needs drawing until the next event.
+pub fn control_flow_for(
+    mode: RenderMode,
+    last_frame: Option<Instant>,
+    now: Instant,
+    animating: bool,
+) -> ControlFlow {
+    let interval = match mode.frame_interval() {
+        Some(interval) => interval,
+        None if animating => interval_for_fps(ANIMATION_FPS),
+        None => return ControlFlow::Wait,
+    };
+    match last_frame.map(|last| last + interval) {
+        Some(next_frame) if next_frame > now => ControlFlow::WaitUntil(next_frame),
+        _ => ControlFlow::Poll,
+    }
+}
+
+/// Time left before the event loop must wake, or `None` when it waits for input.
+fn idle_budget(control_flow: ControlFlow, now: Instant) -> Option<Duration> {
+    match control_flow {
+        ControlFlow::Wait => None,
+        ControlFlow::WaitUntil(deadline) => Some(deadline.saturating_duration_since(now)),
+        ControlFlow::Poll => Some(Duration::ZERO),
+    }
+}
+
+/// Window configuration applied when the application window is created.
+///
+/// Defaults match the window created by [`run_app`]: an 800x600 resizable,
+/// decorated, opaque window titled "Rvue Application".
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowConfig {
+    pub title: String,
+    pub size: LogicalSize<f64>,
+    pub min_size: Option<LogicalSize<f64>>,
+    pub max_size: Option<LogicalSize<f64>>,
+    pub resizable: bool,
+    pub decorations: bool,
+    pub transparent: bool,
+    /// Read the system reduced-motion and high-contrast settings at launch
+    pub system_preferences: bool,
+}
+
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            title: "Rvue Application".to_string(),
+            size: LogicalSize::new(800.0, 600.0),
+            min_size: None,
+            max_size: None,
+            resizable: true,
+            decorations: true,
+            transparent: false,
+            system_preferences: false,
+        }
+    }
+}
+
+impl WindowConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn with_size(mut self, width: f64, height: f64) -> Self {
+        self.size = LogicalSize::new(width, height);
+        self
+    }
+
+    pub fn with_min_size(mut self, width: f64, height: f64) -> Self {
+        self.min_size = Some(LogicalSize::new(width, height));
+        self
+    }
+
+    pub fn with_max_size(mut self, width: f64, height: f64) -> Self {
+        self.max_size = Some(LogicalSize::new(width, height));
+        self
+    }
+
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    pub fn with_decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+
+    /// Follow the system reduced-motion and high-contrast settings where no override is set.
+    ///
+    /// Reading them queries the desktop before the window opens, so it is off by default.
+    pub fn with_system_preferences(mut self, system_preferences: bool) -> Self {
+        self.system_preferences = system_preferences;
+        self
+    }
+
+    /// Builds the winit attributes used to create the window.
+    pub fn window_attributes(&self) -> WindowAttributes {
+        let mut attributes = Window::default_attributes()
+            .with_title(self.title.clone())
+            .with_inner_size(self.size)
+            .with_resizable(self.resizable)
+            .with_decorations(self.decorations)
+            .with_transparent(self.transparent);
+
+        if let Some(min_size) = self.min_size {
+            attributes = attributes.with_min_inner_size(min_size);
+        }
+        if let Some(max_size) = self.max_size {
+            attributes = attributes.with_max_inner_size(max_size);
+        }
+
+        attributes
+    }
+}
+
 /// Application state
 /// Fields are ordered for correct drop order: GC resources first, window last
 pub struct AppState<'a> {
@@ -93,11 +350,26 @@ pub struct AppState<'a> {
     pub focused_path: Vec<Gc<Component>>,
     pub needs_pointer_pass_update: bool,
     pub scroll_drag_state: Option<ScrollDragState>,
+    pub long_press_state: Option<LongPressState>,
+    pub drag_state: Option<DragState>,
+    pub drag_payload: Option<Box<dyn Any>>,
+    /// Text copied with Ctrl+C, kept within the app rather than on the system clipboard
+    copy_buffer: Option<String>,
     pub last_gc_count: usize,
     pub last_anim_duration: Option<u64>,
     pub needs_cursor_blink_update: bool,
     pub is_ime_active: bool,
     pub last_sent_ime_area: Option<(f64, f64, f64, f64)>,
+    pub cursor_state: CursorState,
+    pub window_config: WindowConfig,
+    pub scale_factor: f64,
+    pub shortcuts: ShortcutRegistry,
+    pub render_mode: RenderMode,
+    pub last_frame: Option<Instant>,
+    pub gc_pacer: GcPacer,
+    #[cfg(feature = "inspector")]
+    pub inspector: crate::render::Inspector,
+    accessibility_adapter: Option<accesskit_winit::Adapter>,
     renderer: Option<Renderer>,
     surface: Option<RenderSurface<'a>>,
     render_cx: Option<RenderContext>,
@@ -193,6 +465,22 @@ impl<'a> AppStateLike for AppState<'a> {
         self.scroll_drag_state = state;
     }
 
+    fn long_press_state(&self) -> Option<LongPressState> {
+        self.long_press_state.clone()
+    }
+
+    fn set_long_press_state(&mut self, state: Option<LongPressState>) {
+        self.long_press_state = state;
+    }
+
+    fn drag_state(&self) -> Option<DragState> {
+        self.drag_state
+    }
+
+    fn set_drag_state(&mut self, state: Option<DragState>) {
+        self.drag_state = state;
+    }
+
     fn set_needs_cursor_blink_update(&mut self) {
         self.needs_cursor_blink_update = true;
     }
@@ -243,6 +531,18 @@ impl<'a> AppStateLike for AppState<'a> {
             }
         }
     }
+
+    fn shortcuts(&self) -> &ShortcutRegistry {
+        &self.shortcuts
+    }
+
+    fn shortcuts_mut(&mut self) -> &mut ShortcutRegistry {
+        &mut self.shortcuts
+    }
+
+    fn stylesheet(&self) -> Option<&Stylesheet> {
+        self.stylesheet.as_ref()
+    }
 }
 
 impl EventContextOps for AppState<'_> {
@@ -286,10 +586,6 @@ impl EventContextOps for AppState<'_> {
         self.root_component()
     }
 
-    fn local_position(&self, window_pos: Point) -> Point {
-        window_pos
-    }
-
     fn has_pointer_capture(&self) -> bool {
         false
     }
@@ -301,6 +597,22 @@ impl EventContextOps for AppState<'_> {
     fn set_needs_cursor_blink_update(&mut self) {
         self.needs_cursor_blink_update = true;
     }
+
+    fn drag_payload(&self) -> Option<&dyn Any> {
+        self.drag_payload.as_deref()
+    }
+
+    fn set_drag_payload(&mut self, payload: Option<Box<dyn Any>>) {
+        self.drag_payload = payload;
+    }
+
+    fn copied_text(&self) -> Option<&str> {
+        self.copy_buffer.as_deref()
+    }
+
+    fn set_copied_text(&mut self, text: String) {
+        self.copy_buffer = Some(text);
+    }
 }
 
 impl<'a> AppState<'a> {
@@ -322,15 +634,50 @@ impl<'a> AppState<'a> {
             focused_path: Vec::new(),
             needs_pointer_pass_update: false,
             scroll_drag_state: None,
+            long_press_state: None,
+            drag_state: None,
+            drag_payload: None,
+            copy_buffer: None,
             last_gc_count: 0,
             last_anim_duration: None,
             needs_cursor_blink_update: false,
             is_ime_active: false,
             last_sent_ime_area: None,
+            cursor_state: CursorState::new(),
+            window_config: WindowConfig::default(),
+            scale_factor: 1.0,
+            shortcuts: ShortcutRegistry::new(),
+            render_mode: RenderMode::default(),
+            last_frame: None,
+            gc_pacer: GcPacer::new(),
+            #[cfg(feature = "inspector")]
+            inspector: crate::render::Inspector::new(),
+            accessibility_adapter: None,
             event_translator: WinitTranslator::new(),
         }
     }
 
+    /// Switch between reactive and continuous rendering.
+    pub fn set_render_mode(&mut self, mode: RenderMode) {
+        self.render_mode = mode;
+        if let (RenderMode::Continuous(_), Some(window)) = (mode, &self.window) {
+            window.request_redraw();
+        }
+    }
+
+    /// Applies a mode requested with the free [`set_render_mode`] function.
+    fn apply_pending_render_mode(&mut self) {
+        if let Some(mode) = PENDING_RENDER_MODE.with(Cell::take) {
+            self.set_render_mode(mode);
+        }
+    }
+
+    /// Whether something is still changing on screen without further input.
+    fn has_pending_animation(&self) -> bool {
+        self.long_press_state.is_some()
+            || self.view.as_ref().is_some_and(|v| v.root_component.is_dirty())
+    }
+
     fn handle_translated_pointer_event(
         &mut self,
         event: &ui_events::pointer::PointerEvent,
@@ -342,8 +689,13 @@ impl<'a> AppState<'a> {
             let logical_pos = Point::new(logical_x, logical_y);
             self.last_pointer_pos = Some(logical_pos);
 
-            let new_hovered = hit_test(&self.root_component(), logical_pos);
+            let new_hovered = hit_test_with_stylesheet(
+                &self.root_component(),
+                logical_pos,
+                self.stylesheet.as_ref(),
+            );
             *self.hovered_component.borrow_mut() = new_hovered;
+            self.update_cursor();
         }
 
         self.scene.update();
@@ -352,9 +704,58 @@ impl<'a> AppState<'a> {
             crate::event::types::convert_pointer_event_from_ui_events(event, scale_factor);
 
         run_pointer_event_pass(self, &converted_event);
+        self.poll_long_press();
         self.request_redraw_if_dirty();
     }
 
+    /// Fires a pending long press once it has been held long enough, and keeps
+    /// frames coming until it fires or is cancelled.
+    fn poll_long_press(&mut self) {
+        if self.long_press_state.is_none() {
+            return;
+        }
+        check_long_press(self, Instant::now());
+        if self.long_press_state.is_some() {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+    }
+
+    /// Hides `Overflow::Auto` scrollbars once scrolling has been idle long enough, and keeps
+    /// frames coming until they have.
+    fn poll_scrollbar_auto_hide(&mut self) {
+        let Some(view) = &self.view else {
+            return;
+        };
+        if expire_scrollbar_activity(&view.root_component, Instant::now()) {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+    }
+
+    /// Sends the current component tree to the accessibility adapter while assistive
+    /// technology is listening.
+    fn update_accessibility_tree(&mut self) {
+        let (Some(adapter), Some(view)) = (&mut self.accessibility_adapter, &self.view) else {
+            return;
+        };
+        let root = &view.root_component;
+        let focused = self.focus_state.focused.as_ref();
+        adapter.update_if_active(|| build_tree_update(root, focused));
+    }
+
+    /// Syncs the OS cursor with the `cursor` style of the hovered component.
+    fn update_cursor(&mut self) {
+        let hovered = self.hovered_component.borrow().clone();
+        if let Some(cursor) = self.cursor_state.update(hovered.as_ref(), self.stylesheet.as_ref()) {
+            if let Some(window) = &self.window {
+                window.set_cursor(cursor_icon_for(&cursor));
+            }
+        }
+    }
+
     fn get_global_ime_area(component: &Gc<Component>) -> Option<(f64, f64, f64, f64)> {
         let ime_area = component.ime_area()?;
         let (local_x, local_y, width, height) = ime_area;
@@ -379,15 +780,42 @@ impl<'a> AppState<'a> {
 impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         if self.window.is_none() {
-            let window_attributes = winit::window::Window::default_attributes()
-                .with_title("Rvue Application")
-                .with_inner_size(winit::dpi::LogicalSize::new(800.0, 600.0));
+            // The AccessKit adapter must be created before the window is first shown
+            let window_attributes = self.window_config.window_attributes().with_visible(false);
 
             let window = event_loop.create_window(window_attributes).unwrap();
+            self.accessibility_adapter = Some(accesskit_winit::Adapter::with_direct_handlers(
+                event_loop,
+                &window,
+                DeferredActivationHandler,
+                IgnoredActionHandler,
+                NoopDeactivationHandler,
+            ));
+            window.set_visible(true);
+            self.scale_factor = window.scale_factor();
             self.window = Some(Arc::new(window));
         }
     }
 
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.apply_pending_render_mode();
+        // Shortcuts registered since the last frame join the traced registry before collecting
+        self.shortcuts.extend(take_pending_shortcuts());
+
+        let now = Instant::now();
+        let control_flow =
+            control_flow_for(self.render_mode, self.last_frame, now, self.has_pending_animation());
+        if self.gc_pacer.collect_if_idle(idle_budget(control_flow, now)) {
+            self.monitor_gc();
+        }
+        if control_flow == ControlFlow::Poll {
+            if let Some(window) = &self.window {
+                window.request_redraw();
+            }
+        }
+        event_loop.set_control_flow(control_flow);
+    }
+
     fn user_event(&mut self, _event_loop: &ActiveEventLoop, event: RvueUserEvent) {
         #[cfg(feature = "async")]
         {
@@ -412,6 +840,10 @@ impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
     }
 
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+        if let (Some(adapter), Some(window)) = (&mut self.accessibility_adapter, &self.window) {
+            adapter.process_event(window, &event);
+        }
+
         let scale_factor =
             self.window.as_ref().map(|w: &Arc<Window>| w.scale_factor()).unwrap_or(1.0);
 
@@ -694,9 +1126,28 @@ impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
             WindowEvent::Resized(size) => {
                 self.handle_resize(size);
             }
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.handle_scale_factor_change(scale_factor);
+            }
             WindowEvent::RedrawRequested => {
+                let frame_start = Instant::now();
+                self.last_frame = Some(frame_start);
+                let _span = tracing::debug_span!("frame").entered();
+
                 self.run_update_passes();
+                let updated = Instant::now();
                 self.render_frame();
+                let rendered = Instant::now();
+                self.update_accessibility_tree();
+
+                tracing::debug!(
+                    update = ?updated - frame_start,
+                    render = ?rendered - updated,
+                    accessibility = ?rendered.elapsed(),
+                    total = ?frame_start.elapsed(),
+                    layout_passes = self.scene.layout_passes(),
+                    "frame summary"
+                );
             }
             WindowEvent::CursorMoved { position, .. } => {
                 let scale_factor = self.window.as_ref().map(|w| w.scale_factor()).unwrap_or(1.0);
@@ -708,8 +1159,21 @@ impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
                 // Ensure layout is up to date before hit testing
                 self.scene.update();
 
-                let new_hovered = hit_test(&self.root_component(), point);
+                let new_hovered = hit_test_with_stylesheet(
+                    &self.root_component(),
+                    point,
+                    self.stylesheet.as_ref(),
+                );
                 *self.hovered_component.borrow_mut() = new_hovered;
+                self.update_cursor();
+
+                // The inspector highlights whatever is under the pointer
+                #[cfg(feature = "inspector")]
+                if self.inspector.is_enabled() {
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                }
 
                 let event = PointerEvent::Move(PointerMoveEvent {
                     position: point,
@@ -741,6 +1205,7 @@ impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
                 self.scene.update();
 
                 run_pointer_event_pass(self, &event);
+                self.poll_long_press();
                 self.request_redraw_if_dirty();
             }
             WindowEvent::CursorEntered { .. } => {
@@ -748,9 +1213,22 @@ impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
             }
             WindowEvent::CursorLeft { .. } => {
                 *self.hovered_component.borrow_mut() = None;
+                self.update_cursor();
                 run_pointer_event_pass(self, &PointerEvent::Leave(Default::default()));
             }
             WindowEvent::KeyboardInput { event: input, .. } => {
+                #[cfg(feature = "inspector")]
+                if input.state == ElementState::Pressed
+                    && !input.repeat
+                    && input.physical_key == winit::keyboard::KeyCode::F12
+                {
+                    self.inspector.toggle();
+                    if let Some(window) = &self.window {
+                        window.request_redraw();
+                    }
+                    return;
+                }
+
                 // Process pending focus before handling keyboard events
                 run_update_focus_pass(self);
 
@@ -783,6 +1261,9 @@ impl ApplicationHandler<RvueUserEvent> for AppState<'_> {
             }
             WindowEvent::Focused(focused) if !focused => {
                 *self.pointer_capture.borrow_mut() = None;
+                self.long_press_state = None;
+                self.drag_state = None;
+                self.drag_payload = None;
                 *self.hovered_component.borrow_mut() = None;
             }
             WindowEvent::MouseWheel { delta, .. } => {
@@ -848,13 +1329,44 @@ impl<'a> AppState<'a> {
         }
     }
 
+    /// Handles the window moving to a monitor with a different DPI.
+    ///
+    /// The surface is resized to the new physical size and the whole tree is marked
+    /// dirty so layout and text are rebuilt before the next frame is rendered at the
+    /// new scale.
+    fn handle_scale_factor_change(&mut self, scale_factor: f64) {
+        if self.scale_factor == scale_factor {
+            return;
+        }
+        self.scale_factor = scale_factor;
+
+        if let Some(size) = self.window.as_ref().map(|w| w.inner_size()) {
+            self.handle_resize(size);
+        }
+
+        if let Some(view) = &self.view {
+            view.root_component.mark_dirty();
+        }
+        self.scene.mark_dirty();
+
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+
     fn run_update_passes(&mut self) {
         if self.needs_pointer_pass_update {
             run_update_pointer_pass(self);
             self.needs_pointer_pass_update = false;
+            // Hover and active states changed, which `:hover` and `:active` cursors depend on
+            self.cursor_state.invalidate();
+            self.update_cursor();
         }
         run_update_focus_pass(self);
 
+        self.poll_long_press();
+        self.poll_scrollbar_auto_hide();
+
         // Update cursor blink animation
         if self.needs_cursor_blink_update {
             if let Some(duration) = self.last_anim_duration {
@@ -899,6 +1411,7 @@ impl<'a> AppState<'a> {
     }
 
     fn render_frame(&mut self) {
+        let _span = tracing::debug_span!("render_frame").entered();
         let (scale_factor, size) =
             match self.window.as_ref().map(|w| (w.scale_factor(), w.inner_size())) {
                 Some((sf, s)) if s.width != 0 && s.height != 0 => (sf, s),
@@ -925,7 +1438,11 @@ impl<'a> AppState<'a> {
         let _surface_format = surface.format;
 
         let render_params = vello::RenderParams {
-            base_color: Color::WHITE,
+            base_color: if self.window_config.transparent {
+                Color::TRANSPARENT
+            } else {
+                Color::WHITE
+            },
             width: size.width,
             height: size.height,
             antialiasing_method: AaConfig::Area,
@@ -958,18 +1475,44 @@ impl<'a> AppState<'a> {
         // Update scene (regenerates the underlying vello::Scene if dirty)
         self.scene.update();
 
+        #[cfg(feature = "inspector")]
+        let overlay = self.view.as_ref().filter(|_| self.inspector.is_enabled()).map(|view| {
+            let mut overlay = vello::Scene::new();
+            let hovered = self.hovered_component.borrow().clone();
+            let text_context = &mut self.scene.text_context;
+            self.inspector.paint(
+                &mut overlay,
+                &view.root_component,
+                hovered.as_ref(),
+                text_context,
+            );
+            overlay
+        });
+
         let scene = self.scene.vello_scene();
 
-        let transformed_scene = if scale_factor == 1.0 {
+        #[cfg_attr(not(feature = "inspector"), allow(unused_mut))]
+        let mut transformed_scene = if scale_factor == 1.0 {
             None
         } else {
             let mut new_scene = vello::Scene::new();
             new_scene.append(scene, Some(Affine::scale(scale_factor)));
             Some(new_scene)
         };
+        #[cfg(feature = "inspector")]
+        if let Some(overlay) = &overlay {
+            let composed = transformed_scene.get_or_insert_with(|| {
+                let mut composed = vello::Scene::new();
+                composed.append(scene, None);
+                composed
+            });
+            composed.append(overlay, Some(Affine::scale(scale_factor)));
+        }
         let scene_ref = transformed_scene.as_ref().unwrap_or(scene);
 
         // Clear intermediate texture before rendering
+        let clear_color =
+            if self.window_config.transparent { WgpuColor::TRANSPARENT } else { WgpuColor::WHITE };
         {
             let mut clear_encoder =
                 device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -983,7 +1526,7 @@ impl<'a> AppState<'a> {
                         resolve_target: None,
                         depth_slice: None,
                         ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(WgpuColor::WHITE),
+                            load: wgpu::LoadOp::Clear(clear_color),
                             store: wgpu::StoreOp::Store,
                         },
                     })],
@@ -1105,43 +1648,35 @@ pub fn run_app<F>(view_fn: F) -> Result<(), AppError>
 where
     F: FnOnce() -> ViewStruct + 'static,
 {
-    rudo_gc::set_gc_enabled(false);
-    rudo_gc::set_collect_condition(|_| false);
-
-    let view = view_fn();
-
-    #[cfg(feature = "async")]
-    {
-        let mut event_loop_builder = EventLoop::with_user_event();
-        let event_loop = event_loop_builder
-            .build()
-            .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
-        let proxy = event_loop.create_proxy();
-        let mut app_state = AppState::new();
-        app_state.view = Some(view);
-        app_state.stylesheet = Some(Stylesheet::with_defaults());
-        crate::async_runtime::dispatch::UiDispatchQueue::set_proxy(proxy);
-        event_loop
-            .run_app(&mut app_state)
-            .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
-        return Ok(());
-    }
-
-    #[cfg(not(feature = "async"))]
-    {
-        let event_loop = EventLoop::with_user_event()
-            .build()
-            .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
-        let mut app_state = AppState::new();
-        app_state.view = Some(view);
-        app_state.stylesheet = Some(Stylesheet::with_defaults());
-        event_loop
-            .run_app(&mut app_state)
-            .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
-    }
+    run_app_with_config(view_fn, WindowConfig::default())
+}
 
-    #[allow(unreachable_code)]
-    Ok(())
+/// Run the application with a custom window configuration.
+///
+/// # Example
+///
+/// ```ignore
+/// use rvue::prelude::*;
+/// use rvue::WindowConfig;
+///
+/// fn main() {
+///     let config = WindowConfig::new()
+///         .with_title("My App")
+///         .with_size(1024.0, 768.0)
+///         .with_min_size(400.0, 300.0);
+///
+///     rvue::run_app_with_config(|| {
+///         view! {
+///             <Text value="Hello, Rvue!" />
+///         }
+///     }, config);
+/// }
+/// ```
+pub fn run_app_with_config<F>(view_fn: F, config: WindowConfig) -> Result<(), AppError>
+where
+    F: FnOnce() -> ViewStruct + 'static,
+{
+    launch(view_fn, Stylesheet::with_defaults(), config)
 }
 
 /// Run the application with a stylesheet for CSS selector matching.
@@ -1182,11 +1717,40 @@ pub fn run_app_with_stylesheet<F>(
 where
     F: FnOnce() -> ViewStruct + 'static,
 {
+    let merged_stylesheet = match stylesheet {
+        Some(user_sheet) => {
+            let mut defaults = Stylesheet::with_defaults();
+            defaults.merge(&user_sheet);
+            defaults
+        }
+        None => Stylesheet::with_defaults(),
+    };
+
+    launch(view_fn, merged_stylesheet, WindowConfig::default())
+}
+
+fn launch<F>(view_fn: F, stylesheet: Stylesheet, config: WindowConfig) -> Result<(), AppError>
+where
+    F: FnOnce() -> ViewStruct + 'static,
+{
+    // Collections only run when the GcPacer finds idle time between frames
     rudo_gc::set_gc_enabled(false);
     rudo_gc::set_collect_condition(|_| false);
 
+    if config.system_preferences {
+        load_system_preferences();
+    }
     let view = view_fn();
 
+    let mut app_state = AppState::new();
+    app_state.view = Some(view);
+    app_state.shortcuts.extend(take_pending_shortcuts());
+    if let Some(mode) = PENDING_RENDER_MODE.with(Cell::take) {
+        app_state.render_mode = mode;
+    }
+    app_state.stylesheet = Some(stylesheet);
+    app_state.window_config = config;
+
     #[cfg(feature = "async")]
     {
         let mut event_loop_builder = EventLoop::with_user_event();
@@ -1194,20 +1758,6 @@ where
             .build()
             .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
         let proxy = event_loop.create_proxy();
-
-        let mut app_state = AppState::new();
-        app_state.view = Some(view);
-
-        let merged_stylesheet = match stylesheet {
-            Some(user_sheet) => {
-                let mut defaults = Stylesheet::with_defaults();
-                defaults.merge(&user_sheet);
-                defaults
-            }
-            None => Stylesheet::with_defaults(),
-        };
-        app_state.stylesheet = Some(merged_stylesheet);
-
         crate::async_runtime::dispatch::UiDispatchQueue::set_proxy(proxy);
         event_loop
             .run_app(&mut app_state)
@@ -1220,20 +1770,6 @@ where
         let event_loop = EventLoop::with_user_event()
             .build()
             .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
-
-        let mut app_state = AppState::new();
-        app_state.view = Some(view);
-
-        let merged_stylesheet = match stylesheet {
-            Some(user_sheet) => {
-                let mut defaults = Stylesheet::with_defaults();
-                defaults.merge(&user_sheet);
-                defaults
-            }
-            None => Stylesheet::with_defaults(),
-        };
-        app_state.stylesheet = Some(merged_stylesheet);
-
         event_loop
             .run_app(&mut app_state)
             .map_err(|e| AppError::WindowCreationFailed(e.to_string()))?;
@@ -1272,3 +1808,100 @@ impl std::fmt::Display for AppError {
 }
 
 impl std::error::Error for AppError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::ComponentType;
+    use crate::properties::PropertyMap;
+    use std::time::Duration;
+
+    #[test]
+    fn test_scale_factor_change_marks_root_dirty() {
+        let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+        let mut app_state = AppState::new();
+        app_state.view = Some(ViewStruct::new(Gc::clone(&root)));
+
+        root.clear_dirty();
+        app_state.scene.is_dirty = false;
+
+        app_state.handle_scale_factor_change(2.0);
+
+        assert_eq!(app_state.scale_factor, 2.0);
+        assert!(root.is_dirty());
+        assert!(app_state.scene.is_dirty());
+    }
+
+    #[test]
+    fn test_unchanged_scale_factor_skips_relayout() {
+        let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+        let mut app_state = AppState::new();
+        app_state.view = Some(ViewStruct::new(Gc::clone(&root)));
+
+        root.clear_dirty();
+        app_state.scene.is_dirty = false;
+
+        app_state.handle_scale_factor_change(1.0);
+
+        assert!(!root.is_dirty());
+        assert!(!app_state.scene.is_dirty());
+    }
+
+    #[test]
+    fn test_reactive_mode_sleeps_until_an_animation_is_pending() {
+        let now = Instant::now();
+
+        assert_eq!(control_flow_for(RenderMode::Reactive, None, now, false), ControlFlow::Wait);
+        assert_eq!(
+            control_flow_for(RenderMode::Reactive, Some(now), now, false),
+            ControlFlow::Wait
+        );
+        assert_eq!(
+            control_flow_for(RenderMode::Reactive, Some(now), now, true),
+            ControlFlow::WaitUntil(now + interval_for_fps(ANIMATION_FPS))
+        );
+    }
+
+    #[test]
+    fn test_continuous_mode_is_capped_at_requested_fps() {
+        let mode = RenderMode::Continuous(30);
+        let last_frame = Instant::now();
+        let interval = mode.frame_interval().unwrap();
+        assert_eq!(interval, Duration::from_secs_f64(1.0 / 30.0));
+
+        assert_eq!(control_flow_for(mode, None, last_frame, false), ControlFlow::Poll);
+        assert_eq!(
+            control_flow_for(mode, Some(last_frame), last_frame + interval / 2, false),
+            ControlFlow::WaitUntil(last_frame + interval)
+        );
+        assert_eq!(
+            control_flow_for(mode, Some(last_frame), last_frame + interval, true),
+            ControlFlow::Poll
+        );
+    }
+
+    #[test]
+    fn test_idle_budget_follows_control_flow() {
+        let now = Instant::now();
+        let deadline = now + Duration::from_millis(10);
+
+        assert_eq!(idle_budget(ControlFlow::Wait, now), None);
+        assert_eq!(idle_budget(ControlFlow::Poll, now), Some(Duration::ZERO));
+        assert_eq!(
+            idle_budget(ControlFlow::WaitUntil(deadline), now),
+            Some(Duration::from_millis(10))
+        );
+        assert_eq!(idle_budget(ControlFlow::WaitUntil(now), deadline), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_pending_render_mode_is_applied_before_waiting() {
+        let mut app_state = AppState::new();
+        set_render_mode(RenderMode::Continuous(120));
+
+        app_state.apply_pending_render_mode();
+
+        assert_eq!(app_state.render_mode, RenderMode::Continuous(120));
+        assert_eq!(PENDING_RENDER_MODE.with(Cell::get), None);
+    }
+}
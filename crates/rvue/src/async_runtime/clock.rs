@@ -0,0 +1,291 @@
+//! Time source for async timers.
+//!
+//! Interval, debounce and signal-watch loops read time through [`TaskTimer`] instead of
+//! calling tokio directly. Normally that is the wall clock. With the `testing` feature, tests
+//! can switch to a virtual clock with [`enable_virtual_clock`] and move it forward with
+//! [`advance_virtual_clock`], so timer-driven code runs deterministically without sleeping.
+
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+static START: OnceLock<Instant> = OnceLock::new();
+
+fn start() -> Instant {
+    *START.get_or_init(Instant::now)
+}
+
+/// Timer owned by one background task.
+///
+/// Times are durations since a fixed process-wide origin, so deadlines can be computed
+/// with plain arithmetic. A task created while the virtual clock is enabled keeps using it
+/// for its whole lifetime.
+pub(crate) struct TaskTimer {
+    #[cfg(feature = "testing")]
+    virtual_timer: Option<virtual_clock::VirtualTimer>,
+}
+
+impl TaskTimer {
+    pub(crate) fn new() -> Self {
+        Self {
+            #[cfg(feature = "testing")]
+            virtual_timer: virtual_clock::VirtualTimer::new(),
+        }
+    }
+
+    pub(crate) fn now(&self) -> Duration {
+        #[cfg(feature = "testing")]
+        if self.virtual_timer.is_some() {
+            return virtual_clock::now();
+        }
+        start().elapsed()
+    }
+
+    pub(crate) async fn sleep_until(&self, deadline: Duration) {
+        #[cfg(feature = "testing")]
+        if let Some(timer) = &self.virtual_timer {
+            return timer.sleep_until(deadline).await;
+        }
+        tokio::time::sleep_until((start() + deadline).into()).await;
+    }
+
+    /// Record that the task received a message sent through [`TimerWaker::wake`].
+    pub(crate) fn received(&self) {
+        #[cfg(feature = "testing")]
+        if let Some(timer) = &self.virtual_timer {
+            timer.received();
+        }
+    }
+
+    /// Mark the task as waiting before it blocks on something other than this timer.
+    pub(crate) fn idle(&self) {
+        #[cfg(feature = "testing")]
+        if let Some(timer) = &self.virtual_timer {
+            timer.idle();
+        }
+    }
+
+    /// Handle for senders that wake this task with a message.
+    pub(crate) fn waker(&self) -> TimerWaker {
+        TimerWaker {
+            #[cfg(feature = "testing")]
+            is_virtual: self.virtual_timer.is_some(),
+        }
+    }
+}
+
+/// Lets a sender tell the virtual clock that a message is on its way to a task, so
+/// [`advance_virtual_clock`] waits for the task to handle it.
+#[derive(Clone)]
+pub(crate) struct TimerWaker {
+    #[cfg(feature = "testing")]
+    is_virtual: bool,
+}
+
+impl TimerWaker {
+    /// Send a message with `send`, which returns whether the task can still receive it.
+    pub(crate) fn wake(&self, send: impl FnOnce() -> bool) {
+        #[cfg(feature = "testing")]
+        if self.is_virtual {
+            virtual_clock::send_counted(send);
+            return;
+        }
+        send();
+    }
+}
+
+/// A fixed-period ticker that skips missed ticks, like a tokio interval with
+/// `MissedTickBehavior::Skip`. The first tick completes immediately.
+pub(crate) struct Ticker {
+    timer: TaskTimer,
+    period: Duration,
+    next: Option<Duration>,
+}
+
+impl Ticker {
+    pub(crate) fn new(timer: TaskTimer, period: Duration) -> Self {
+        assert!(period > Duration::ZERO, "`period` must be non-zero.");
+        Self { timer, period, next: None }
+    }
+
+    pub(crate) async fn tick(&mut self) {
+        let now = self.timer.now();
+        let next = *self.next.get_or_insert(now);
+        if next > now {
+            self.timer.sleep_until(next).await;
+        }
+
+        let now = self.timer.now();
+        let mut following = next + self.period;
+        while following <= now {
+            following += self.period;
+        }
+        self.next = Some(following);
+    }
+}
+
+#[cfg(feature = "testing")]
+pub use virtual_clock::{advance_virtual_clock, enable_virtual_clock, is_virtual_clock_enabled};
+
+#[cfg(feature = "testing")]
+mod virtual_clock {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    use tokio::sync::oneshot;
+
+    /// Longest time [`advance_virtual_clock`] waits for tasks to finish reacting to a step.
+    const SETTLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    struct Sleeper {
+        deadline: Duration,
+        wake: oneshot::Sender<()>,
+    }
+
+    struct ClockState {
+        enabled: bool,
+        now: Duration,
+        sleepers: Vec<Sleeper>,
+    }
+
+    static STATE: Mutex<ClockState> =
+        Mutex::new(ClockState { enabled: false, now: Duration::ZERO, sleepers: Vec::new() });
+
+    /// Wake-ups (task starts, fired timers, sent messages) that a task has not yet handled
+    /// and gone back to waiting.
+    static OUTSTANDING: AtomicUsize = AtomicUsize::new(0);
+
+    /// Switch timers created from now on to the virtual clock.
+    ///
+    /// The clock is process-wide and never goes back, so enabling it again is a no-op.
+    pub fn enable_virtual_clock() {
+        STATE.lock().unwrap().enabled = true;
+    }
+
+    pub fn is_virtual_clock_enabled() -> bool {
+        STATE.lock().unwrap().enabled
+    }
+
+    /// Move the virtual clock forward by `by`, firing due timers in deadline order.
+    ///
+    /// After each step this waits until every woken task is waiting again, so callbacks
+    /// that schedule further timers within the window fire as well.
+    pub fn advance_virtual_clock(by: Duration) {
+        let target = STATE.lock().unwrap().now + by;
+
+        loop {
+            wait_until_settled();
+
+            let mut state = STATE.lock().unwrap();
+            state.sleepers.retain(|sleeper| !sleeper.wake.is_closed());
+
+            let next = state
+                .sleepers
+                .iter()
+                .map(|sleeper| sleeper.deadline)
+                .filter(|deadline| *deadline <= target)
+                .min();
+            let Some(next) = next else {
+                state.now = target;
+                break;
+            };
+
+            state.now = next;
+            let (due, pending): (Vec<_>, Vec<_>) =
+                std::mem::take(&mut state.sleepers).into_iter().partition(|s| s.deadline <= next);
+            state.sleepers = pending;
+
+            for sleeper in due {
+                send_counted(|| sleeper.wake.send(()).is_ok());
+            }
+        }
+
+        wait_until_settled();
+    }
+
+    pub(super) fn now() -> Duration {
+        STATE.lock().unwrap().now
+    }
+
+    pub(super) fn send_counted(send: impl FnOnce() -> bool) {
+        OUTSTANDING.fetch_add(1, Ordering::SeqCst);
+        if !send() {
+            OUTSTANDING.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    fn wait_until_settled() {
+        let started = Instant::now();
+        while OUTSTANDING.load(Ordering::SeqCst) > 0 {
+            if started.elapsed() > SETTLE_TIMEOUT {
+                log::warn!("virtual clock: timed out waiting for async tasks to settle");
+                return;
+            }
+            std::thread::sleep(Duration::from_micros(100));
+        }
+    }
+
+    /// Per-task bookkeeping for the virtual clock.
+    pub(super) struct VirtualTimer {
+        /// Wake-ups this task has received since it last went back to waiting.
+        handled: AtomicUsize,
+    }
+
+    impl VirtualTimer {
+        /// Returns a timer if the virtual clock is enabled. The task counts as woken until it
+        /// first waits.
+        pub(super) fn new() -> Option<Self> {
+            if !is_virtual_clock_enabled() {
+                return None;
+            }
+            OUTSTANDING.fetch_add(1, Ordering::SeqCst);
+            Some(Self { handled: AtomicUsize::new(1) })
+        }
+
+        pub(super) fn received(&self) {
+            self.handled.fetch_add(1, Ordering::SeqCst);
+        }
+
+        pub(super) fn idle(&self) {
+            let handled = self.handled.swap(0, Ordering::SeqCst);
+            OUTSTANDING.fetch_sub(handled, Ordering::SeqCst);
+        }
+
+        pub(super) async fn sleep_until(&self, deadline: Duration) {
+            let receiver = {
+                let mut state = STATE.lock().unwrap();
+                if deadline <= state.now {
+                    return;
+                }
+                let (wake, receiver) = oneshot::channel();
+                state.sleepers.push(Sleeper { deadline, wake });
+                // Go idle while holding the lock so the clock can't fire before we're counted
+                self.idle();
+                receiver
+            };
+
+            let mut pending = PendingWake(receiver);
+            if (&mut pending.0).await.is_ok() {
+                self.received();
+            }
+        }
+    }
+
+    impl Drop for VirtualTimer {
+        fn drop(&mut self) {
+            self.idle();
+        }
+    }
+
+    /// Releases a fired wake-up that the task dropped without handling, e.g. when another
+    /// `select!` branch won.
+    struct PendingWake(oneshot::Receiver<()>);
+
+    impl Drop for PendingWake {
+        fn drop(&mut self) {
+            if self.0.try_recv().is_ok() {
+                OUTSTANDING.fetch_sub(1, Ordering::SeqCst);
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! This module provides `ComponentScope` for tracking multiple components
 //! that can be accessed safely from async contexts using rudo-gc's GcScope.
+//! A scope created with [`ComponentScope::for_component`] also owns the tasks it
+//! spawns, cancelling them when that component unmounts.
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -9,23 +11,58 @@ use std::rc::Rc;
 use rudo_gc::handles::GcScope;
 use rudo_gc::Gc;
 
-use crate::component::Component;
+use crate::async_runtime::registry::TaskRegistry;
+use crate::async_runtime::task::{spawn_untracked_task, TaskHandle};
+use crate::component::{Component, ComponentId};
 
 #[derive(Default)]
 pub struct ComponentScope {
     scope: Rc<RefCell<GcScope>>,
+    owner: Option<ComponentId>,
 }
 
 impl Clone for ComponentScope {
     fn clone(&self) -> Self {
-        Self { scope: Rc::clone(&self.scope) }
+        Self { scope: Rc::clone(&self.scope), owner: self.owner }
     }
 }
 
 impl ComponentScope {
     #[inline]
     pub fn new() -> Self {
-        Self { scope: Rc::new(RefCell::new(GcScope::new())) }
+        Self { scope: Rc::new(RefCell::new(GcScope::new())), owner: None }
+    }
+
+    /// Create a scope that tracks `component` and owns the tasks spawned through it.
+    ///
+    /// Tasks from [`Self::spawn_task`] are aborted when `component` unmounts.
+    pub fn for_component(component: &Gc<Component>) -> Self {
+        let mut scope = Self::new();
+        scope.track(component);
+        scope.owner = Some(component.id);
+        scope
+    }
+
+    /// The component whose unmount cancels this scope's tasks, if any.
+    #[inline]
+    pub fn owner(&self) -> Option<ComponentId> {
+        self.owner
+    }
+
+    /// Spawn an async task owned by this scope's component.
+    ///
+    /// Without an owner this behaves like [`spawn_task`](crate::async_runtime::spawn_task)
+    /// and falls back to the component currently being built.
+    pub fn spawn_task<F>(&self, future: F) -> TaskHandle
+    where
+        F: std::future::Future<Output = ()> + Send + 'static,
+    {
+        let Some(owner) = self.owner else {
+            return crate::async_runtime::spawn_task(future);
+        };
+        let handle = spawn_untracked_task(future);
+        TaskRegistry::register_task(owner, handle.clone());
+        handle
     }
 
     #[inline]
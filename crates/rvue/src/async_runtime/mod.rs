@@ -6,12 +6,17 @@
 //! # Overview
 //!
 //! - [`spawn_task`] - Spawn an async task that runs on the Tokio runtime
+//! - [`spawn_task_into`] - Spawn an async task and store its result in a signal
 //! - [`spawn_interval`] - Run a callback at a regular interval
 //! - [`spawn_debounced`] - Debounce a callback
+//! - [`create_debounced_signal`] / [`create_throttled_signal`] - Follow a signal on a timer
 //! - [`watch_signal`] - Watch a signal and invoke callback on changes
+//! - [`watch`] - Invoke a callback with the new and previous value of a signal
 //! - [`dispatch_to_ui`] - Dispatch a closure to be executed on the UI thread
 //! - [`UiThreadDispatcher`] - Send signal updates from async contexts
 //! - [`ComponentScope`] - Dynamic component tracking for async operations
+//! - [`create_resource`] - Fetch data whenever a source signal changes
+//! - [`create_resource_with_trigger`] - Fetch data on source changes or explicit reloads
 //!
 //! # GC Safety
 //!
@@ -51,6 +56,9 @@
 #[cfg(feature = "async")]
 pub mod cancellation;
 
+#[cfg(feature = "async")]
+pub mod clock;
+
 #[cfg(feature = "async")]
 pub mod dispatch;
 
@@ -66,13 +74,17 @@ pub mod component_scope;
 #[cfg(feature = "async")]
 pub mod resource;
 
+#[cfg(feature = "async")]
+pub mod timed_signal;
+
 #[cfg(feature = "async")]
 pub use dispatch::{dispatch_to_ui, UiDispatchQueue};
 
 #[cfg(feature = "async")]
 pub use task::{
     block_on, get_or_init_runtime, spawn_debounced, spawn_interval, spawn_on_runtime, spawn_task,
-    watch_signal, DebouncedTask, IntervalHandle, SignalWatcher, TaskHandle, TaskId,
+    spawn_task_into, watch, watch_signal, DebouncedTask, IntervalHandle, SignalWatcher, TaskHandle,
+    TaskId,
 };
 
 #[cfg(feature = "async")]
@@ -88,4 +100,10 @@ pub use registry::TaskRegistry;
 pub use component_scope::ComponentScope;
 
 #[cfg(feature = "async")]
-pub use resource::{create_resource, Resource, ResourceState};
+pub use resource::{
+    create_reload_trigger, create_resource, create_resource_with_trigger, ReloadTrigger, Resource,
+    ResourceState,
+};
+
+#[cfg(feature = "async")]
+pub use timed_signal::{create_debounced_signal, create_throttled_signal};
@@ -11,6 +11,7 @@ use crate::async_runtime::cancellation::Cancellation;
 use crate::async_runtime::get_or_init_runtime;
 use crate::effect::{create_effect, on_cleanup, Effect};
 use crate::signal::{create_signal, ReadSignal, SignalDataExt, WriteSignal};
+use crate::widgets::SuspenseContext;
 
 thread_local! {
     static CURRENT_CANCELLATION: RefCell<Option<Cancellation>> = const { RefCell::new(None) };
@@ -94,7 +95,70 @@ unsafe impl<T: Trace + Clone + 'static, S: Trace + Clone + 'static> Trace for Re
     }
 }
 
+/// Write half of a reload trigger created with [`create_reload_trigger`].
+#[derive(Clone)]
+pub struct ReloadTrigger(WriteSignal<u32>);
+
+impl ReloadTrigger {
+    /// Bump the trigger, refetching every resource that listens to it.
+    pub fn reload(&self) {
+        self.0.update(|v| *v = v.wrapping_add(1));
+    }
+}
+
+unsafe impl Trace for ReloadTrigger {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.0.trace(visitor);
+    }
+}
+
+/// Create a trigger signal for [`create_resource_with_trigger`] and the handle that bumps it.
+pub fn create_reload_trigger() -> (ReadSignal<u32>, ReloadTrigger) {
+    let (trigger, set_trigger) = create_signal(0u32);
+    (trigger, ReloadTrigger(set_trigger))
+}
+
 pub fn create_resource<S, T, Fu, Fetcher>(source: ReadSignal<S>, fetcher: Fetcher) -> Resource<T, S>
+where
+    S: PartialEq + Clone + Trace + 'static + Send,
+    T: Trace + Clone + 'static + Send + Sync,
+    Fu: Future<Output = Result<T, String>> + Send + 'static,
+    Fetcher: Fn(S) -> Fu + Clone + Send + 'static,
+{
+    create_resource_inner(source, None, fetcher)
+}
+
+/// Like [`create_resource`], but also refetches whenever `trigger` changes.
+///
+/// The trigger carries no data; bump it (for example with [`ReloadTrigger::reload`]) to reload
+/// with the current source value.
+///
+/// # Example
+/// ```ignore
+/// let (trigger, reload) = create_reload_trigger();
+/// let stories = create_resource_with_trigger(feed, trigger, fetch_stories);
+/// // Later, from a refresh button:
+/// reload.reload();
+/// ```
+pub fn create_resource_with_trigger<S, T, Fu, Fetcher>(
+    source: ReadSignal<S>,
+    trigger: ReadSignal<u32>,
+    fetcher: Fetcher,
+) -> Resource<T, S>
+where
+    S: PartialEq + Clone + Trace + 'static + Send,
+    T: Trace + Clone + 'static + Send + Sync,
+    Fu: Future<Output = Result<T, String>> + Send + 'static,
+    Fetcher: Fn(S) -> Fu + Clone + Send + 'static,
+{
+    create_resource_inner(source, Some(trigger), fetcher)
+}
+
+fn create_resource_inner<S, T, Fu, Fetcher>(
+    source: ReadSignal<S>,
+    trigger: Option<ReadSignal<u32>>,
+    fetcher: Fetcher,
+) -> Resource<T, S>
 where
     S: PartialEq + Clone + Trace + 'static + Send,
     T: Trace + Clone + 'static + Send + Sync,
@@ -120,6 +184,9 @@ where
 
     let effect = create_effect(move || {
         let _ = refetch_counter_read.get();
+        if let Some(trigger) = &trigger {
+            let _ = trigger.get();
+        }
         let current_version = version_clone.fetch_add(1, Ordering::SeqCst) + 1;
         cancellation_clone.cancel();
         let task_cancellation = Cancellation::new();
@@ -217,5 +284,12 @@ where
         });
     });
 
+    // Hold the nearest Suspense boundary in its fallback while this resource is fetching
+    if let Some(suspense) = crate::context::inject::<SuspenseContext>() {
+        let state = state.clone();
+        suspense
+            .track(move || matches!(*state.get(), ResourceState::Pending | ResourceState::Loading));
+    }
+
     Resource { state, refetch_counter, source, effect, version, cancellation }
 }
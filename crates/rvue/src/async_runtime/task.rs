@@ -9,15 +9,16 @@ use std::time::Duration;
 
 use tokio::runtime::Runtime;
 use tokio::sync::mpsc;
-use tokio::time;
 
 use rudo_gc::handles::AsyncHandleScope;
 use rudo_gc::Trace;
 
+use crate::async_runtime::clock::{TaskTimer, Ticker, TimerWaker};
 use crate::async_runtime::registry::TaskRegistry;
 use crate::async_runtime::ui_thread_dispatcher::WriteSignalUiExt;
+use crate::effect::create_effect;
 use crate::runtime;
-use crate::signal::{ReadSignal, WriteSignal};
+use crate::signal::{leak_effect, ReadSignal, WriteSignal};
 
 static TOKIO_RUNTIME: OnceLock<Runtime> = OnceLock::new();
 
@@ -127,6 +128,16 @@ where
 ///
 /// Returns a handle for managing the task.
 pub fn spawn_task<F>(future: F) -> TaskHandle
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let handle = spawn_untracked_task(future);
+    register_task_in_component_scope(&handle);
+    handle
+}
+
+/// Spawn a task without registering it with the current owner.
+pub(crate) fn spawn_untracked_task<F>(future: F) -> TaskHandle
 where
     F: std::future::Future<Output = ()> + Send + 'static,
 {
@@ -144,11 +155,36 @@ where
         completed.store(true, Ordering::SeqCst);
     });
 
-    register_task_in_component_scope(&handle);
-
     handle
 }
 
+/// Spawn an async task and store its output in `signal`.
+///
+/// Must be called on the UI thread. When `future` completes, the value is queued as a
+/// `set` on the UI thread through the signal's [`UiThreadDispatcher`], so effects that read
+/// the signal run there as usual.
+///
+/// [`UiThreadDispatcher`]: crate::async_runtime::UiThreadDispatcher
+///
+/// # Example
+/// ```ignore
+/// use rvue::async_runtime::spawn_task_into;
+///
+/// let (user, set_user) = create_signal(None);
+/// spawn_task_into(async move { fetch_user(42).await.ok() }, &set_user);
+/// ```
+pub fn spawn_task_into<T, F>(future: F, signal: &WriteSignal<T>) -> TaskHandle
+where
+    T: Trace + Clone + Send + Sync + 'static,
+    F: std::future::Future<Output = T> + Send + 'static,
+{
+    let dispatcher = signal.ui_dispatcher();
+    spawn_task(async move {
+        let value = future.await;
+        dispatcher.set(value).await;
+    })
+}
+
 /// Handle for stopping a signal watcher.
 ///
 /// `stop()` may be delayed by up to one polling period before the
@@ -191,6 +227,52 @@ impl Default for SignalWatcher {
     }
 }
 
+/// Watch a signal and invoke `callback` with the new and previous value on each change.
+///
+/// Unlike [`watch_signal`], this does not poll: the callback runs on the UI thread as soon
+/// as `source` is set. The previous value is kept in the watcher and starts as the value
+/// `source` had when the watcher was created. Stopping the watcher, or unmounting the
+/// component it was created in, stops further callbacks.
+///
+/// # Example
+/// ```ignore
+/// use rvue::async_runtime::watch;
+///
+/// let (online, set_online) = create_signal(true);
+/// let _watcher = watch(online, |now, before| {
+///     if before && !now {
+///         show_offline_banner();
+///     }
+/// });
+/// ```
+pub fn watch<T>(source: ReadSignal<T>, callback: impl FnMut(T, T) + 'static) -> SignalWatcher
+where
+    T: Trace + Clone + 'static,
+{
+    let watcher = SignalWatcher::new();
+    let stopped = watcher.stopped.clone();
+    let previous = std::cell::RefCell::new(source.get_untracked());
+    let callback = std::cell::RefCell::new(callback);
+    let is_first = std::cell::Cell::new(true);
+
+    let effect = create_effect(move || {
+        let current = source.get();
+        // The first run only subscribes to `source`
+        if is_first.replace(false) {
+            return;
+        }
+        let old = previous.replace(current.clone());
+        if !stopped.load(Ordering::SeqCst) {
+            (callback.borrow_mut())(current, old);
+        }
+    });
+    leak_effect(effect);
+
+    register_watcher_in_component_scope(&watcher);
+
+    watcher
+}
+
 /// Watch a signal and invoke callback on changes.
 ///
 /// Uses AsyncHandle for safe GC-managed access in async context.
@@ -255,13 +337,13 @@ where
     let panic_handler_clone = panic_handler.clone();
 
     let watcher = SignalWatcher { stopped: stopped_clone.clone(), panic_count, panic_handler };
+    let timer = TaskTimer::new();
 
     get_or_init_runtime().spawn(async move {
         // Keep scope alive for the duration of the task
         let _scope = scope;
 
-        let mut interval = time::interval(period);
-        interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+        let mut interval = Ticker::new(timer, period);
 
         loop {
             tokio::select! {
@@ -354,10 +436,10 @@ where
     let stopped_clone = stopped.clone();
 
     let handle = IntervalHandle { stopped: stopped_clone.clone() };
+    let timer = TaskTimer::new();
 
     get_or_init_runtime().spawn(async move {
-        let mut interval = time::interval(period);
-        interval.set_missed_tick_behavior(time::MissedTickBehavior::Skip);
+        let mut interval = Ticker::new(timer, period);
 
         loop {
             interval.tick().await;
@@ -380,11 +462,12 @@ where
 pub struct DebouncedTask<T: Send + 'static> {
     sender: mpsc::UnboundedSender<T>,
     stopped: Arc<AtomicBool>,
+    waker: TimerWaker,
 }
 
 impl<T: Send + 'static> DebouncedTask<T> {
     pub fn call(&self, value: T) {
-        let _ = self.sender.send(value);
+        self.waker.wake(|| self.sender.send(value).is_ok());
     }
 
     /// Request cancellation. The background task exits when it next regains
@@ -429,16 +512,21 @@ where
     let stopped = Arc::new(AtomicBool::new(false));
     let stopped_clone = stopped.clone();
 
-    let task = DebouncedTask { sender, stopped: stopped_clone.clone() };
+    let timer = TaskTimer::new();
+    let task = DebouncedTask { sender, stopped: stopped_clone.clone(), waker: timer.waker() };
 
     get_or_init_runtime().spawn(async move {
         let mut pending_value: Option<T> = None;
-        let mut timer = Box::pin(time::sleep(delay));
+        let mut deadline = Duration::ZERO;
 
         loop {
+            if pending_value.is_none() {
+                timer.idle();
+            }
+
             tokio::select! {
                 biased;
-                _ = &mut timer => {
+                _ = timer.sleep_until(deadline), if pending_value.is_some() => {
                     if let Some(value) = pending_value.take() {
                         handler(value).await;
                     }
@@ -446,8 +534,9 @@ where
                 value = receiver.recv() => {
                     match value {
                         Some(v) => {
+                            timer.received();
                             pending_value = Some(v);
-                            timer = Box::pin(time::sleep(delay));
+                            deadline = timer.now() + delay;
                         }
                         None => break,
                     }
@@ -0,0 +1,115 @@
+//! Signals that follow another signal on a timer.
+//!
+//! [`create_debounced_signal`] and [`create_throttled_signal`] derive a `ReadSignal` from a
+//! source signal. The timing runs on the async runtime and the derived signal is always
+//! written on the UI thread through its [`UiThreadDispatcher`](super::UiThreadDispatcher).
+
+use std::time::Duration;
+
+use rudo_gc::Trace;
+use tokio::sync::mpsc;
+
+use crate::async_runtime::clock::TaskTimer;
+use crate::async_runtime::task::{get_or_init_runtime, spawn_debounced};
+use crate::async_runtime::ui_thread_dispatcher::WriteSignalUiExt;
+use crate::effect::create_effect;
+use crate::signal::{create_signal, leak_effect, ReadSignal};
+
+/// Create a signal that takes `source`'s value once it has stopped changing for `delay`.
+///
+/// Must be called on the UI thread. The returned signal starts with the source's current
+/// value.
+///
+/// # Example
+/// ```ignore
+/// use rvue::async_runtime::create_debounced_signal;
+/// use std::time::Duration;
+///
+/// let (query, set_query) = create_signal(String::new());
+/// let search_query = create_debounced_signal(query, Duration::from_millis(300));
+/// ```
+pub fn create_debounced_signal<T>(source: ReadSignal<T>, delay: Duration) -> ReadSignal<T>
+where
+    T: Trace + Clone + Send + Sync + 'static,
+{
+    let (read, write) = create_signal(source.get_untracked());
+    let dispatcher = write.ui_dispatcher();
+
+    let debounced = spawn_debounced(delay, move |value: T| {
+        let dispatcher = dispatcher.clone();
+        async move { dispatcher.set(value).await }
+    });
+
+    let is_first = std::cell::Cell::new(true);
+    let effect = create_effect(move || {
+        let value = source.get();
+        if !is_first.replace(false) {
+            debounced.call(value);
+        }
+    });
+    leak_effect(effect);
+
+    read
+}
+
+/// Create a signal that follows `source` at most once per `interval`.
+///
+/// The first change after a quiet period is applied right away; further changes within
+/// `interval` are collapsed into one update with the latest value when the interval ends.
+/// Must be called on the UI thread.
+pub fn create_throttled_signal<T>(source: ReadSignal<T>, interval: Duration) -> ReadSignal<T>
+where
+    T: Trace + Clone + Send + Sync + 'static,
+{
+    let (read, write) = create_signal(source.get_untracked());
+    let dispatcher = write.ui_dispatcher();
+
+    let (sender, mut receiver) = mpsc::unbounded_channel::<T>();
+    let timer = TaskTimer::new();
+    let waker = timer.waker();
+
+    get_or_init_runtime().spawn(async move {
+        let mut latest: Option<T> = None;
+        let mut window_end: Option<Duration> = None;
+
+        loop {
+            if window_end.is_none() {
+                timer.idle();
+            }
+
+            tokio::select! {
+                biased;
+                _ = timer.sleep_until(window_end.unwrap_or_default()), if window_end.is_some() => {
+                    window_end = match latest.take() {
+                        Some(value) => {
+                            dispatcher.set(value).await;
+                            Some(timer.now() + interval)
+                        }
+                        None => None,
+                    };
+                }
+                value = receiver.recv() => {
+                    let Some(value) = value else { break };
+                    timer.received();
+                    if window_end.is_some() {
+                        latest = Some(value);
+                    } else {
+                        dispatcher.set(value).await;
+                        window_end = Some(timer.now() + interval);
+                    }
+                }
+            }
+        }
+    });
+
+    let is_first = std::cell::Cell::new(true);
+    let effect = create_effect(move || {
+        let value = source.get();
+        if !is_first.replace(false) {
+            waker.wake(|| sender.send(value).is_ok());
+        }
+    });
+    leak_effect(effect);
+
+    read
+}
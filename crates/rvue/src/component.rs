@@ -1,21 +1,35 @@
 //! Component trait and lifecycle management
 
 use crate::effect::Effect;
+use crate::event::focus::{request_focus_change, FocusRequest};
 use crate::event::handler::EventHandlers;
 use crate::event::status::{ComponentFlags, StatusUpdate};
 use crate::layout::LayoutNode;
 use crate::properties::{
-    CheckboxChecked, FlexAlignItems, FlexDirection, FlexGap, FlexJustifyContent, ForItemCount,
-    NumberInputValue, PropertyMap, RadioChecked, RadioValue, ShowCondition, TextContent,
-    TextInputValue, WidgetStyles,
+    ButtonSubmit, CheckboxChecked, CheckboxIndeterminate, ContextMenuPosition, FlexAlignItems,
+    FlexDirection, FlexGap, FlexJustifyContent, ForItemCount, LinkHref, NumberInputRange,
+    NumberInputSteppers, NumberInputValue, ProgressIndeterminate, ProgressValue, PropertyMap,
+    RadioChecked, RadioValue, ScrollbarActivity, ShowCondition, ShowTransition,
+    ShowTransitionState, SpinnerRotation, SuspensePending, SwitchActiveArm, TextAreaScrollOffset,
+    TextContent, TextInputScrollOffset, TextInputValue, TextSelectable, TextSelection, TextSpans,
+    TooltipOpen, ValidationMessage, WidgetStyles,
 };
 use crate::render::FlexScrollState;
+use crate::signal::WriteSignal;
 use crate::text::cursor::GcCursorBlinkState;
 use crate::text::editor::SharedTextEditor;
+use crate::text::span::{spans_text, TextSpan};
 use crate::text::TextContext;
+use crate::widgets::canvas::CanvasDraw;
+use crate::widgets::context_menu::ContextMenuHandle;
+use crate::widgets::form::FormContext;
+use crate::widgets::show::{Transition, TransitionPhase};
+use crate::widgets::suspense::SuspenseContext;
 use rudo_gc::{Gc, GcCell, Trace};
 use std::any::{Any, TypeId};
+use std::f64::consts::TAU;
 use std::sync::atomic::AtomicBool;
+use std::time::Instant;
 use taffy::TaffyTree;
 use vello::Scene;
 
@@ -51,6 +65,8 @@ pub enum ContextValueEnum {
     Bool(Gc<bool>),
     GcString(Gc<String>),
     GcVecString(Gc<Vec<String>>),
+    Suspense(Gc<SuspenseContext>),
+    Form(Gc<FormContext>),
 }
 
 impl ContextValueEnum {
@@ -87,6 +103,14 @@ impl ContextValueEnum {
             let gc_vec: Gc<Vec<String>> = unsafe { Gc::from_raw(ptr) };
             return Self::GcVecString(gc_vec);
         }
+        if type_id == TypeId::of::<SuspenseContext>() {
+            let gc_suspense: Gc<SuspenseContext> = unsafe { Gc::from_raw(ptr) };
+            return Self::Suspense(gc_suspense);
+        }
+        if type_id == TypeId::of::<FormContext>() {
+            let gc_form: Gc<FormContext> = unsafe { Gc::from_raw(ptr) };
+            return Self::Form(gc_form);
+        }
         panic!("Unsupported context type");
     }
 
@@ -168,6 +192,30 @@ impl ContextValueEnum {
                     None
                 }
             }
+            ContextValueEnum::Suspense(gc) => {
+                if TypeId::of::<T>() == TypeId::of::<SuspenseContext>() {
+                    let ptr = Gc::internal_ptr(gc);
+                    let cloned = Gc::clone(gc);
+                    let from_raw: Gc<SuspenseContext> = unsafe { Gc::from_raw(ptr) };
+                    std::mem::forget(from_raw);
+                    let result: Gc<T> = unsafe { std::mem::transmute(cloned) };
+                    Some(result)
+                } else {
+                    None
+                }
+            }
+            ContextValueEnum::Form(gc) => {
+                if TypeId::of::<T>() == TypeId::of::<FormContext>() {
+                    let ptr = Gc::internal_ptr(gc);
+                    let cloned = Gc::clone(gc);
+                    let from_raw: Gc<FormContext> = unsafe { Gc::from_raw(ptr) };
+                    std::mem::forget(from_raw);
+                    let result: Gc<T> = unsafe { std::mem::transmute(cloned) };
+                    Some(result)
+                } else {
+                    None
+                }
+            }
         }
     }
 }
@@ -181,6 +229,8 @@ unsafe impl Trace for ContextValueEnum {
             ContextValueEnum::Bool(gc) => gc.trace(visitor),
             ContextValueEnum::GcString(gc) => gc.trace(visitor),
             ContextValueEnum::GcVecString(gc) => gc.trace(visitor),
+            ContextValueEnum::Suspense(gc) => gc.trace(visitor),
+            ContextValueEnum::Form(gc) => gc.trace(visitor),
         }
     }
 }
@@ -202,6 +252,8 @@ impl GcCapture for ContextValueEnum {
             ContextValueEnum::Bool(gc) => gc.capture_gc_ptrs_into(ptrs),
             ContextValueEnum::GcString(gc) => gc.capture_gc_ptrs_into(ptrs),
             ContextValueEnum::GcVecString(gc) => gc.capture_gc_ptrs_into(ptrs),
+            ContextValueEnum::Suspense(gc) => gc.capture_gc_ptrs_into(ptrs),
+            ContextValueEnum::Form(gc) => gc.capture_gc_ptrs_into(ptrs),
         }
     }
 }
@@ -233,12 +285,20 @@ pub enum ComponentType {
     Text,
     Button,
     TextInput,
+    TextArea,
     NumberInput,
     Checkbox,
     Radio,
     Show,
     For,
+    Switch,
+    Suspense,
     Flex,
+    ContextMenu,
+    Tooltip,
+    ProgressBar,
+    Spinner,
+    Canvas,
     Custom(String),
 }
 
@@ -250,7 +310,10 @@ pub struct Component {
     pub parent: GcCell<Option<Gc<Component>>>,
     pub effects: GcCell<Vec<Gc<Effect>>>,
     pub properties: GcCell<PropertyMap>,
-    pub is_dirty: AtomicBool,
+    /// Needs repainting; set whenever `layout_dirty` is
+    pub paint_dirty: AtomicBool,
+    /// Needs relayout before repainting
+    pub layout_dirty: AtomicBool,
     pub is_updating: AtomicBool,
     pub user_data: GcCell<Option<Box<dyn std::any::Any>>>,
     pub layout_node: GcCell<Option<LayoutNode>>,
@@ -263,10 +326,14 @@ pub struct Component {
     pub has_focus_target: GcCell<bool>,
     pub event_handlers: GcCell<EventHandlers>,
     pub vello_cache: GcCell<Option<SceneWrapper>>,
+    /// This component's scene composited with its children's, reused while the subtree is clean
+    pub subtree_cache: GcCell<Option<SceneWrapper>>,
     pub contexts: GcCell<Vec<ContextEntry>>,
     pub cleanups: GcCell<Vec<Box<dyn FnOnce() + 'static>>>,
     pub classes: GcCell<Vec<String>>,
     pub element_id: GcCell<Option<String>>,
+    /// Accessible name override for assistive technology
+    pub aria_label: GcCell<Option<String>>,
     /// Flag to prevent scroll transform accumulation in nested overflow containers.
     /// When a parent overflow container applies scroll_transform, children should
     /// not apply their own scroll_transform to avoid coordinate space issues.
@@ -275,6 +342,12 @@ pub struct Component {
     pub text_editor: GcCell<Option<SharedTextEditor>>,
     /// Cursor blink state for TextInput components
     pub cursor_blink: GcCell<Option<GcCursorBlinkState>>,
+    /// Signal a TextArea writes its content to after every edit
+    pub text_binding: GcCell<Option<WriteSignal<String>>>,
+    /// Handle a ContextMenu closes through when dismissed from outside
+    pub context_menu: GcCell<Option<ContextMenuHandle>>,
+    /// Callback a Canvas draws its content with
+    pub canvas_draw: GcCell<Option<CanvasDraw>>,
     /// Whether to clip content to the component bounds.
     /// Used by TextInput to hide overflowing text.
     pub clip: GcCell<bool>,
@@ -298,13 +371,25 @@ unsafe impl Trace for Component {
         self.is_focused.trace(visitor);
         self.has_focus_target.trace(visitor);
         self.vello_cache.trace(visitor);
-        // Cleanups are not traced since they are closures
+        self.subtree_cache.trace(visitor);
+        self.event_handlers.trace(visitor);
+        // User data and cleanups are opaque, so scan them for captured Gc pointers
+        if let Some(data) = self.user_data.borrow().as_ref() {
+            crate::gc::trace_opaque(&**data, visitor);
+        }
+        for cleanup in self.cleanups.borrow().iter() {
+            crate::gc::trace_opaque(&**cleanup, visitor);
+        }
         // Trace context values by directly visiting Gc pointers
         self.contexts.trace(visitor);
         self.classes.trace(visitor);
         self.element_id.trace(visitor);
+        self.aria_label.trace(visitor);
         self.text_editor.trace(visitor);
         self.cursor_blink.trace(visitor);
+        self.text_binding.trace(visitor);
+        self.context_menu.trace(visitor);
+        self.canvas_draw.trace(visitor);
         self.ime_area.trace(visitor);
     }
 }
@@ -318,7 +403,8 @@ impl Clone for Component {
             parent: GcCell::new(None),
             effects: GcCell::new(self.effects.borrow().clone()),
             properties: GcCell::new(self.properties.borrow().clone()),
-            is_dirty: AtomicBool::new(self.is_dirty.load(Ordering::SeqCst)),
+            paint_dirty: AtomicBool::new(self.paint_dirty.load(Ordering::SeqCst)),
+            layout_dirty: AtomicBool::new(self.layout_dirty.load(Ordering::SeqCst)),
             is_updating: AtomicBool::new(false),
             user_data: GcCell::new(None),
             layout_node: GcCell::new(self.layout_node.borrow().clone()),
@@ -331,13 +417,18 @@ impl Clone for Component {
             has_focus_target: GcCell::new(*self.has_focus_target.borrow()),
             event_handlers: GcCell::new(self.event_handlers.borrow().clone()),
             vello_cache: GcCell::new(self.vello_cache.borrow().clone()),
+            subtree_cache: GcCell::new(self.subtree_cache.borrow().clone()),
             contexts: GcCell::new(Vec::new()),
             cleanups: GcCell::new(Vec::new()),
             classes: GcCell::new(self.classes.borrow().clone()),
             element_id: GcCell::new(self.element_id.borrow().clone()),
+            aria_label: GcCell::new(self.aria_label.borrow().clone()),
             is_in_scrolling_parent: AtomicBool::new(false),
             text_editor: GcCell::new(self.text_editor.borrow().clone()),
             cursor_blink: GcCell::new(self.cursor_blink.borrow().clone()),
+            text_binding: GcCell::new(self.text_binding.borrow().clone()),
+            context_menu: GcCell::new(self.context_menu.borrow().clone()),
+            canvas_draw: GcCell::new(self.canvas_draw.borrow().clone()),
             clip: GcCell::new(*self.clip.borrow()),
             ime_area: GcCell::new(*self.ime_area.borrow()),
         }
@@ -372,12 +463,16 @@ impl Component {
         match component_type {
             ComponentType::Button
             | ComponentType::TextInput
+            | ComponentType::TextArea
             | ComponentType::NumberInput
             | ComponentType::Checkbox
             | ComponentType::Radio => {
                 flags.insert(ComponentFlags::ACCEPTS_POINTER);
                 flags.insert(ComponentFlags::ACCEPTS_FOCUS);
             }
+            ComponentType::ContextMenu => {
+                flags.insert(ComponentFlags::ACCEPTS_POINTER);
+            }
             _ => {}
         }
 
@@ -388,7 +483,8 @@ impl Component {
             parent: GcCell::new(None),
             effects: GcCell::new(Vec::new()),
             properties: GcCell::new(properties),
-            is_dirty: AtomicBool::new(true),
+            paint_dirty: AtomicBool::new(true),
+            layout_dirty: AtomicBool::new(true),
             is_updating: AtomicBool::new(false),
             user_data: GcCell::new(None),
             layout_node: GcCell::new(None),
@@ -401,13 +497,18 @@ impl Component {
             has_focus_target: GcCell::new(false),
             event_handlers: GcCell::new(EventHandlers::default()),
             vello_cache: GcCell::new(None),
+            subtree_cache: GcCell::new(None),
             contexts: GcCell::new(Vec::new()),
             cleanups: GcCell::new(Vec::new()),
             classes: GcCell::new(Vec::new()),
             element_id: GcCell::new(None),
+            aria_label: GcCell::new(None),
             is_in_scrolling_parent: AtomicBool::new(false),
             text_editor: GcCell::new(None),
             cursor_blink: GcCell::new(None),
+            text_binding: GcCell::new(None),
+            context_menu: GcCell::new(None),
+            canvas_draw: GcCell::new(None),
             clip: GcCell::new(false),
             ime_area: GcCell::new(None),
         })
@@ -428,37 +529,80 @@ impl Component {
         Self::with_properties(id, component_type, properties)
     }
 
-    /// Mark the component as dirty (needs re-render)
+    /// Mark the component as dirty (needs relayout and re-render)
     pub fn mark_dirty(&self) {
+        self.mark_layout_dirty();
+    }
+
+    /// Mark the component as needing relayout, which also needs a repaint
+    pub fn mark_layout_dirty(&self) {
         // Avoid re-marking if already dirty
-        if self.is_dirty.load(Ordering::SeqCst) {
+        if self.layout_dirty.load(Ordering::SeqCst) {
             return;
         }
-        self.is_dirty.store(true, Ordering::SeqCst);
-        // Clear vello cache when dirty
-        *self.vello_cache.borrow_mut_gen_only() = None;
+        self.layout_dirty.store(true, Ordering::SeqCst);
+        self.paint_dirty.store(true, Ordering::SeqCst);
+        self.clear_render_caches();
         // Propagate dirty flag to all children (Leptos-style)
         for child in self.children.borrow().iter() {
-            child.mark_dirty();
+            child.mark_layout_dirty();
         }
         // Propagate dirty flag upwards so parents know they need to re-render
         if let Some(parent) = self.parent.borrow().as_ref() {
-            parent.mark_dirty();
+            parent.mark_layout_dirty();
+        }
+    }
+
+    /// Mark the component as needing a repaint only, keeping the current layout
+    pub fn mark_paint_dirty(&self) {
+        *self.vello_cache.borrow_mut_gen_only() = None;
+        self.mark_subtree_paint_dirty();
+    }
+
+    /// Flag that something in this subtree needs repainting, keeping this component's own
+    /// scene. Children keep their cached scenes too.
+    fn mark_subtree_paint_dirty(&self) {
+        if self.paint_dirty.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        *self.subtree_cache.borrow_mut_gen_only() = None;
+        if let Some(parent) = self.parent.borrow().as_ref() {
+            parent.mark_subtree_paint_dirty();
         }
     }
 
-    /// Clear the dirty flag
+    /// Drop the cached scenes so the next render redraws this component
+    pub fn clear_render_caches(&self) {
+        *self.vello_cache.borrow_mut_gen_only() = None;
+        *self.subtree_cache.borrow_mut_gen_only() = None;
+    }
+
+    /// Clear the dirty flags
     pub fn clear_dirty(&self) {
-        self.is_dirty.store(false, Ordering::SeqCst);
+        self.paint_dirty.store(false, Ordering::SeqCst);
+        self.layout_dirty.store(false, Ordering::SeqCst);
         // Also clear dirty flag for all children
         for child in self.children.borrow().iter() {
             child.clear_dirty();
         }
     }
 
-    /// Check if the component is dirty
+    /// Check if the component is paint dirty, the same as [`Self::is_paint_dirty`]
+    ///
+    /// Marking layout dirty also marks paint dirty, so this covers both. It replaces the
+    /// removed `is_dirty` field; use `paint_dirty` or `layout_dirty` to reach the flags directly.
     pub fn is_dirty(&self) -> bool {
-        self.is_dirty.load(Ordering::SeqCst)
+        self.paint_dirty.load(Ordering::SeqCst)
+    }
+
+    /// Check if the component needs a repaint
+    pub fn is_paint_dirty(&self) -> bool {
+        self.paint_dirty.load(Ordering::SeqCst)
+    }
+
+    /// Check if the component needs relayout
+    pub fn is_layout_dirty(&self) -> bool {
+        self.layout_dirty.load(Ordering::SeqCst)
     }
 
     /// Get user data
@@ -482,12 +626,61 @@ impl Component {
         self.children.borrow_mut().push(Gc::clone(&child));
     }
 
+    /// Add `child` as the last child and make this component its parent
+    pub fn append_child(self: &Gc<Self>, child: Gc<Component>) {
+        child.set_parent(Some(Gc::clone(self)));
+        self.add_child(child);
+    }
+
     /// Remove a child component
     pub fn remove_child(&self, child: &Gc<Component>) {
         let mut children = self.children.borrow_mut();
         children.retain(|c| !Gc::ptr_eq(c, child));
     }
 
+    /// Tear down this component and its subtree so they can be collected
+    ///
+    /// Unmounts the subtree, which runs its cleanups and cancels its component-scoped tasks, then
+    /// disposes its effects, detaches it from its parent, and drops the references its
+    /// components hold to each other and to their handlers. The component must not be mounted
+    /// again afterwards.
+    pub fn dispose(&self) {
+        self.unmount();
+
+        let parent = self.parent.borrow_mut_gen_only().take();
+        if let Some(parent) = parent {
+            parent.children.borrow_mut_gen_only().retain(|c| !std::ptr::eq(&**c, self));
+            parent.mark_dirty();
+        }
+
+        self.release();
+    }
+
+    /// Drop the references held by this component and its subtree
+    fn release(&self) {
+        let children = std::mem::take(&mut *self.children.borrow_mut_gen_only());
+        for child in children {
+            *child.parent.borrow_mut_gen_only() = None;
+            child.release();
+        }
+
+        let effects = std::mem::take(&mut *self.effects.borrow_mut_gen_only());
+        for effect in effects {
+            effect.dispose();
+        }
+
+        *self.event_handlers.borrow_mut_gen_only() = EventHandlers::default();
+        self.contexts.borrow_mut_gen_only().clear();
+        *self.user_data.borrow_mut_gen_only() = None;
+        *self.layout_node.borrow_mut_gen_only() = None;
+        self.clear_render_caches();
+        *self.text_editor.borrow_mut_gen_only() = None;
+        *self.cursor_blink.borrow_mut_gen_only() = None;
+        *self.text_binding.borrow_mut_gen_only() = None;
+        *self.context_menu.borrow_mut_gen_only() = None;
+        *self.canvas_draw.borrow_mut_gen_only() = None;
+    }
+
     /// Set layout node
     pub fn set_layout_node(&self, layout_node: LayoutNode) {
         *self.layout_node.borrow_mut_gen_only() = Some(layout_node);
@@ -532,7 +725,10 @@ impl Component {
     }
 
     pub fn accepts_text_input(&self) -> bool {
-        matches!(self.component_type, ComponentType::TextInput | ComponentType::NumberInput)
+        matches!(
+            self.component_type,
+            ComponentType::TextInput | ComponentType::TextArea | ComponentType::NumberInput
+        )
     }
 
     pub fn is_disabled(&self) -> bool {
@@ -543,6 +739,23 @@ impl Component {
         self.flags.borrow().contains(ComponentFlags::IS_STASHED)
     }
 
+    /// Move keyboard focus to this component on the next focus update pass.
+    ///
+    /// Does nothing if the component does not accept focus or is disabled.
+    pub fn focus(&self) {
+        if !self.accepts_focus() || self.is_disabled() {
+            return;
+        }
+        request_focus_change(FocusRequest::Focus(self.id));
+        self.mark_dirty();
+    }
+
+    /// Remove keyboard focus from this component on the next focus update pass.
+    pub fn blur(&self) {
+        request_focus_change(FocusRequest::Blur(self.id));
+        self.mark_dirty();
+    }
+
     pub fn on_status_update(&self, update: &StatusUpdate) {
         match update {
             StatusUpdate::Mounted => {
@@ -554,6 +767,14 @@ impl Component {
             StatusUpdate::HoveredChanged(hovered) => {
                 *self.is_hovered.borrow_mut_gen_only() = *hovered;
                 self.mark_dirty();
+                if self.link_href().is_some() {
+                    crate::widgets::link::link_hovered(self, *hovered);
+                }
+                for child in self.children.borrow().iter() {
+                    if child.component_type == ComponentType::Tooltip {
+                        crate::widgets::tooltip::anchor_hovered(child, *hovered);
+                    }
+                }
             }
             StatusUpdate::ActiveChanged(active) => {
                 *self.is_active.borrow_mut_gen_only() = *active;
@@ -580,8 +801,21 @@ impl Component {
 
     /// Set text content (for Text components)
     pub fn set_text_content(&self, content: String) {
+        // Offsets into the old content no longer mean anything
+        if self.text_selection().is_some() {
+            self.properties.borrow_mut_gen_only().insert(TextSelection(None));
+        }
+        // Plain content replaces any styled runs
+        if !self.text_spans().is_empty() {
+            self.properties.borrow_mut_gen_only().insert(TextSpans(Vec::new()));
+        }
         self.properties.borrow_mut_gen_only().insert(TextContent(content));
-        self.mark_dirty();
+        // The scene reshapes text before painting and relays out only if its size changed
+        if self.component_type == ComponentType::Text {
+            self.mark_paint_dirty();
+        } else {
+            self.mark_dirty();
+        }
     }
 
     /// Get text content
@@ -597,6 +831,96 @@ impl Component {
         String::new()
     }
 
+    /// Let the user select this text component's content with the pointer and copy it
+    pub fn set_text_selectable(&self, selectable: bool) {
+        self.properties.borrow_mut_gen_only().insert(TextSelectable(selectable));
+        if selectable {
+            self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+        } else if self.text_selection().is_some() {
+            self.set_text_selection(None);
+        }
+    }
+
+    /// Check if this text component's content can be selected
+    pub fn is_text_selectable(&self) -> bool {
+        self.properties.borrow().get::<TextSelectable>().map(|s| s.0).unwrap_or(false)
+    }
+
+    /// Set the selected range as `(anchor, focus)` byte offsets into the text content
+    pub fn set_text_selection(&self, selection: Option<(usize, usize)>) {
+        self.properties.borrow_mut_gen_only().insert(TextSelection(selection));
+        self.mark_dirty();
+    }
+
+    /// Get the selected range as `(anchor, focus)` byte offsets into the text content
+    pub fn text_selection(&self) -> Option<(usize, usize)> {
+        self.properties.borrow().get::<TextSelection>().and_then(|s| s.0)
+    }
+
+    /// Get the selected part of the text content, if any is selected
+    pub fn selected_text(&self) -> Option<String> {
+        let (anchor, focus) = self.text_selection()?;
+        let content = self.text_content();
+        let start = anchor.min(focus).min(content.len());
+        let end = anchor.max(focus).min(content.len());
+        if start == end {
+            return None;
+        }
+        content.get(start..end).map(str::to_string)
+    }
+
+    /// Set styled runs of text, replacing the content with their joined text
+    pub fn set_text_spans(&self, spans: Vec<TextSpan>) {
+        self.set_text_content(spans_text(&spans));
+        self.properties.borrow_mut_gen_only().insert(TextSpans(spans));
+    }
+
+    /// Get the styled runs of a rich text component, empty for plain text
+    pub fn text_spans(&self) -> Vec<TextSpan> {
+        self.properties.borrow().get::<TextSpans>().map(|s| s.0.clone()).unwrap_or_default()
+    }
+
+    /// Get the name exposed to assistive technology.
+    ///
+    /// An `aria_label` wins; otherwise text components use their content and
+    /// buttons, checkboxes and radios use the text of their descendants.
+    pub fn accessible_name(&self) -> Option<String> {
+        if let Some(label) = self.aria_label() {
+            return Some(label);
+        }
+
+        let name = match &self.component_type {
+            ComponentType::Text => self.text_content(),
+            ComponentType::Button | ComponentType::Checkbox => self.descendant_text(),
+            ComponentType::Radio => {
+                let text = self.descendant_text();
+                if text.is_empty() {
+                    self.radio_value()
+                } else {
+                    text
+                }
+            }
+            _ => String::new(),
+        };
+
+        let name = name.trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    fn descendant_text(&self) -> String {
+        let mut parts = Vec::new();
+        for child in self.children.borrow().iter() {
+            let text = match child.component_type {
+                ComponentType::Text => child.text_content(),
+                _ => child.descendant_text(),
+            };
+            if !text.trim().is_empty() {
+                parts.push(text.trim().to_string());
+            }
+        }
+        parts.join(" ")
+    }
+
     /// Set flex direction (for Flex components)
     pub fn set_flex_direction(&self, direction: String) {
         self.properties.borrow_mut_gen_only().insert(FlexDirection(direction));
@@ -698,14 +1022,26 @@ impl Component {
 
     /// Set widget styles
     pub fn set_widget_styles(&self, styles: rvue_style::ComputedStyles) {
+        let change = self.widget_styles().unwrap_or_default().diff(&styles);
         self.properties.borrow_mut_gen_only().insert(WidgetStyles(styles));
-        self.mark_dirty();
+        if change.needs_layout() {
+            self.mark_layout_dirty();
+        } else if change.is_paint_only() {
+            self.mark_paint_dirty();
+        }
     }
 
     /// Set scroll state for a Flex component (used internally after layout calculation)
     pub fn set_scroll_state(&self, scroll_state: crate::render::widget::FlexScrollState) {
-        let mut user_data = self.user_data.borrow_mut_gen_only();
-        *user_data = Some(Box::new(scroll_state));
+        *self.user_data.borrow_mut_gen_only() = Some(Box::new(scroll_state));
+
+        let on_scroll_offset_change = self.event_handlers.borrow().on_scroll_offset_change.clone();
+        if let Some(handler) = on_scroll_offset_change {
+            handler.notify(vello::kurbo::Vec2::new(
+                scroll_state.scroll_offset_x as f64,
+                scroll_state.scroll_offset_y as f64,
+            ));
+        }
     }
 
     /// Get scroll state for a Flex component (returns default if not set)
@@ -721,6 +1057,22 @@ impl Component {
         }
     }
 
+    /// Record that this scroll container just scrolled, keeping `Overflow::Auto` scrollbars
+    /// visible for a while
+    pub fn mark_scroll_activity(&self) {
+        self.properties.borrow_mut_gen_only().insert(ScrollbarActivity(Some(Instant::now())));
+    }
+
+    /// Get when this scroll container last scrolled, if its scrollbars are still shown for it
+    pub fn scroll_activity(&self) -> Option<Instant> {
+        self.properties.borrow().get::<ScrollbarActivity>().and_then(|a| a.0)
+    }
+
+    /// Forget the last scroll activity once its auto-hide delay has passed
+    pub fn clear_scroll_activity(&self) {
+        self.properties.borrow_mut_gen_only().insert(ScrollbarActivity(None));
+    }
+
     /// Set the flag indicating this component is inside a scrolling parent.
     /// This prevents scroll transform accumulation in nested overflow containers.
     pub fn set_is_in_scrolling_parent(&self, is_scrolling: bool) {
@@ -732,6 +1084,29 @@ impl Component {
         self.is_in_scrolling_parent.load(Ordering::SeqCst)
     }
 
+    /// Make a Button submit its enclosing form when activated
+    pub fn set_button_submit(&self, submit: bool) {
+        self.properties.borrow_mut_gen_only().insert(ButtonSubmit(submit));
+        if submit {
+            self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+        }
+    }
+
+    /// Whether a Button submits its enclosing form when activated
+    pub fn is_submit_button(&self) -> bool {
+        self.properties.borrow().get::<ButtonSubmit>().is_some_and(|s| s.0)
+    }
+
+    /// Make a Text a link to `href`, underlined while hovered
+    pub fn set_link_href(&self, href: String) {
+        self.properties.borrow_mut_gen_only().insert(LinkHref(href));
+    }
+
+    /// URL a Link points at, or `None` for other components
+    pub fn link_href(&self) -> Option<String> {
+        self.properties.borrow().get::<LinkHref>().map(|h| h.0.clone())
+    }
+
     /// Set checkbox checked state (for Checkbox components)
     pub fn set_checkbox_checked(&self, checked: bool) {
         self.properties.borrow_mut_gen_only().insert(CheckboxChecked(checked));
@@ -743,6 +1118,17 @@ impl Component {
         self.properties.borrow().get::<CheckboxChecked>().map(|c| c.0).unwrap_or(false)
     }
 
+    /// Set checkbox indeterminate state (for Checkbox components)
+    pub fn set_checkbox_indeterminate(&self, indeterminate: bool) {
+        self.properties.borrow_mut_gen_only().insert(CheckboxIndeterminate(indeterminate));
+        self.mark_dirty();
+    }
+
+    /// Get checkbox indeterminate state
+    pub fn checkbox_indeterminate(&self) -> bool {
+        self.properties.borrow().get::<CheckboxIndeterminate>().map(|c| c.0).unwrap_or(false)
+    }
+
     /// Set radio checked state (for Radio components)
     pub fn set_radio_checked(&self, checked: bool) {
         self.properties.borrow_mut_gen_only().insert(RadioChecked(checked));
@@ -776,6 +1162,26 @@ impl Component {
         self.properties.borrow().get::<TextInputValue>().map(|v| v.0.clone()).unwrap_or_default()
     }
 
+    /// Set how far a text area's content is scrolled (for TextArea components)
+    pub fn set_text_area_scroll_offset(&self, offset: f64) {
+        self.properties.borrow_mut_gen_only().insert(TextAreaScrollOffset(offset));
+    }
+
+    /// Get how far a text area's content is scrolled
+    pub fn text_area_scroll_offset(&self) -> f64 {
+        self.properties.borrow().get::<TextAreaScrollOffset>().map(|o| o.0).unwrap_or(0.0)
+    }
+
+    /// Set how far a single-line input's text is scrolled horizontally (for TextInput components)
+    pub fn set_text_input_scroll_offset(&self, offset: f64) {
+        self.properties.borrow_mut_gen_only().insert(TextInputScrollOffset(offset));
+    }
+
+    /// Get how far a single-line input's text is scrolled horizontally
+    pub fn text_input_scroll_offset(&self) -> f64 {
+        self.properties.borrow().get::<TextInputScrollOffset>().map(|o| o.0).unwrap_or(0.0)
+    }
+
     /// Set clip mode for this component.
     /// When true, content overflowing the component bounds will be hidden.
     pub fn set_clip(&self, clip: bool) {
@@ -793,6 +1199,63 @@ impl Component {
         self.properties.borrow().get::<NumberInputValue>().map(|v| v.0).unwrap_or(0.0)
     }
 
+    /// Set the bounds and step of a NumberInput's steppers and wheel adjustment
+    pub fn set_number_input_range(&self, min: f64, max: f64, step: f64) {
+        self.properties.borrow_mut_gen_only().insert(NumberInputRange { min, max, step });
+    }
+
+    /// Get the bounds and step of a NumberInput
+    pub fn number_input_range(&self) -> NumberInputRange {
+        self.properties.borrow().get_or_default::<NumberInputRange>().clone()
+    }
+
+    /// Show or hide a NumberInput's up/down stepper buttons
+    pub fn set_number_input_steppers(&self, steppers: bool) {
+        self.properties.borrow_mut_gen_only().insert(NumberInputSteppers(steppers));
+        self.mark_paint_dirty();
+    }
+
+    /// Whether a NumberInput shows its stepper buttons
+    pub fn number_input_steppers(&self) -> bool {
+        self.properties.borrow().get::<NumberInputSteppers>().is_some_and(|s| s.0)
+    }
+
+    /// Set the validation error shown below an input, or `None` once the value is valid
+    ///
+    /// An input with an error matches the `:invalid` pseudo-class.
+    pub fn set_error_message(&self, message: Option<String>) {
+        if self.error_message() == message {
+            return;
+        }
+        self.properties.borrow_mut_gen_only().insert(ValidationMessage(message));
+        // The message takes up room below the input, so this is a layout change
+        self.mark_dirty();
+    }
+
+    /// Get the validation error shown below an input
+    pub fn error_message(&self) -> Option<String> {
+        self.properties.borrow().get::<ValidationMessage>().and_then(|m| m.0.clone())
+    }
+
+    /// Whether an input has a validation error
+    pub fn is_invalid(&self) -> bool {
+        self.properties.borrow().get::<ValidationMessage>().is_some_and(|m| m.0.is_some())
+    }
+
+    /// Move a NumberInput's value by `steps` times its step, clamped to its bounds
+    ///
+    /// Returns the new value, or `None` when clamping left the value unchanged.
+    pub fn step_number_input(&self, steps: f64) -> Option<f64> {
+        let range = self.number_input_range();
+        let value = self.number_input_value();
+        let stepped = crate::widgets::input::step_number(value, steps, &range);
+        if stepped == value {
+            return None;
+        }
+        self.set_number_input_value(stepped);
+        Some(stepped)
+    }
+
     /// Set show condition (for Show components)
     pub fn set_show_when(&self, when: bool) {
         self.properties.borrow_mut_gen_only().insert(ShowCondition(when));
@@ -804,6 +1267,171 @@ impl Component {
         self.properties.borrow().get::<ShowCondition>().map(|w| w.0).unwrap_or(true)
     }
 
+    /// Set the enter/exit transition of a Show component
+    pub fn set_show_transition(&self, transition: Option<Transition>) {
+        let content_visible = self.show_when();
+        let mut properties = self.properties.borrow_mut_gen_only();
+        properties.insert(ShowTransition(transition));
+        properties.insert(ShowTransitionState { content_visible, active: None });
+    }
+
+    /// Get the enter/exit transition of a Show component
+    pub fn show_transition(&self) -> Option<Transition> {
+        self.properties.borrow().get::<ShowTransition>().and_then(|t| t.0)
+    }
+
+    /// Whether a Show component's children are displayed
+    ///
+    /// Unlike [`Self::show_when`], this stays true while the exit transition plays.
+    pub fn show_content_visible(&self) -> bool {
+        match self.properties.borrow().get::<ShowTransitionState>() {
+            Some(state) if self.show_transition().is_some() => state.content_visible,
+            _ => self.show_when(),
+        }
+    }
+
+    /// Phase of a Show component's running transition and its progress from 0.0 to 1.0
+    pub fn show_transition_progress(&self, now: Instant) -> Option<(TransitionPhase, f64)> {
+        let transition = self.show_transition()?;
+        let (phase, started) = self.properties.borrow().get::<ShowTransitionState>()?.active?;
+        let elapsed = now.saturating_duration_since(started).as_secs_f64();
+        let duration = transition.playing_duration();
+        let progress =
+            if duration.is_zero() { 1.0 } else { (elapsed / duration.as_secs_f64()).min(1.0) };
+        Some((phase, progress))
+    }
+
+    /// Start, advance or finish a Show component's transition as of `now`
+    ///
+    /// Children are mounted when an enter transition starts and unmounted once the
+    /// exit transition has run for its full duration. [`Component::update_at`] calls
+    /// this once per frame.
+    pub fn update_show_transition(&self, now: Instant) {
+        let Some(transition) = self.show_transition() else {
+            return;
+        };
+        let when = self.show_when();
+        let mut state = self
+            .properties
+            .borrow()
+            .get::<ShowTransitionState>()
+            .copied()
+            .unwrap_or(ShowTransitionState { content_visible: when, active: None });
+        let phase = state.active.map(|(phase, _)| phase);
+
+        if when && (!state.content_visible || phase == Some(TransitionPhase::Exiting)) {
+            if !state.content_visible {
+                for child in self.children.borrow().iter() {
+                    child.mount(None);
+                }
+            }
+            state.content_visible = true;
+            state.active = Some((TransitionPhase::Entering, now));
+        } else if !when && state.content_visible && phase != Some(TransitionPhase::Exiting) {
+            state.active = Some((TransitionPhase::Exiting, now));
+        }
+
+        if let Some((phase, started)) = state.active {
+            if now.saturating_duration_since(started) >= transition.playing_duration() {
+                state.active = None;
+                if phase == TransitionPhase::Exiting {
+                    state.content_visible = false;
+                    for child in self.children.borrow().iter() {
+                        child.unmount();
+                    }
+                }
+            }
+            // Keep repainting while the transition plays
+            self.mark_dirty();
+        }
+
+        self.properties.borrow_mut_gen_only().insert(state);
+    }
+
+    /// Set the position a context menu is open at, or `None` to close it
+    pub fn set_context_menu_position(&self, position: Option<(f64, f64)>) {
+        self.properties.borrow_mut_gen_only().insert(ContextMenuPosition(position));
+        self.mark_dirty();
+    }
+
+    /// Get the position a context menu is open at
+    pub fn context_menu_position(&self) -> Option<(f64, f64)> {
+        self.properties.borrow().get::<ContextMenuPosition>().and_then(|p| p.0)
+    }
+
+    /// Show or hide a tooltip next to its anchor
+    pub fn set_tooltip_open(&self, open: bool) {
+        self.properties.borrow_mut_gen_only().insert(TooltipOpen(open));
+        self.mark_dirty();
+    }
+
+    /// Check whether a tooltip is showing
+    pub fn tooltip_open(&self) -> bool {
+        self.properties.borrow().get::<TooltipOpen>().is_some_and(|p| p.0)
+    }
+
+    /// Set how complete a progress bar is, clamped to `0.0..=1.0`
+    pub fn set_progress_value(&self, value: f64) {
+        let value = crate::widgets::progress::clamp_progress(value);
+        self.properties.borrow_mut_gen_only().insert(ProgressValue(value));
+        self.mark_paint_dirty();
+    }
+
+    /// Get how complete a progress bar is, from 0.0 to 1.0
+    pub fn progress_value(&self) -> f64 {
+        self.properties.borrow().get::<ProgressValue>().map(|p| p.0).unwrap_or(0.0)
+    }
+
+    /// Switch a progress bar between showing its value and the indeterminate animation
+    pub fn set_progress_indeterminate(&self, indeterminate: bool) {
+        self.properties.borrow_mut_gen_only().insert(ProgressIndeterminate(indeterminate));
+        self.mark_paint_dirty();
+    }
+
+    /// Check whether a progress bar is indeterminate
+    pub fn progress_indeterminate(&self) -> bool {
+        self.properties.borrow().get::<ProgressIndeterminate>().is_some_and(|p| p.0)
+    }
+
+    /// Get the rotation of a spinner's arc in radians, from 0 up to a full turn
+    pub fn spinner_angle(&self) -> f64 {
+        self.properties.borrow().get::<SpinnerRotation>().map(|r| r.angle).unwrap_or(0.0)
+    }
+
+    /// Turn a spinner's arc by the time since its previous frame and request a repaint
+    ///
+    /// Does nothing while the spinner is unmounted, so a hidden spinner stops asking for
+    /// frames. [`Component::update_at`] calls this once per frame.
+    pub fn update_spinner(&self, now: Instant) {
+        let mut rotation = *self.properties.borrow().get_or_default::<SpinnerRotation>();
+        if !rotation.running || crate::app::prefers_reduced_motion() {
+            return;
+        }
+        if let Some(last_frame) = rotation.last_frame {
+            let turns = now.saturating_duration_since(last_frame).as_secs_f64()
+                / crate::widgets::spinner::SPINNER_PERIOD.as_secs_f64();
+            rotation.angle = (rotation.angle + turns * TAU).rem_euclid(TAU);
+        }
+        rotation.last_frame = Some(now);
+        self.properties.borrow_mut_gen_only().insert(rotation);
+        self.mark_paint_dirty();
+    }
+
+    /// Start or stop a spinner's rotation as it is mounted or unmounted
+    ///
+    /// Visible Show components mount their children every frame, so only a change restarts
+    /// the frame clock.
+    fn set_spinner_running(&self, running: bool) {
+        let rotation = *self.properties.borrow().get_or_default::<SpinnerRotation>();
+        if rotation.running != running {
+            self.properties.borrow_mut_gen_only().insert(SpinnerRotation {
+                last_frame: None,
+                running,
+                ..rotation
+            });
+        }
+    }
+
     /// Set for item count (for For components)
     pub fn set_for_item_count(&self, item_count: usize) {
         self.properties.borrow_mut_gen_only().insert(ForItemCount(item_count));
@@ -815,6 +1443,36 @@ impl Component {
         self.properties.borrow().get::<ForItemCount>().map(|c| c.0).unwrap_or(0)
     }
 
+    /// Set the active arm index (for Switch components)
+    pub fn set_switch_active_arm(&self, index: Option<usize>) {
+        self.properties.borrow_mut_gen_only().insert(SwitchActiveArm(index));
+        self.mark_dirty();
+    }
+
+    /// Get the active arm index of a Switch, `None` when no arm matches
+    pub fn switch_active_arm(&self) -> Option<usize> {
+        self.properties.borrow().get::<SwitchActiveArm>().and_then(|arm| arm.0)
+    }
+
+    /// Set whether a Suspense boundary is waiting on its content
+    pub fn set_suspense_pending(&self, pending: bool) {
+        self.properties.borrow_mut_gen_only().insert(SuspensePending(pending));
+        self.mark_dirty();
+    }
+
+    /// Whether a Suspense boundary is waiting on its content
+    pub fn suspense_pending(&self) -> bool {
+        self.properties.borrow().get::<SuspensePending>().map(|p| p.0).unwrap_or(false)
+    }
+
+    /// The child a Suspense boundary shows: the fallback while pending, otherwise the content
+    ///
+    /// Returns `None` while pending if the boundary has no fallback.
+    pub fn suspense_visible_child(&self) -> Option<Gc<Component>> {
+        let index = usize::from(self.suspense_pending());
+        self.children.borrow().get(index).cloned()
+    }
+
     pub fn init_text_editor(&self, text: &str) {
         let editor = SharedTextEditor::with_text(text);
         *self.text_editor.borrow_mut_gen_only() = Some(editor);
@@ -829,6 +1487,15 @@ impl Component {
         self.cursor_blink.borrow().clone()
     }
 
+    /// Set the signal a TextArea writes its content to after every edit
+    pub fn set_text_binding(&self, binding: WriteSignal<String>) {
+        *self.text_binding.borrow_mut_gen_only() = Some(binding);
+    }
+
+    pub fn text_binding(&self) -> Option<WriteSignal<String>> {
+        self.text_binding.borrow().clone()
+    }
+
     pub fn update_cursor_blink(&self, interval_ms: u64, is_focused: bool) -> bool {
         if let Some(blink) = self.cursor_blink.borrow().as_ref() {
             blink.update(interval_ms, is_focused)
@@ -898,23 +1565,25 @@ impl Component {
         self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_down_0arg<F>(self: &Gc<Self>, handler: F)
+    pub fn on_context_menu_0arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn() + 'static,
     {
         let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
-        self.event_handlers.borrow_mut_gen_only().on_pointer_down = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_context_menu = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_down_1arg<F>(self: &Gc<Self>, handler: F)
+    pub fn on_context_menu_1arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
     {
         let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
-        self.event_handlers.borrow_mut_gen_only().on_pointer_down = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_context_menu = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_down<F>(self: &Gc<Self>, handler: F)
+    pub fn on_context_menu<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
             + 'static,
@@ -923,26 +1592,29 @@ impl Component {
             crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
                 handler,
             );
-        self.event_handlers.borrow_mut_gen_only().on_pointer_down = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_context_menu = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_up_0arg<F>(self: &Gc<Self>, handler: F)
+    pub fn on_long_press_0arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn() + 'static,
     {
         let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
-        self.event_handlers.borrow_mut_gen_only().on_pointer_up = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_long_press = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_up_1arg<F>(self: &Gc<Self>, handler: F)
+    pub fn on_long_press_1arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
     {
         let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
-        self.event_handlers.borrow_mut_gen_only().on_pointer_up = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_long_press = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_up<F>(self: &Gc<Self>, handler: F)
+    pub fn on_long_press<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
             + 'static,
@@ -951,35 +1623,262 @@ impl Component {
             crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
                 handler,
             );
-        self.event_handlers.borrow_mut_gen_only().on_pointer_up = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_long_press = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_enter_0arg<F>(self: &Gc<Self>, handler: F)
+    pub fn on_drag_start_0arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn() + 'static,
     {
         let handler =
-            crate::event::handler::EventHandler::<crate::event::types::PointerInfo>::new_0arg(
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_0arg(
                 handler,
             );
-        self.event_handlers.borrow_mut_gen_only().on_pointer_enter = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drag_start = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_enter_1arg<F>(self: &Gc<Self>, handler: F)
+    pub fn on_drag_start_1arg<F>(self: &Gc<Self>, handler: F)
     where
-        F: Fn(&crate::event::types::PointerInfo) + 'static,
+        F: Fn(&crate::event::types::DragEvent) + 'static,
     {
         let handler =
-            crate::event::handler::EventHandler::<crate::event::types::PointerInfo>::new_1arg(
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_1arg(
                 handler,
             );
-        self.event_handlers.borrow_mut_gen_only().on_pointer_enter = Some(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drag_start = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
     }
 
-    pub fn on_pointer_enter<F>(self: &Gc<Self>, handler: F)
+    pub fn on_drag_start<F>(self: &Gc<Self>, handler: F)
     where
-        F: Fn(&crate::event::types::PointerInfo, &mut crate::event::context::EventContext)
-            + 'static,
+        F: Fn(&crate::event::types::DragEvent, &mut crate::event::context::EventContext) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drag_start = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_0arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drag = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_1arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drag = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent, &mut crate::event::context::EventContext) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drag = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_end_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_0arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drag_end = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_end_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_1arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drag_end = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_end<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent, &mut crate::event::context::EventContext) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drag_end = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_over_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_0arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drag_over = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_over_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_1arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drag_over = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drag_over<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent, &mut crate::event::context::EventContext) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drag_over = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drop_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_0arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drop = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drop_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new_1arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_drop = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_drop<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::DragEvent, &mut crate::event::context::EventContext) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::DragEvent>::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_drop = Some(handler);
+        self.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
+    }
+
+    pub fn on_pointer_down_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_down = Some(handler);
+    }
+
+    pub fn on_pointer_down_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_down = Some(handler);
+    }
+
+    pub fn on_pointer_down<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
+            + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_pointer_down = Some(handler);
+    }
+
+    pub fn on_pointer_up_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_up = Some(handler);
+    }
+
+    pub fn on_pointer_up_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_up = Some(handler);
+    }
+
+    pub fn on_pointer_up<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
+            + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_pointer_up = Some(handler);
+    }
+
+    pub fn on_pointer_enter_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerInfo>::new_0arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_pointer_enter = Some(handler);
+    }
+
+    pub fn on_pointer_enter_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerInfo) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerInfo>::new_1arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_pointer_enter = Some(handler);
+    }
+
+    pub fn on_pointer_enter<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerInfo, &mut crate::event::context::EventContext)
+            + 'static,
     {
         let handler =
             crate::event::handler::EventHandler::<crate::event::types::PointerInfo>::new(handler);
@@ -1084,6 +1983,122 @@ impl Component {
         self.event_handlers.borrow_mut_gen_only().on_key_down = Some(handler);
     }
 
+    pub fn on_click_capture_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_click_capture = Some(handler);
+    }
+
+    pub fn on_click_capture_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_click_capture = Some(handler);
+    }
+
+    pub fn on_click_capture<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
+            + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_click_capture = Some(handler);
+    }
+
+    pub fn on_pointer_down_capture_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_down_capture = Some(handler);
+    }
+
+    pub fn on_pointer_down_capture_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_down_capture = Some(handler);
+    }
+
+    pub fn on_pointer_down_capture<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
+            + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_pointer_down_capture = Some(handler);
+    }
+
+    pub fn on_pointer_up_capture_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_0arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_up_capture = Some(handler);
+    }
+
+    pub fn on_pointer_up_capture_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent) + 'static,
+    {
+        let handler = crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new_1arg(handler);
+        self.event_handlers.borrow_mut_gen_only().on_pointer_up_capture = Some(handler);
+    }
+
+    pub fn on_pointer_up_capture<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::PointerButtonEvent, &mut crate::event::context::EventContext)
+            + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::PointerButtonEvent>::new(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_pointer_up_capture = Some(handler);
+    }
+
+    pub fn on_key_down_capture_0arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn() + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::KeyboardEvent>::new_0arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_key_down_capture = Some(handler);
+    }
+
+    pub fn on_key_down_capture_1arg<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::KeyboardEvent) + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::KeyboardEvent>::new_1arg(
+                handler,
+            );
+        self.event_handlers.borrow_mut_gen_only().on_key_down_capture = Some(handler);
+    }
+
+    pub fn on_key_down_capture<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(&crate::event::types::KeyboardEvent, &mut crate::event::context::EventContext)
+            + 'static,
+    {
+        let handler =
+            crate::event::handler::EventHandler::<crate::event::types::KeyboardEvent>::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_key_down_capture = Some(handler);
+    }
+
     pub fn on_key_up_0arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn() + 'static,
@@ -1180,6 +2195,39 @@ impl Component {
         self.event_handlers.borrow_mut_gen_only().on_blur = Some(handler);
     }
 
+    /// Call `handler` with the new size whenever layout changes this component's size,
+    /// including the first layout
+    pub fn on_resize<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(vello::kurbo::Size) + 'static,
+    {
+        let handler = crate::event::handler::ChangeHandler::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_resize = Some(handler);
+    }
+
+    /// Call `handler` with the scroll offset whenever this scroll container's offset changes,
+    /// whether from the user scrolling or from `set_scroll_state`
+    pub fn on_scroll_offset_change<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(vello::kurbo::Vec2) + 'static,
+    {
+        let handler = crate::event::handler::ChangeHandler::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_scroll_offset_change = Some(handler);
+    }
+
+    /// Call `handler` when this component scrolls into (`true`) or out of (`false`) the
+    /// visible area of the scroll container it is a direct child of
+    ///
+    /// Visibility is checked when the container paints, so the first call reports the initial
+    /// state.
+    pub fn on_visibility_change<F>(self: &Gc<Self>, handler: F)
+    where
+        F: Fn(bool) + 'static,
+    {
+        let handler = crate::event::handler::ChangeHandler::new(handler);
+        self.event_handlers.borrow_mut_gen_only().on_visibility_change = Some(handler);
+    }
+
     pub fn on_input_0arg<F>(self: &Gc<Self>, handler: F)
     where
         F: Fn() + 'static,
@@ -1273,6 +2321,48 @@ impl Component {
         self.element_id.borrow().clone()
     }
 
+    /// First descendant, in tree order, that matches `selector`
+    ///
+    /// Selectors match the way stylesheet rules do: `.class`, `#id`, a tag name such as
+    /// `button`, or a compound of these like `button.primary`. The component itself is not
+    /// considered.
+    pub fn query_selector(self: &Gc<Self>, selector: &str) -> Option<Gc<Component>> {
+        self.query_descendants(selector, true).pop()
+    }
+
+    /// Every descendant, in tree order, that matches `selector`
+    ///
+    /// See [`Self::query_selector`] for the supported selectors.
+    pub fn query_selector_all(self: &Gc<Self>, selector: &str) -> Vec<Gc<Component>> {
+        self.query_descendants(selector, false)
+    }
+
+    fn query_descendants(self: &Gc<Self>, selector: &str, first_only: bool) -> Vec<Gc<Component>> {
+        let resolver = rvue_style::StyleResolver::new();
+        let mut matches = Vec::new();
+        let mut stack: Vec<Gc<Component>> = self.children.borrow().iter().rev().cloned().collect();
+        while let Some(component) = stack.pop() {
+            let element = crate::style::component_to_element(&component);
+            if resolver.matches_selector(&element, selector) {
+                matches.push(Gc::clone(&component));
+                if first_only {
+                    break;
+                }
+            }
+            stack.extend(component.children.borrow().iter().rev().cloned());
+        }
+        matches
+    }
+
+    /// Set the accessible name, overriding the name derived from text content
+    pub fn set_aria_label(&self, label: impl Into<String>) {
+        *self.aria_label.borrow_mut_gen_only() = Some(label.into());
+    }
+
+    pub fn aria_label(&self) -> Option<String> {
+        self.aria_label.borrow().clone()
+    }
+
     pub fn classes(&self) -> Vec<String> {
         self.classes.borrow().clone()
     }
@@ -1323,7 +2413,7 @@ fn collect_child_node_ids(
         if let Some(node_id) = child_layout.taffy_node() {
             node_ids.push(node_id);
         } else if matches!(child.component_type, ComponentType::Show) {
-            if child.show_when() {
+            if child.show_content_visible() {
                 for grandchild in child.children.borrow().iter() {
                     if let Some(grandchild_layout) = grandchild.layout_node() {
                         if let Some(node_id) = grandchild_layout.taffy_node() {
@@ -1332,7 +2422,7 @@ fn collect_child_node_ids(
                     }
                 }
             }
-        } else if matches!(child.component_type, ComponentType::For) {
+        } else if matches!(child.component_type, ComponentType::For | ComponentType::Switch) {
             for grandchild in child.children.borrow().iter() {
                 if let Some(grandchild_layout) = grandchild.layout_node() {
                     if let Some(node_id) = grandchild_layout.taffy_node() {
@@ -1340,6 +2430,13 @@ fn collect_child_node_ids(
                     }
                 }
             }
+        } else if matches!(child.component_type, ComponentType::Suspense) {
+            if let Some(visible) = child.suspense_visible_child() {
+                if let Some(node_id) = visible.layout_node().and_then(|layout| layout.taffy_node())
+                {
+                    node_ids.push(node_id);
+                }
+            }
         }
     }
 
@@ -1361,10 +2458,12 @@ pub fn build_layout_tree(
         .map(|child| build_layout_tree(child, taffy, text_context, stylesheet))
         .collect();
 
-    // Control-flow components (For, Show) are transparent - their children's
+    // Control-flow components (For, Show, Switch) are transparent - their children's
     // Taffy nodes should be passed through to the parent, not wrapped
-    let is_control_flow =
-        matches!(component.component_type, ComponentType::For | ComponentType::Show);
+    let is_control_flow = matches!(
+        component.component_type,
+        ComponentType::For | ComponentType::Show | ComponentType::Switch | ComponentType::Suspense
+    );
 
     // Get Taffy node IDs from child layouts, including grandchildren for control-flow
     let child_node_ids = collect_child_node_ids(component, &child_layouts);
@@ -1405,6 +2504,10 @@ impl Component {
 
 impl ComponentLifecycle for Component {
     fn mount(&self, _parent: Option<Gc<Component>>) {
+        if self.component_type == ComponentType::Spinner {
+            self.set_spinner_running(true);
+        }
+
         // For Show components, mount/unmount children based on when condition
         if let ComponentType::Show = self.component_type {
             if self.show_when() {
@@ -1429,6 +2532,10 @@ impl ComponentLifecycle for Component {
             TaskRegistry::cleanup_completed();
         }
 
+        if self.component_type == ComponentType::Spinner {
+            self.set_spinner_running(false);
+        }
+
         for child in self.children.borrow().iter() {
             child.unmount();
         }
@@ -1443,6 +2550,16 @@ impl ComponentLifecycle for Component {
     }
 
     fn update(&self) {
+        self.update_at(Instant::now());
+    }
+}
+
+impl Component {
+    /// Run one frame of [`ComponentLifecycle::update`] as of `now`
+    ///
+    /// Animations such as Show transitions and spinners, and tooltip hover delays, advance to
+    /// `now`, which lets tests step frames at a fixed rate.
+    pub fn update_at(&self, now: Instant) {
         // Use atomic operation to detect cycles
         let is_updating = self.is_updating.swap(true, Ordering::SeqCst);
         if is_updating {
@@ -1457,7 +2574,9 @@ impl ComponentLifecycle for Component {
 
         // For Show components, update children mounting based on when condition
         if let ComponentType::Show = self.component_type {
-            if self.show_when() {
+            if self.show_transition().is_some() {
+                self.update_show_transition(now);
+            } else if self.show_when() {
                 // Ensure children are mounted
                 for child in self.children.borrow().iter() {
                     child.mount(None);
@@ -1470,9 +2589,25 @@ impl ComponentLifecycle for Component {
             }
         }
 
+        // An indeterminate bar is repainted every frame to advance its animation
+        if self.component_type == ComponentType::ProgressBar
+            && self.progress_indeterminate()
+            && !crate::app::prefers_reduced_motion()
+        {
+            self.mark_paint_dirty();
+        }
+
+        if self.component_type == ComponentType::Spinner {
+            self.update_spinner(now);
+        }
+
+        if self.component_type == ComponentType::Tooltip {
+            crate::widgets::tooltip::update_tooltip(self, now);
+        }
+
         // Update all children
         for child in self.children.borrow().iter() {
-            child.update();
+            child.update_at(now);
         }
 
         self.is_updating.store(false, Ordering::SeqCst);
@@ -1485,10 +2620,22 @@ pub fn propagate_layout_results(component: &Gc<Component>, taffy: &TaffyTree<()>
     if let Some(mut layout_node) = component.layout_node() {
         if let Some(node_id) = layout_node.taffy_node() {
             if let Ok(layout) = taffy.layout(node_id) {
-                layout_node.layout_result = Some(*layout);
+                let mut layout = *layout;
+                if component.component_type == ComponentType::Tooltip {
+                    crate::layout::node::keep_tooltip_on_screen(component, &mut layout);
+                }
+                layout_node.layout_result = Some(layout);
                 layout_node.is_dirty = false;
                 component.set_layout_node(layout_node.clone());
 
+                let on_resize = component.event_handlers.borrow().on_resize.clone();
+                if let Some(on_resize) = on_resize {
+                    on_resize.notify(vello::kurbo::Size::new(
+                        layout.size.width as f64,
+                        layout.size.height as f64,
+                    ));
+                }
+
                 // === 新增：更新 Flex Scroll State ===
                 if matches!(component.component_type, ComponentType::Flex) {
                     // Use Taffy's scroll dimensions directly
@@ -1527,13 +2674,25 @@ pub fn propagate_layout_results(component: &Gc<Component>, taffy: &TaffyTree<()>
 /// This builds a Taffy tree, computes layout, and propagates results to update scroll_state
 #[cfg(feature = "testing")]
 pub fn compute_layout_for_testing(component: &Gc<Component>, size: vello::kurbo::Size) {
+    compute_layout_for_testing_with_stylesheet(component, size, None);
+}
+
+/// Compute layout for a component tree (for testing purposes), resolving styles against
+/// `stylesheet`
+#[cfg(feature = "testing")]
+pub fn compute_layout_for_testing_with_stylesheet(
+    component: &Gc<Component>,
+    size: vello::kurbo::Size,
+    stylesheet: Option<&crate::style::Stylesheet>,
+) {
     use crate::text::TextContext;
     use taffy::{AvailableSpace, Size as TaffySize, TaffyTree};
 
     let mut taffy = TaffyTree::new();
     let mut text_context = TextContext::new();
-    let stylesheet = None;
 
+    // Node ids from a previous call belong to another tree
+    clear_layout_nodes(component);
     let root_layout_node = build_layout_tree(component, &mut taffy, &mut text_context, stylesheet);
 
     component.set_layout_node(root_layout_node);
@@ -1549,3 +2708,11 @@ pub fn compute_layout_for_testing(component: &Gc<Component>, size: vello::kurbo:
 
     propagate_layout_results(component, &taffy);
 }
+
+#[cfg(feature = "testing")]
+fn clear_layout_nodes(component: &Gc<Component>) {
+    *component.layout_node.borrow_mut_gen_only() = None;
+    for child in component.children.borrow().iter() {
+        clear_layout_nodes(child);
+    }
+}
@@ -4,6 +4,7 @@
 //! automatic dependency tracking and reactive updates.
 
 use crate::component::Component;
+use crate::gc::trace_opaque;
 use rudo_gc::{Gc, GcCell, Trace, Visitor, Weak};
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -70,18 +71,6 @@ pub struct Effect {
     pub(crate) subscriptions: GcCell<Vec<(usize, Weak<()>, Weak<()>)>>, // (signal_ptr, signal_weak, effect_weak)
 }
 
-/// Conservatively scan an opaque value (e.g. `Box<dyn Fn()>`) for captured Gc pointers.
-/// Use when the value's layout is unknown but may contain Gc<T> (e.g. closure captures).
-#[inline]
-fn trace_opaque<T: ?Sized>(value: &T, visitor: &mut impl Visitor) {
-    let ptr = std::ptr::from_ref(value).cast::<u8>();
-    let layout = std::alloc::Layout::for_value(value);
-    // SAFETY: The caller guarantees the value is valid for reading layout.size() bytes.
-    unsafe {
-        visitor.visit_region(ptr, layout.size());
-    }
-}
-
 unsafe impl Trace for Effect {
     fn trace(&self, visitor: &mut impl Visitor) {
         self.owner.trace(visitor);
@@ -155,6 +144,10 @@ impl Effect {
     pub fn run(gc_effect: &Gc<Self>) {
         log::debug!("Effect::run: starting effect");
 
+        if !gc_effect.is_valid() {
+            return;
+        }
+
         // Prevent recursive execution
         if gc_effect.is_running.swap(true, Ordering::SeqCst) {
             return;
@@ -212,6 +205,26 @@ impl Effect {
         }
     }
 
+    /// Stop the effect for good: run its pending cleanups and drop it from every signal it
+    /// subscribed to, so it never runs again and nothing but its handles keeps it alive.
+    pub fn dispose(&self) {
+        if !self.is_valid.swap(false, Ordering::SeqCst) {
+            return;
+        }
+
+        let cleanups = std::mem::take(&mut *self.cleanups.borrow_mut_gen_only());
+        for cleanup in cleanups {
+            cleanup();
+        }
+
+        let effect_ptr = self as *const Self as usize;
+        let subscriptions = std::mem::take(&mut *self.subscriptions.borrow_mut_gen_only());
+        for (signal_ptr, _, _) in subscriptions {
+            crate::signal::remove_subscriber(signal_ptr, effect_ptr);
+        }
+        *self.owner.borrow_mut_gen_only() = None;
+    }
+
     /// Unsubscribe from all signals this effect is subscribed to
     ///
     /// This properly removes the weak ref from each signal's subscriber list.
@@ -53,3 +53,38 @@ pub struct Blur;
 impl EventDescriptor for Blur {
     type EventType = crate::event::status::FocusEvent;
 }
+
+pub struct ContextMenu;
+impl EventDescriptor for ContextMenu {
+    type EventType = crate::event::types::PointerButtonEvent;
+}
+
+pub struct LongPress;
+impl EventDescriptor for LongPress {
+    type EventType = crate::event::types::PointerButtonEvent;
+}
+
+pub struct DragStart;
+impl EventDescriptor for DragStart {
+    type EventType = crate::event::types::DragEvent;
+}
+
+pub struct Drag;
+impl EventDescriptor for Drag {
+    type EventType = crate::event::types::DragEvent;
+}
+
+pub struct DragEnd;
+impl EventDescriptor for DragEnd {
+    type EventType = crate::event::types::DragEvent;
+}
+
+pub struct DragOver;
+impl EventDescriptor for DragOver {
+    type EventType = crate::event::types::DragEvent;
+}
+
+pub struct Drop;
+impl EventDescriptor for Drop {
+    type EventType = crate::event::types::DragEvent;
+}
@@ -1,5 +1,6 @@
 use crate::component::Component;
 use rudo_gc::Gc;
+use std::any::Any;
 use vello::kurbo::Point;
 
 pub trait EventContextOps {
@@ -12,16 +13,20 @@ pub trait EventContextOps {
     fn set_handled(&mut self);
     fn is_handled(&self) -> bool;
     fn target(&self) -> Gc<Component>;
-    fn local_position(&self, window_pos: Point) -> Point;
     fn has_pointer_capture(&self) -> bool;
     fn set_pending_focus(&mut self, component: Gc<Component>);
     fn set_needs_cursor_blink_update(&mut self);
+    fn drag_payload(&self) -> Option<&dyn Any>;
+    fn set_drag_payload(&mut self, payload: Option<Box<dyn Any>>);
+    fn copied_text(&self) -> Option<&str>;
+    fn set_copied_text(&mut self, text: String);
 }
 
 pub struct EventContext<'a> {
     target: Gc<Component>,
     app_state: &'a mut dyn EventContextOps,
     is_handled: bool,
+    default_prevented: bool,
     pointer_capture: Option<Gc<Component>>,
 }
 
@@ -31,13 +36,20 @@ impl<'a> EventContext<'a> {
         app_state: &'a mut dyn EventContextOps,
         pointer_capture: Option<Gc<Component>>,
     ) -> Self {
-        EventContext { target, app_state, is_handled: false, pointer_capture }
+        EventContext {
+            target,
+            app_state,
+            is_handled: false,
+            default_prevented: false,
+            pointer_capture,
+        }
     }
 
     pub fn target(&self) -> Gc<Component> {
         Gc::clone(&self.target)
     }
 
+    /// Stop the event from bubbling to ancestor handlers
     pub fn stop_propagation(&mut self) {
         self.is_handled = true;
     }
@@ -46,6 +58,16 @@ impl<'a> EventContext<'a> {
         self.is_handled
     }
 
+    /// Skip the built-in behavior for this event, such as focusing an input on
+    /// press or inserting a typed character
+    pub fn prevent_default(&mut self) {
+        self.default_prevented = true;
+    }
+
+    pub fn is_default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+
     pub fn capture_pointer(&mut self) {
         self.pointer_capture = Some(Gc::clone(&self.target));
         self.app_state.capture_pointer(Gc::clone(&self.target));
@@ -78,7 +100,31 @@ impl<'a> EventContext<'a> {
         self.app_state.request_layout();
     }
 
+    /// Convert a window position into the target's local coordinate space, accounting for
+    /// nesting and the scroll offset of any scrolled ancestors
     pub fn local_position(&self, window_pos: Point) -> Point {
-        self.app_state.local_position(window_pos)
+        crate::event::hit_test::window_to_local(&self.target, window_pos)
+    }
+
+    /// Set the payload carried by the current drag, typically from `on_drag_start`
+    pub fn set_drag_payload<T: Any>(&mut self, payload: T) {
+        self.app_state.set_drag_payload(Some(Box::new(payload)));
+    }
+
+    /// Get the payload of the current drag if it has type `T`
+    pub fn drag_payload<T: Any>(&self) -> Option<&T> {
+        self.app_state.drag_payload().and_then(|payload| payload.downcast_ref::<T>())
+    }
+
+    /// Get the text most recently copied within the app
+    ///
+    /// This is an in-app copy buffer, not the system clipboard: other applications don't see it.
+    pub fn copied_text(&self) -> Option<&str> {
+        self.app_state.copied_text()
+    }
+
+    /// Put `text` in the in-app copy buffer, replacing what was there
+    pub fn set_copied_text(&mut self, text: impl Into<String>) {
+        self.app_state.set_copied_text(text.into());
     }
 }
@@ -0,0 +1,133 @@
+use crate::component::{Component, ComponentType};
+use crate::style::{resolve_styles_for_component, Stylesheet};
+use rudo_gc::Gc;
+use rvue_style::properties::Cursor;
+use winit::window::CursorIcon;
+
+/// Tracks the cursor currently shown for the hovered component.
+///
+/// The cursor is resolved once per hovered component rather than on every pointer move.
+#[derive(Debug, Default)]
+pub struct CursorState {
+    pub current: Cursor,
+    /// Id of the component `current` was resolved for, `None` when nothing is hovered
+    resolved_for: Option<u64>,
+    is_stale: bool,
+}
+
+impl CursorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve the cursor again on the next update, for when styles such as `:hover` changed
+    pub fn invalidate(&mut self) {
+        self.is_stale = true;
+    }
+
+    /// Resolves the cursor for `hovered` and returns it if it differs from the current one.
+    ///
+    /// Nothing is resolved while the same component stays hovered, unless the state was
+    /// invalidated since.
+    pub fn update(
+        &mut self,
+        hovered: Option<&Gc<Component>>,
+        stylesheet: Option<&Stylesheet>,
+    ) -> Option<Cursor> {
+        let hovered_id = hovered.map(|component| component.id);
+        if hovered_id == self.resolved_for && !self.is_stale {
+            return None;
+        }
+        self.resolved_for = hovered_id;
+        self.is_stale = false;
+
+        let cursor = resolve_cursor(hovered, stylesheet);
+        if cursor == self.current {
+            return None;
+        }
+        self.current = cursor.clone();
+        Some(cursor)
+    }
+}
+
+/// Maps a style cursor to the winit cursor icon.
+pub fn cursor_icon_for(cursor: &Cursor) -> CursorIcon {
+    match cursor {
+        Cursor::Default => CursorIcon::Default,
+        Cursor::Pointer => CursorIcon::Pointer,
+        Cursor::Text => CursorIcon::Text,
+        Cursor::Move => CursorIcon::Move,
+        Cursor::NotAllowed => CursorIcon::NotAllowed,
+        Cursor::Progress => CursorIcon::Progress,
+        Cursor::Wait => CursorIcon::Wait,
+        Cursor::Crosshair => CursorIcon::Crosshair,
+        Cursor::Help => CursorIcon::Help,
+        Cursor::ResizeNS => CursorIcon::NsResize,
+        Cursor::ResizeEW => CursorIcon::EwResize,
+        Cursor::ResizeNESW => CursorIcon::NeswResize,
+        Cursor::ResizeNWSE => CursorIcon::NwseResize,
+    }
+}
+
+/// Resolves the cursor for a hovered component.
+///
+/// An explicit `cursor` style wins; otherwise interactive widgets fall back to their
+/// native cursor. Components without either inherit the cursor of their parent.
+pub fn resolve_cursor(target: Option<&Gc<Component>>, stylesheet: Option<&Stylesheet>) -> Cursor {
+    let mut current = target.cloned();
+
+    while let Some(component) = current {
+        let styles = match stylesheet {
+            Some(sheet) => resolve_styles_for_component(&component, sheet),
+            None => component.widget_styles().unwrap_or_default(),
+        };
+
+        if let Some(cursor) = styles.cursor {
+            return cursor;
+        }
+
+        if let Some(cursor) = default_cursor_for(&component.component_type) {
+            return cursor;
+        }
+
+        current = component.parent.borrow().clone();
+    }
+
+    Cursor::Default
+}
+
+fn default_cursor_for(component_type: &ComponentType) -> Option<Cursor> {
+    match component_type {
+        ComponentType::Button | ComponentType::Checkbox | ComponentType::Radio => {
+            Some(Cursor::Pointer)
+        }
+        ComponentType::TextInput | ComponentType::TextArea | ComponentType::NumberInput => {
+            Some(Cursor::Text)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_icon_mapping() {
+        assert_eq!(cursor_icon_for(&Cursor::Default), CursorIcon::Default);
+        assert_eq!(cursor_icon_for(&Cursor::Pointer), CursorIcon::Pointer);
+        assert_eq!(cursor_icon_for(&Cursor::Text), CursorIcon::Text);
+        assert_eq!(cursor_icon_for(&Cursor::NotAllowed), CursorIcon::NotAllowed);
+        assert_eq!(cursor_icon_for(&Cursor::ResizeNS), CursorIcon::NsResize);
+        assert_eq!(cursor_icon_for(&Cursor::ResizeEW), CursorIcon::EwResize);
+        assert_eq!(cursor_icon_for(&Cursor::ResizeNESW), CursorIcon::NeswResize);
+        assert_eq!(cursor_icon_for(&Cursor::ResizeNWSE), CursorIcon::NwseResize);
+    }
+
+    #[test]
+    fn test_default_cursor_for_widgets() {
+        assert_eq!(default_cursor_for(&ComponentType::Button), Some(Cursor::Pointer));
+        assert_eq!(default_cursor_for(&ComponentType::TextInput), Some(Cursor::Text));
+        assert_eq!(default_cursor_for(&ComponentType::Flex), None);
+    }
+}
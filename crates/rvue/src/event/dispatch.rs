@@ -1,11 +1,27 @@
 use crate::component::{Component, ComponentType};
 use crate::event::context::EventContext;
+use crate::event::drag::{begin_drag_tracking, run_drag_pass};
 use crate::event::focus::find_next_focusable;
-use crate::event::hit_test::hit_test;
+use crate::event::handler::{EventHandlers, LongPressState};
+use crate::event::hit_test::{hit_test_scrollbar_thumb, hit_test_with_stylesheet, window_to_local};
+use crate::event::observer::{notify_handler, DispatchPhase, DispatchedEvent};
 use crate::event::path::merge_state_up;
-use crate::event::types::{KeyState, PointerEvent, ScrollDelta, TextEvent};
+use crate::event::shortcut::take_pending_shortcuts;
+use crate::event::status::{InputEvent, InputEventType};
+use crate::event::types::{
+    KeyState, KeyboardEvent, Modifiers, PointerButton, PointerButtonEvent, PointerEvent,
+    ScrollDelta, TextEvent,
+};
 use crate::style::get_inline_styles;
+use crate::text::editor::EditKind;
+use crate::text::ParleyLayoutWrapper;
+use crate::widgets::context_menu::dismiss_context_menus;
+use crate::widgets::form::submit_enclosing_form;
+use crate::widgets::input::{single_line_text, stepper_at};
 use rudo_gc::Gc;
+use std::borrow::Cow;
+use std::time::Instant;
+use vello::kurbo::{Point, Size, Vec2};
 use winit::keyboard::{Key, NamedKey};
 
 pub fn find_scroll_container(component: &Gc<Component>) -> Option<Gc<Component>> {
@@ -22,7 +38,7 @@ pub fn find_scroll_container(component: &Gc<Component>) -> Option<Gc<Component>>
                 .and_then(|s| s.overflow_y)
                 .unwrap_or(rvue_style::properties::Overflow::Visible);
 
-            if overflow_x.should_clip() || overflow_y.should_clip() {
+            if overflow_x.is_scroll_container() || overflow_y.is_scroll_container() {
                 return Some(Gc::clone(&p));
             }
         }
@@ -45,7 +61,7 @@ fn find_any_scroll_container(root: &Gc<Component>) -> Option<Gc<Component>> {
                 .and_then(|s| s.overflow_y)
                 .unwrap_or(rvue_style::properties::Overflow::Visible);
 
-            if overflow_x.should_clip() || overflow_y.should_clip() {
+            if overflow_x.is_scroll_container() || overflow_y.is_scroll_container() {
                 return Some(Gc::clone(comp));
             }
         }
@@ -63,7 +79,7 @@ fn find_any_scroll_container(root: &Gc<Component>) -> Option<Gc<Component>> {
 }
 
 /// Find a component by its ID in the tree
-fn find_component_by_id(root: &Gc<Component>, id: u64) -> Option<Gc<Component>> {
+pub(crate) fn find_component_by_id(root: &Gc<Component>, id: u64) -> Option<Gc<Component>> {
     if root.id == id {
         return Some(Gc::clone(root));
     }
@@ -85,6 +101,8 @@ pub fn run_pointer_event_pass(
     app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
     event: &PointerEvent,
 ) -> Handled {
+    let _span = tracing::debug_span!("pointer_event_pass").entered();
+
     // Handle scroll drag operations first
     if let Some(drag_state) = app_state.scroll_drag_state() {
         match event {
@@ -102,6 +120,7 @@ pub fn run_pointer_event_pass(
                         scroll_state.scroll_offset_x = new_offset as f32;
                     }
                     component.set_scroll_state(scroll_state);
+                    component.mark_scroll_activity();
                     component.mark_dirty();
                 }
                 return Handled::Yes;
@@ -114,14 +133,58 @@ pub fn run_pointer_event_pass(
         }
     }
 
+    // Pressing a scrollbar thumb starts dragging it
+    if let PointerEvent::Down(e) = event {
+        if e.button == PointerButton::Primary {
+            let root = app_state.root_component();
+            if let Some(drag_state) =
+                hit_test_scrollbar_thumb(&root, e.position, app_state.stylesheet())
+            {
+                app_state.set_scroll_drag_state(Some(drag_state));
+                return Handled::Yes;
+            }
+        }
+    }
+
     // Ensure state is up to date before dispatching
     if app_state.needs_pointer_pass_update() {
         crate::event::update::run_update_pointer_pass(app_state);
         app_state.set_needs_pointer_pass_update(false);
     }
 
+    // An active drag routes events to the source and drop target instead
+    if let Some(handled) = run_drag_pass(app_state, event) {
+        return handled;
+    }
+
+    // Moving too far or releasing cancels a pending long press
+    if let Some(long_press) = app_state.long_press_state() {
+        let cancel = match event {
+            PointerEvent::Move(e) => long_press.exceeds_slop(e.position),
+            PointerEvent::Up(_) | PointerEvent::Cancel(_) | PointerEvent::Leave(_) => true,
+            _ => false,
+        };
+        if cancel {
+            app_state.set_long_press_state(None);
+        }
+    }
+
     let target = get_pointer_target(app_state);
 
+    if let (PointerEvent::Down(e), Some(target)) = (event, target.as_ref()) {
+        if e.button == PointerButton::Primary {
+            let state = find_long_press_target(target)
+                .map(|component| LongPressState::new(component.id, e.clone(), Instant::now()));
+            app_state.set_long_press_state(state);
+        }
+        begin_drag_tracking(app_state, target, e);
+    }
+
+    // Pressing outside an open context menu dismisses it
+    if matches!(event, PointerEvent::Down(_)) {
+        dismiss_context_menus(&app_state.root_component(), target.as_ref());
+    }
+
     // Special handling for scroll events: if no target found, find scroll container
     if target.is_none() && matches!(event, PointerEvent::Scroll(_)) {
         let root = app_state.root_component();
@@ -140,10 +203,17 @@ pub fn run_pointer_event_pass(
 
         if matches!(event, PointerEvent::Up(_) | PointerEvent::Cancel(_)) {
             app_state.clear_pointer_capture();
+            // Release the active state as soon as nothing holds the pointer
+            crate::event::update::run_update_pointer_pass(app_state);
+            app_state.set_needs_pointer_pass_update(false);
         }
 
         result
     } else {
+        // Moving onto empty space still ends the hover of whatever was under the pointer
+        if matches!(event, PointerEvent::Move(_)) {
+            app_state.set_needs_pointer_pass_update(true);
+        }
         Handled::No
     }
 }
@@ -154,7 +224,8 @@ fn get_pointer_target(app_state: &impl crate::app::AppStateLike) -> Option<Gc<Co
     }
 
     if let Some(pos) = app_state.last_pointer_pos() {
-        let result = hit_test(&app_state.root_component(), pos);
+        let result =
+            hit_test_with_stylesheet(&app_state.root_component(), pos, app_state.stylesheet());
         return result;
     }
 
@@ -166,6 +237,19 @@ fn dispatch_pointer_event(
     target: &Gc<Component>,
     event: &PointerEvent,
 ) -> Handled {
+    // A release only clicks what is still under the pointer
+    let released_over = match event {
+        PointerEvent::Up(e) => hit_test_with_stylesheet(
+            &app_state.root_component(),
+            e.position,
+            app_state.stylesheet(),
+        ),
+        _ => None,
+    };
+    if run_pointer_capture_phase(app_state, released_over.as_ref(), target, event) == Handled::Yes {
+        return Handled::Yes;
+    }
+
     let mut current = Some(Gc::clone(target));
     let mut handled = Handled::No;
 
@@ -176,52 +260,137 @@ fn dispatch_pointer_event(
         }
 
         let capture_clone = app_state.pointer_capture_mut().clone();
+        let is_captured = capture_clone.is_some();
         let mut ctx = EventContext::new(Gc::clone(&component), app_state, capture_clone);
 
         let handlers = component.event_handlers.borrow();
         match event {
             PointerEvent::Down(e) => {
                 if let Some(handler) = handlers.get_pointer_down() {
+                    observe_pointer(&component, "pointer_down", DispatchPhase::Bubble, event);
                     handler.call(e, &mut ctx);
                 }
 
-                if matches!(
-                    component.component_type,
-                    ComponentType::TextInput | ComponentType::NumberInput
-                ) {
-                    ctx.request_focus();
+                if !ctx.is_default_prevented() {
+                    if matches!(
+                        component.component_type,
+                        ComponentType::TextInput
+                            | ComponentType::TextArea
+                            | ComponentType::NumberInput
+                    ) {
+                        ctx.request_focus();
+                    }
+
+                    // Pressing a stepper button steps a NumberInput's value
+                    if component.component_type == ComponentType::NumberInput
+                        && component.number_input_steppers()
+                        && e.button == PointerButton::Primary
+                    {
+                        let local = window_to_local(&component, e.position);
+                        if let Some(steps) = stepper_at(layout_size(&component), local) {
+                            step_number_input(&component, &handlers, steps, &mut ctx);
+                            ctx.stop_propagation();
+                        }
+                    }
+
+                    // Pressing selectable text starts a new selection that dragging extends
+                    let starts_selection = component.component_type == ComponentType::Text
+                        && component.is_text_selectable()
+                        && e.button == PointerButton::Primary;
+                    if starts_selection {
+                        let index = text_index_at(&component, e.position);
+                        component.set_text_selection(index.map(|index| (index, index)));
+                        ctx.request_focus();
+                    }
+
+                    // The innermost clickable keeps the capture so its click is not lost
+                    let is_clickable = handlers.get_click().is_some()
+                        || component.component_type == ComponentType::Checkbox
+                        || component.is_submit_button()
+                        || starts_selection;
+                    if is_clickable && !is_captured {
+                        ctx.capture_pointer();
+                    }
                 }
 
-                if handlers.get_click().is_some() {
-                    ctx.capture_pointer();
+                if e.button == PointerButton::Secondary {
+                    if let Some(handler) = handlers.get_context_menu() {
+                        observe_pointer(&component, "context_menu", DispatchPhase::Bubble, event);
+                        handler.call(e, &mut ctx);
+                        ctx.stop_propagation();
+                    }
                 }
             }
             PointerEvent::Up(e) => {
                 if let Some(handler) = handlers.get_pointer_up() {
+                    observe_pointer(&component, "pointer_up", DispatchPhase::Bubble, event);
                     handler.call(e, &mut ctx);
                 }
 
+                // Only a release over the pressed component counts as a click
+                let is_click = *component.is_active.borrow()
+                    && is_pointer_over(released_over.as_ref(), &component);
+
+                // Clicking a mixed-state checkbox resolves it to checked
+                if is_click
+                    && component.component_type == ComponentType::Checkbox
+                    && component.checkbox_indeterminate()
+                {
+                    component.set_checkbox_indeterminate(false);
+                    component.set_checkbox_checked(true);
+                }
+
                 if let Some(handler) = handlers.get_click() {
-                    if *component.is_active.borrow() {
+                    if is_click {
+                        observe_pointer(&component, "click", DispatchPhase::Bubble, event);
                         handler.call(e, &mut ctx);
                     }
                 }
+
+                // A submit button submits its form after its own click handler has run
+                if is_click && component.is_submit_button() && !ctx.is_default_prevented() {
+                    submit_enclosing_form(&component);
+                }
             }
             PointerEvent::Move(e) => {
                 if let Some(handler) = handlers.get_pointer_move() {
+                    observe_pointer(&component, "pointer_move", DispatchPhase::Bubble, event);
                     handler.call(e, &mut ctx);
                 }
+
+                if component.is_text_selectable() && ctx.has_pointer_capture() {
+                    if let (Some((anchor, _)), Some(focus)) =
+                        (component.text_selection(), text_index_at(&component, e.position))
+                    {
+                        component.set_text_selection(Some((anchor, focus)));
+                    }
+                }
             }
             PointerEvent::Enter(e) => {
                 if let Some(handler) = handlers.get_pointer_enter() {
+                    observe_pointer(&component, "pointer_enter", DispatchPhase::Bubble, event);
                     handler.call(e, &mut ctx);
                 }
             }
             PointerEvent::Leave(e) => {
                 if let Some(handler) = handlers.get_pointer_leave() {
+                    observe_pointer(&component, "pointer_leave", DispatchPhase::Bubble, event);
                     handler.call(e, &mut ctx);
                 }
             }
+            PointerEvent::Scroll(e) if component.component_type == ComponentType::NumberInput => {
+                // The wheel steps the value instead of scrolling, one step per notch
+                let lines = match e.delta {
+                    ScrollDelta::Line(lines) => lines,
+                    ScrollDelta::Pixel(_, dy) => dy,
+                };
+                if lines != 0.0 {
+                    step_number_input(&component, &handlers, lines.signum(), &mut ctx);
+                }
+                handled = Handled::Yes;
+                ctx.stop_propagation();
+                break;
+            }
             PointerEvent::Scroll(e) => {
                 let delta_y = match e.delta {
                     ScrollDelta::Line(lines) => (lines * 20.0) as f32,
@@ -247,6 +416,7 @@ fn dispatch_pointer_event(
                             .clamp(0.0, scroll_state.scroll_width.max(0.0));
                     }
                     component.set_scroll_state(new_state);
+                    component.mark_scroll_activity();
                     component.mark_dirty();
 
                     if let Some(parent) = component.parent.borrow().clone() {
@@ -274,6 +444,7 @@ fn dispatch_pointer_event(
                                 .clamp(0.0, container_scroll_state.scroll_width.max(0.0));
                         }
                         scroll_container.set_scroll_state(new_state);
+                        scroll_container.mark_scroll_activity();
                         scroll_container.mark_dirty();
 
                         if let Some(parent) = scroll_container.parent.borrow().clone() {
@@ -287,6 +458,7 @@ fn dispatch_pointer_event(
                 }
 
                 if let Some(handler) = handlers.get_scroll() {
+                    observe_pointer(&component, "scroll", DispatchPhase::Bubble, event);
                     handler.call(e, &mut ctx);
                 }
             }
@@ -305,10 +477,212 @@ fn dispatch_pointer_event(
     handled
 }
 
+/// Size of `component`'s laid-out box, or zero before layout
+fn layout_size(component: &Gc<Component>) -> Size {
+    component
+        .layout_node()
+        .and_then(|node| {
+            node.layout().map(|l| Size::new(l.size.width as f64, l.size.height as f64))
+        })
+        .unwrap_or(Size::ZERO)
+}
+
+/// Step a NumberInput's value by `steps` and report the new value to its `on_input` handler
+fn step_number_input(
+    component: &Gc<Component>,
+    handlers: &EventHandlers,
+    steps: f64,
+    ctx: &mut EventContext,
+) {
+    let Some(number_value) = component.step_number_input(steps) else {
+        return;
+    };
+    if let Some(handler) = handlers.get_input() {
+        let input = InputEvent {
+            value: number_value.to_string(),
+            number_value,
+            checked: false,
+            input_type: InputEventType::Number,
+        };
+        handler.call(&input, ctx);
+    }
+}
+
+/// Byte offset in a text component's content nearest to `position`, in window coordinates
+fn text_index_at(component: &Gc<Component>, position: Point) -> Option<usize> {
+    let local = window_to_local(component, position);
+    let user_data = component.user_data.borrow();
+    let ParleyLayoutWrapper(layout) = user_data.as_ref()?.downcast_ref::<ParleyLayoutWrapper>()?;
+    Some(parley::Cursor::from_point(layout, local.x as f32, local.y as f32).index())
+}
+
+fn find_long_press_target(target: &Gc<Component>) -> Option<Gc<Component>> {
+    let mut current = Some(Gc::clone(target));
+    while let Some(component) = current {
+        if !component.is_disabled() && component.event_handlers.borrow().get_long_press().is_some()
+        {
+            return Some(component);
+        }
+        current = component.parent.borrow().clone();
+    }
+    None
+}
+
+/// Fire `on_long_press` if the pending press has been held past the threshold at `now`.
+///
+/// A fired long press consumes the gesture, so the following release does not click.
+pub fn check_long_press(
+    app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
+    now: Instant,
+) -> Handled {
+    let Some(long_press) = app_state.long_press_state() else {
+        return Handled::No;
+    };
+    if !long_press.is_elapsed(now) {
+        return Handled::No;
+    }
+    app_state.set_long_press_state(None);
+
+    let root = app_state.root_component();
+    let Some(component) = find_component_by_id(&root, long_press.component_id) else {
+        return Handled::No;
+    };
+    let Some(handler) = component.event_handlers.borrow().get_long_press().cloned() else {
+        return Handled::No;
+    };
+
+    app_state.clear_pointer_capture();
+    app_state.set_needs_pointer_pass_update(true);
+
+    let capture_clone = app_state.pointer_capture_mut().clone();
+    let mut ctx = EventContext::new(Gc::clone(&component), app_state, capture_clone);
+    handler.call(&long_press.event, &mut ctx);
+    Handled::Yes
+}
+
+/// Report a pointer handler about to run to the installed dispatch observer
+fn observe_pointer(
+    component: &Gc<Component>,
+    handler: &'static str,
+    phase: DispatchPhase,
+    event: &PointerEvent,
+) {
+    notify_handler(component, handler, phase, || DispatchedEvent::Pointer(event.clone()));
+}
+
+/// Report a keyboard handler about to run to the installed dispatch observer
+fn observe_keyboard(
+    component: &Gc<Component>,
+    handler: &'static str,
+    phase: DispatchPhase,
+    event: &KeyboardEvent,
+) {
+    notify_handler(component, handler, phase, || DispatchedEvent::Keyboard(event.clone()));
+}
+
+/// The enabled components from the root down to `target`
+fn capture_path(target: &Gc<Component>) -> Vec<Gc<Component>> {
+    let mut path = Vec::new();
+    let mut current = Some(Gc::clone(target));
+    while let Some(component) = current {
+        current = component.parent.borrow().clone();
+        if !component.is_disabled() {
+            path.push(component);
+        }
+    }
+    path.reverse();
+    path
+}
+
+/// Run capture-phase pointer handlers from the root down to `target`.
+///
+/// Returns `Handled::Yes` if a handler stopped propagation, which also skips
+/// the bubbling phase.
+fn run_pointer_capture_phase(
+    app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
+    released_over: Option<&Gc<Component>>,
+    target: &Gc<Component>,
+    event: &PointerEvent,
+) -> Handled {
+    if !matches!(event, PointerEvent::Down(_) | PointerEvent::Up(_)) {
+        return Handled::No;
+    }
+
+    for component in capture_path(target) {
+        let capture_clone = app_state.pointer_capture_mut().clone();
+        let mut ctx = EventContext::new(Gc::clone(&component), app_state, capture_clone);
+
+        let handlers = component.event_handlers.borrow();
+        match event {
+            PointerEvent::Down(e) => {
+                if let Some(handler) = handlers.get_pointer_down_capture() {
+                    observe_pointer(
+                        &component,
+                        "pointer_down_capture",
+                        DispatchPhase::Capture,
+                        event,
+                    );
+                    handler.call(e, &mut ctx);
+                }
+            }
+            PointerEvent::Up(e) => {
+                if let Some(handler) = handlers.get_pointer_up_capture() {
+                    observe_pointer(
+                        &component,
+                        "pointer_up_capture",
+                        DispatchPhase::Capture,
+                        event,
+                    );
+                    handler.call(e, &mut ctx);
+                }
+
+                if let Some(handler) = handlers.get_click_capture() {
+                    if !ctx.is_handled()
+                        && *component.is_active.borrow()
+                        && is_pointer_over(released_over, &component)
+                    {
+                        observe_pointer(&component, "click_capture", DispatchPhase::Capture, event);
+                        handler.call(e, &mut ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if ctx.is_handled() {
+            return Handled::Yes;
+        }
+    }
+
+    Handled::No
+}
+
+/// Check whether `hit`, the component under the pointer, is `component` or one of its descendants
+fn is_pointer_over(hit: Option<&Gc<Component>>, component: &Gc<Component>) -> bool {
+    let mut current = hit.cloned();
+    while let Some(c) = current {
+        if Gc::ptr_eq(&c, component) {
+            return true;
+        }
+        current = c.parent.borrow().clone();
+    }
+    false
+}
+
 pub fn run_text_event_pass(
     app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
     event: &TextEvent,
 ) -> Handled {
+    let _span = tracing::debug_span!("text_event_pass").entered();
+
+    // App-wide shortcuts take precedence over the focused widget
+    if let TextEvent::Keyboard(key_event) = event {
+        app_state.shortcuts_mut().extend(take_pending_shortcuts());
+        if app_state.shortcuts().dispatch(key_event) == Handled::Yes {
+            return Handled::Yes;
+        }
+    }
+
     // Check focused first, then pending_focus (which might have been set by a recent click)
     let target = app_state
         .focused()
@@ -338,6 +712,25 @@ fn dispatch_text_event(
     target: &Gc<Component>,
     event: &TextEvent,
 ) -> Handled {
+    if let TextEvent::Keyboard(e) = event {
+        if e.state == KeyState::Down {
+            for component in capture_path(target) {
+                let Some(handler) =
+                    component.event_handlers.borrow().get_key_down_capture().cloned()
+                else {
+                    continue;
+                };
+                let capture_clone = app_state.pointer_capture_mut().clone();
+                let mut ctx = EventContext::new(Gc::clone(&component), app_state, capture_clone);
+                observe_keyboard(&component, "key_down_capture", DispatchPhase::Capture, e);
+                handler.call(e, &mut ctx);
+                if ctx.is_handled() {
+                    return Handled::Yes;
+                }
+            }
+        }
+    }
+
     let mut current = Some(Gc::clone(target));
     let mut handled = Handled::No;
 
@@ -353,36 +746,52 @@ fn dispatch_text_event(
         let handlers = component.event_handlers.borrow();
         match event {
             TextEvent::Keyboard(e) => {
-                let is_text_input = matches!(component.component_type, ComponentType::TextInput);
-
-                if is_text_input {
-                    handle_text_input_keyboard_event(&component, e, &mut ctx);
-                }
-
-                if !ctx.is_handled() {
-                    match e.state {
-                        KeyState::Down => {
-                            if let Some(handler) = handlers.get_key_down() {
-                                handler.call(e, &mut ctx);
-                            }
+                match e.state {
+                    KeyState::Down => {
+                        if let Some(handler) = handlers.get_key_down() {
+                            observe_keyboard(&component, "key_down", DispatchPhase::Bubble, e);
+                            handler.call(e, &mut ctx);
                         }
-                        KeyState::Up => {
-                            if let Some(handler) = handlers.get_key_up() {
-                                handler.call(e, &mut ctx);
-                            }
+                    }
+                    KeyState::Up => {
+                        if let Some(handler) = handlers.get_key_up() {
+                            observe_keyboard(&component, "key_up", DispatchPhase::Bubble, e);
+                            handler.call(e, &mut ctx);
                         }
                     }
                 }
+
+                // Editing and activation run after the handlers so they can prevent them
+                let is_text_input = matches!(
+                    component.component_type,
+                    ComponentType::TextInput | ComponentType::TextArea
+                );
+                if is_text_input && !ctx.is_default_prevented() {
+                    handle_text_input_keyboard_event(&component, e, &mut ctx);
+                }
+                if Gc::ptr_eq(&component, target) && !ctx.is_default_prevented() {
+                    handle_keyboard_activation(&component, &handlers, e, &mut ctx);
+                }
+                if component.is_text_selectable() && !ctx.is_default_prevented() {
+                    handle_text_copy(&component, e, &mut ctx);
+                }
             }
             TextEvent::Ime(e) => {
-                if matches!(component.component_type, ComponentType::TextInput) {
+                if matches!(
+                    component.component_type,
+                    ComponentType::TextInput | ComponentType::TextArea
+                ) {
                     handle_ime_event(&component, e, &mut ctx);
                 }
             }
             TextEvent::Paste(text) => {
-                if matches!(component.component_type, ComponentType::TextInput) {
+                if matches!(
+                    component.component_type,
+                    ComponentType::TextInput | ComponentType::TextArea
+                ) {
                     if let Some(editor) = component.text_editor() {
-                        editor.editor().insert_text(text);
+                        let text = insertable_text(&component, text);
+                        editor.editor().edit(EditKind::Other, |e| e.insert_text(&text));
                         component.reset_cursor_blink();
                         component.mark_dirty();
                         update_text_input_value(&component);
@@ -404,6 +813,15 @@ fn dispatch_text_event(
     handled
 }
 
+/// `text` as `component` accepts it: single-line inputs drop line breaks
+fn insertable_text<'a>(component: &Gc<Component>, text: &'a str) -> Cow<'a, str> {
+    if component.component_type == ComponentType::TextArea {
+        text.into()
+    } else {
+        single_line_text(text)
+    }
+}
+
 fn handle_ime_event(
     component: &Gc<Component>,
     event: &crate::event::types::ImeEvent,
@@ -422,7 +840,8 @@ fn handle_ime_event(
                 ctx.stop_propagation();
             }
             crate::event::types::ImeEvent::Commit(text) => {
-                editor.editor().insert_text(text);
+                let text = insertable_text(component, text);
+                editor.editor().edit(EditKind::Other, |e| e.insert_text(&text));
                 component.reset_cursor_blink();
                 component.mark_dirty();
                 update_text_input_value(component);
@@ -454,32 +873,57 @@ fn handle_text_input_keyboard_event(
             return;
         }
         let text_editor = editor.editor();
+        let is_multiline = component.component_type == ComponentType::TextArea;
+
+        let is_shortcut = event.modifiers.ctrl || event.modifiers.logo;
 
         match &event.key {
+            // Ctrl+Z undoes, Ctrl+Shift+Z and Ctrl+Y redo
+            Key::Character(ch)
+                if is_shortcut
+                    && (ch.eq_ignore_ascii_case("z") || ch.eq_ignore_ascii_case("y")) =>
+            {
+                let is_redo = ch.eq_ignore_ascii_case("y") || event.modifiers.shift;
+                let changed = if is_redo { text_editor.redo() } else { text_editor.undo() };
+                if changed {
+                    component.reset_cursor_blink();
+                    component.mark_dirty();
+                    update_text_input_value(component);
+                }
+                ctx.stop_propagation();
+            }
             Key::Character(ch)
                 if !event.modifiers.alt && !event.modifiers.ctrl && !event.modifiers.logo =>
             {
-                text_editor.insert_text(ch);
+                text_editor.edit(EditKind::Typing, |e| e.insert_text(ch));
                 component.reset_cursor_blink();
                 component.mark_dirty();
                 update_text_input_value(component);
                 ctx.stop_propagation();
             }
             Key::Named(NamedKey::Backspace) => {
-                text_editor.backspace();
+                text_editor.edit(EditKind::Deleting, |e| e.backspace());
                 component.reset_cursor_blink();
                 component.mark_dirty();
                 update_text_input_value(component);
                 ctx.stop_propagation();
             }
             Key::Named(NamedKey::Delete) => {
-                text_editor.delete();
+                text_editor.edit(EditKind::Deleting, |e| e.delete());
                 component.reset_cursor_blink();
                 component.mark_dirty();
                 update_text_input_value(component);
                 ctx.stop_propagation();
             }
             Key::Named(NamedKey::Enter) => {
+                if is_multiline {
+                    text_editor.edit(EditKind::Other, |e| e.insert_text("\n"));
+                    component.reset_cursor_blink();
+                    component.mark_dirty();
+                    update_text_input_value(component);
+                } else {
+                    submit_enclosing_form(component);
+                }
                 ctx.stop_propagation();
             }
             Key::Named(NamedKey::ArrowLeft) => {
@@ -494,6 +938,18 @@ fn handle_text_input_keyboard_event(
                 component.mark_dirty();
                 ctx.stop_propagation();
             }
+            Key::Named(NamedKey::ArrowUp) if is_multiline => {
+                text_editor.move_line(-1);
+                component.reset_cursor_blink();
+                component.mark_dirty();
+                ctx.stop_propagation();
+            }
+            Key::Named(NamedKey::ArrowDown) if is_multiline => {
+                text_editor.move_line(1);
+                component.reset_cursor_blink();
+                component.mark_dirty();
+                ctx.stop_propagation();
+            }
             Key::Named(NamedKey::ArrowUp) => {
                 text_editor.move_to_start();
                 component.reset_cursor_blink();
@@ -526,10 +982,105 @@ fn handle_text_input_keyboard_event(
     }
 }
 
+/// Activate a focused control the way a click would: Space presses a button, toggles a
+/// checkbox or selects a radio, and Enter presses a button
+fn handle_keyboard_activation(
+    component: &Gc<Component>,
+    handlers: &EventHandlers,
+    event: &KeyboardEvent,
+    ctx: &mut EventContext,
+) {
+    if event.state != KeyState::Down || event.repeat {
+        return;
+    }
+    let is_space = event.key == Key::Named(NamedKey::Space);
+    let is_enter = event.key == Key::Named(NamedKey::Enter);
+
+    match component.component_type {
+        ComponentType::Button if is_space || is_enter => {
+            if let Some(handler) = handlers.get_click() {
+                observe_keyboard(component, "click", DispatchPhase::Bubble, event);
+                handler.call(&keyboard_click_event(component, event.modifiers), ctx);
+            }
+            if component.is_submit_button() && !ctx.is_default_prevented() {
+                submit_enclosing_form(component);
+            }
+            ctx.stop_propagation();
+        }
+        ComponentType::Checkbox if is_space => {
+            // A mixed checkbox resolves to checked, like a click
+            let checked = component.checkbox_indeterminate() || !component.checkbox_checked();
+            component.set_checkbox_indeterminate(false);
+            component.set_checkbox_checked(checked);
+            if let Some(handler) = handlers.get_change() {
+                let change = InputEvent {
+                    value: String::new(),
+                    number_value: 0.0,
+                    checked,
+                    input_type: InputEventType::Checkbox,
+                };
+                handler.call(&change, ctx);
+            }
+            ctx.stop_propagation();
+        }
+        ComponentType::Radio if is_space => {
+            if !component.radio_checked() {
+                component.set_radio_checked(true);
+                if let Some(handler) = handlers.get_change() {
+                    let change = InputEvent {
+                        value: component.radio_value(),
+                        number_value: 0.0,
+                        checked: true,
+                        input_type: InputEventType::Radio,
+                    };
+                    handler.call(&change, ctx);
+                }
+            }
+            ctx.stop_propagation();
+        }
+        _ => {}
+    }
+}
+
+/// Ctrl+C copies a selectable text component's selection to the in-app copy buffer
+fn handle_text_copy(component: &Gc<Component>, event: &KeyboardEvent, ctx: &mut EventContext) {
+    let is_shortcut = event.modifiers.ctrl || event.modifiers.logo;
+    let is_copy = matches!(&event.key, Key::Character(ch) if ch.eq_ignore_ascii_case("c"));
+    if event.state != KeyState::Down || !is_shortcut || !is_copy {
+        return;
+    }
+    if let Some(text) = component.selected_text() {
+        ctx.set_copied_text(text);
+        ctx.stop_propagation();
+    }
+}
+
+/// A primary click at the center of `component`, for activations that come from the keyboard
+fn keyboard_click_event(component: &Gc<Component>, modifiers: Modifiers) -> PointerButtonEvent {
+    let origin = Point::ZERO - window_to_local(component, Point::ZERO).to_vec2();
+    let size = component
+        .layout_node
+        .borrow()
+        .as_ref()
+        .and_then(|n| n.layout_result)
+        .map(|layout| Vec2::new(layout.size.width as f64, layout.size.height as f64))
+        .unwrap_or(Vec2::ZERO);
+    PointerButtonEvent {
+        button: PointerButton::Primary,
+        position: origin + size / 2.0,
+        click_count: 1,
+        modifiers,
+    }
+}
+
 fn update_text_input_value(component: &Gc<Component>) {
     if let Some(editor) = component.text_editor() {
         let content = editor.editor().content();
-        component.set_text_input_value(content);
+        component.set_text_input_value(content.clone());
+
+        if let Some(binding) = component.text_binding() {
+            binding.set(content);
+        }
     }
 }
 
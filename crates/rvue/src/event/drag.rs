@@ -0,0 +1,177 @@
+use crate::component::Component;
+use crate::event::context::EventContext;
+use crate::event::dispatch::{find_component_by_id, Handled};
+use crate::event::hit_test::hit_test_with_stylesheet;
+use crate::event::types::{DragEvent, Modifiers, PointerButton, PointerButtonEvent, PointerEvent};
+use rudo_gc::Gc;
+use vello::kurbo::Point;
+
+/// How far the pointer must move from the press before a drag starts
+pub const DRAG_THRESHOLD: f64 = 4.0;
+
+/// State for tracking a drag from a component with `on_drag_start`
+#[derive(Clone, Copy, Debug)]
+pub struct DragState {
+    /// The component being dragged
+    pub source_id: u64,
+    /// Position where the pointer was pressed
+    pub start_position: Point,
+    /// Whether the pointer has moved far enough to start the drag
+    pub is_dragging: bool,
+}
+
+impl DragState {
+    /// Create a pending drag for a press at `start_position`
+    pub fn new(source_id: u64, start_position: Point) -> Self {
+        Self { source_id, start_position, is_dragging: false }
+    }
+
+    fn event(&self, position: Point, modifiers: Modifiers) -> DragEvent {
+        DragEvent { position, start_position: self.start_position, modifiers }
+    }
+}
+
+/// Start tracking a possible drag when the primary button is pressed on a draggable component.
+///
+/// The pointer is only captured for the source once the drag starts, so until then a
+/// clickable inside the source keeps the press and still gets its click.
+pub fn begin_drag_tracking(
+    app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
+    target: &Gc<Component>,
+    event: &PointerButtonEvent,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    let Some(source) =
+        find_handler_ancestor(target, |c| c.event_handlers.borrow().get_drag_start().is_some())
+    else {
+        return;
+    };
+
+    app_state.set_drag_state(Some(DragState::new(source.id, event.position)));
+    app_state.set_drag_payload(None);
+}
+
+/// Route pointer events to the drag source and the drop target under the pointer.
+///
+/// Returns `None` when no drag is in progress so the event is dispatched normally.
+pub fn run_drag_pass(
+    app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
+    event: &PointerEvent,
+) -> Option<Handled> {
+    let mut drag = app_state.drag_state()?;
+    let root = app_state.root_component();
+    let Some(source) = find_component_by_id(&root, drag.source_id) else {
+        app_state.set_drag_state(None);
+        app_state.set_drag_payload(None);
+        return None;
+    };
+
+    match event {
+        PointerEvent::Move(e) => {
+            if !drag.is_dragging {
+                if (e.position - drag.start_position).hypot() <= DRAG_THRESHOLD {
+                    return None;
+                }
+                drag.is_dragging = true;
+                app_state.set_drag_state(Some(drag));
+                app_state.set_long_press_state(None);
+                app_state.capture_pointer(Gc::clone(&source));
+                app_state.set_needs_pointer_pass_update(true);
+                call_drag_handler(app_state, &source, &drag.event(e.position, e.modifiers), |h| {
+                    h.on_drag_start.clone()
+                });
+            }
+
+            let drag_event = drag.event(e.position, e.modifiers);
+            call_drag_handler(app_state, &source, &drag_event, |h| h.on_drag.clone());
+
+            if let Some(over) = find_drop_target(app_state, &source, e.position, |c| {
+                c.event_handlers.borrow().get_drag_over().is_some()
+            }) {
+                call_drag_handler(app_state, &over, &drag_event, |h| h.on_drag_over.clone());
+            }
+            Some(Handled::Yes)
+        }
+        PointerEvent::Up(e) => {
+            app_state.set_drag_state(None);
+            if !drag.is_dragging {
+                return None;
+            }
+
+            let drag_event = drag.event(e.position, e.modifiers);
+            if let Some(target) = find_drop_target(app_state, &source, e.position, |c| {
+                c.event_handlers.borrow().get_drop().is_some()
+            }) {
+                call_drag_handler(app_state, &target, &drag_event, |h| h.on_drop.clone());
+            }
+            finish_drag(app_state, &source, &drag_event);
+            Some(Handled::Yes)
+        }
+        PointerEvent::Cancel(_) => {
+            app_state.set_drag_state(None);
+            if drag.is_dragging {
+                let position = app_state.last_pointer_pos().unwrap_or(drag.start_position);
+                let drag_event = drag.event(position, Modifiers::default());
+                finish_drag(app_state, &source, &drag_event);
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn finish_drag(
+    app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
+    source: &Gc<Component>,
+    drag_event: &DragEvent,
+) {
+    call_drag_handler(app_state, source, drag_event, |h| h.on_drag_end.clone());
+    app_state.set_drag_payload(None);
+    app_state.clear_pointer_capture();
+    app_state.set_needs_pointer_pass_update(true);
+}
+
+fn call_drag_handler(
+    app_state: &mut (impl crate::app::AppStateLike + crate::event::context::EventContextOps),
+    component: &Gc<Component>,
+    event: &DragEvent,
+    select: impl Fn(
+        &crate::event::handler::EventHandlers,
+    ) -> Option<crate::event::handler::EventHandler<DragEvent>>,
+) {
+    let Some(handler) = select(&component.event_handlers.borrow()) else {
+        return;
+    };
+    let capture_clone = app_state.pointer_capture_mut().clone();
+    let mut ctx = EventContext::new(Gc::clone(component), app_state, capture_clone);
+    handler.call(event, &mut ctx);
+}
+
+/// Find the component under `position` that accepts the drop, ignoring the drag source itself.
+fn find_drop_target(
+    app_state: &impl crate::app::AppStateLike,
+    source: &Gc<Component>,
+    position: Point,
+    accepts: impl Fn(&Gc<Component>) -> bool,
+) -> Option<Gc<Component>> {
+    let hit =
+        hit_test_with_stylesheet(&app_state.root_component(), position, app_state.stylesheet())?;
+    find_handler_ancestor(&hit, |c| !Gc::ptr_eq(c, source) && accepts(c))
+}
+
+fn find_handler_ancestor(
+    target: &Gc<Component>,
+    matches: impl Fn(&Gc<Component>) -> bool,
+) -> Option<Gc<Component>> {
+    let mut current = Some(Gc::clone(target));
+    while let Some(component) = current {
+        if !component.is_disabled() && matches(&component) {
+            return Some(component);
+        }
+        current = component.parent.borrow().clone();
+    }
+    None
+}
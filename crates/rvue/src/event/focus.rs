@@ -1,5 +1,29 @@
-use crate::component::Component;
+use crate::component::{Component, ComponentId};
 use rudo_gc::Gc;
+use std::cell::Cell;
+
+thread_local! {
+    /// Focus change requested through [`Component::focus`] or [`Component::blur`],
+    /// applied by the next focus update pass.
+    static FOCUS_REQUEST: Cell<Option<FocusRequest>> = const { Cell::new(None) };
+}
+
+/// A pending imperative focus change, keyed by component id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FocusRequest {
+    Focus(ComponentId),
+    Blur(ComponentId),
+}
+
+/// Records `request`, replacing any earlier request that has not been applied yet.
+pub(crate) fn request_focus_change(request: FocusRequest) {
+    FOCUS_REQUEST.with(|cell| cell.set(Some(request)));
+}
+
+/// Takes the pending imperative focus change, if any.
+pub(crate) fn take_focus_request() -> Option<FocusRequest> {
+    FOCUS_REQUEST.with(|cell| cell.take())
+}
 
 pub fn find_next_focusable(
     app_state: &impl crate::app::AppStateLike,
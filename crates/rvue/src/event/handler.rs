@@ -1,11 +1,14 @@
 use crate::event::context::EventContext;
 use crate::event::status::{FocusEvent, InputEvent};
 use crate::event::types::{
-    KeyboardEvent, PointerButtonEvent, PointerInfo, PointerMoveEvent, PointerScrollEvent,
+    DragEvent, KeyboardEvent, PointerButtonEvent, PointerInfo, PointerMoveEvent, PointerScrollEvent,
 };
+use crate::gc::trace_opaque;
 use rudo_gc::Trace;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::time::{Duration, Instant};
+use vello::kurbo::{Size, Vec2};
 
 enum DynHandler {
     ZeroArg(Box<dyn Fn()>),
@@ -16,6 +19,7 @@ enum DynHandler {
     OneArgPointerMove(Box<dyn Fn(&PointerMoveEvent)>),
     OneArgPointerInfo(Box<dyn Fn(&PointerInfo)>),
     OneArgPointerScroll(Box<dyn Fn(&PointerScrollEvent)>),
+    OneArgDrag(Box<dyn Fn(&DragEvent)>),
     TwoArgPointerButton(Box<dyn Fn(&PointerButtonEvent, &mut EventContext)>),
     TwoArgInput(Box<dyn Fn(&InputEvent, &mut EventContext)>),
     TwoArgKeyboard(Box<dyn Fn(&KeyboardEvent, &mut EventContext)>),
@@ -23,6 +27,32 @@ enum DynHandler {
     TwoArgPointerMove(Box<dyn Fn(&PointerMoveEvent, &mut EventContext)>),
     TwoArgPointerInfo(Box<dyn Fn(&PointerInfo, &mut EventContext)>),
     TwoArgPointerScroll(Box<dyn Fn(&PointerScrollEvent, &mut EventContext)>),
+    TwoArgDrag(Box<dyn Fn(&DragEvent, &mut EventContext)>),
+}
+
+// Handlers capture signals and components, which are only reachable through the closures
+unsafe impl Trace for DynHandler {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        match self {
+            DynHandler::ZeroArg(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgPointerButton(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgInput(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgKeyboard(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgFocus(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgPointerMove(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgPointerInfo(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgPointerScroll(f) => trace_opaque(&**f, visitor),
+            DynHandler::OneArgDrag(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgPointerButton(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgInput(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgKeyboard(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgFocus(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgPointerMove(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgPointerInfo(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgPointerScroll(f) => trace_opaque(&**f, visitor),
+            DynHandler::TwoArgDrag(f) => trace_opaque(&**f, visitor),
+        }
+    }
 }
 
 pub enum AnyEventHandler {
@@ -59,7 +89,11 @@ impl<E> Clone for EventHandler<E> {
 }
 
 unsafe impl<E: 'static> Trace for EventHandler<E> {
-    fn trace(&self, _visitor: &mut impl rudo_gc::Visitor) {}
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        if let Some(handler) = self.inner.borrow().as_ref() {
+            handler.trace(visitor);
+        }
+    }
 }
 
 impl EventHandler<PointerButtonEvent> {
@@ -286,6 +320,38 @@ impl EventHandler<PointerScrollEvent> {
     }
 }
 
+impl EventHandler<DragEvent> {
+    pub fn new_0arg<F>(handler: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        EventHandler {
+            inner: Rc::new(RefCell::new(Some(DynHandler::ZeroArg(Box::new(handler))))),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn new_1arg<F>(handler: F) -> Self
+    where
+        F: Fn(&DragEvent) + 'static,
+    {
+        EventHandler {
+            inner: Rc::new(RefCell::new(Some(DynHandler::OneArgDrag(Box::new(handler))))),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&DragEvent, &mut EventContext) + 'static,
+    {
+        EventHandler {
+            inner: Rc::new(RefCell::new(Some(DynHandler::TwoArgDrag(Box::new(handler))))),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+}
+
 impl EventHandler<PointerButtonEvent> {
     pub fn call(&self, event: &PointerButtonEvent, ctx: &mut EventContext) {
         if let Some(handler) = self.inner.borrow().as_ref() {
@@ -377,6 +443,53 @@ impl EventHandler<PointerScrollEvent> {
     }
 }
 
+impl EventHandler<DragEvent> {
+    pub fn call(&self, event: &DragEvent, ctx: &mut EventContext) {
+        if let Some(handler) = self.inner.borrow().as_ref() {
+            match handler {
+                DynHandler::ZeroArg(f) => f(),
+                DynHandler::OneArgDrag(f) => f(event),
+                DynHandler::TwoArgDrag(f) => f(event, ctx),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Handler for a value derived from layout or rendering, such as `Component::on_resize`
+///
+/// The handler only hears about a value when it differs from the last one it was called with.
+#[derive(Clone)]
+pub struct ChangeHandler<T> {
+    handler: Rc<dyn Fn(T)>,
+    last: Rc<Cell<Option<T>>>,
+}
+
+impl<T: Copy + PartialEq + 'static> ChangeHandler<T> {
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(T) + 'static,
+    {
+        Self { handler: Rc::new(handler), last: Rc::new(Cell::new(None)) }
+    }
+
+    /// Call the handler if `value` differs from the last value it was called with
+    pub fn notify(&self, value: T) -> bool {
+        if self.last.get() == Some(value) {
+            return false;
+        }
+        self.last.set(Some(value));
+        (self.handler)(value);
+        true
+    }
+}
+
+unsafe impl<T> Trace for ChangeHandler<T> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        trace_opaque(&*self.handler, visitor);
+    }
+}
+
 #[derive(Default, Clone)]
 pub struct EventHandlers {
     pub on_pointer_down: Option<EventHandler<PointerButtonEvent>>,
@@ -385,6 +498,8 @@ pub struct EventHandlers {
     pub on_pointer_enter: Option<EventHandler<PointerInfo>>,
     pub on_pointer_leave: Option<EventHandler<PointerInfo>>,
     pub on_click: Option<EventHandler<PointerButtonEvent>>,
+    pub on_context_menu: Option<EventHandler<PointerButtonEvent>>,
+    pub on_long_press: Option<EventHandler<PointerButtonEvent>>,
     pub on_key_down: Option<EventHandler<KeyboardEvent>>,
     pub on_key_up: Option<EventHandler<KeyboardEvent>>,
     pub on_focus: Option<EventHandler<FocusEvent>>,
@@ -392,11 +507,49 @@ pub struct EventHandlers {
     pub on_input: Option<EventHandler<InputEvent>>,
     pub on_change: Option<EventHandler<InputEvent>>,
     pub on_scroll: Option<EventHandler<PointerScrollEvent>>,
+    pub on_drag_start: Option<EventHandler<DragEvent>>,
+    pub on_drag: Option<EventHandler<DragEvent>>,
+    pub on_drag_end: Option<EventHandler<DragEvent>>,
+    pub on_drag_over: Option<EventHandler<DragEvent>>,
+    pub on_drop: Option<EventHandler<DragEvent>>,
+    pub on_click_capture: Option<EventHandler<PointerButtonEvent>>,
+    pub on_pointer_down_capture: Option<EventHandler<PointerButtonEvent>>,
+    pub on_pointer_up_capture: Option<EventHandler<PointerButtonEvent>>,
+    pub on_key_down_capture: Option<EventHandler<KeyboardEvent>>,
+    pub on_resize: Option<ChangeHandler<Size>>,
+    pub on_visibility_change: Option<ChangeHandler<bool>>,
+    pub on_scroll_offset_change: Option<ChangeHandler<Vec2>>,
 }
 
 unsafe impl Trace for EventHandlers {
-    fn trace(&self, _visitor: &mut impl rudo_gc::Visitor) {
-        // EventHandlers contain EventHandler which don't contain GC pointers
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.on_pointer_down.trace(visitor);
+        self.on_pointer_up.trace(visitor);
+        self.on_pointer_move.trace(visitor);
+        self.on_pointer_enter.trace(visitor);
+        self.on_pointer_leave.trace(visitor);
+        self.on_click.trace(visitor);
+        self.on_context_menu.trace(visitor);
+        self.on_long_press.trace(visitor);
+        self.on_key_down.trace(visitor);
+        self.on_key_up.trace(visitor);
+        self.on_focus.trace(visitor);
+        self.on_blur.trace(visitor);
+        self.on_input.trace(visitor);
+        self.on_change.trace(visitor);
+        self.on_scroll.trace(visitor);
+        self.on_drag_start.trace(visitor);
+        self.on_drag.trace(visitor);
+        self.on_drag_end.trace(visitor);
+        self.on_drag_over.trace(visitor);
+        self.on_drop.trace(visitor);
+        self.on_click_capture.trace(visitor);
+        self.on_pointer_down_capture.trace(visitor);
+        self.on_pointer_up_capture.trace(visitor);
+        self.on_key_down_capture.trace(visitor);
+        self.on_resize.trace(visitor);
+        self.on_visibility_change.trace(visitor);
+        self.on_scroll_offset_change.trace(visitor);
     }
 }
 
@@ -425,6 +578,14 @@ impl EventHandlers {
         self.on_click.as_ref()
     }
 
+    pub fn get_context_menu(&self) -> Option<&EventHandler<PointerButtonEvent>> {
+        self.on_context_menu.as_ref()
+    }
+
+    pub fn get_long_press(&self) -> Option<&EventHandler<PointerButtonEvent>> {
+        self.on_long_press.as_ref()
+    }
+
     pub fn get_key_down(&self) -> Option<&EventHandler<KeyboardEvent>> {
         self.on_key_down.as_ref()
     }
@@ -453,6 +614,42 @@ impl EventHandlers {
         self.on_scroll.as_ref()
     }
 
+    pub fn get_drag_start(&self) -> Option<&EventHandler<DragEvent>> {
+        self.on_drag_start.as_ref()
+    }
+
+    pub fn get_drag(&self) -> Option<&EventHandler<DragEvent>> {
+        self.on_drag.as_ref()
+    }
+
+    pub fn get_drag_end(&self) -> Option<&EventHandler<DragEvent>> {
+        self.on_drag_end.as_ref()
+    }
+
+    pub fn get_drag_over(&self) -> Option<&EventHandler<DragEvent>> {
+        self.on_drag_over.as_ref()
+    }
+
+    pub fn get_drop(&self) -> Option<&EventHandler<DragEvent>> {
+        self.on_drop.as_ref()
+    }
+
+    pub fn get_click_capture(&self) -> Option<&EventHandler<PointerButtonEvent>> {
+        self.on_click_capture.as_ref()
+    }
+
+    pub fn get_pointer_down_capture(&self) -> Option<&EventHandler<PointerButtonEvent>> {
+        self.on_pointer_down_capture.as_ref()
+    }
+
+    pub fn get_pointer_up_capture(&self) -> Option<&EventHandler<PointerButtonEvent>> {
+        self.on_pointer_up_capture.as_ref()
+    }
+
+    pub fn get_key_down_capture(&self) -> Option<&EventHandler<KeyboardEvent>> {
+        self.on_key_down_capture.as_ref()
+    }
+
     pub fn set_handler<E: 'static>(&mut self, handler: EventHandler<E>) {
         let type_id = std::any::TypeId::of::<E>();
         if type_id == std::any::TypeId::of::<PointerButtonEvent>() {
@@ -506,7 +703,7 @@ pub struct ScrollDragState {
     pub start_mouse_pos: f64,
     /// Scroll offset at drag start
     pub start_scroll_offset: f64,
-    /// The scrollable content length
+    /// How far the content can scroll (content length minus container length)
     pub scroll_content_length: f64,
     /// The container (portal) size
     pub container_length: f64,
@@ -550,21 +747,55 @@ impl ScrollDragState {
     }
 
     /// Calculate new scroll offset based on current mouse position
+    ///
+    /// Uses the same thumb geometry as the rendered scrollbar, so the thumb stays under the
+    /// cursor: moving it across the free part of the track scrolls through the whole content.
     pub fn calculate_new_offset(&self, current_mouse_pos: f64) -> f64 {
         let delta = current_mouse_pos - self.start_mouse_pos;
+        let scrollable = self.scroll_content_length.max(0.0);
         let track_length = self.container_length;
 
-        // Calculate thumb dimensions
-        let thumb_ratio = (self.container_length / self.scroll_content_length).max(0.0001);
-        const MIN_THUMB_LENGTH: f64 = 20.0;
-        let thumb_length = (thumb_ratio * track_length).max(MIN_THUMB_LENGTH);
+        let thumb_length = crate::widgets::scroll_bar::thumb_length_for(track_length, scrollable);
 
         // Convert mouse delta to scroll delta
         // The thumb can move within (track_length - thumb_length)
         let available_track = (track_length - thumb_length).max(1.0);
-        let scroll_per_pixel = self.scroll_content_length / available_track;
+        let scroll_per_pixel = scrollable / available_track;
+
+        (self.start_scroll_offset + (delta * scroll_per_pixel)).clamp(0.0, scrollable)
+    }
+}
+
+/// How long the pointer must be held before `on_long_press` fires
+pub const LONG_PRESS_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// How far the pointer may move before a pending long press is cancelled
+pub const LONG_PRESS_SLOP: f64 = 5.0;
+
+/// State for tracking a pending long press
+#[derive(Clone, Debug)]
+pub struct LongPressState {
+    /// The component with the `on_long_press` handler
+    pub component_id: u64,
+    /// The press that started the gesture
+    pub event: PointerButtonEvent,
+    /// When the press started
+    pub pressed_at: Instant,
+}
+
+impl LongPressState {
+    /// Create a new long press state starting at `pressed_at`
+    pub fn new(component_id: u64, event: PointerButtonEvent, pressed_at: Instant) -> Self {
+        Self { component_id, event, pressed_at }
+    }
+
+    /// Check whether the pointer moved too far from the press position
+    pub fn exceeds_slop(&self, position: vello::kurbo::Point) -> bool {
+        (position - self.event.position).hypot() > LONG_PRESS_SLOP
+    }
 
-        (self.start_scroll_offset + (delta * scroll_per_pixel))
-            .clamp(0.0, self.scroll_content_length.max(0.0))
+    /// Check whether the press has been held long enough at `now`
+    pub fn is_elapsed(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.pressed_at) >= LONG_PRESS_THRESHOLD
     }
 }
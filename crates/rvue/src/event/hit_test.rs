@@ -1,11 +1,26 @@
-use crate::component::Component;
-use crate::style::get_inline_styles;
+use crate::component::{Component, ComponentType};
+use crate::event::handler::ScrollDragState;
+use crate::render::widget::{children_in_paint_order, get_styles, overflow_clip_rect};
+use crate::style::{get_inline_styles, Stylesheet};
+use crate::widgets::scroll_bar::ScrollBar;
 use rudo_gc::Gc;
 use rvue_style::properties::Overflow;
-use vello::kurbo::{Point, Rect, Size};
+use vello::kurbo::{Point, Rect, RoundedRect, Shape, Size};
 
 pub fn hit_test(root: &Gc<Component>, point: Point) -> Option<Gc<Component>> {
-    hit_test_recursive(root, point, Point::new(0.0, 0.0))
+    hit_test_with_stylesheet(root, point, None)
+}
+
+/// Hit test with styles resolved against `stylesheet`, as they are when painted
+///
+/// Stylesheet rules can change which sibling is on top (`z-index`), what a container clips
+/// and how round its corners are, so the app passes the stylesheet it renders with.
+pub fn hit_test_with_stylesheet(
+    root: &Gc<Component>,
+    point: Point,
+    stylesheet: Option<&Stylesheet>,
+) -> Option<Gc<Component>> {
+    hit_test_recursive(root, point, Point::new(0.0, 0.0), stylesheet)
 }
 
 fn get_overflow_for_component(component: &Gc<Component>) -> (Overflow, Overflow) {
@@ -15,10 +30,136 @@ fn get_overflow_for_component(component: &Gc<Component>) -> (Overflow, Overflow)
     (overflow_x, overflow_y)
 }
 
+/// Convert a window position into `component`'s local coordinate space
+///
+/// Walks up the parent chain the way [`hit_test`] walks down it: every layout origin along the
+/// path is subtracted, and the scroll offset of each clipping ancestor is added back.
+pub fn window_to_local(component: &Gc<Component>, window_pos: Point) -> Point {
+    let mut local = window_pos;
+    let mut current = Some(Gc::clone(component));
+
+    while let Some(node) = current {
+        if !Gc::ptr_eq(&node, component) {
+            let (overflow_x, overflow_y) = get_overflow_for_component(&node);
+            if overflow_x.should_clip() || overflow_y.should_clip() {
+                let scroll_state = node.scroll_state();
+                local.x += scroll_state.scroll_offset_x as f64;
+                local.y += scroll_state.scroll_offset_y as f64;
+            }
+        }
+
+        let layout = node.layout_node.borrow().as_ref().and_then(|n| n.layout_result);
+        if let Some(layout) = layout {
+            local.x -= layout.location.x as f64;
+            local.y -= layout.location.y as f64;
+        }
+
+        current = node.parent.borrow().clone();
+    }
+
+    local
+}
+
+/// Find the scrollbar thumb under `point` and start dragging it
+///
+/// Thumbs are laid out exactly as `render_flex_background` draws them. Nested scroll
+/// containers are checked before their ancestors, so the innermost thumb wins.
+pub fn hit_test_scrollbar_thumb(
+    root: &Gc<Component>,
+    point: Point,
+    stylesheet: Option<&Stylesheet>,
+) -> Option<ScrollDragState> {
+    scrollbar_thumb_recursive(root, point, Point::ZERO, stylesheet)
+}
+
+fn scrollbar_thumb_recursive(
+    component: &Gc<Component>,
+    point: Point,
+    global_offset: Point,
+    stylesheet: Option<&Stylesheet>,
+) -> Option<ScrollDragState> {
+    let layout = component.layout_node.borrow().as_ref().and_then(|node| node.layout_result)?;
+    let origin = Point::new(
+        global_offset.x + layout.location.x as f64,
+        global_offset.y + layout.location.y as f64,
+    );
+    let (width, height) = (layout.size.width as f64, layout.size.height as f64);
+
+    let styles = get_styles(component, stylesheet);
+    let overflow_x = styles.overflow_x.unwrap_or(Overflow::Visible);
+    let overflow_y = styles.overflow_y.unwrap_or(Overflow::Visible);
+    let scroll_state = component.scroll_state();
+    let (scroll_x, scroll_y) = if overflow_x.should_clip() || overflow_y.should_clip() {
+        (scroll_state.scroll_offset_x as f64, scroll_state.scroll_offset_y as f64)
+    } else {
+        (0.0, 0.0)
+    };
+
+    let child_offset = Point::new(origin.x - scroll_x, origin.y - scroll_y);
+    for child in children_in_paint_order(component, stylesheet).iter().rev() {
+        if let Some(drag) = scrollbar_thumb_recursive(child, point, child_offset, stylesheet) {
+            return Some(drag);
+        }
+    }
+
+    if !matches!(component.component_type, ComponentType::Flex) {
+        return None;
+    }
+
+    let show_vertical = matches!(overflow_y, Overflow::Scroll)
+        || (matches!(overflow_y, Overflow::Auto) && scroll_state.scroll_height > 0.0);
+    if show_vertical {
+        let scroll_height = scroll_state.scroll_height as f64;
+        let thumb = ScrollBar::vertical_thumb_rect(
+            origin.x,
+            origin.y,
+            width,
+            height,
+            scroll_y,
+            scroll_height,
+        );
+        if thumb.is_some_and(|thumb| thumb.contains(point)) {
+            return Some(ScrollDragState::new_vertical(
+                component.id,
+                point.y,
+                scroll_y,
+                scroll_height,
+                height,
+            ));
+        }
+    }
+
+    let show_horizontal = matches!(overflow_x, Overflow::Scroll)
+        || (matches!(overflow_x, Overflow::Auto) && scroll_state.scroll_width > 0.0);
+    if show_horizontal {
+        let scroll_width = scroll_state.scroll_width as f64;
+        let thumb = ScrollBar::horizontal_thumb_rect(
+            origin.x,
+            origin.y,
+            width,
+            height,
+            scroll_x,
+            scroll_width,
+        );
+        if thumb.is_some_and(|thumb| thumb.contains(point)) {
+            return Some(ScrollDragState::new_horizontal(
+                component.id,
+                point.x,
+                scroll_x,
+                scroll_width,
+                width,
+            ));
+        }
+    }
+
+    None
+}
+
 fn hit_test_recursive(
     component: &Gc<Component>,
     point: Point,
     global_offset: Point,
+    stylesheet: Option<&Stylesheet>,
 ) -> Option<Gc<Component>> {
     let layout_result = component.layout_node.borrow().as_ref().and_then(|node| node.layout_result);
     let layout = layout_result?;
@@ -30,13 +171,21 @@ fn hit_test_recursive(
         Point::new(global_offset.x + local_origin.x, global_offset.y + local_origin.y);
     let global_bounds = Rect::from_origin_size(global_origin, local_size);
 
-    let contains = global_bounds.contains(point);
+    // Rounded corners are cut out of the hit area, just as they are when painted
+    let styles = get_styles(component, stylesheet);
+    let border_radius = styles.border_radius.map(|r| r.0 as f64).unwrap_or(0.0);
+    let contains = if border_radius > 0.0 {
+        RoundedRect::from_rect(global_bounds, border_radius).contains(point)
+    } else {
+        global_bounds.contains(point)
+    };
 
     if !contains {
         return None;
     }
 
-    let (overflow_x, overflow_y) = get_overflow_for_component(component);
+    let overflow_x = styles.overflow_x.unwrap_or(Overflow::Visible);
+    let overflow_y = styles.overflow_y.unwrap_or(Overflow::Visible);
     let should_clip = overflow_x.should_clip() || overflow_y.should_clip();
 
     // Get scroll offset for this container
@@ -45,51 +194,32 @@ fn hit_test_recursive(
     let scroll_offset_y =
         if should_clip { component.scroll_state().scroll_offset_y as f64 } else { 0.0 };
 
-    // Calculate visible bounds for clipping
-    // Clip is in local coordinates (0, 0 to container_size)
-    let visible_bounds = if should_clip {
+    // Children of a clipping container can only be hit inside its visible area, which leaves
+    // out content scrolled past the clipped edges and the scrollbar gutter
+    let children_visible = {
         let scroll_state = component.scroll_state();
-        let visible_width = scroll_state.container_width as f64;
-        let visible_height = scroll_state.container_height as f64;
-        // Clip rectangle in local coordinates
-        Some(Rect::new(0.0, 0.0, visible_width, visible_height))
-    } else {
-        None
+        let visible_width = if scroll_state.container_width > 0.0 {
+            scroll_state.container_width as f64
+        } else {
+            local_size.width
+        };
+        let visible_height = if scroll_state.container_height > 0.0 {
+            scroll_state.container_height as f64
+        } else {
+            local_size.height
+        };
+        overflow_clip_rect(overflow_x, overflow_y, Size::new(visible_width, visible_height))
+            .is_none_or(|clip| (clip + global_origin.to_vec2()).contains(point))
     };
 
-    // New global offset includes this container's position
-    let new_global_offset =
-        Point::new(global_offset.x + local_origin.x, global_offset.y + local_origin.y);
-
-    for child in component.children.borrow().iter().rev() {
+    if children_visible {
         // Apply this container's scroll offset to direct children only
-        let adjusted_offset = if scroll_offset_x != 0.0 || scroll_offset_y != 0.0 {
-            Point::new(new_global_offset.x - scroll_offset_x, new_global_offset.y - scroll_offset_y)
-        } else {
-            new_global_offset
-        };
+        let child_offset =
+            Point::new(global_origin.x - scroll_offset_x, global_origin.y - scroll_offset_y);
 
-        if let Some(hit) = hit_test_recursive(child, point, adjusted_offset) {
-            if let Some(bounds) = visible_bounds {
-                let child_layout =
-                    child.layout_node.borrow().as_ref().and_then(|n| n.layout_result);
-                if let Some(child_layout) = child_layout {
-                    let child_origin = Point::new(
-                        adjusted_offset.x + child_layout.location.x as f64,
-                        adjusted_offset.y + child_layout.location.y as f64,
-                    );
-                    let child_size =
-                        Size::new(child_layout.size.width as f64, child_layout.size.height as f64);
-                    let child_bounds = Rect::from_origin_size(child_origin, child_size);
-                    if bounds.contains_rect(child_bounds)
-                        || child_bounds.intersect(bounds).area() > 0.0
-                    {
-                        return Some(hit);
-                    }
-                } else {
-                    return Some(hit);
-                }
-            } else {
+        // Topmost children first, the reverse of paint order
+        for child in children_in_paint_order(component, stylesheet).iter().rev() {
+            if let Some(hit) = hit_test_recursive(child, point, child_offset, stylesheet) {
                 return Some(hit);
             }
         }
@@ -101,3 +231,155 @@ fn hit_test_recursive(
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::ComponentType;
+    use crate::properties::PropertyMap;
+    use crate::render::Scene;
+    use rvue_style::properties::{BorderRadius, FlexDirection};
+    use rvue_style::ComputedStyles;
+
+    fn sized(id: u64, component_type: ComponentType, width: f32, height: f32) -> Gc<Component> {
+        let component = Component::with_properties(id, component_type, PropertyMap::new());
+        component.set_widget_styles(ComputedStyles {
+            width: Some(rvue_style::Width(rvue_style::Size::Pixels(width))),
+            height: Some(rvue_style::Height(rvue_style::Size::Pixels(height))),
+            min_height: Some(rvue_style::properties::MinHeight(rvue_style::Size::Pixels(height))),
+            flex_direction: Some(FlexDirection::Column),
+            ..Default::default()
+        });
+        component
+    }
+
+    fn lay_out(root: &Gc<Component>) -> Scene {
+        let mut scene = Scene::new();
+        scene.add_fragment(Gc::clone(root));
+        scene.update();
+        scene
+    }
+
+    fn hit_id(root: &Gc<Component>, x: f64, y: f64) -> Option<u64> {
+        hit_test(root, Point::new(x, y)).map(|hit| hit.id)
+    }
+
+    #[test]
+    fn test_rounded_corner_misses() {
+        let root = sized(1, ComponentType::Flex, 200.0, 200.0);
+        let button = sized(2, ComponentType::Button, 100.0, 100.0);
+        let mut styles = button.widget_styles().unwrap_or_default();
+        styles.border_radius = Some(BorderRadius(50.0));
+        button.set_widget_styles(styles);
+        root.append_child(Gc::clone(&button));
+        let _scene = lay_out(&root);
+
+        assert_eq!(hit_id(&root, 50.0, 50.0), Some(2));
+        assert_eq!(hit_id(&root, 50.0, 2.0), Some(2));
+        assert_eq!(hit_id(&root, 5.0, 5.0), None);
+        assert_eq!(hit_id(&root, 95.0, 95.0), None);
+    }
+
+    #[test]
+    fn test_child_scrolled_out_of_hidden_container_misses() {
+        let root = sized(1, ComponentType::Flex, 200.0, 300.0);
+        root.append_child(sized(2, ComponentType::Flex, 200.0, 50.0));
+
+        let container = sized(3, ComponentType::Flex, 100.0, 100.0);
+        let mut styles = container.widget_styles().unwrap_or_default();
+        styles.overflow_x = Some(Overflow::Hidden);
+        styles.overflow_y = Some(Overflow::Hidden);
+        container.set_widget_styles(styles);
+        root.append_child(Gc::clone(&container));
+
+        let first = sized(4, ComponentType::Button, 100.0, 60.0);
+        let second = sized(5, ComponentType::Button, 100.0, 60.0);
+        container.append_child(Gc::clone(&first));
+        container.append_child(Gc::clone(&second));
+
+        let mut scroll_state = container.scroll_state();
+        scroll_state.scroll_offset_y = 30.0;
+        container.set_scroll_state(scroll_state);
+        let _scene = lay_out(&root);
+
+        // The container spans y 50..150 and `first` is drawn at y 20..80
+        assert_eq!(hit_id(&root, 10.0, 30.0), None);
+        assert_eq!(hit_id(&root, 10.0, 60.0), Some(4));
+        assert_eq!(hit_id(&root, 10.0, 100.0), Some(5));
+    }
+
+    fn place(component: &Gc<Component>, x: f32, y: f32, width: f32, height: f32) {
+        let mut layout = taffy::Layout::new();
+        layout.location = taffy::Point { x, y };
+        layout.size = taffy::Size { width, height };
+        component.set_layout_node(crate::layout::LayoutNode {
+            taffy_node: None,
+            is_dirty: false,
+            layout_result: Some(layout),
+        });
+    }
+
+    #[test]
+    fn test_higher_z_index_paints_on_top_and_wins_hit() {
+        use crate::widgets::CanvasDraw;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let painted = Rc::new(RefCell::new(Vec::new()));
+        let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+        place(&root, 0.0, 0.0, 200.0, 200.0);
+
+        // Square 2 comes first in the tree, so only its z-index lifts it above square 3
+        for (id, z_index, offset) in [(2, 1, 0.0), (3, 0, 50.0)] {
+            let square = Component::with_properties(id, ComponentType::Canvas, PropertyMap::new());
+            square.set_widget_styles(ComputedStyles {
+                z_index: Some(rvue_style::ZIndex(z_index)),
+                ..Default::default()
+            });
+            let log = Rc::clone(&painted);
+            *square.canvas_draw.borrow_mut_gen_only() =
+                Some(CanvasDraw(Rc::new(move |_, _| log.borrow_mut().push(id))));
+            square.on_click_0arg(|| {});
+            place(&square, offset, offset, 100.0, 100.0);
+            root.append_child(Gc::clone(&square));
+        }
+
+        let mut scene = vello::Scene::new();
+        crate::render::render_component(
+            &root,
+            &mut scene,
+            vello::kurbo::Affine::IDENTITY,
+            &mut rustc_hash::FxHashSet::default(),
+            None,
+            &mut crate::text::TextContext::new(),
+        );
+
+        assert_eq!(*painted.borrow(), vec![3, 2]);
+        assert_eq!(hit_id(&root, 75.0, 75.0), Some(2));
+        assert_eq!(hit_id(&root, 125.0, 125.0), Some(3));
+    }
+
+    #[test]
+    fn test_stylesheet_z_index_wins_hit() {
+        use crate::style::Stylesheet;
+        use rvue_style::Properties;
+
+        let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+        place(&root, 0.0, 0.0, 200.0, 200.0);
+        for (id, offset) in [(2, 0.0), (3, 50.0)] {
+            let square = Component::with_properties(id, ComponentType::Canvas, PropertyMap::new());
+            square.on_click_0arg(|| {});
+            place(&square, offset, offset, 100.0, 100.0);
+            root.append_child(Gc::clone(&square));
+        }
+        root.children.borrow()[0].add_class("raised");
+
+        let mut stylesheet = Stylesheet::new();
+        stylesheet.add_rule(".raised", Properties::with(rvue_style::ZIndex(1)));
+
+        // Without the stylesheet the later sibling is on top where they overlap
+        assert_eq!(hit_id(&root, 75.0, 75.0), Some(3));
+        let hit = hit_test_with_stylesheet(&root, Point::new(75.0, 75.0), Some(&stylesheet));
+        assert_eq!(hit.map(|hit| hit.id), Some(2));
+    }
+}
@@ -1,9 +1,13 @@
 pub mod context;
+pub mod cursor;
 pub mod dispatch;
+pub mod drag;
 pub mod focus;
 pub mod handler;
 pub mod hit_test;
+pub mod observer;
 pub mod path;
+pub mod shortcut;
 pub mod status;
 pub mod types;
 pub mod update;
@@ -12,14 +16,23 @@ pub mod winit_translator;
 pub use winit_translator::{get_pointer_event_position, WinitTranslator};
 
 pub use context::EventContext;
-pub use dispatch::{run_pointer_event_pass, run_text_event_pass};
+pub use cursor::{cursor_icon_for, resolve_cursor, CursorState};
+pub use dispatch::{check_long_press, run_pointer_event_pass, run_text_event_pass};
+pub use drag::{DragState, DRAG_THRESHOLD};
 pub use focus::find_next_focusable;
-pub use handler::{AnyEventHandler, EventHandler, EventHandlers, ScrollDragState};
-pub use hit_test::hit_test;
+pub use handler::{
+    AnyEventHandler, EventHandler, EventHandlers, LongPressState, ScrollDragState,
+    LONG_PRESS_THRESHOLD,
+};
+pub use hit_test::{hit_test, hit_test_with_stylesheet};
+pub use observer::{
+    set_dispatch_observer, DispatchObserver, DispatchPhase, DispatchedEvent, HandlerInvocation,
+};
+pub use shortcut::{register_shortcut, Shortcut, ShortcutRegistry};
 pub use status::StatusUpdate;
 pub use types::{
-    ImeEvent, KeyboardEvent, PointerButton, PointerButtonEvent, PointerEvent, PointerMoveEvent,
-    PointerScrollEvent, RvueEvent, TextEvent, WindowEvent,
+    DragEvent, ImeEvent, KeyboardEvent, PointerButton, PointerButtonEvent, PointerEvent,
+    PointerMoveEvent, PointerScrollEvent, RvueEvent, TextEvent, WindowEvent,
 };
 pub use update::{run_update_focus_pass, run_update_pointer_pass};
 
@@ -0,0 +1,61 @@
+//! Hook for observing which event handlers run during dispatch
+//!
+//! Test tooling installs an observer to record the order handlers fire in, e.g. to check that a
+//! click bubbled from a button up to its container.
+
+use crate::component::{Component, ComponentId};
+use crate::event::types::{KeyboardEvent, PointerEvent};
+use rudo_gc::Gc;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Callback told about every handler invocation
+pub type DispatchObserver = Rc<dyn Fn(&HandlerInvocation)>;
+
+thread_local! {
+    static OBSERVER: RefCell<Option<DispatchObserver>> = const { RefCell::new(None) };
+}
+
+/// Phase of dispatch a handler ran in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchPhase {
+    /// Root down to the target, e.g. `on_click_capture`
+    Capture,
+    /// Target up to the root, e.g. `on_click`
+    Bubble,
+}
+
+/// Event being dispatched when a handler ran
+#[derive(Debug, Clone, PartialEq)]
+pub enum DispatchedEvent {
+    Pointer(PointerEvent),
+    Keyboard(KeyboardEvent),
+}
+
+/// One handler call, reported before the handler runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerInvocation {
+    pub component_id: ComponentId,
+    /// Handler name as registered, e.g. `"click"` or `"pointer_down_capture"`
+    pub handler: &'static str,
+    pub phase: DispatchPhase,
+    pub event: DispatchedEvent,
+}
+
+/// Install or remove the observer for handlers dispatched on this thread
+pub fn set_dispatch_observer(observer: Option<DispatchObserver>) {
+    OBSERVER.with(|cell| *cell.borrow_mut() = observer);
+}
+
+/// Report a handler about to run on `component`; cheap when no observer is installed
+pub(crate) fn notify_handler(
+    component: &Gc<Component>,
+    handler: &'static str,
+    phase: DispatchPhase,
+    event: impl FnOnce() -> DispatchedEvent,
+) {
+    let Some(observer) = OBSERVER.with(|cell| cell.borrow().clone()) else {
+        return;
+    };
+    observer(&HandlerInvocation { component_id: component.id, handler, phase, event: event() });
+}
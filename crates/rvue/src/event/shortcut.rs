@@ -0,0 +1,299 @@
+use crate::event::dispatch::Handled;
+use crate::event::types::{KeyState, KeyboardEvent, Modifiers};
+use crate::gc::trace_opaque;
+use rudo_gc::cell::GcCapture;
+use rudo_gc::{Gc, GcBox, GcCell, Trace};
+use std::cell::RefCell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+use winit::keyboard::{Key, NamedKey};
+
+thread_local! {
+    /// Shortcuts registered since the app state last took them.
+    static PENDING_SHORTCUTS: RefCell<ShortcutRegistry> = RefCell::new(ShortcutRegistry::new());
+}
+
+/// A key combination such as `Ctrl+S`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl Shortcut {
+    pub fn new(key: Key, modifiers: Modifiers) -> Self {
+        Self { key: normalize_key(key), modifiers }
+    }
+
+    /// Parses a combination like `"Ctrl+S"`, `"Ctrl+Shift+Z"` or `"Escape"`.
+    ///
+    /// Modifier names are case-insensitive; `Cmd`, `Meta`, `Super` and `Logo` all map
+    /// to the logo key.
+    pub fn parse(keys: &str) -> Option<Self> {
+        let mut modifiers = Modifiers::default();
+        let mut key = None;
+
+        for part in keys.split('+').map(str::trim) {
+            match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => modifiers.ctrl = true,
+                "shift" => modifiers.shift = true,
+                "alt" | "option" => modifiers.alt = true,
+                "cmd" | "meta" | "super" | "logo" => modifiers.logo = true,
+                "" => return None,
+                _ => {
+                    if key.is_some() {
+                        return None;
+                    }
+                    key = Some(parse_key(part)?);
+                }
+            }
+        }
+
+        Some(Self::new(key?, modifiers))
+    }
+
+    /// Returns true if `event` is a key press of this combination.
+    pub fn matches(&self, event: &KeyboardEvent) -> bool {
+        event.state == KeyState::Down
+            && event.modifiers == self.modifiers
+            && normalize_key(event.key.clone()) == self.key
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let named = match name.to_ascii_lowercase().as_str() {
+        "enter" | "return" => NamedKey::Enter,
+        "escape" | "esc" => NamedKey::Escape,
+        "tab" => NamedKey::Tab,
+        "space" => NamedKey::Space,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" => NamedKey::PageUp,
+        "pagedown" => NamedKey::PageDown,
+        "up" | "arrowup" => NamedKey::ArrowUp,
+        "down" | "arrowdown" => NamedKey::ArrowDown,
+        "left" | "arrowleft" => NamedKey::ArrowLeft,
+        "right" | "arrowright" => NamedKey::ArrowRight,
+        "f1" => NamedKey::F1,
+        "f2" => NamedKey::F2,
+        "f3" => NamedKey::F3,
+        "f4" => NamedKey::F4,
+        "f5" => NamedKey::F5,
+        "f6" => NamedKey::F6,
+        "f7" => NamedKey::F7,
+        "f8" => NamedKey::F8,
+        "f9" => NamedKey::F9,
+        "f10" => NamedKey::F10,
+        "f11" => NamedKey::F11,
+        "f12" => NamedKey::F12,
+        _ if name.chars().count() == 1 => return Some(Key::Character(name.into())),
+        _ => return None,
+    };
+    Some(Key::Named(named))
+}
+
+/// Lowercases character keys so `Ctrl+S` matches whether or not Shift changed the case.
+fn normalize_key(key: Key) -> Key {
+    match key {
+        Key::Character(ch) => Key::Character(ch.to_lowercase().into()),
+        Key::Named(NamedKey::Space) => Key::Character(" ".into()),
+        other => other,
+    }
+}
+
+struct ShortcutEntry {
+    shortcut: Shortcut,
+    handler: Rc<RefCell<dyn FnMut(&KeyboardEvent)>>,
+    stop_propagation: bool,
+}
+
+unsafe impl Trace for ShortcutEntry {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        trace_opaque(&*self.handler, visitor);
+    }
+}
+
+impl GcCapture for ShortcutEntry {
+    fn capture_gc_ptrs(&self) -> &[NonNull<GcBox<()>>] {
+        &[]
+    }
+}
+
+/// Per-app table of keyboard shortcuts, checked before key events reach the focused widget.
+///
+/// The entries live on the GC heap so the signals and components the handlers capture are
+/// traced.
+pub struct ShortcutRegistry {
+    entries: Gc<GcCell<Vec<ShortcutEntry>>>,
+}
+
+impl Default for ShortcutRegistry {
+    fn default() -> Self {
+        Self { entries: Gc::new(GcCell::new(Vec::new())) }
+    }
+}
+
+impl ShortcutRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a shortcut that consumes the key event when it fires.
+    pub fn register<F>(&mut self, shortcut: Shortcut, handler: F)
+    where
+        F: FnMut(&KeyboardEvent) + 'static,
+    {
+        self.insert(shortcut, handler, true);
+    }
+
+    /// Registers a shortcut that still lets the focused widget receive the key event.
+    pub fn register_passthrough<F>(&mut self, shortcut: Shortcut, handler: F)
+    where
+        F: FnMut(&KeyboardEvent) + 'static,
+    {
+        self.insert(shortcut, handler, false);
+    }
+
+    fn insert<F>(&mut self, shortcut: Shortcut, handler: F, stop_propagation: bool)
+    where
+        F: FnMut(&KeyboardEvent) + 'static,
+    {
+        self.unregister(&shortcut);
+        self.entries.borrow_mut().push(ShortcutEntry {
+            shortcut,
+            handler: Rc::new(RefCell::new(handler)),
+            stop_propagation,
+        });
+    }
+
+    /// Removes the handler registered for `shortcut`, if any.
+    pub fn unregister(&mut self, shortcut: &Shortcut) {
+        self.entries.borrow_mut().retain(|entry| &entry.shortcut != shortcut);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    /// Moves every shortcut from `other` into this registry.
+    pub fn extend(&mut self, other: ShortcutRegistry) {
+        let other_entries = std::mem::take(&mut *other.entries.borrow_mut());
+        for entry in other_entries {
+            self.unregister(&entry.shortcut);
+            self.entries.borrow_mut().push(entry);
+        }
+    }
+
+    /// Fires the handler matching `event`.
+    ///
+    /// Returns `Handled::Yes` if the matching shortcut stops propagation.
+    pub fn dispatch(&self, event: &KeyboardEvent) -> Handled {
+        let Some((handler, stop_propagation)) = self
+            .entries
+            .borrow()
+            .iter()
+            .find(|entry| entry.shortcut.matches(event))
+            .map(|entry| (Rc::clone(&entry.handler), entry.stop_propagation))
+        else {
+            return Handled::No;
+        };
+
+        (handler.borrow_mut())(event);
+
+        if stop_propagation {
+            Handled::Yes
+        } else {
+            Handled::No
+        }
+    }
+}
+
+/// Registers an app-wide keyboard shortcut.
+///
+/// Works while building the view and after the app has started; the shortcut is moved
+/// onto the app state before the next key event. Returns `None` if `keys` cannot be parsed.
+///
+/// # Example
+///
+/// ```ignore
+/// register_shortcut("Ctrl+S", |_| save_document());
+/// ```
+pub fn register_shortcut<F>(keys: &str, handler: F) -> Option<Shortcut>
+where
+    F: FnMut(&KeyboardEvent) + 'static,
+{
+    let shortcut = Shortcut::parse(keys)?;
+    PENDING_SHORTCUTS.with(|pending| pending.borrow_mut().register(shortcut.clone(), handler));
+    Some(shortcut)
+}
+
+/// Takes the shortcuts registered with [`register_shortcut`] since the last call.
+pub fn take_pending_shortcuts() -> ShortcutRegistry {
+    PENDING_SHORTCUTS.with(|pending| std::mem::take(&mut *pending.borrow_mut()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_event(key: Key, modifiers: Modifiers) -> KeyboardEvent {
+        KeyboardEvent {
+            key,
+            code: winit::keyboard::PhysicalKey::Unidentified(
+                winit::keyboard::NativeKeyCode::Unidentified,
+            ),
+            state: KeyState::Down,
+            modifiers,
+            repeat: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_shortcut() {
+        let shortcut = Shortcut::parse("Ctrl+Shift+Z").unwrap();
+        assert_eq!(shortcut.key, Key::Character("z".into()));
+        assert!(shortcut.modifiers.ctrl);
+        assert!(shortcut.modifiers.shift);
+        assert!(!shortcut.modifiers.alt);
+
+        let escape = Shortcut::parse("Escape").unwrap();
+        assert_eq!(escape.key, Key::Named(NamedKey::Escape));
+        assert_eq!(escape.modifiers, Modifiers::default());
+
+        assert!(Shortcut::parse("Ctrl+").is_none());
+        assert!(Shortcut::parse("Ctrl+A+B").is_none());
+        assert!(Shortcut::parse("Ctrl+Unknown").is_none());
+    }
+
+    #[test]
+    fn test_shortcut_requires_exact_modifiers() {
+        let shortcut = Shortcut::parse("Ctrl+S").unwrap();
+        let ctrl = Modifiers { ctrl: true, ..Default::default() };
+        let ctrl_shift = Modifiers { ctrl: true, shift: true, ..Default::default() };
+
+        assert!(shortcut.matches(&key_event(Key::Character("s".into()), ctrl)));
+        assert!(shortcut.matches(&key_event(Key::Character("S".into()), ctrl)));
+        assert!(!shortcut.matches(&key_event(Key::Character("s".into()), ctrl_shift)));
+        assert!(!shortcut.matches(&key_event(Key::Character("s".into()), Modifiers::default())));
+    }
+
+    #[test]
+    fn test_passthrough_shortcut_does_not_stop_propagation() {
+        let mut registry = ShortcutRegistry::new();
+        let fired = Rc::new(RefCell::new(0));
+        let fired_clone = Rc::clone(&fired);
+        registry.register_passthrough(Shortcut::parse("F5").unwrap(), move |_| {
+            *fired_clone.borrow_mut() += 1;
+        });
+
+        let handled = registry.dispatch(&key_event(Key::Named(NamedKey::F5), Modifiers::default()));
+        assert_eq!(handled, Handled::No);
+        assert_eq!(*fired.borrow(), 1);
+    }
+}
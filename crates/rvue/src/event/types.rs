@@ -73,6 +73,13 @@ impl Default for PointerInfo {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragEvent {
+    pub position: Point,
+    pub start_position: Point,
+    pub modifiers: Modifiers,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PointerEvent {
     Down(PointerButtonEvent),
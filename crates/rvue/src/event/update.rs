@@ -1,8 +1,11 @@
+use crate::event::dispatch::find_component_by_id;
+use crate::event::focus::{take_focus_request, FocusRequest};
 use crate::event::path::get_component_path;
 use crate::event::status::StatusUpdate;
 use rudo_gc::Gc;
 
 pub fn run_update_pointer_pass(app_state: &mut impl crate::app::AppStateLike) {
+    let _span = tracing::debug_span!("update_pointer_pass").entered();
     let prev_active_path = std::mem::take(app_state.active_path());
     let prev_hovered_path = std::mem::take(app_state.hovered_path());
 
@@ -89,9 +92,12 @@ pub fn run_update_pointer_pass(app_state: &mut impl crate::app::AppStateLike) {
 }
 
 pub fn run_update_focus_pass(app_state: &mut impl crate::app::AppStateLike) {
+    let _span = tracing::debug_span!("update_focus_pass").entered();
     let prev_focused_text_input =
         app_state.focused().as_ref().map(|c| c.accepts_text_input()).unwrap_or(false);
 
+    apply_focus_request(app_state);
+
     if let Some(pending) = app_state.pending_focus().take() {
         let prev_focused = app_state.focused().clone();
 
@@ -136,3 +142,27 @@ pub fn run_update_focus_pass(app_state: &mut impl crate::app::AppStateLike) {
 
     *app_state.focused_path() = next_focused_path;
 }
+
+/// Applies a focus change requested through `Component::focus` or `Component::blur`.
+fn apply_focus_request(app_state: &mut impl crate::app::AppStateLike) {
+    match take_focus_request() {
+        Some(FocusRequest::Focus(id)) => {
+            if let Some(component) = find_component_by_id(&app_state.root_component(), id) {
+                *app_state.pending_focus() = Some(component);
+            }
+        }
+        Some(FocusRequest::Blur(id)) => {
+            if app_state.pending_focus().as_ref().is_some_and(|c| c.id == id) {
+                *app_state.pending_focus() = None;
+            }
+
+            let focused = app_state.focused().clone();
+            if let Some(focused) = focused.filter(|c| c.id == id) {
+                *focused.is_focused.borrow_mut_gen_only() = false;
+                focused.on_status_update(&StatusUpdate::FocusChanged(false));
+                *app_state.focused_mut() = None;
+            }
+        }
+        None => {}
+    }
+}
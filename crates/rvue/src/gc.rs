@@ -1 +1,117 @@
+//! Garbage collection support for the event loop
+
+use std::time::Duration;
+
 pub use rudo_gc::impl_gc_capture;
+use rudo_gc::Visitor;
+
+/// Heap growth that makes an idle collection worthwhile.
+const DEFAULT_GROWTH_THRESHOLD: usize = 4 * 1024 * 1024;
+
+/// Heap growth after which a collection runs even with no idle time.
+const DEFAULT_HARD_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Smallest idle window a collection may run in.
+const DEFAULT_MIN_IDLE: Duration = Duration::from_millis(4);
+
+/// Schedules garbage collections into the idle time between frames.
+///
+/// The app turns off allocation-triggered collection, so without pacing the heap only grows.
+/// The pacer collects once the heap has grown past `growth_threshold` and the event loop has
+/// at least `min_idle` (or the last pause, if longer) before the next frame. Past `hard_limit`
+/// it collects regardless, trading one slow frame for bounded memory.
+///
+/// Collections are generational, so a paced collection usually only walks recent allocations.
+/// Cyclic garbage such as parent links survives those and is reclaimed by the major collection
+/// that runs once the old generation has grown.
+#[derive(Debug, Clone)]
+pub struct GcPacer {
+    pub growth_threshold: usize,
+    pub hard_limit: usize,
+    pub min_idle: Duration,
+    baseline: usize,
+    last_pause: Duration,
+}
+
+impl Default for GcPacer {
+    fn default() -> Self {
+        Self {
+            growth_threshold: DEFAULT_GROWTH_THRESHOLD,
+            hard_limit: DEFAULT_HARD_LIMIT,
+            min_idle: DEFAULT_MIN_IDLE,
+            baseline: 0,
+            last_pause: Duration::ZERO,
+        }
+    }
+}
+
+impl GcPacer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_growth_threshold(mut self, bytes: usize) -> Self {
+        self.growth_threshold = bytes;
+        self
+    }
+
+    pub fn with_hard_limit(mut self, bytes: usize) -> Self {
+        self.hard_limit = bytes;
+        self
+    }
+
+    pub fn with_min_idle(mut self, min_idle: Duration) -> Self {
+        self.min_idle = min_idle;
+        self
+    }
+
+    /// Whether a collection should run now, given the heap size and the idle time before the
+    /// next frame. `None` means the event loop is waiting for input with no frame scheduled.
+    pub fn should_collect(&self, heap_size: usize, idle: Option<Duration>) -> bool {
+        let growth = heap_size.saturating_sub(self.baseline);
+        if growth >= self.hard_limit {
+            return true;
+        }
+        if growth < self.growth_threshold {
+            return false;
+        }
+        idle.is_none_or(|idle| idle >= self.min_idle.max(self.last_pause))
+    }
+
+    /// Collects if [`should_collect`](Self::should_collect) allows it, returning whether it did.
+    pub fn collect_if_idle(&mut self, idle: Option<Duration>) -> bool {
+        if !self.should_collect(rudo_gc::current_heap_size(), idle) {
+            return false;
+        }
+        self.collect();
+        true
+    }
+
+    /// The pause of the last paced collection.
+    pub fn last_pause(&self) -> Duration {
+        self.last_pause
+    }
+
+    fn collect(&mut self) {
+        let _span = tracing::debug_span!("gc_collect").entered();
+        rudo_gc::set_gc_enabled(true);
+        rudo_gc::collect();
+        rudo_gc::set_gc_enabled(false);
+
+        self.last_pause = rudo_gc::last_gc_metrics().duration;
+        self.baseline = rudo_gc::current_heap_size();
+        tracing::debug!(pause = ?self.last_pause, heap = self.baseline, "paced collection");
+    }
+}
+
+/// Conservatively scan an opaque value (e.g. `Box<dyn Fn()>`) for captured Gc pointers.
+/// Use when the value's layout is unknown but may contain Gc<T> (e.g. closure captures).
+#[inline]
+pub(crate) fn trace_opaque<T: ?Sized>(value: &T, visitor: &mut impl Visitor) {
+    let ptr = std::ptr::from_ref(value).cast::<u8>();
+    let layout = std::alloc::Layout::for_value(value);
+    // SAFETY: The caller guarantees the value is valid for reading layout.size() bytes.
+    unsafe {
+        visitor.visit_region(ptr, layout.size());
+    }
+}
@@ -0,0 +1,98 @@
+//! JSON serialization of the component tree for debugging and golden-file tests
+
+use crate::component::{Component, ComponentType};
+use serde_json::{json, Map, Value};
+
+impl Component {
+    /// Serialize this component and its descendants to JSON
+    ///
+    /// Each node holds its `type`, `id`, `element_id`, sorted `classes`, the `props` that
+    /// describe its state, its `layout` relative to its parent (`null` before layout) and its
+    /// `children`. Handlers, effects and other runtime state are left out.
+    pub fn to_json(&self) -> Value {
+        let layout = self.layout_node().and_then(|node| node.layout_result).map(|layout| {
+            json!({
+                "x": layout.location.x,
+                "y": layout.location.y,
+                "width": layout.size.width,
+                "height": layout.size.height,
+            })
+        });
+
+        let mut classes = self.classes();
+        classes.sort();
+
+        let children: Vec<Value> =
+            self.children.borrow().iter().map(|child| child.to_json()).collect();
+
+        json!({
+            "type": type_name(&self.component_type),
+            "id": self.id,
+            "element_id": self.element_id.borrow().clone(),
+            "classes": classes,
+            "props": props(self),
+            "layout": layout,
+            "children": children,
+        })
+    }
+}
+
+fn type_name(component_type: &ComponentType) -> String {
+    match component_type {
+        ComponentType::Custom(name) => name.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn props(component: &Component) -> Map<String, Value> {
+    let mut props = Map::new();
+    match &component.component_type {
+        ComponentType::Text => {
+            props.insert("text".into(), component.text_content().into());
+        }
+        ComponentType::TextInput | ComponentType::TextArea => {
+            props.insert("value".into(), component.text_input_value().into());
+        }
+        ComponentType::NumberInput => {
+            props.insert("value".into(), component.number_input_value().into());
+        }
+        ComponentType::Checkbox => {
+            props.insert("checked".into(), component.checkbox_checked().into());
+        }
+        ComponentType::ProgressBar => {
+            if component.progress_indeterminate() {
+                props.insert("indeterminate".into(), true.into());
+            } else {
+                props.insert("value".into(), component.progress_value().into());
+            }
+        }
+        ComponentType::Radio => {
+            props.insert("value".into(), component.radio_value().into());
+            props.insert("checked".into(), component.radio_checked().into());
+        }
+        ComponentType::Show => {
+            props.insert("when".into(), component.show_when().into());
+        }
+        ComponentType::For => {
+            props.insert("items".into(), component.for_item_count().into());
+        }
+        ComponentType::Switch => {
+            props.insert("arm".into(), component.switch_active_arm().into());
+        }
+        ComponentType::Suspense => {
+            props.insert("pending".into(), component.suspense_pending().into());
+        }
+        _ => {}
+    }
+
+    if let Some(label) = component.aria_label() {
+        props.insert("aria_label".into(), label.into());
+    }
+    if component.is_disabled() {
+        props.insert("disabled".into(), true.into());
+    }
+    if let Some(message) = component.error_message() {
+        props.insert("error".into(), message.into());
+    }
+    props
+}
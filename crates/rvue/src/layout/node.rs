@@ -2,15 +2,59 @@
 
 use crate::component::{Component, ComponentType};
 use crate::style::Stylesheet;
-use crate::text::{BrushIndex, ParleyLayoutWrapper, TextContext};
+use crate::text::span::push_span_styles;
+use crate::text::{text_layout_builder, BrushIndex, ParleyLayoutWrapper, TextContext};
+use crate::widgets::input::VALIDATION_MESSAGE_HEIGHT;
 use parley::Layout;
-use rudo_gc::Trace;
+use rudo_gc::{Gc, Trace};
 use rvue_style::ComputedStyles;
 use rvue_style::Size as RvueSize;
+use std::borrow::Cow;
 use taffy::geometry::Point;
 use taffy::prelude::*;
 use taffy::TaffyTree;
 
+/// Space between a tooltip and its anchor
+pub const TOOLTIP_GAP: f32 = 4.0;
+
+/// Horizontal and vertical padding inside a tooltip
+const TOOLTIP_PADDING: (f32, f32) = (6.0, 3.0);
+
+/// Move an open tooltip's `layout` so it stays inside the root component
+///
+/// The tooltip flips above its anchor when it would run past the bottom edge and there is room
+/// above, and shifts left when it would run past the right edge.
+pub(crate) fn keep_tooltip_on_screen(tooltip: &Gc<Component>, layout: &mut taffy::Layout) {
+    let Some(anchor) = tooltip.parent.borrow().clone() else {
+        return;
+    };
+    let mut root = Gc::clone(&anchor);
+    loop {
+        let parent = root.parent.borrow().clone();
+        match parent {
+            Some(parent) => root = parent,
+            None => break,
+        }
+    }
+    let Some(viewport) = root.layout_node().and_then(|node| node.layout_result).map(|l| l.size)
+    else {
+        return;
+    };
+    let local = crate::event::hit_test::window_to_local(&anchor, vello::kurbo::Point::ZERO);
+    let (anchor_x, anchor_y) = (-local.x as f32, -local.y as f32);
+
+    let bottom = anchor_y + layout.location.y + layout.size.height;
+    let above = -(layout.size.height + TOOLTIP_GAP);
+    if bottom > viewport.height && anchor_y + above >= 0.0 {
+        layout.location.y = above;
+    }
+
+    let overflow_x = anchor_x + layout.location.x + layout.size.width - viewport.width;
+    if overflow_x > 0.0 {
+        layout.location.x = (layout.location.x - overflow_x).max(-anchor_x);
+    }
+}
+
 fn size_to_dimension(size: &RvueSize) -> Dimension {
     match size {
         RvueSize::Auto => Dimension::auto(),
@@ -91,6 +135,52 @@ pub fn overflow_to_taffy(
     Point { x, y: x }
 }
 
+/// Text as laid out under `white_space`: unless line breaks are preserved, each line break
+/// or tab becomes a single space, so byte offsets into the content still line up
+fn white_space_text(content: &str, white_space: rvue_style::WhiteSpace) -> Cow<'_, str> {
+    let is_break = |c: char| matches!(c, '\n' | '\r' | '\t');
+    if white_space.preserves_line_breaks() || !content.contains(is_break) {
+        Cow::Borrowed(content)
+    } else {
+        Cow::Owned(content.replace(is_break, " "))
+    }
+}
+
+/// Shape a Text component's content and measure the size its layout node gets
+pub(crate) fn shape_text(
+    component: &Component,
+    text_context: &mut TextContext,
+) -> (Layout<BrushIndex>, Size<Dimension>) {
+    let styles = component.widget_styles();
+    let white_space = styles.as_ref().and_then(|s| s.white_space).unwrap_or_default();
+    let content = white_space_text(&component.text_content(), white_space).into_owned();
+
+    let font_size =
+        styles.as_ref().and_then(|s| s.font_size.as_ref()).map(|fs| fs.0).unwrap_or(16.0);
+
+    // Normal text wraps at an explicit pixel width; everything else only breaks at line breaks
+    let max_advance = match styles.as_ref().and_then(|s| s.width.as_ref()) {
+        Some(rvue_style::Width(RvueSize::Pixels(width))) if white_space.wraps() => Some(*width),
+        _ => None,
+    };
+
+    // Eagerly build text layout to get dimensions
+    let mut layout_builder = text_layout_builder(text_context, &content, font_size);
+    push_span_styles(&mut layout_builder, &component.text_spans());
+
+    let mut layout: Layout<BrushIndex> = layout_builder.build(&content);
+    layout.break_all_lines(max_advance);
+
+    let parley_width = layout.width();
+    let parley_height = layout.height();
+
+    // If width/height is 0, provide a small default to avoid collapse in tests if fonts aren't loaded
+    let width = if parley_width > 0.0 { parley_width } else { 10.0 * content.len() as f32 };
+    let height = if parley_height > 0.0 { parley_height } else { 20.0 };
+
+    (layout, Size { width: length(width), height: length(height) })
+}
+
 /// Layout node wrapper holding calculation results
 #[derive(Clone)]
 pub struct LayoutNode {
@@ -126,9 +216,15 @@ impl LayoutNode {
         text_context: &mut TextContext,
         stylesheet: Option<&Stylesheet>,
     ) -> Self {
-        // Control-flow components (For, Show) have no layout of their own
+        // Control-flow components (For, Show, Switch, Suspense) have no layout of their own
         // They are transparent to the layout system
-        if matches!(component.component_type, ComponentType::For | ComponentType::Show) {
+        if matches!(
+            component.component_type,
+            ComponentType::For
+                | ComponentType::Show
+                | ComponentType::Switch
+                | ComponentType::Suspense
+        ) {
             return Self { taffy_node: None, is_dirty: true, layout_result: None };
         }
 
@@ -166,30 +262,7 @@ impl LayoutNode {
         mut style: Style,
         text_context: &mut TextContext,
     ) -> Self {
-        let content = component.text_content();
-
-        let font_size = component
-            .widget_styles()
-            .as_ref()
-            .and_then(|s| s.font_size.as_ref())
-            .map(|fs| fs.0)
-            .unwrap_or(16.0);
-
-        // Eagerly build text layout to get dimensions
-        let mut layout_builder =
-            text_context.layout_ctx.ranged_builder(&mut text_context.font_ctx, &content, 1.0, true);
-        layout_builder.push_default(parley::style::StyleProperty::FontSize(font_size));
-        layout_builder.push_default(parley::style::StyleProperty::Brush(BrushIndex(0)));
-        // Use a more robust font stack
-        layout_builder.push_default(parley::style::FontStack::Source(std::borrow::Cow::Borrowed(
-            "sans-serif",
-        )));
-
-        let mut layout: Layout<BrushIndex> = layout_builder.build(&content);
-        layout.break_all_lines(None); // Generate lines (no max advance)
-
-        let parley_width = layout.width();
-        let parley_height = layout.height();
+        let (layout, size) = shape_text(component, text_context);
 
         // Store layout in user_data for rendering
         {
@@ -197,17 +270,12 @@ impl LayoutNode {
             *user_data = Some(Box::new(ParleyLayoutWrapper(layout)));
         }
 
-        // Set explicit size in style based on text measurement
-        // If width/height is 0, provide a small default to avoid collapse in tests if fonts aren't loaded
-        let width = if parley_width > 0.0 { parley_width } else { 10.0 * content.len() as f32 };
-        let height = if parley_height > 0.0 { parley_height } else { 20.0 };
-
         // Reset gap to avoid inherited spacing
         style.gap = Size::zero();
 
-        style.size = Size { width: length(width), height: length(height) };
-        style.min_size = Size { width: length(0.0), height: length(height) };
-        style.max_size = Size { width: length(f32::MAX), height: length(height) };
+        style.size = size;
+        style.min_size = Size { width: length(0.0), height: size.height };
+        style.max_size = Size { width: length(f32::MAX), height: size.height };
 
         let taffy_node = taffy.new_leaf(style).ok();
 
@@ -276,6 +344,12 @@ impl LayoutNode {
                     style.size = read_size_from_styles(&computed);
                     style.min_size = read_min_size_from_styles(&computed);
                     style.max_size = read_max_size_from_styles(&computed);
+                    if let Some(grow) = computed.flex_grow {
+                        style.flex_grow = grow.0;
+                    }
+                    if let Some(shrink) = computed.flex_shrink {
+                        style.flex_shrink = shrink.0;
+                    }
 
                     // Apply overflow settings
                     let _overflow_x = computed.overflow_x;
@@ -327,16 +401,24 @@ impl LayoutNode {
                 }
                 style
             }
-            ComponentType::TextInput => {
+            ComponentType::TextInput | ComponentType::TextArea | ComponentType::NumberInput => {
                 let mut style = Style::default();
                 if let Some(computed) = computed {
                     style.size = read_size_from_styles(&computed);
                     style.min_size = read_min_size_from_styles(&computed);
                     style.max_size = read_max_size_from_styles(&computed);
                 }
+                // Leave room for the validation error drawn below the input
+                if component.is_invalid() {
+                    style.margin.bottom = length(VALIDATION_MESSAGE_HEIGHT);
+                }
                 style
             }
-            ComponentType::NumberInput => {
+            ComponentType::Checkbox
+            | ComponentType::Radio
+            | ComponentType::ProgressBar
+            | ComponentType::Spinner
+            | ComponentType::Canvas => {
                 let mut style = Style::default();
                 if let Some(computed) = computed {
                     style.size = read_size_from_styles(&computed);
@@ -345,8 +427,57 @@ impl LayoutNode {
                 }
                 style
             }
-            ComponentType::Checkbox | ComponentType::Radio => {
-                let mut style = Style::default();
+            ComponentType::ContextMenu => {
+                let Some((x, y)) = component.context_menu_position() else {
+                    return Style { display: Display::None, ..Default::default() };
+                };
+                let mut style = Style {
+                    display: Display::Flex,
+                    flex_direction: taffy::prelude::FlexDirection::Column,
+                    position: Position::Absolute,
+                    inset: taffy::Rect {
+                        left: length(x as f32),
+                        top: length(y as f32),
+                        right: auto(),
+                        bottom: auto(),
+                    },
+                    ..Default::default()
+                };
+                if let Some(computed) = computed {
+                    style.size = read_size_from_styles(&computed);
+                    style.min_size = read_min_size_from_styles(&computed);
+                    style.max_size = read_max_size_from_styles(&computed);
+                }
+                style
+            }
+            ComponentType::Tooltip => {
+                if !component.tooltip_open() {
+                    return Style { display: Display::None, ..Default::default() };
+                }
+                // Below the anchor by default; `keep_tooltip_on_screen` flips it when needed
+                let mut style = Style {
+                    display: Display::Flex,
+                    position: Position::Absolute,
+                    inset: taffy::Rect {
+                        left: zero(),
+                        top: percent(1.0_f32),
+                        right: auto(),
+                        bottom: auto(),
+                    },
+                    margin: taffy::Rect {
+                        left: zero(),
+                        right: zero(),
+                        top: length(TOOLTIP_GAP),
+                        bottom: zero(),
+                    },
+                    padding: taffy::Rect {
+                        left: length(TOOLTIP_PADDING.0),
+                        right: length(TOOLTIP_PADDING.0),
+                        top: length(TOOLTIP_PADDING.1),
+                        bottom: length(TOOLTIP_PADDING.1),
+                    },
+                    ..Default::default()
+                };
                 if let Some(computed) = computed {
                     style.size = read_size_from_styles(&computed);
                     style.min_size = read_min_size_from_styles(&computed);
@@ -354,7 +485,10 @@ impl LayoutNode {
                 }
                 style
             }
-            ComponentType::For | ComponentType::Show => Style::default(),
+            ComponentType::For
+            | ComponentType::Show
+            | ComponentType::Switch
+            | ComponentType::Suspense => Style::default(),
             _ => Style::default(),
         }
     }
@@ -4,9 +4,13 @@
 //! - Lazy renderer initialization
 //! - Optimized component tree creation
 //! - Efficient memory allocation patterns
+//!
+//! Frames, scene updates, layout, paint, event passes and paced GC collections run inside
+//! debug-level [`tracing`] spans, and each frame ends with a `frame summary` debug event.
 
 #![feature(arbitrary_self_types)]
 
+pub mod accessibility;
 pub mod app;
 pub mod component;
 pub mod context;
@@ -15,7 +19,9 @@ pub mod error;
 pub mod ev;
 pub mod event;
 pub mod gc;
+pub mod json;
 pub mod layout;
+pub mod node_ref;
 pub mod prelude;
 pub mod properties;
 pub mod reactivity;
@@ -81,6 +87,16 @@ pub mod headless {
         }
     }
 
+    /// Advance the virtual clock by `by`, then settle the UI queue and effects
+    ///
+    /// Only timers spawned after [`crate::async_runtime::clock::enable_virtual_clock`]
+    /// follow the virtual clock.
+    #[cfg(feature = "testing")]
+    pub fn advance_time(by: std::time::Duration) {
+        crate::async_runtime::clock::advance_virtual_clock(by);
+        settle();
+    }
+
     /// Run async operations until the resource reaches a specific state
     pub fn wait_for_resource_state<T, S, F>(
         resource: &crate::async_runtime::Resource<T, S>,
@@ -103,7 +119,11 @@ pub mod headless {
     }
 }
 
-pub use app::{run_app, run_app_with_stylesheet, AppError};
+pub use app::{
+    prefers_high_contrast, prefers_reduced_motion, run_app, run_app_with_config,
+    run_app_with_stylesheet, set_high_contrast, set_reduced_motion, set_render_mode, AppError,
+    RenderMode, WindowConfig,
+};
 pub use component::{Component, ComponentId, ComponentLifecycle, ComponentType};
 pub use effect::{
     create_effect, flush_pending_effects, on_cleanup, set_defer_effect_run, untracked, Effect,
@@ -112,27 +132,37 @@ pub use error::{
     validate_email, validate_number_input, validate_text_input, ValidationError, ValidationResult,
 };
 pub use event::ScrollDragState;
-pub use gc::impl_gc_capture;
+pub use gc::{impl_gc_capture, GcPacer};
+pub use node_ref::NodeRef;
 pub use properties::{
-    CheckboxChecked, FlexAlignItems, FlexDirection, FlexGap, FlexJustifyContent, ForItemCount,
-    GcPropertyMap, NumberInputValue, PropertyMap, RadioChecked, RadioValue, ShowCondition,
-    TextContent, TextInputValue, WidgetProperty, WidgetStyles,
+    ButtonSubmit, CheckboxChecked, CheckboxIndeterminate, ContextMenuPosition, FlexAlignItems,
+    FlexDirection, FlexGap, FlexJustifyContent, ForItemCount, GcPropertyMap, LinkHref,
+    NumberInputRange, NumberInputSteppers, NumberInputValue, ProgressIndeterminate, ProgressValue,
+    PropertyMap, RadioChecked, RadioValue, ScrollbarActivity, ShowCondition, SpinnerRotation,
+    SuspensePending, SwitchActiveArm, TextContent, TextInputValue, TextSelectable, TextSelection,
+    TextSpans, TooltipOpen, ValidationMessage, WidgetProperty, WidgetStyles,
 };
 pub use render::{render_component, FlexScrollState, Scene};
 pub use rudo_gc::handles::HandleScope;
 pub use rudo_gc::Gc;
 pub use rvue_style::Overflow;
 pub use signal::{
-    create_memo, create_memo_with_equality, create_signal, ReadSignal, SignalRead, SignalWrite,
-    WriteSignal,
+    create_derived, create_list, create_memo, create_memo_with_equality, create_signal,
+    create_signal_with_equality, DerivedWriteSignal, ListSignal, ReadSignal, SignalRead,
+    SignalWrite, WeakReadSignal, WeakWriteSignal, WriteSignal,
 };
 pub use style::{Stylesheet, StylesheetProvider};
 pub use taffy::TaffyTree;
+pub use text::span::TextSpan;
 pub use text::TextContext;
 pub use view::{View, ViewStruct};
 pub use widget::{
     get_build_context, get_current_ctx, with_build_context, with_current_ctx, BuildContext,
-    BuildContextHandle, IntoReactiveValue, IntoWidget, Mountable, ReactiveValue, Widget,
-    WidgetWrapper,
+    BuildContextHandle, FragmentWidget, IntoReactiveValue, IntoWidget, Mountable, OptionWidget,
+    ReactiveValue, Widget, WidgetWrapper,
+};
+pub use widgets::{
+    Accordion, AccordionGroup, Button, Canvas, Checkbox, ContextMenu, ContextMenuHandle, Divider,
+    Flex, For, Form, Link, NumberInput, Once, ProgressBar, Radio, Show, Spacer, Spinner, Tab, Tabs,
+    Text, TextArea, TextInput, Tooltip, VirtualFor,
 };
-pub use widgets::{Button, Checkbox, Flex, For, NumberInput, Radio, Show, Text, TextInput};
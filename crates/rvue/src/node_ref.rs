@@ -0,0 +1,49 @@
+//! Imperative references to rendered components
+//!
+//! A [`NodeRef`] is filled in by the `view!` macro when an element has a
+//! `ref={node_ref}` attribute, giving event handlers and effects a handle to the
+//! built component.
+
+use crate::component::Component;
+use rudo_gc::{Gc, GcCell, Trace};
+use std::rc::Rc;
+
+/// A shared, cloneable handle to a component that is set once the component is built
+#[derive(Clone, Default)]
+pub struct NodeRef {
+    inner: Rc<GcCell<Option<Gc<Component>>>>,
+}
+
+unsafe impl Trace for NodeRef {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.inner.trace(visitor);
+    }
+}
+
+impl NodeRef {
+    /// Create an empty ref
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Point the ref at `component`
+    pub fn set(&self, component: Gc<Component>) {
+        *self.inner.borrow_mut() = Some(component);
+    }
+
+    /// Get the referenced component, or `None` before it has been built
+    pub fn get(&self) -> Option<Gc<Component>> {
+        self.inner.borrow().clone()
+    }
+
+    /// Detach the ref from its component
+    pub fn clear(&self) {
+        *self.inner.borrow_mut() = None;
+    }
+}
+
+impl std::fmt::Debug for NodeRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodeRef").field("component", &self.get().map(|c| c.id)).finish()
+    }
+}
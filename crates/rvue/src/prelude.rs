@@ -5,11 +5,13 @@ pub use crate::effect::{create_effect, on_cleanup, untracked, Effect};
 pub use crate::ev::{
     Blur, Change, Click, Focus, Input, KeyDown, KeyUp, PointerDown, PointerMove, PointerUp,
 };
-pub use crate::event::{EventContext, EventDescriptor, EventHandler};
+pub use crate::event::{register_shortcut, EventContext, EventDescriptor, EventHandler};
+pub use crate::node_ref::NodeRef;
 pub use crate::signal::{
     create_memo, create_signal, ReadSignal, SignalRead, SignalWrite, WriteSignal,
 };
 pub use crate::slot::{Children, ChildrenFn, MaybeChildren, ToChildren};
+pub use crate::text::span::TextSpan;
 pub use crate::view::{View, ViewStruct};
 pub use crate::widget::{IntoReactiveValue, IntoWidget, ReactiveValue};
 pub use rvue_style::{
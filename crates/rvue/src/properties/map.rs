@@ -32,8 +32,10 @@ impl<T: Clone + 'static + Send + Sync> DynClone for T {
     }
 }
 
+/// A type-erased property stored in a [`PropertyMap`]
 pub struct DynProperty {
     type_id: TypeId,
+    type_name: &'static str,
     value: Box<dyn DynClone>,
 }
 
@@ -42,10 +44,34 @@ impl DynProperty {
     where
         P: WidgetProperty,
     {
-        Self { type_id: TypeId::of::<P>(), value: Box::new(value) }
+        Self {
+            type_id: TypeId::of::<P>(),
+            type_name: std::any::type_name::<P>(),
+            value: Box::new(value),
+        }
+    }
+
+    /// The `TypeId` of the stored property type
+    #[inline]
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The name of the stored property type, for debugging
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Check whether the stored property has type `P`
+    #[inline]
+    pub fn is<P: WidgetProperty>(&self) -> bool {
+        self.type_id == TypeId::of::<P>()
     }
 
-    fn downcast<P: WidgetProperty>(&self) -> Option<&P> {
+    /// Get the stored property if it has type `P`
+    #[inline]
+    pub fn downcast<P: WidgetProperty>(&self) -> Option<&P> {
         self.value.as_any().downcast_ref::<P>()
     }
 
@@ -53,6 +79,8 @@ impl DynProperty {
         self.value.as_any_mut().downcast_mut::<P>()
     }
 
+    // Properties are `Send + Sync`, so they cannot own `Gc` pointers; only the computed
+    // styles carry a `Trace` impl that does anything
     fn trace_inner(&self, visitor: &mut impl Visitor) {
         if self.type_id == TypeId::of::<WidgetStyles>() {
             if let Some(styles) = self.downcast::<WidgetStyles>() {
@@ -64,7 +92,13 @@ impl DynProperty {
 
 impl Clone for DynProperty {
     fn clone(&self) -> Self {
-        Self { type_id: self.type_id, value: self.value.clone_box() }
+        Self { type_id: self.type_id, type_name: self.type_name, value: self.value.clone_box() }
+    }
+}
+
+impl std::fmt::Debug for DynProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DynProperty").field(&self.type_name).finish()
     }
 }
 
@@ -99,6 +133,12 @@ impl PropertyMap {
         self.get::<P>().unwrap_or_else(|| P::static_default())
     }
 
+    /// Get a copy of the property, or `default` when it is not set
+    #[inline]
+    pub fn get_or<P: WidgetProperty>(&self, default: P) -> P {
+        self.get::<P>().cloned().unwrap_or(default)
+    }
+
     #[inline]
     pub fn get_mut<P: WidgetProperty>(&mut self) -> Option<&mut P> {
         self.map.get_mut(&TypeId::of::<P>()).and_then(|p| p.downcast_mut::<P>())
@@ -161,6 +201,12 @@ impl PropertyMap {
     pub fn iter_mut(&mut self) -> IterMut<'_> {
         IterMut(self.map.iter_mut())
     }
+
+    /// Iterate over the stored properties, in no particular order
+    #[inline]
+    pub fn values(&self) -> impl Iterator<Item = &DynProperty> {
+        self.map.values()
+    }
 }
 
 pub struct Iter<'a>(std::collections::hash_map::Iter<'a, TypeId, DynProperty>);
@@ -252,6 +298,50 @@ impl GcPropertyMap {
     pub fn get_or_default<P: WidgetProperty>(&self) -> std::cell::Ref<'_, P> {
         std::cell::Ref::map(self.0.borrow(), |m| m.get_or_default::<P>())
     }
+
+    /// Get a copy of the property, or `default` when it is not set
+    #[inline]
+    pub fn get_or<P: WidgetProperty>(&self, default: P) -> P {
+        self.0.borrow().get_or(default)
+    }
+
+    /// Set a property, returning the value it replaced
+    #[inline]
+    pub fn insert<P: WidgetProperty>(&self, value: P) -> Option<P> {
+        self.0.borrow_mut().insert(value)
+    }
+
+    /// Remove a property, returning its value
+    #[inline]
+    pub fn remove<P: WidgetProperty>(&self) -> Option<P> {
+        self.0.borrow_mut().remove::<P>()
+    }
+
+    #[inline]
+    pub fn contains<P: WidgetProperty>(&self) -> bool {
+        self.0.borrow().contains::<P>()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    /// Names of the stored property types, in no particular order
+    pub fn type_names(&self) -> Vec<&'static str> {
+        self.0.borrow().values().map(DynProperty::type_name).collect()
+    }
+}
+
+unsafe impl Trace for GcPropertyMap {
+    fn trace(&self, visitor: &mut impl Visitor) {
+        self.0.trace(visitor);
+    }
 }
 
 impl<P: WidgetProperty> From<P> for GcPropertyMap {
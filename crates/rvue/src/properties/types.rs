@@ -4,8 +4,11 @@
 //! They are orthogonal to styling properties and can be combined freely.
 
 use crate::properties::WidgetProperty;
+use crate::text::span::TextSpan;
+use crate::widgets::show::{Transition, TransitionPhase};
 use rudo_gc::{Trace, Visitor};
 use rvue_style::ComputedStyles;
+use std::time::Instant;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct TextContent(pub String);
@@ -21,6 +24,51 @@ unsafe impl Trace for TextContent {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
 
+/// Styled runs of a rich text component, whose joined text is its `TextContent`
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSpans(pub Vec<TextSpan>);
+
+impl WidgetProperty for TextSpans {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TextSpans = TextSpans(Vec::new());
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for TextSpans {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Whether a text component lets the user select and copy its content
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSelectable(pub bool);
+
+impl WidgetProperty for TextSelectable {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TextSelectable = TextSelectable(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for TextSelectable {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Selected range of a text component as `(anchor, focus)` byte offsets into its content
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSelection(pub Option<(usize, usize)>);
+
+impl WidgetProperty for TextSelection {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TextSelection = TextSelection(None);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for TextSelection {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct WidgetStyles(pub ComputedStyles);
 
@@ -66,6 +114,36 @@ unsafe impl Trace for ForItemCount {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
 
+/// Index of the arm a Switch is rendering, `None` when no arm matches
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchActiveArm(pub Option<usize>);
+
+impl WidgetProperty for SwitchActiveArm {
+    fn static_default() -> &'static Self {
+        static DEFAULT: SwitchActiveArm = SwitchActiveArm(None);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for SwitchActiveArm {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Whether a Suspense boundary is showing its fallback
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuspensePending(pub bool);
+
+impl WidgetProperty for SuspensePending {
+    fn static_default() -> &'static Self {
+        static DEFAULT: SuspensePending = SuspensePending(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for SuspensePending {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct TextInputValue(pub String);
 
@@ -80,6 +158,35 @@ unsafe impl Trace for TextInputValue {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
 
+/// How far a text area's content is scrolled up to keep the cursor in view
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextAreaScrollOffset(pub f64);
+
+impl WidgetProperty for TextAreaScrollOffset {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TextAreaScrollOffset = TextAreaScrollOffset(0.0);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for TextAreaScrollOffset {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextInputScrollOffset(pub f64);
+
+impl WidgetProperty for TextInputScrollOffset {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TextInputScrollOffset = TextInputScrollOffset(0.0);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for TextInputScrollOffset {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct NumberInputValue(pub f64);
 
@@ -94,6 +201,84 @@ unsafe impl Trace for NumberInputValue {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
 
+/// Bounds a NumberInput's steppers and wheel clamp to, and the amount they change it by
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberInputRange {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+impl WidgetProperty for NumberInputRange {
+    fn static_default() -> &'static Self {
+        static DEFAULT: NumberInputRange =
+            NumberInputRange { min: f64::NEG_INFINITY, max: f64::INFINITY, step: 1.0 };
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for NumberInputRange {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Validation error an input shows below itself, which also puts it in the `:invalid` state
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationMessage(pub Option<String>);
+
+impl WidgetProperty for ValidationMessage {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ValidationMessage = ValidationMessage(None);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ValidationMessage {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumberInputSteppers(pub bool);
+
+impl WidgetProperty for NumberInputSteppers {
+    fn static_default() -> &'static Self {
+        static DEFAULT: NumberInputSteppers = NumberInputSteppers(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for NumberInputSteppers {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ButtonSubmit(pub bool);
+
+impl WidgetProperty for ButtonSubmit {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ButtonSubmit = ButtonSubmit(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ButtonSubmit {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// URL a Link points at
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinkHref(pub String);
+
+impl WidgetProperty for LinkHref {
+    fn static_default() -> &'static Self {
+        static DEFAULT: LinkHref = LinkHref(String::new());
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for LinkHref {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct CheckboxChecked(pub bool);
 
@@ -108,6 +293,21 @@ unsafe impl Trace for CheckboxChecked {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
 
+/// Whether a checkbox shows the mixed (indeterminate) state instead of its checked state
+#[derive(Clone, Debug, PartialEq)]
+pub struct CheckboxIndeterminate(pub bool);
+
+impl WidgetProperty for CheckboxIndeterminate {
+    fn static_default() -> &'static Self {
+        static DEFAULT: CheckboxIndeterminate = CheckboxIndeterminate(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for CheckboxIndeterminate {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct RadioValue(pub String);
 
@@ -349,3 +549,122 @@ impl WidgetProperty for ProgressMax {
 unsafe impl Trace for ProgressMax {
     fn trace(&self, _visitor: &mut impl Visitor) {}
 }
+
+/// Whether a progress bar animates a sliding segment instead of showing its value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProgressIndeterminate(pub bool);
+
+impl WidgetProperty for ProgressIndeterminate {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ProgressIndeterminate = ProgressIndeterminate(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ProgressIndeterminate {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Pointer position a context menu is open at, or `None` while it is closed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContextMenuPosition(pub Option<(f64, f64)>);
+
+impl WidgetProperty for ContextMenuPosition {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ContextMenuPosition = ContextMenuPosition(None);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ContextMenuPosition {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Whether a tooltip is showing next to its anchor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TooltipOpen(pub bool);
+
+impl WidgetProperty for TooltipOpen {
+    fn static_default() -> &'static Self {
+        static DEFAULT: TooltipOpen = TooltipOpen(false);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for TooltipOpen {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Enter/exit transition played by a Show component, if any.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShowTransition(pub Option<Transition>);
+
+impl WidgetProperty for ShowTransition {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ShowTransition = ShowTransition(None);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ShowTransition {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Runtime state of a Show component's transition.
+///
+/// `content_visible` stays true while the exit transition plays after `when` turns false.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShowTransitionState {
+    pub content_visible: bool,
+    pub active: Option<(TransitionPhase, Instant)>,
+}
+
+impl WidgetProperty for ShowTransitionState {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ShowTransitionState =
+            ShowTransitionState { content_visible: true, active: None };
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ShowTransitionState {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// Rotation of a spinner's arc, advanced once per frame while it is mounted.
+///
+/// `last_frame` is `None` until the first frame after mounting, so time spent unmounted
+/// does not jump the arc forward.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpinnerRotation {
+    pub angle: f64,
+    pub last_frame: Option<Instant>,
+    pub running: bool,
+}
+
+impl WidgetProperty for SpinnerRotation {
+    fn static_default() -> &'static Self {
+        static DEFAULT: SpinnerRotation =
+            SpinnerRotation { angle: 0.0, last_frame: None, running: true };
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for SpinnerRotation {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
+
+/// When a scroll container last scrolled, used to auto-hide `Overflow::Auto` scrollbars.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollbarActivity(pub Option<Instant>);
+
+impl WidgetProperty for ScrollbarActivity {
+    fn static_default() -> &'static Self {
+        static DEFAULT: ScrollbarActivity = ScrollbarActivity(None);
+        &DEFAULT
+    }
+}
+
+unsafe impl Trace for ScrollbarActivity {
+    fn trace(&self, _visitor: &mut impl Visitor) {}
+}
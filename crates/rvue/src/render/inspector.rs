@@ -0,0 +1,217 @@
+//! Inspector overlay for debugging layouts
+//!
+//! When enabled, every laid-out component is outlined and labelled with its id and size, and
+//! the hovered component is highlighted with its margin, border, padding and content boxes.
+//! The overlay is painted into its own scene on top of the normal one.
+
+use crate::component::{Component, ComponentId};
+use crate::render::widget::render_text_layout;
+use crate::text::{text_layout_builder, BrushIndex, TextContext};
+use parley::Layout;
+use rudo_gc::Gc;
+use vello::kurbo::{Affine, BezPath, Point, Rect, Shape, Stroke, Vec2};
+use vello::peniko::{Color, Fill};
+
+const BOUNDS_COLOR: Color = Color::from_rgba8(0, 120, 255, 160);
+const MARGIN_COLOR: Color = Color::from_rgba8(246, 178, 107, 140);
+const BORDER_COLOR: Color = Color::from_rgba8(255, 229, 153, 140);
+const PADDING_COLOR: Color = Color::from_rgba8(147, 196, 125, 140);
+const CONTENT_COLOR: Color = Color::from_rgba8(111, 168, 220, 140);
+const LABEL_BACKGROUND: Color = Color::from_rgba8(0, 0, 0, 180);
+const LABEL_FONT_SIZE: f32 = 10.0;
+const LABEL_PADDING: f64 = 2.0;
+
+/// Part of the overlay drawn for a component
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverlayKind {
+    /// Outline of the component's border box
+    Bounds,
+    /// Margin area of the hovered component
+    Margin,
+    /// Border area of the hovered component
+    Border,
+    /// Padding area of the hovered component
+    Padding,
+    /// Content box of the hovered component
+    Content,
+}
+
+/// One shape of the inspector overlay, in window coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OverlayItem {
+    pub component_id: ComponentId,
+    pub kind: OverlayKind,
+    pub rect: Rect,
+    /// Area cut out of `rect`, so box model layers only cover their own band
+    pub hole: Option<Rect>,
+}
+
+/// Runtime inspector, toggled with F12 in a running app
+#[derive(Debug, Default)]
+pub struct Inspector {
+    enabled: bool,
+}
+
+impl Inspector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Flip between enabled and disabled, returning the new state
+    pub fn toggle(&mut self) -> bool {
+        self.enabled = !self.enabled;
+        self.enabled
+    }
+
+    /// Shapes of the overlay for the tree under `root`, empty while disabled
+    ///
+    /// Bounds come first in tree order, followed by the box model of `hovered`.
+    pub fn overlay_items(
+        &self,
+        root: &Gc<Component>,
+        hovered: Option<&Gc<Component>>,
+    ) -> Vec<OverlayItem> {
+        let mut items = Vec::new();
+        if !self.enabled {
+            return items;
+        }
+        collect_bounds(root, Point::ZERO, &mut items);
+        if let Some(hovered) = hovered {
+            push_box_model(hovered, &mut items);
+        }
+        items
+    }
+
+    /// Paint the overlay for the tree under `root` into `scene`
+    pub fn paint(
+        &self,
+        scene: &mut vello::Scene,
+        root: &Gc<Component>,
+        hovered: Option<&Gc<Component>>,
+        text_context: &mut TextContext,
+    ) {
+        for item in self.overlay_items(root, hovered) {
+            paint_item(scene, &item, text_context);
+        }
+    }
+}
+
+fn layout_of(component: &Gc<Component>) -> Option<taffy::Layout> {
+    component.layout_node.borrow().as_ref().and_then(|node| node.layout_result)
+}
+
+fn collect_bounds(component: &Gc<Component>, parent_origin: Point, items: &mut Vec<OverlayItem>) {
+    // Control-flow components have no box of their own, so show their children instead
+    let Some(layout) = layout_of(component) else {
+        for child in component.children.borrow().iter() {
+            collect_bounds(child, parent_origin, items);
+        }
+        return;
+    };
+    let origin = parent_origin + Vec2::new(layout.location.x as f64, layout.location.y as f64);
+    let size = (layout.size.width as f64, layout.size.height as f64);
+    items.push(OverlayItem {
+        component_id: component.id,
+        kind: OverlayKind::Bounds,
+        rect: Rect::from_origin_size(origin, size),
+        hole: None,
+    });
+
+    let scroll = component.scroll_state();
+    let children_origin =
+        origin - Vec2::new(scroll.scroll_offset_x as f64, scroll.scroll_offset_y as f64);
+    for child in component.children.borrow().iter() {
+        collect_bounds(child, children_origin, items);
+    }
+}
+
+/// Window position of `component`'s border box
+fn window_origin(component: &Gc<Component>) -> Point {
+    let local = crate::event::hit_test::window_to_local(component, Point::ZERO);
+    Point::new(-local.x, -local.y)
+}
+
+fn push_box_model(component: &Gc<Component>, items: &mut Vec<OverlayItem>) {
+    let Some(layout) = layout_of(component) else {
+        return;
+    };
+    let border_box = Rect::from_origin_size(
+        window_origin(component),
+        (layout.size.width as f64, layout.size.height as f64),
+    );
+    let inset = |rect: Rect, edges: taffy::Rect<f32>| {
+        Rect::new(
+            rect.x0 + edges.left as f64,
+            rect.y0 + edges.top as f64,
+            rect.x1 - edges.right as f64,
+            rect.y1 - edges.bottom as f64,
+        )
+    };
+    let outset = |rect: Rect, edges: taffy::Rect<f32>| {
+        Rect::new(
+            rect.x0 - edges.left as f64,
+            rect.y0 - edges.top as f64,
+            rect.x1 + edges.right as f64,
+            rect.y1 + edges.bottom as f64,
+        )
+    };
+    let margin_box = outset(border_box, layout.margin);
+    let padding_box = inset(border_box, layout.border);
+    let content_box = inset(padding_box, layout.padding);
+
+    let layers = [
+        (OverlayKind::Margin, margin_box, Some(border_box)),
+        (OverlayKind::Border, border_box, Some(padding_box)),
+        (OverlayKind::Padding, padding_box, Some(content_box)),
+        (OverlayKind::Content, content_box, None),
+    ];
+    for (kind, rect, hole) in layers {
+        items.push(OverlayItem { component_id: component.id, kind, rect, hole });
+    }
+}
+
+fn paint_item(scene: &mut vello::Scene, item: &OverlayItem, text_context: &mut TextContext) {
+    let fill_color = match item.kind {
+        OverlayKind::Bounds => {
+            scene.stroke(&Stroke::new(1.0), Affine::IDENTITY, BOUNDS_COLOR, None, &item.rect);
+            paint_label(scene, item, text_context);
+            return;
+        }
+        OverlayKind::Margin => MARGIN_COLOR,
+        OverlayKind::Border => BORDER_COLOR,
+        OverlayKind::Padding => PADDING_COLOR,
+        OverlayKind::Content => CONTENT_COLOR,
+    };
+
+    let mut band = BezPath::new();
+    band.extend(item.rect.path_elements(0.1));
+    if let Some(hole) = item.hole {
+        band.extend(hole.path_elements(0.1));
+    }
+    scene.fill(Fill::EvenOdd, Affine::IDENTITY, fill_color, None, &band);
+}
+
+/// Label a component's bounds with its id and size, e.g. `#12 120×40`
+fn paint_label(scene: &mut vello::Scene, item: &OverlayItem, text_context: &mut TextContext) {
+    let label = format!("#{} {}×{}", item.component_id, item.rect.width(), item.rect.height());
+    let mut layout: Layout<BrushIndex> =
+        text_layout_builder(text_context, &label, LABEL_FONT_SIZE).build(&label);
+    layout.break_all_lines(None);
+
+    let origin = item.rect.origin();
+    let background = Rect::from_origin_size(
+        origin,
+        (layout.width() as f64 + 2.0 * LABEL_PADDING, layout.height() as f64 + 2.0 * LABEL_PADDING),
+    );
+    scene.fill(Fill::NonZero, Affine::IDENTITY, LABEL_BACKGROUND, None, &background);
+    let transform = Affine::translate(origin.to_vec2() + Vec2::new(LABEL_PADDING, LABEL_PADDING));
+    render_text_layout(&layout, scene, transform, Color::WHITE);
+}
@@ -1,8 +1,12 @@
 //! Render module for Vello integration
 
+#[cfg(feature = "inspector")]
+pub mod inspector;
 pub mod scene;
 pub mod widget;
 
+#[cfg(feature = "inspector")]
+pub use inspector::{Inspector, OverlayItem, OverlayKind};
 pub use scene::Scene;
 pub use widget::{render_component, FlexScrollState};
 
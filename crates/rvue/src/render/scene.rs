@@ -1,16 +1,16 @@
 //! Scene graph management for Vello rendering
 
 use crate::component::build_layout_tree;
-use crate::component::Component;
-use crate::layout::node::LayoutNode;
+use crate::component::{Component, ComponentType};
+use crate::layout::node::{shape_text, LayoutNode};
 use crate::render::widget::render_component;
 use crate::style::Stylesheet;
-use crate::text::TextContext;
+use crate::text::{ParleyLayoutWrapper, TextContext};
 use rudo_gc::{Gc, GcCell};
 use rustc_hash::FxHashSet;
 use taffy::prelude::*;
 use taffy::TaffyTree;
-use vello::kurbo::Affine;
+use vello::kurbo::{Affine, Point, Rect, Vec2};
 
 /// Scene structure for managing Vello rendering
 pub struct Scene {
@@ -21,6 +21,10 @@ pub struct Scene {
     pub taffy: TaffyTree<()>,
     pub text_context: TextContext,
     pub stylesheet: Option<Stylesheet>,
+    /// Number of layout passes run so far
+    layout_passes: u64,
+    /// Area the last update redrew, in scene coordinates
+    dirty_rect: Option<Rect>,
 }
 
 impl Scene {
@@ -34,6 +38,8 @@ impl Scene {
             taffy: TaffyTree::new(),
             text_context: TextContext::new(),
             stylesheet: None,
+            layout_passes: 0,
+            dirty_rect: None,
         }
     }
 
@@ -65,86 +71,164 @@ impl Scene {
     fn clear_all_component_layout_nodes(components: &[Gc<Component>]) {
         for component in components {
             component.set_layout_node(LayoutNode::new());
+            // Sizes may change with the new layout, so nothing drawn for the old one is reused
+            component.clear_render_caches();
             Self::clear_all_component_layout_nodes(&component.children.borrow());
         }
     }
 
+    /// Reshape paint-dirty text in place, marking it for relayout when its size changed
+    fn reshape_dirty_text(&mut self, component: &Gc<Component>) {
+        if !component.is_paint_dirty() || component.is_layout_dirty() {
+            return;
+        }
+        if component.component_type == ComponentType::Text {
+            let (layout, size) = shape_text(component, &mut self.text_context);
+            let laid_out = component
+                .layout_node()
+                .and_then(|node| node.taffy_node())
+                .and_then(|node| self.taffy.style(node).ok())
+                .map(|style| style.size);
+            if laid_out == Some(size) {
+                *component.user_data.borrow_mut_gen_only() =
+                    Some(Box::new(ParleyLayoutWrapper(layout)));
+            } else {
+                component.mark_layout_dirty();
+                return;
+            }
+        }
+        let children = component.children.borrow().clone();
+        for child in &children {
+            self.reshape_dirty_text(child);
+        }
+    }
+
+    /// Union the bounds of components whose own scene must be redrawn, walking only into
+    /// dirty subtrees. `origin` is the scene position `component`'s location is relative to.
+    fn collect_dirty_rect(component: &Gc<Component>, origin: Point, dirty: &mut Option<Rect>) {
+        if !component.is_dirty() {
+            return;
+        }
+        let layout = component.layout_node().and_then(|node| node.layout().copied());
+        if let Some(layout) = layout.filter(|_| component.vello_cache.borrow().is_none()) {
+            union_rect(dirty, layout_rect(&layout, origin));
+        }
+        let origin = layout.map_or(origin, |layout| layout_rect(&layout, origin).origin());
+
+        // Children are drawn shifted against the scroll offset
+        let scroll = component.scroll_state();
+        let children_origin =
+            origin - Vec2::new(scroll.scroll_offset_x as f64, scroll.scroll_offset_y as f64);
+        for child in component.children.borrow().iter() {
+            Self::collect_dirty_rect(child, children_origin, dirty);
+        }
+    }
+
+    /// Area redrawn by the last [`Scene::update`], in scene coordinates, or `None` if it
+    /// redrew nothing. A relayout redraws every root.
+    pub fn dirty_rect(&self) -> Option<Rect> {
+        self.dirty_rect
+    }
+
     /// Update the scene by regenerating dirty fragments
+    ///
+    /// Layout is rebuilt only when the scene or a component is layout-dirty; paint-only
+    /// changes re-render the dirty components over the existing layout.
+    ///
+    /// Runs inside a `scene_update` tracing span, with `layout` and `paint` spans per root.
     pub fn update(&mut self) {
         let root_components = self.root_components.borrow().clone();
         let any_dirty = root_components.iter().any(|c| c.is_dirty());
         if !self.is_dirty && !any_dirty {
+            self.dirty_rect = None;
             return;
         }
+        let _span = tracing::debug_span!("scene_update").entered();
 
         self.ensure_initialized();
 
-        if self.is_dirty || any_dirty {
-            if let Some(ref mut scene) = self.vello_scene {
-                scene.reset();
+        if !self.is_dirty {
+            for component in &root_components {
+                self.reshape_dirty_text(component);
             }
+        }
+        let rebuild_layout = self.needs_layout();
+
+        if let Some(ref mut scene) = self.vello_scene {
+            scene.reset();
+        }
+        if rebuild_layout {
             self.taffy = TaffyTree::new();
             Self::clear_all_component_layout_nodes(&root_components);
         }
 
         let mut already_appended = FxHashSet::default();
-
-        let force_rebuild_layout = self.is_dirty || any_dirty;
+        let mut dirty_rect = None;
 
         for component in &root_components {
-            crate::effect::set_defer_effect_run(true);
-
-            let layout = build_layout_tree(
-                component,
-                &mut self.taffy,
-                &mut self.text_context,
-                self.stylesheet.as_ref(),
-            );
-            component.set_layout_node(layout.clone());
-
-            crate::effect::flush_pending_effects();
-            crate::effect::set_defer_effect_run(false);
-
-            if let Some(node_id) = layout.taffy_node() {
-                if let Err(e) = self.taffy.compute_layout(node_id, Size::MAX_CONTENT) {
-                    log::error!("Scene layout calculation failed: {:?}", e);
+            if rebuild_layout {
+                let _span = tracing::debug_span!("layout", root = component.id).entered();
+                crate::effect::set_defer_effect_run(true);
+
+                let layout = build_layout_tree(
+                    component,
+                    &mut self.taffy,
+                    &mut self.text_context,
+                    self.stylesheet.as_ref(),
+                );
+                component.set_layout_node(layout.clone());
+
+                crate::effect::flush_pending_effects();
+                crate::effect::set_defer_effect_run(false);
+
+                if let Some(node_id) = layout.taffy_node() {
+                    self.layout_passes += 1;
+                    if let Err(e) = self.taffy.compute_layout(node_id, Size::MAX_CONTENT) {
+                        log::error!("Scene layout calculation failed: {:?}", e);
+                    }
                 }
+
+                crate::component::propagate_layout_results(component, &self.taffy);
             }
 
-            crate::component::propagate_layout_results(component, &self.taffy);
+            if !rebuild_layout {
+                Self::collect_dirty_rect(component, Point::ZERO, &mut dirty_rect);
+            } else if let Some(layout) = component.layout_node().and_then(|n| n.layout().copied()) {
+                union_rect(&mut dirty_rect, layout_rect(&layout, Point::ZERO));
+            }
 
             if let Some(ref mut scene) = self.vello_scene {
-                let comp_dirty = component.is_dirty();
-                let cache_none = component.vello_cache.borrow().is_none();
-
-                if comp_dirty || force_rebuild_layout || cache_none {
-                    *component.vello_cache.borrow_mut_gen_only() = None;
-                    render_component(
-                        component,
-                        scene,
-                        Affine::IDENTITY,
-                        &mut already_appended,
-                        self.stylesheet.as_ref(),
-                        &mut self.text_context,
-                    );
-                } else if let Some(ref cached) = *component.vello_cache.borrow() {
-                    scene.append(&cached.0, Some(Affine::IDENTITY));
-                } else {
-                    render_component(
-                        component,
-                        scene,
-                        Affine::IDENTITY,
-                        &mut already_appended,
-                        self.stylesheet.as_ref(),
-                        &mut self.text_context,
-                    );
-                }
+                let _span = tracing::debug_span!("paint", root = component.id).entered();
+                render_component(
+                    component,
+                    scene,
+                    Affine::IDENTITY,
+                    &mut already_appended,
+                    self.stylesheet.as_ref(),
+                    &mut self.text_context,
+                );
             }
         }
 
+        if let Some(rect) = dirty_rect {
+            log::trace!("Scene repainted {rect:?}");
+        }
+        self.dirty_rect = dirty_rect;
         self.is_dirty = false;
     }
 
+    /// Whether the next update has to rebuild layout, rather than reuse the previous results.
+    ///
+    /// Layout dirtiness propagates up to the roots, so checking them covers the whole tree.
+    pub fn needs_layout(&self) -> bool {
+        self.is_dirty || self.root_components.borrow().iter().any(|c| c.is_layout_dirty())
+    }
+
+    /// Number of times `compute_layout` has run on a root, for profiling and tests
+    pub fn layout_passes(&self) -> u64 {
+        self.layout_passes
+    }
+
     /// Mark the scene as dirty (needs re-render)
     pub fn mark_dirty(&mut self) {
         self.is_dirty = true;
@@ -168,6 +252,16 @@ impl Scene {
     }
 }
 
+/// Bounds of a layout box whose location is relative to `origin`
+fn layout_rect(layout: &taffy::Layout, origin: Point) -> Rect {
+    let location = Vec2::new(layout.location.x as f64, layout.location.y as f64);
+    Rect::from_origin_size(origin + location, (layout.size.width as f64, layout.size.height as f64))
+}
+
+fn union_rect(dirty: &mut Option<Rect>, bounds: Rect) {
+    *dirty = Some(dirty.map_or(bounds, |rect| rect.union(bounds)));
+}
+
 impl Default for Scene {
     fn default() -> Self {
         Self::new()
@@ -2,16 +2,29 @@
 
 use crate::component::{Component, ComponentType, SceneWrapper};
 use crate::style::{resolve_styles_for_component, Stylesheet};
-use crate::text::{BrushIndex, ParleyLayoutWrapper};
-use crate::widgets::scroll_bar::{render_horizontal_scrollbar, render_vertical_scrollbar};
+use crate::text::span::span_brush_color;
+use crate::text::{text_layout_builder, BrushIndex, ParleyLayoutWrapper};
+use crate::widgets::canvas::CanvasDraw;
+use crate::widgets::input::{
+    stepper_rects, VALIDATION_MESSAGE_FONT_SIZE, VALIDATION_MESSAGE_HEIGHT,
+};
+use crate::widgets::progress::{
+    indeterminate_elapsed, indeterminate_fill_rect, progress_fill_rect,
+};
+use crate::widgets::scroll_bar::{
+    auto_scrollbar_visible, render_horizontal_scrollbar, render_vertical_scrollbar,
+};
+use crate::widgets::show::{TransitionKind, TransitionPhase};
+use crate::widgets::text_area::{text_area_display_text, text_area_layout};
 use parley::Cluster;
-use parley::FontStack;
 use parley::Layout;
 use parley::PositionedLayoutItem;
 use rudo_gc::Gc;
 use rustc_hash::FxHashSet;
-use rvue_style::{BorderStyle, ComputedStyles};
-use vello::kurbo::{Affine, Circle, Rect, RoundedRect, Stroke};
+use rvue_style::{BorderStyle, ComputedStyles, TextDecoration};
+use vello::kurbo::{
+    Affine, Arc, BezPath, Cap, Circle, Line, Point, Rect, RoundedRect, Size, Stroke,
+};
 use vello::peniko::Color;
 
 /// Scroll state for Flex widgets with overflow
@@ -63,10 +76,21 @@ pub fn render_component(
     stylesheet: Option<&Stylesheet>,
     text_context: &mut crate::text::TextContext,
 ) -> bool {
-    let is_dirty = component.is_dirty();
+    // Dirtiness propagates to ancestors, so a clean component has a clean subtree whose
+    // composited scene can be appended without walking the children
+    if !component.is_dirty() {
+        if let Some(SceneWrapper(ref subtree)) = *component.subtree_cache.borrow() {
+            if already_appended.insert(component.id) {
+                scene.append(subtree, Some(transform));
+            }
+            return false;
+        }
+    }
+
+    // Marking a component dirty drops its scene, while ancestors only lose their subtree cache
     let cache_was_none = component.vello_cache.borrow().is_none();
 
-    if is_dirty || cache_was_none {
+    if cache_was_none {
         let mut local_scene = vello::Scene::new();
 
         match &component.component_type {
@@ -85,6 +109,15 @@ pub fn render_component(
                     text_context,
                 );
             }
+            ComponentType::TextArea => {
+                render_text_area(
+                    component,
+                    &mut local_scene,
+                    Affine::IDENTITY,
+                    stylesheet,
+                    text_context,
+                );
+            }
             ComponentType::NumberInput => {
                 render_number_input(
                     component,
@@ -103,46 +136,162 @@ pub fn render_component(
             ComponentType::Flex => {
                 render_flex_background(component, &mut local_scene, stylesheet);
             }
+            ComponentType::ContextMenu => {
+                render_context_menu(component, &mut local_scene, stylesheet);
+            }
+            ComponentType::Tooltip => {
+                render_tooltip(component, &mut local_scene, stylesheet);
+            }
+            ComponentType::ProgressBar => {
+                render_progress_bar(component, &mut local_scene, stylesheet);
+            }
+            ComponentType::Spinner => {
+                render_spinner(component, &mut local_scene, stylesheet);
+            }
+            ComponentType::Canvas => {
+                render_canvas(component, &mut local_scene);
+            }
             _ => {}
         }
 
+        if let Some(message) = component.error_message() {
+            render_validation_message(component, &message, &mut local_scene, text_context);
+        }
+
         *component.vello_cache.borrow_mut_gen_only() = Some(SceneWrapper(local_scene));
-        component.clear_dirty();
     }
+    component.clear_dirty();
 
+    // Children are drawn relative to this component, so the subtree can be reused anywhere
+    let mut subtree = vello::Scene::new();
     if !already_appended.contains(&component.id) {
         if let Some(SceneWrapper(ref local_scene)) = *component.vello_cache.borrow() {
-            scene.append(local_scene, Some(transform));
+            subtree.append(local_scene, None);
             already_appended.insert(component.id);
         }
     }
 
     let should_render_children = match &component.component_type {
-        ComponentType::Show => component.show_when(),
-        ComponentType::For => true,
+        ComponentType::Show => component.show_content_visible(),
+        ComponentType::For | ComponentType::Switch | ComponentType::Suspense => true,
         ComponentType::Flex => true,
+        ComponentType::ContextMenu => component.context_menu_position().is_some(),
+        ComponentType::Tooltip => component.tooltip_open(),
         _ => !component.children.borrow().is_empty(),
     };
 
-    let force_render_children = matches!(
-        &component.component_type,
-        ComponentType::For | ComponentType::Flex | ComponentType::Show
-    );
-
     // Render children
     if should_render_children {
+        let (children_transform, pushed_layer) =
+            push_show_transition(component, &mut subtree, Affine::IDENTITY);
         render_children(
             component,
-            scene,
-            transform,
+            &mut subtree,
+            children_transform,
             already_appended,
-            force_render_children,
             stylesheet,
             text_context,
         );
+        if pushed_layer {
+            subtree.pop_layer();
+        }
     }
 
-    is_dirty || cache_was_none
+    scene.append(&subtree, Some(transform));
+    *component.subtree_cache.borrow_mut_gen_only() = Some(SceneWrapper(subtree));
+
+    cache_was_none
+}
+
+/// Half-extent of a clip rect along an axis that does not clip
+const UNCLIPPED_EXTENT: f64 = 1.0e5;
+
+/// Clip rect for a container's children in its local coordinates, or `None` if neither axis
+/// clips.
+///
+/// Each axis is clipped on its own: an axis whose overflow is `Visible` spans far past the
+/// container, so `overflow_x: hidden, overflow_y: visible` only cuts content off at the sides.
+pub fn overflow_clip_rect(
+    overflow_x: rvue_style::properties::Overflow,
+    overflow_y: rvue_style::properties::Overflow,
+    size: Size,
+) -> Option<Rect> {
+    if !overflow_x.should_clip() && !overflow_y.should_clip() {
+        return None;
+    }
+    let (x0, x1) = if overflow_x.should_clip() {
+        (0.0, size.width)
+    } else {
+        (-UNCLIPPED_EXTENT, UNCLIPPED_EXTENT)
+    };
+    let (y0, y1) = if overflow_y.should_clip() {
+        (0.0, size.height)
+    } else {
+        (-UNCLIPPED_EXTENT, UNCLIPPED_EXTENT)
+    };
+    Some(Rect::new(x0, y0, x1, y1))
+}
+
+/// Distance in pixels that `Slide` content travels while entering or leaving
+const SLIDE_TRANSITION_DISTANCE: f64 = 16.0;
+
+/// Apply a running Show transition to its children.
+///
+/// Returns the transform to render the children with and whether an alpha layer was pushed.
+fn push_show_transition(
+    component: &Gc<Component>,
+    scene: &mut vello::Scene,
+    transform: Affine,
+) -> (Affine, bool) {
+    let (Some(transition), Some((phase, progress))) = (
+        component.show_transition(),
+        component.show_transition_progress(std::time::Instant::now()),
+    ) else {
+        return (transform, false);
+    };
+
+    let amount = match phase {
+        TransitionPhase::Entering => progress,
+        TransitionPhase::Exiting => 1.0 - progress,
+    };
+    let transform = match transition.kind {
+        TransitionKind::Fade | TransitionKind::Collapse => transform,
+        TransitionKind::Slide => {
+            transform * Affine::translate((0.0, (1.0 - amount) * SLIDE_TRANSITION_DISTANCE))
+        }
+    };
+
+    // Show has no layout box of its own, so fade everything its children draw
+    let unclipped =
+        Rect::new(-UNCLIPPED_EXTENT, -UNCLIPPED_EXTENT, UNCLIPPED_EXTENT, UNCLIPPED_EXTENT);
+    let (alpha, bounds) = match transition.kind {
+        TransitionKind::Fade | TransitionKind::Slide => (amount as f32, unclipped),
+        TransitionKind::Collapse => (1.0, collapse_clip(component, amount)),
+    };
+    scene.push_layer(
+        vello::peniko::Fill::NonZero,
+        vello::peniko::Mix::Normal,
+        alpha,
+        transform,
+        &bounds,
+    );
+    (transform, true)
+}
+
+/// Clip revealing the top `amount` of a Show's children, for the `Collapse` transition
+fn collapse_clip(component: &Gc<Component>, amount: f64) -> Rect {
+    let mut top = f64::INFINITY;
+    let mut bottom = f64::NEG_INFINITY;
+    for child in component.children.borrow().iter() {
+        if let Some(layout) = child.layout_node().and_then(|ln| ln.layout().copied()) {
+            top = top.min(layout.location.y as f64);
+            bottom = bottom.max((layout.location.y + layout.size.height) as f64);
+        }
+    }
+    if top > bottom {
+        return Rect::ZERO;
+    }
+    Rect::new(-UNCLIPPED_EXTENT, top, UNCLIPPED_EXTENT, top + (bottom - top) * amount)
 }
 
 fn render_children(
@@ -150,7 +299,6 @@ fn render_children(
     scene: &mut vello::Scene,
     transform: Affine,
     already_appended: &mut FxHashSet<u64>,
-    force_render_children: bool,
     stylesheet: Option<&Stylesheet>,
     text_context: &mut crate::text::TextContext,
 ) {
@@ -159,8 +307,6 @@ fn render_children(
     let overflow_x = styles.overflow_x.unwrap_or(rvue_style::properties::Overflow::Visible);
     let overflow_y = styles.overflow_y.unwrap_or(rvue_style::properties::Overflow::Visible);
 
-    let should_clip = overflow_x.should_clip() || overflow_y.should_clip();
-
     // Get scroll offset for this container
     let scroll_state = component.scroll_state();
     let scroll_offset_x = scroll_state.scroll_offset_x as f64;
@@ -168,31 +314,43 @@ fn render_children(
 
     // Get container layout for clipping
     // Clip is in local coordinates relative to the container
-    let container_rect = if should_clip {
-        component.layout_node().and_then(|ln| {
-            ln.layout().map(|layout| {
-                Rect::new(0.0, 0.0, layout.size.width as f64, layout.size.height as f64)
-            })
+    let container_rect = component.layout_node().and_then(|ln| {
+        ln.layout().and_then(|layout| {
+            let size = Size::new(layout.size.width as f64, layout.size.height as f64);
+            overflow_clip_rect(overflow_x, overflow_y, size)
         })
-    } else {
-        None
-    };
+    });
 
     // Push clip layer if needed
     // Clip stays with container - use transform for positioning
-    if should_clip {
-        if let Some(ref rect) = container_rect {
-            scene.push_clip_layer(vello::peniko::Fill::NonZero, transform, rect);
-        }
+    if let Some(ref rect) = container_rect {
+        scene.push_clip_layer(vello::peniko::Fill::NonZero, transform, rect);
     }
 
-    // Render children
+    // A Suspense boundary draws either its content or its fallback
+    let suspense_visible = match component.component_type {
+        ComponentType::Suspense => Some(component.suspense_visible_child().map(|child| child.id)),
+        _ => None,
+    };
+
+    // Render children, higher z-index on top
     // Apply scroll offset to content (content moves opposite to scroll)
-    for child in component.children.borrow().iter() {
+    for child in children_in_paint_order(component, stylesheet).iter() {
+        if suspense_visible.is_some_and(|visible| visible != Some(child.id)) {
+            continue;
+        }
+
         let child_transform = if let Some(layout_node) = child.layout_node() {
             if let Some(layout) = layout_node.layout() {
                 let tx = layout.location.x as f64;
                 let ty = layout.location.y as f64;
+                if let Some(ref clip) = container_rect {
+                    let child_rect = Rect::from_origin_size(
+                        (tx - scroll_offset_x, ty - scroll_offset_y),
+                        (layout.size.width as f64, layout.size.height as f64),
+                    );
+                    notify_visibility(child, clip.intersect(child_rect).area() > 0.0);
+                }
                 // Subtract scroll offset to move content in opposite direction
                 if scroll_offset_x != 0.0 || scroll_offset_y != 0.0 {
                     Affine::translate((tx - scroll_offset_x, ty - scroll_offset_y))
@@ -206,30 +364,53 @@ fn render_children(
             Affine::IDENTITY
         };
 
-        let is_dirty = child.is_dirty();
-        let cache_was_none = child.vello_cache.borrow().is_none();
-
-        let final_transform = transform * child_transform;
-
-        if force_render_children || is_dirty || cache_was_none {
-            render_component(
-                child,
-                scene,
-                final_transform,
-                already_appended,
-                stylesheet,
-                text_context,
-            );
-        }
+        // Clean children append their cached scene, since the frame is rebuilt from scratch
+        render_component(
+            child,
+            scene,
+            transform * child_transform,
+            already_appended,
+            stylesheet,
+            text_context,
+        );
     }
 
     // Pop clip layer if it was pushed
-    if should_clip && container_rect.is_some() {
+    if container_rect.is_some() {
         scene.pop_layer();
     }
 }
 
-fn get_styles(component: &Gc<Component>, stylesheet: Option<&Stylesheet>) -> ComputedStyles {
+/// Tell an `on_visibility_change` handler whether the child shows inside its container's clip
+fn notify_visibility(child: &Gc<Component>, visible: bool) {
+    let handler = child.event_handlers.borrow().on_visibility_change.clone();
+    if let Some(handler) = handler {
+        handler.notify(visible);
+    }
+}
+
+/// Children of `component` in the order they are painted.
+///
+/// Siblings are sorted by `z_index`, keeping tree order among equal values, so the last child
+/// is the topmost one.
+pub(crate) fn children_in_paint_order(
+    component: &Gc<Component>,
+    stylesheet: Option<&Stylesheet>,
+) -> Vec<Gc<Component>> {
+    let mut children = component.children.borrow().clone();
+    if children.len() > 1 {
+        children.sort_by_cached_key(|child| {
+            get_styles(child, stylesheet).z_index.map(|z| z.0).unwrap_or(0)
+        });
+    }
+    children
+}
+
+/// Styles of `component`, resolved against `stylesheet` when there is one
+pub(crate) fn get_styles(
+    component: &Gc<Component>,
+    stylesheet: Option<&Stylesheet>,
+) -> ComputedStyles {
     match stylesheet {
         Some(sheet) => resolve_styles_for_component(component, sheet),
         None => component.widget_styles().unwrap_or_default(),
@@ -252,7 +433,7 @@ fn render_text(
             .as_ref()
             .map(|tc| {
                 let rgb = tc.0 .0;
-                Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
             })
             .unwrap_or(Color::BLACK);
 
@@ -261,7 +442,21 @@ fn render_text(
             let bg_color = Color::from_rgb8(255, 165, 0);
             scene.fill(vello::peniko::Fill::NonZero, transform, bg_color, None, &rect);
         } else {
-            render_text_layout(layout, scene, transform, brush);
+            if let Some((anchor, focus)) = component.text_selection() {
+                render_text_selection(layout, anchor, focus, scene, transform);
+            }
+            let spans = component.text_spans();
+            let brush_color = |index: &BrushIndex| {
+                span_brush_color(&spans, index)
+                    .map(|c| Color::from_rgba8(c.0.r, c.0.g, c.0.b, c.0.a))
+                    .unwrap_or(brush)
+            };
+            render_text_layout_with(layout, scene, transform, brush_color);
+            let decoration = styles.text_decoration.unwrap_or_default();
+            for (rect, index) in text_decoration_rects(layout, decoration) {
+                let fill = vello::peniko::Fill::NonZero;
+                scene.fill(fill, transform, brush_color(&index), None, &rect);
+            }
         }
     } else if user_data.is_some() {
         let rect = Rect::new(0.0, 0.0, 100.0, 20.0);
@@ -274,11 +469,73 @@ fn render_text(
     }
 }
 
-fn render_text_layout(
+/// Highlight the text between byte offsets `anchor` and `focus`, drawn beneath the glyphs
+fn render_text_selection(
+    layout: &Layout<BrushIndex>,
+    anchor: usize,
+    focus: usize,
+    scene: &mut vello::Scene,
+    transform: Affine,
+) {
+    if anchor == focus {
+        return;
+    }
+    let selection = parley::Selection::new(
+        parley::Cursor::from_byte_index(layout, anchor, parley::Affinity::Downstream),
+        parley::Cursor::from_byte_index(layout, focus, parley::Affinity::Downstream),
+    );
+    let highlight = Color::from_rgba8(51, 144, 255, 96);
+    for (bounds, _) in selection.geometry(layout) {
+        let rect = Rect::new(bounds.x0, bounds.y0, bounds.x1, bounds.y1);
+        scene.fill(vello::peniko::Fill::NonZero, transform, highlight, None, &rect);
+    }
+}
+
+/// Lines drawn for `decoration` across each glyph run of `layout`, with the brush of the run
+///
+/// Offsets and thicknesses come from the font's underline and strikeout metrics, relative to
+/// the run's baseline.
+pub fn text_decoration_rects(
+    layout: &Layout<BrushIndex>,
+    decoration: TextDecoration,
+) -> Vec<(Rect, BrushIndex)> {
+    let mut rects = Vec::new();
+    if decoration == TextDecoration::None {
+        return rects;
+    }
+    for line in layout.lines() {
+        for item in line.items() {
+            if let PositionedLayoutItem::GlyphRun(glyph_run) = item {
+                let metrics = glyph_run.run().metrics();
+                let (offset, size) = match decoration {
+                    TextDecoration::Underline => (metrics.underline_offset, metrics.underline_size),
+                    _ => (metrics.strikethrough_offset, metrics.strikethrough_size),
+                };
+                let x = glyph_run.offset() as f64;
+                let y = (glyph_run.baseline() - offset) as f64;
+                let rect = Rect::new(x, y, x + glyph_run.advance() as f64, y + size as f64);
+                rects.push((rect, glyph_run.style().brush.clone()));
+            }
+        }
+    }
+    rects
+}
+
+pub(crate) fn render_text_layout(
     layout: &Layout<BrushIndex>,
     scene: &mut vello::Scene,
     transform: Affine,
     color: Color,
+) {
+    render_text_layout_with(layout, scene, transform, |_| color);
+}
+
+/// Draw a text layout, coloring each glyph run by its brush
+fn render_text_layout_with(
+    layout: &Layout<BrushIndex>,
+    scene: &mut vello::Scene,
+    transform: Affine,
+    brush_color: impl Fn(&BrushIndex) -> Color,
 ) {
     use parley::PositionedLayoutItem;
     use vello::peniko::Fill;
@@ -314,7 +571,7 @@ fn render_text_layout(
                     .transform(transform)
                     .glyph_transform(glyph_xform)
                     .normalized_coords(coords)
-                    .brush(color)
+                    .brush(brush_color(&glyph_run.style().brush))
                     .hint(true)
                     .draw(fill, vello_glyphs.into_iter());
             }
@@ -342,7 +599,7 @@ fn render_button(
                 .as_ref()
                 .map(|bg| {
                     let rgb = bg.0 .0;
-                    Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                    Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                 })
                 .unwrap_or_else(|| Color::from_rgb8(70, 130, 180));
 
@@ -371,7 +628,7 @@ fn render_text_input(
         .as_ref()
         .map(|tc| {
             let rgb = tc.0 .0;
-            Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
         })
         .unwrap_or(Color::BLACK);
 
@@ -388,7 +645,7 @@ fn render_text_input(
                 .as_ref()
                 .map(|bg| {
                     let rgb = bg.0 .0;
-                    Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                    Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                 })
                 .unwrap_or_else(|| Color::from_rgb8(255, 255, 255));
 
@@ -420,63 +677,49 @@ fn render_text_input(
                 component.text_input_value()
             };
 
-            let font_stack = "Noto Sans CJK SC, Noto Sans CJK, Noto Sans, sans-serif";
-
-            let mut layout_builder = text_context.layout_ctx.ranged_builder(
-                &mut text_context.font_ctx,
-                &text_value,
-                1.0,
-                true,
-            );
-            layout_builder.push_default(parley::style::StyleProperty::FontSize(font_size as f32));
-            layout_builder.push_default(parley::style::StyleProperty::Brush(BrushIndex(0)));
-            layout_builder.push_default(parley::style::FontStack::Source(
-                std::borrow::Cow::Borrowed(font_stack),
-            ));
-
+            let layout_builder = text_layout_builder(text_context, &text_value, font_size as f32);
             let mut text_layout: Layout<BrushIndex> = layout_builder.build(&text_value);
             text_layout.break_all_lines(None);
 
-            if !text_value.is_empty() {
-                render_text_layout(&text_layout, scene, transform, text_color);
+            // The caret sits after any composed text, which is inserted at the cursor
+            let is_focused = *component.is_focused.borrow();
+            let caret_x = component.text_editor().filter(|_| is_focused).map(|editor| {
+                let editor_ref = editor.editor();
+                let mut cursor_idx = editor_ref.selection().cursor();
+                if editor_ref.is_composing() {
+                    cursor_idx += editor_ref.composition().text.chars().count();
+                }
+                get_text_position(&text_value, cursor_idx, font_size, Some(&text_layout)).0
+            });
+
+            // Text wider than the box is scrolled sideways just enough to keep the caret in
+            // view, never past either end; the end leaves room for the caret after the text
+            let max_offset = (text_layout.width() as f64 + 1.0 - width).max(0.0);
+            let mut offset = component.text_input_scroll_offset();
+            if let Some(caret_x) = caret_x {
+                offset = scroll_to_caret(offset, caret_x, width);
             }
+            let offset = offset.clamp(0.0, max_offset);
+            component.set_text_input_scroll_offset(offset);
+            let content_transform = transform * Affine::translate((-offset, 0.0));
 
-            if *component.is_focused.borrow() {
-                if let Some(editor) = component.text_editor() {
-                    let editor_ref = editor.editor();
-                    let selection = editor_ref.selection();
-                    let is_composing = editor_ref.is_composing();
-
-                    let cursor_idx = if is_composing {
-                        let content_count = editor_ref.content().chars().count();
-                        let comp = editor_ref.composition();
-                        let preedit_char_offset = comp.text.chars().take(comp.cursor_start).count();
-                        let preedit_char_count = comp.text.chars().count();
-                        let cursor_idx_rendered = content_count + preedit_char_count;
-                        let cursor_idx_final = content_count + preedit_char_offset;
-                        (cursor_idx_rendered, cursor_idx_final)
-                    } else {
-                        (selection.cursor(), selection.cursor())
-                    };
-
-                    let cursor_pos =
-                        get_text_position(&text_value, cursor_idx.0, font_size, Some(&text_layout));
+            if !text_value.is_empty() {
+                render_text_layout(&text_layout, scene, content_transform, text_color);
+            }
 
-                    component.set_ime_area(cursor_pos.0 - 1.0, 0.0, 2.0, height);
+            if is_focused {
+                if let Some(caret_x) = caret_x {
+                    // The candidate window follows the caret on screen, so it is scrolled too
+                    component.set_ime_area(caret_x - 1.0 - offset, 0.0, 2.0, height);
 
                     if let Some(blink) = component.cursor_blink() {
                         if blink.is_visible() {
                             let cursor_color = Color::BLACK;
 
-                            let cursor_rect = Rect::new(
-                                cursor_pos.0 as f64 - 1.0,
-                                0.0,
-                                cursor_pos.0 as f64 + 1.0,
-                                height,
-                            );
+                            let cursor_rect = Rect::new(caret_x - 1.0, 0.0, caret_x + 1.0, height);
                             scene.fill(
                                 vello::peniko::Fill::NonZero,
-                                transform,
+                                content_transform,
                                 cursor_color,
                                 None,
                                 &cursor_rect,
@@ -495,6 +738,118 @@ fn render_text_input(
     }
 }
 
+/// Scroll offset nearest to `offset` that shows the caret at `caret_x` inside `width`
+///
+/// The caret is 2px wide and centered on `caret_x`, so it keeps a pixel clear of either edge.
+fn scroll_to_caret(offset: f64, caret_x: f64, width: f64) -> f64 {
+    if caret_x - 1.0 < offset {
+        caret_x - 1.0
+    } else if caret_x + 1.0 > offset + width {
+        caret_x + 1.0 - width
+    } else {
+        offset
+    }
+}
+
+fn render_text_area(
+    component: &Gc<Component>,
+    scene: &mut vello::Scene,
+    transform: Affine,
+    stylesheet: Option<&Stylesheet>,
+    text_context: &mut crate::text::TextContext,
+) {
+    let styles = get_styles(component, stylesheet);
+    let Some(area_layout) = component.layout_node.borrow().as_ref().and_then(|n| n.layout_result)
+    else {
+        return;
+    };
+    let width = area_layout.size.width as f64;
+    let height = area_layout.size.height as f64;
+
+    let text_color = styles
+        .text_color
+        .as_ref()
+        .map(|tc| {
+            let rgb = tc.0 .0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or(Color::BLACK);
+    let bg_color = styles
+        .background_color
+        .as_ref()
+        .map(|bg| {
+            let rgb = bg.0 .0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or_else(|| Color::from_rgb8(255, 255, 255));
+    let font_size = styles.font_size.as_ref().map(|fs| fs.0).unwrap_or(14.0);
+    let border_radius = styles.border_radius.as_ref().map(|r| r.0 as f64).unwrap_or(4.0);
+
+    let rounded_rect = RoundedRect::new(0.0, 0.0, width, height, border_radius);
+    scene.fill(vello::peniko::Fill::NonZero, transform, bg_color, None, &rounded_rect);
+    render_border(scene, transform, &styles, 0.0, 0.0, width, height, border_radius);
+
+    let text_value = text_area_display_text(component);
+    let text_layout = text_area_layout(&text_value, font_size, Some(width as f32), text_context);
+
+    // The caret sits after any composed text, matching the single-line input
+    let is_focused = *component.is_focused.borrow();
+    let caret = component.text_editor().filter(|_| is_focused).map(|editor| {
+        let editor = editor.editor();
+        let mut caret_index = editor.selection().cursor();
+        if editor.is_composing() {
+            caret_index += editor.composition().text.chars().count();
+        }
+        let byte_index =
+            text_value.char_indices().nth(caret_index).map(|(i, _)| i).unwrap_or(text_value.len());
+        parley::Cursor::from_byte_index(&text_layout, byte_index, parley::Affinity::Downstream)
+            .geometry(&text_layout, 2.0)
+    });
+
+    // Scroll just enough to keep the caret inside the box
+    let max_offset = (text_layout.height() as f64 - height).max(0.0);
+    let mut offset = component.text_area_scroll_offset().min(max_offset);
+    if let Some(caret) = caret {
+        if caret.y0 < offset {
+            offset = caret.y0;
+        } else if caret.y1 > offset + height {
+            offset = caret.y1 - height;
+        }
+    }
+    let offset = offset.clamp(0.0, max_offset);
+    component.set_text_area_scroll_offset(offset);
+
+    scene.push_clip_layer(
+        vello::peniko::Fill::NonZero,
+        transform,
+        &Rect::new(0.0, 0.0, width, height),
+    );
+    let content_transform = transform * Affine::translate((0.0, -offset));
+    if !text_value.is_empty() {
+        render_text_layout(&text_layout, scene, content_transform, text_color);
+    }
+
+    match caret {
+        Some(caret) => {
+            let caret_rect = Rect::new(caret.x0 - 1.0, caret.y0, caret.x0 + 1.0, caret.y1);
+            component.set_ime_area(caret_rect.x0, caret_rect.y0 - offset, 2.0, caret_rect.height());
+            if component.cursor_blink().is_some_and(|blink| blink.is_visible()) {
+                scene.fill(
+                    vello::peniko::Fill::NonZero,
+                    content_transform,
+                    Color::BLACK,
+                    None,
+                    &caret_rect,
+                );
+            }
+        }
+        None if is_focused => component.clear_ime_area(),
+        None => {}
+    }
+
+    scene.pop_layer();
+}
+
 fn render_number_input(
     component: &Gc<Component>,
     scene: &mut vello::Scene,
@@ -512,7 +867,7 @@ fn render_number_input(
         .as_ref()
         .map(|tc| {
             let rgb = tc.0 .0;
-            Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
         })
         .unwrap_or(Color::BLACK);
 
@@ -529,7 +884,7 @@ fn render_number_input(
                 .as_ref()
                 .map(|bg| {
                     let rgb = bg.0 .0;
-                    Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                    Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                 })
                 .unwrap_or_else(|| Color::from_rgb8(255, 255, 255));
 
@@ -539,26 +894,71 @@ fn render_number_input(
             render_border(scene, transform, &styles, 0.0, 0.0, width, height, border_radius);
 
             if !text_value.is_empty() && text_value != "0" {
-                let mut layout_builder = text_context.layout_ctx.ranged_builder(
-                    &mut text_context.font_ctx,
-                    &text_value,
-                    1.0,
-                    true,
-                );
-                layout_builder.push_default(parley::style::StyleProperty::FontSize(font_size));
-                layout_builder.push_default(parley::style::StyleProperty::Brush(BrushIndex(0)));
-                layout_builder
-                    .push_default(FontStack::Source(std::borrow::Cow::Borrowed("sans-serif")));
-
+                let layout_builder = text_layout_builder(text_context, &text_value, font_size);
                 let mut text_layout: Layout<BrushIndex> = layout_builder.build(&text_value);
                 text_layout.break_all_lines(None);
 
                 render_text_layout(&text_layout, scene, transform, text_color);
             }
+
+            if component.number_input_steppers() {
+                render_number_steppers(scene, transform, Size::new(width, height), text_color);
+            }
         }
     }
 }
 
+/// Draw an input's validation error in the room its layout leaves below it
+fn render_validation_message(
+    component: &Gc<Component>,
+    message: &str,
+    scene: &mut vello::Scene,
+    text_context: &mut crate::text::TextContext,
+) {
+    let Some(height) = component.layout_node().and_then(|ln| ln.layout().map(|l| l.size.height))
+    else {
+        return;
+    };
+    let mut layout: Layout<BrushIndex> =
+        text_layout_builder(text_context, message, VALIDATION_MESSAGE_FONT_SIZE).build(message);
+    layout.break_all_lines(None);
+    let gap = (VALIDATION_MESSAGE_HEIGHT - layout.height()).max(0.0) / 2.0;
+    let transform = Affine::translate((0.0, (height + gap) as f64));
+    render_text_layout(&layout, scene, transform, Color::from_rgb8(220, 38, 38));
+}
+
+/// Draw the up/down stepper buttons of a NumberInput, separated from the field by a line
+fn render_number_steppers(scene: &mut vello::Scene, transform: Affine, size: Size, color: Color) {
+    let (up, down) = stepper_rects(size);
+    let separator = Color::from_rgb8(224, 224, 224);
+    let stroke = Stroke::new(1.0);
+    scene.stroke(
+        &stroke,
+        transform,
+        separator,
+        None,
+        &Line::new((up.x0, 0.0), (up.x0, size.height)),
+    );
+    scene.stroke(&stroke, transform, separator, None, &Line::new((up.x0, up.y1), (up.x1, up.y1)));
+
+    for (rect, pointing_up) in [(up, true), (down, false)] {
+        let center = rect.center();
+        let half_width = (rect.width() / 4.0).min(rect.height() / 3.0);
+        let half_height = half_width / 2.0;
+        let (tip, base) = if pointing_up {
+            (center.y - half_height, center.y + half_height)
+        } else {
+            (center.y + half_height, center.y - half_height)
+        };
+        let mut arrow = BezPath::new();
+        arrow.move_to((center.x - half_width, base));
+        arrow.line_to((center.x, tip));
+        arrow.line_to((center.x + half_width, base));
+        arrow.close_path();
+        scene.fill(vello::peniko::Fill::NonZero, transform, color, None, &arrow);
+    }
+}
+
 fn get_text_position(
     text: &str,
     char_index: usize,
@@ -627,6 +1027,7 @@ fn render_checkbox(
     let styles = get_styles(component, stylesheet);
     let layout_node = component.layout_node();
     let is_checked = component.checkbox_checked();
+    let is_indeterminate = component.checkbox_indeterminate();
 
     if let Some(layout) = layout_node {
         if let Some(checkbox_layout) = layout.layout() {
@@ -638,7 +1039,7 @@ fn render_checkbox(
                 .as_ref()
                 .map(|bg| {
                     let rgb = bg.0 .0;
-                    Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                    Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                 })
                 .unwrap_or_else(|| Color::from_rgb8(255, 255, 255));
 
@@ -650,19 +1051,25 @@ fn render_checkbox(
 
             render_border(scene, transform, &styles, 0.0, 0.0, size, size, border_radius);
 
-            if is_checked {
+            if is_checked || is_indeterminate {
                 let checked_color = styles
                     .color
                     .as_ref()
                     .map(|c| {
                         let rgb = c.0;
-                        Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                        Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                     })
                     .unwrap_or_else(|| Color::from_rgb8(0, 120, 215));
 
+                // The mixed state draws a horizontal dash in place of the check mark
                 let inner_size = size - 6.0;
-                let inner_rect =
-                    RoundedRect::new(3.0, 3.0, inner_size, inner_size, border_radius.max(2.0));
+                let inner_rect = if is_indeterminate {
+                    let dash_height = (size * 0.2).max(2.0);
+                    let top = (size - dash_height) / 2.0;
+                    RoundedRect::new(3.0, top, inner_size, top + dash_height, dash_height / 2.0)
+                } else {
+                    RoundedRect::new(3.0, 3.0, inner_size, inner_size, border_radius.max(2.0))
+                };
                 scene.fill(
                     vello::peniko::Fill::NonZero,
                     transform,
@@ -696,7 +1103,7 @@ fn render_radio(
                 .as_ref()
                 .map(|bg| {
                     let rgb = bg.0 .0;
-                    Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                    Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                 })
                 .unwrap_or_else(|| Color::from_rgb8(255, 255, 255));
 
@@ -711,7 +1118,7 @@ fn render_radio(
                     .as_ref()
                     .map(|border| {
                         let rgb = border.0 .0;
-                        Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                        Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                     })
                     .unwrap_or_else(|| Color::from_rgb8(100, 100, 100));
 
@@ -734,7 +1141,7 @@ fn render_radio(
                     .as_ref()
                     .map(|c| {
                         let rgb = c.0;
-                        Color::from_rgb8(rgb.r, rgb.g, rgb.b)
+                        Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
                     })
                     .unwrap_or_else(|| Color::from_rgb8(0, 120, 215));
 
@@ -767,7 +1174,7 @@ fn render_border(
     {
         if *bs != BorderStyle::None {
             let rgb = border.0 .0;
-            let border_color = Color::from_rgb8(rgb.r, rgb.g, rgb.b);
+            let border_color = Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a);
             let border_width = bw.0;
 
             let half_width = border_width as f64 / 2.0;
@@ -789,6 +1196,190 @@ fn render_border(
     }
 }
 
+fn render_context_menu(
+    component: &Gc<Component>,
+    scene: &mut vello::Scene,
+    stylesheet: Option<&Stylesheet>,
+) {
+    if component.context_menu_position().is_none() {
+        return;
+    }
+
+    let styles = get_styles(component, stylesheet);
+    let Some(layout) = component.layout_node().and_then(|n| n.layout_result) else {
+        return;
+    };
+    let width = layout.size.width as f64;
+    let height = layout.size.height as f64;
+
+    let bg_color = styles
+        .background_color
+        .as_ref()
+        .map(|bg| {
+            let rgb = bg.0 .0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or(Color::WHITE);
+    let border_radius = styles.border_radius.as_ref().map(|r| r.0 as f64).unwrap_or(4.0);
+
+    let rounded_rect = RoundedRect::new(0.0, 0.0, width, height, border_radius);
+    scene.fill(vello::peniko::Fill::NonZero, Affine::IDENTITY, bg_color, None, &rounded_rect);
+
+    if styles.border_color.is_some() {
+        render_border(scene, Affine::IDENTITY, &styles, 0.0, 0.0, width, height, border_radius);
+    } else {
+        scene.stroke(
+            &Stroke::new(1.0),
+            Affine::IDENTITY,
+            Color::from_rgb8(180, 180, 180),
+            None,
+            &rounded_rect,
+        );
+    }
+}
+
+fn render_tooltip(
+    component: &Gc<Component>,
+    scene: &mut vello::Scene,
+    stylesheet: Option<&Stylesheet>,
+) {
+    if !component.tooltip_open() {
+        return;
+    }
+
+    let styles = get_styles(component, stylesheet);
+    let Some(layout) = component.layout_node().and_then(|n| n.layout_result) else {
+        return;
+    };
+
+    let bg_color = styles
+        .background_color
+        .as_ref()
+        .map(|bg| {
+            let rgb = bg.0 .0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or(Color::from_rgba8(40, 40, 40, 235));
+    let border_radius = styles.border_radius.as_ref().map(|r| r.0 as f64).unwrap_or(4.0);
+
+    let rounded_rect = RoundedRect::new(
+        0.0,
+        0.0,
+        layout.size.width as f64,
+        layout.size.height as f64,
+        border_radius,
+    );
+    scene.fill(vello::peniko::Fill::NonZero, Affine::IDENTITY, bg_color, None, &rounded_rect);
+}
+
+fn render_progress_bar(
+    component: &Gc<Component>,
+    scene: &mut vello::Scene,
+    stylesheet: Option<&Stylesheet>,
+) {
+    let styles = get_styles(component, stylesheet);
+    let Some(layout) = component.layout_node().and_then(|n| n.layout_result) else {
+        return;
+    };
+    let track = Size::new(layout.size.width as f64, layout.size.height as f64);
+
+    let track_color = styles
+        .background_color
+        .as_ref()
+        .map(|bg| {
+            let rgb = bg.0 .0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or_else(|| Color::from_rgb8(224, 224, 224));
+    let fill_color = styles
+        .color
+        .as_ref()
+        .map(|c| {
+            let rgb = c.0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or_else(|| Color::from_rgb8(0, 120, 215));
+    // Pill-shaped unless the styles say otherwise
+    let border_radius =
+        styles.border_radius.as_ref().map(|r| r.0 as f64).unwrap_or(track.height / 2.0);
+
+    let track_rect = RoundedRect::from_rect(track.to_rect(), border_radius);
+    scene.fill(vello::peniko::Fill::NonZero, Affine::IDENTITY, track_color, None, &track_rect);
+
+    let fill = if component.progress_indeterminate() {
+        indeterminate_fill_rect(track, indeterminate_elapsed(std::time::Instant::now()))
+    } else {
+        progress_fill_rect(track, component.progress_value())
+    };
+    if fill.width() > 0.0 {
+        let fill_rect = RoundedRect::from_rect(fill, border_radius);
+        scene.fill(vello::peniko::Fill::NonZero, Affine::IDENTITY, fill_color, None, &fill_rect);
+    }
+
+    render_border(
+        scene,
+        Affine::IDENTITY,
+        &styles,
+        0.0,
+        0.0,
+        track.width,
+        track.height,
+        border_radius,
+    );
+}
+
+fn render_spinner(
+    component: &Gc<Component>,
+    scene: &mut vello::Scene,
+    stylesheet: Option<&Stylesheet>,
+) {
+    let styles = get_styles(component, stylesheet);
+    let Some(layout) = component.layout_node().and_then(|n| n.layout_result) else {
+        return;
+    };
+
+    let color = styles
+        .color
+        .as_ref()
+        .map(|c| {
+            let rgb = c.0;
+            Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a)
+        })
+        .unwrap_or_else(|| Color::from_rgb8(0, 120, 215));
+
+    // A three-quarter arc, its stroke an eighth of the spinner's size
+    let size = (layout.size.width as f64).min(layout.size.height as f64);
+    let stroke_width = (size / 8.0).max(1.0);
+    let radius = (size - stroke_width) / 2.0;
+    let center = Point::new(layout.size.width as f64 / 2.0, layout.size.height as f64 / 2.0);
+    let start = component.spinner_angle() - std::f64::consts::FRAC_PI_2;
+    let arc = Arc::new(center, (radius, radius), start, 1.5 * std::f64::consts::PI, 0.0);
+    scene.stroke(
+        &Stroke::new(stroke_width).with_caps(Cap::Round),
+        Affine::IDENTITY,
+        color,
+        None,
+        &arc,
+    );
+}
+
+fn render_canvas(component: &Gc<Component>, scene: &mut vello::Scene) {
+    let Some(layout) = component.layout_node.borrow().as_ref().and_then(|node| node.layout_result)
+    else {
+        return;
+    };
+    let draw = component.canvas_draw.borrow().clone();
+    let Some(CanvasDraw(draw)) = draw else {
+        return;
+    };
+
+    let size = Size::new(layout.size.width as f64, layout.size.height as f64);
+    let bounds = Rect::from_origin_size((0.0, 0.0), size);
+    scene.push_clip_layer(vello::peniko::Fill::NonZero, Affine::IDENTITY, &bounds);
+    draw(scene, size);
+    scene.pop_layer();
+}
+
 fn render_flex_background(
     component: &Gc<Component>,
     scene: &mut vello::Scene,
@@ -810,7 +1401,7 @@ fn render_flex_background(
 
             if let Some(bg) = styles.background_color.as_ref() {
                 let rgb = bg.0 .0;
-                let bg_color = Color::from_rgb8(rgb.r, rgb.g, rgb.b);
+                let bg_color = Color::from_rgba8(rgb.r, rgb.g, rgb.b, rgb.a);
                 scene.fill(
                     vello::peniko::Fill::NonZero,
                     Affine::IDENTITY,
@@ -833,13 +1424,21 @@ fn render_flex_background(
                 let scroll_state = get_or_create_scroll_state(component);
 
                 // Check if we need to show scrollbars
+                // Auto scrollbars only show while hovered or shortly after scrolling
+                let auto_visible = auto_scrollbar_visible(
+                    *component.is_hovered.borrow(),
+                    component.scroll_activity(),
+                    std::time::Instant::now(),
+                );
                 let show_vertical = matches!(overflow_y, rvue_style::properties::Overflow::Scroll)
                     || (matches!(overflow_y, rvue_style::properties::Overflow::Auto)
-                        && scroll_state.scroll_height > 0.0);
+                        && scroll_state.scroll_height > 0.0
+                        && auto_visible);
                 let show_horizontal =
                     matches!(overflow_x, rvue_style::properties::Overflow::Scroll)
                         || (matches!(overflow_x, rvue_style::properties::Overflow::Auto)
-                            && scroll_state.scroll_width > 0.0);
+                            && scroll_state.scroll_width > 0.0
+                            && auto_visible);
 
                 // Render vertical scrollbar if needed
                 if show_vertical {
@@ -133,6 +133,19 @@ impl<T: Trace + Clone + 'static> SignalDataExt for SignalData<T> {
     }
 }
 
+/// Drop an effect from a signal's subscriber list, releasing the strong reference it held.
+pub(crate) fn remove_subscriber(signal_ptr: usize, effect_ptr: usize) {
+    STRONG_SIGNAL_SUBS.with(|subs| {
+        let mut subs = subs.borrow_mut();
+        if let Some(list) = subs.get_mut(&signal_ptr) {
+            list.retain(|e| e.as_ptr() as usize != effect_ptr);
+            if list.is_empty() {
+                subs.remove(&signal_ptr);
+            }
+        }
+    });
+}
+
 #[allow(dead_code)]
 pub(crate) fn unsubscribe_by_ptr(signal_ptr: *const (), weak_opaque: &Weak<()>) {
     unsafe {
@@ -187,6 +200,40 @@ impl<T: Trace + Clone + 'static> ReadSignal<T> {
         self.data.get()
     }
 
+    /// Derive a memo of `f` applied to this signal's value
+    pub fn map<U, F>(&self, f: F) -> ReadSignal<U>
+    where
+        U: Trace + Clone + 'static,
+        F: Fn(T) -> U + 'static,
+    {
+        let source = self.clone();
+        create_memo(move || f(source.get()))
+    }
+
+    /// Derive a memo of the values `f` maps to `Some`
+    ///
+    /// When `f` returns `None` the memo keeps the last `Some` value; it is `None` only until the
+    /// first value passes.
+    pub fn filter_map<U, F>(&self, f: F) -> ReadSignal<Option<U>>
+    where
+        U: Trace + Clone + 'static,
+        F: Fn(T) -> Option<U> + 'static,
+    {
+        let source = self.clone();
+        let last = RefCell::new(None);
+        create_memo(move || {
+            if let Some(value) = f(source.get()) {
+                *last.borrow_mut() = Some(value);
+            }
+            last.borrow().clone()
+        })
+    }
+
+    /// Create a weak handle that does not keep the signal alive
+    pub fn downgrade(&self) -> WeakReadSignal<T> {
+        WeakReadSignal { data: Gc::downgrade(&self.data) }
+    }
+
     /// Gets the value WITHOUT effect tracking or scope validation.
     ///
     /// # Safety
@@ -238,11 +285,9 @@ impl<T: Trace + Clone + 'static> WriteSignal<T> {
         let signal_ptr = self.data.as_ptr() as *const ();
         log::debug!("WriteSignal::set: signal {:?} setting new value", signal_ptr);
         // Defer drop of old value until after notify_subscribers completes.
-        let _old_value = {
-            let mut guard = self.data.value.borrow_mut_simple();
-            std::mem::replace(&mut *guard, value)
+        let Some(_old_value) = self.data.replace(value) else {
+            return;
         };
-        self.data.version.fetch_add(1, Ordering::SeqCst);
         self.data.notify_subscribers();
         log::debug!("WriteSignal::set: notified subscribers");
     }
@@ -254,17 +299,33 @@ impl<T: Trace + Clone + 'static> WriteSignal<T> {
     {
         let signal_ptr = self.data.as_ptr() as *const ();
         log::debug!("WriteSignal::update: signal {:?} starting update", signal_ptr);
-        let _old_value = {
-            let mut guard = self.data.value.borrow_mut_simple();
-            let old = (*guard).clone();
-            f(&mut *guard);
-            old
+        let Some(_old_value) = self.data.modify(f) else {
+            return;
         };
-        self.data.version.fetch_add(1, Ordering::SeqCst);
         self.data.notify_subscribers();
         log::debug!("WriteSignal::update: notified subscribers");
     }
 
+    /// Modify the value in place and notify subscribers, without cloning the old value
+    ///
+    /// Whatever `f` returns is held until subscribers have run, like the old value in `update`.
+    pub(crate) fn update_in_place<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let result = self.data.modify_in_place(f);
+        self.data.notify_subscribers();
+        result
+    }
+
+    /// Create a weak handle that does not keep the signal alive
+    ///
+    /// Useful for long-lived tasks and callbacks that should stop writing once the view that
+    /// owns the signal is gone.
+    pub fn downgrade(&self) -> WeakWriteSignal<T> {
+        WeakWriteSignal { data: Gc::downgrade(&self.data) }
+    }
+
     /// Sets the value WITHOUT effect tracking or scope validation.
     ///
     /// # Safety
@@ -297,6 +358,47 @@ unsafe impl<T: Trace + Clone + 'static> Trace for WriteSignal<T> {
     }
 }
 
+/// Weak read handle for a signal; [`upgrade`](Self::upgrade) returns `None` once it is gone.
+#[derive(Clone)]
+pub struct WeakReadSignal<T: Trace + Clone + 'static> {
+    data: Weak<SignalData<T>>,
+}
+
+impl<T: Trace + Clone + 'static> WeakReadSignal<T> {
+    /// Get a strong read handle if the signal is still alive
+    pub fn upgrade(&self) -> Option<ReadSignal<T>> {
+        self.data.upgrade().map(|data| ReadSignal { data })
+    }
+}
+
+/// Weak write handle for a signal; writes through it are dropped once the signal is gone.
+#[derive(Clone)]
+pub struct WeakWriteSignal<T: Trace + Clone + 'static> {
+    data: Weak<SignalData<T>>,
+}
+
+impl<T: Trace + Clone + 'static> WeakWriteSignal<T> {
+    /// Get a strong write handle if the signal is still alive
+    pub fn upgrade(&self) -> Option<WriteSignal<T>> {
+        self.data.upgrade().map(|data| WriteSignal { data })
+    }
+
+    /// Set a new value and notify subscribers if the signal is still alive, returning whether
+    /// it was
+    pub fn set(&self, value: T) -> bool {
+        self.upgrade().map(|signal| signal.set(value)).is_some()
+    }
+
+    /// Modify the value and notify subscribers if the signal is still alive, returning whether
+    /// it was
+    pub fn update<F>(&self, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        self.upgrade().map(|signal| signal.update(f)).is_some()
+    }
+}
+
 impl<T: Trace + Clone + 'static> SignalRead<T> for ReadSignal<T> {
     fn get(&self) -> T {
         ReadSignal::get(self)
@@ -330,6 +432,22 @@ pub fn create_signal<T: Trace + Clone + 'static>(
     (ReadSignal { data: Gc::clone(&escaped_gc) }, WriteSignal { data: escaped_gc })
 }
 
+/// Create a signal whose writes are skipped when `eq` finds the new value equal to the current one
+///
+/// Skipped writes leave the version alone and notify no one, so data arriving unchanged does
+/// not re-run effects.
+pub fn create_signal_with_equality<T: Trace + Clone + 'static, F>(
+    initial_value: T,
+    eq: F,
+) -> (ReadSignal<T>, WriteSignal<T>)
+where
+    F: Fn(&T, &T) -> bool + Send + Sync + 'static,
+{
+    let data = Gc::new(SignalData::with_equality(initial_value, eq));
+
+    (ReadSignal { data: Gc::clone(&data) }, WriteSignal { data })
+}
+
 pub fn leak_effect(effect: Gc<Effect>) {
     // Register as global GC root for conservative tracing integrations.
     let ptr = effect.as_ptr();
@@ -399,3 +517,166 @@ where
 
     read
 }
+
+/// Write handle of a derived signal made with [`create_derived`]
+///
+/// Writes are passed to the setter, which updates the source signals; the read handle then
+/// recomputes from them.
+pub struct DerivedWriteSignal<T: Trace + Clone + 'static> {
+    read: ReadSignal<T>,
+    setter: Gc<DerivedSetter<T>>,
+}
+
+/// The setter of a [`DerivedWriteSignal`], kept on the GC heap so the signals it captures are
+/// traced through it
+struct DerivedSetter<T>(Box<dyn Fn(T)>);
+
+unsafe impl<T> Trace for DerivedSetter<T> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        crate::gc::trace_opaque(&*self.0, visitor);
+    }
+}
+
+impl<T: Trace + Clone + 'static> Clone for DerivedWriteSignal<T> {
+    fn clone(&self) -> Self {
+        Self { read: self.read.clone(), setter: Gc::clone(&self.setter) }
+    }
+}
+
+unsafe impl<T: Trace + Clone + 'static> Trace for DerivedWriteSignal<T> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.read.trace(visitor);
+        self.setter.trace(visitor);
+    }
+}
+
+impl<T: Trace + Clone + 'static> DerivedWriteSignal<T> {
+    pub fn set(&self, value: T) {
+        (self.setter.0)(value);
+    }
+
+    /// Modify the current derived value and write the result back through the setter
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        let mut value = self.read.get_untracked();
+        f(&mut value);
+        self.set(value);
+    }
+}
+
+impl<T: Trace + Clone + 'static> SignalWrite<T> for DerivedWriteSignal<T> {
+    fn set(&self, value: T) {
+        DerivedWriteSignal::set(self, value);
+    }
+
+    fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        DerivedWriteSignal::update(self, f);
+    }
+}
+
+/// Create a two-way derived signal
+///
+/// Reads are a memo of `get_fn`, tracking the signals it reads. Writes call `set_fn`, which
+/// should write the value back into those sources, e.g. a Fahrenheit view of a Celsius signal.
+pub fn create_derived<T, G, S>(get_fn: G, set_fn: S) -> (ReadSignal<T>, DerivedWriteSignal<T>)
+where
+    T: Trace + Clone + 'static,
+    G: Fn() -> T + 'static,
+    S: Fn(T) + 'static,
+{
+    let read = create_memo(get_fn);
+    let write =
+        DerivedWriteSignal { read: read.clone(), setter: Gc::new(DerivedSetter(Box::new(set_fn))) };
+    (read, write)
+}
+
+/// A `Vec`-backed signal with in-place list operations, made with [`create_list`]
+///
+/// Each operation edits the list in place, without cloning it, and bumps the version once; one
+/// that changes nothing, like removing past the end, leaves it alone. Pass [`ListSignal::read`]
+/// (or the list itself) to a keyed `For`, which keeps the components of items whose keys are
+/// unchanged.
+pub struct ListSignal<T: Trace + Clone + 'static> {
+    read: ReadSignal<Vec<T>>,
+    write: WriteSignal<Vec<T>>,
+}
+
+impl<T: Trace + Clone + 'static> Clone for ListSignal<T> {
+    fn clone(&self) -> Self {
+        Self { read: self.read.clone(), write: self.write.clone() }
+    }
+}
+
+unsafe impl<T: Trace + Clone + 'static> Trace for ListSignal<T> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.read.trace(visitor);
+        self.write.trace(visitor);
+    }
+}
+
+impl<T: Trace + Clone + 'static> ListSignal<T> {
+    /// The items, tracked by the current effect
+    pub fn get(&self) -> Vec<T> {
+        self.read.get()
+    }
+
+    /// Read handle for the whole list
+    pub fn read(&self) -> ReadSignal<Vec<T>> {
+        self.read.clone()
+    }
+
+    /// Number of items, tracked by the current effect
+    pub fn len(&self) -> usize {
+        self.read.get().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn push(&self, item: T) {
+        self.write.update_in_place(|items| items.push(item));
+    }
+
+    /// Remove and return the item at `index`, or `None` if it is out of range
+    pub fn remove(&self, index: usize) -> Option<T> {
+        if index >= self.len_untracked() {
+            return None;
+        }
+        Some(self.write.update_in_place(|items| items.remove(index)))
+    }
+
+    /// Modify the item at `index` in place, returning whether it existed
+    pub fn update_at<F>(&self, index: usize, f: F) -> bool
+    where
+        F: FnOnce(&mut T),
+    {
+        if index >= self.len_untracked() {
+            return false;
+        }
+        self.write.update_in_place(|items| f(&mut items[index]));
+        true
+    }
+
+    pub fn clear(&self) {
+        if self.len_untracked() > 0 {
+            // The cleared items are dropped once subscribers have run
+            let _cleared = self.write.update_in_place(std::mem::take);
+        }
+    }
+
+    fn len_untracked(&self) -> usize {
+        self.read.data.value.borrow().len()
+    }
+}
+
+/// Create a list signal with the given items
+pub fn create_list<T: Trace + Clone + 'static>(initial_items: Vec<T>) -> ListSignal<T> {
+    let (read, write) = create_signal(initial_items);
+    ListSignal { read, write }
+}
@@ -9,8 +9,8 @@ use std::rc::Rc;
 
 use crate::component::Component;
 use rvue_style::{
-    default_stylesheet, BackgroundColor, Color, ComputedStyles, ElementState, Properties,
-    RvueElement, StyleResolver,
+    default_stylesheet, BackgroundColor, ButtonShades, Color, ComputedStyles, ElementState,
+    Properties, RvueElement, StyleResolver,
 };
 
 #[derive(Debug)]
@@ -67,6 +67,16 @@ impl Stylesheet {
         self.add_background_color(&format!("{}:disabled", selector), disabled);
     }
 
+    /// Add a background-color rule for a selector, deriving hover, active, and disabled colors
+    pub fn add_button_style(&mut self, selector: &str, base: Color) {
+        self.inner.borrow_mut().add_button_style(selector, base);
+    }
+
+    /// Like [`Stylesheet::add_button_style`], with custom derivation factors
+    pub fn add_button_style_with(&mut self, selector: &str, base: Color, shades: ButtonShades) {
+        self.inner.borrow_mut().add_button_style_with(selector, base, shades);
+    }
+
     pub fn len(&self) -> usize {
         self.inner.borrow().len()
     }
@@ -98,6 +108,14 @@ impl Default for Stylesheet {
     }
 }
 
+/// Parse a color string attribute from `view!`, falling back to black with a logged warning
+pub fn color_from_attr(value: &str) -> Color {
+    value.parse().unwrap_or_else(|err| {
+        log::warn!("{err}");
+        Color::default()
+    })
+}
+
 pub fn component_to_element(component: &Component) -> RvueElement {
     let tag_name = component_type_to_tag_name(&component.component_type);
 
@@ -123,6 +141,9 @@ pub fn component_to_element(component: &Component) -> RvueElement {
     if component.is_disabled() {
         element.state.insert(ElementState::DISABLED);
     }
+    if component.is_invalid() {
+        element.state.insert(ElementState::INVALID);
+    }
 
     element
 }
@@ -132,12 +153,20 @@ fn component_type_to_tag_name(component_type: &crate::component::ComponentType)
         crate::component::ComponentType::Text => "text",
         crate::component::ComponentType::Button => "button",
         crate::component::ComponentType::TextInput => "input",
+        crate::component::ComponentType::TextArea => "textarea",
         crate::component::ComponentType::NumberInput => "input",
         crate::component::ComponentType::Checkbox => "checkbox",
         crate::component::ComponentType::Radio => "radio",
         crate::component::ComponentType::Show => "show",
         crate::component::ComponentType::For => "for",
+        crate::component::ComponentType::Switch => "switch",
+        crate::component::ComponentType::Suspense => "suspense",
         crate::component::ComponentType::Flex => "flex",
+        crate::component::ComponentType::ContextMenu => "context-menu",
+        crate::component::ComponentType::Tooltip => "tooltip",
+        crate::component::ComponentType::ProgressBar => "progress",
+        crate::component::ComponentType::Spinner => "spinner",
+        crate::component::ComponentType::Canvas => "canvas",
         crate::component::ComponentType::Custom(name) => name,
     }
     .to_string()
@@ -145,112 +174,78 @@ fn component_type_to_tag_name(component_type: &crate::component::ComponentType)
 
 /// Resolve styles for a component (accepts &Component instead of &Gc<Component>)
 /// This provides a unified style resolution path for both layout and rendering systems.
+///
+/// Inline styles take precedence over normal stylesheet declarations, see
+/// [`merge_inline_styles`], while `!important` declarations win over both. In high-contrast
+/// mode the result is then adjusted by [`StyleResolver::apply_high_contrast`].
 pub fn resolve_styles_for_component(
     component: &Component,
     stylesheet: &Stylesheet,
 ) -> ComputedStyles {
-    let element = component_to_element(component);
     let resolver = StyleResolver::new();
-    let inline_styles = get_inline_styles(component);
     let inner_sheet = stylesheet.inner.borrow();
-    let resolved = resolver.resolve_styles(&element, &inner_sheet);
-    let mut merged = resolved;
+    let mut merged = cascade_with_inline(component, &resolver, &inner_sheet);
 
-    if let Some(inline) = inline_styles {
-        if let Some(w) = inline.width.as_ref() {
-            if !matches!(w.0, rvue_style::Size::Auto) {
-                merged.width = Some(w.clone());
-            }
-        }
-        if let Some(h) = inline.height.as_ref() {
-            if !matches!(h.0, rvue_style::Size::Auto) {
-                merged.height = Some(h.clone());
+    if crate::app::prefers_high_contrast() {
+        let backdrop = opaque_backdrop(component, &resolver, &inner_sheet);
+        resolver.apply_high_contrast(&mut merged, backdrop);
+    }
+
+    merged
+}
+
+/// Stylesheet styles for `component` with its inline styles layered between the normal and
+/// `!important` declarations, as in CSS
+fn cascade_with_inline(
+    component: &Component,
+    resolver: &StyleResolver,
+    sheet: &rvue_style::Stylesheet,
+) -> ComputedStyles {
+    let (mut styles, important) = resolver.resolve_cascade(&component_to_element(component), sheet);
+    if let Some(inline) = get_inline_styles(component) {
+        merge_inline_styles(&mut styles, &inline);
+    }
+    styles.merge_with_computed(&important);
+    styles
+}
+
+/// Background of the nearest ancestor with an opaque one, or the white window behind them all
+///
+/// Translucent ancestor backgrounds are skipped.
+fn opaque_backdrop(
+    component: &Component,
+    resolver: &StyleResolver,
+    sheet: &rvue_style::Stylesheet,
+) -> Color {
+    let mut current = component.parent.borrow().clone();
+    while let Some(ancestor) = current {
+        let styles = cascade_with_inline(&ancestor, resolver, sheet);
+        if let Some(BackgroundColor(color)) = styles.background_color {
+            if color.0.a == 255 {
+                return color;
             }
         }
-        if inline.background_color.is_some() {
-            merged.background_color = inline.background_color;
-        }
-        if inline.color.is_some() {
-            merged.color = inline.color;
-        }
-        if inline.text_color.is_some() {
-            merged.text_color = inline.text_color;
-        }
-        if inline.font_size.is_some() {
-            merged.font_size = inline.font_size;
-        }
-        if inline.font_family.is_some() {
-            merged.font_family = inline.font_family;
-        }
-        if inline.font_weight.is_some() {
-            merged.font_weight = inline.font_weight;
-        }
-        if inline.padding.is_some() {
-            merged.padding = inline.padding;
-        }
-        if inline.margin.is_some() {
-            merged.margin = inline.margin;
-        }
-        if inline.display.is_some() {
-            merged.display = inline.display;
-        }
-        if inline.flex_direction.is_some() {
-            merged.flex_direction = inline.flex_direction;
-        }
-        if inline.justify_content.is_some() {
-            merged.justify_content = inline.justify_content;
-        }
-        if inline.align_items.is_some() {
-            merged.align_items = inline.align_items;
-        }
-        if inline.align_self.is_some() {
-            merged.align_self = inline.align_self;
-        }
-        if inline.flex_grow.is_some() {
-            merged.flex_grow = inline.flex_grow;
-        }
-        if inline.flex_shrink.is_some() {
-            merged.flex_shrink = inline.flex_shrink;
-        }
-        if inline.flex_basis.is_some() {
-            merged.flex_basis = inline.flex_basis;
-        }
-        if inline.gap.is_some() {
-            merged.gap = inline.gap;
-        }
-        if inline.border_color.is_some() {
-            merged.border_color = inline.border_color;
-        }
-        if inline.border_width.is_some() {
-            merged.border_width = inline.border_width;
-        }
-        if inline.border_radius.is_some() {
-            merged.border_radius = inline.border_radius;
-        }
-        if inline.border_style.is_some() {
-            merged.border_style = inline.border_style;
-        }
-        if inline.opacity.is_some() {
-            merged.opacity = inline.opacity;
-        }
-        if inline.visibility.is_some() {
-            merged.visibility = inline.visibility;
-        }
-        if inline.z_index.is_some() {
-            merged.z_index = inline.z_index;
-        }
-        if inline.cursor.is_some() {
-            merged.cursor = inline.cursor;
-        }
-        if inline.overflow_x.is_some() {
-            merged.overflow_x = inline.overflow_x;
-        }
-        if inline.overflow_y.is_some() {
-            merged.overflow_y = inline.overflow_y;
-        }
+        current = ancestor.parent.borrow().clone();
     }
+    Color::rgb(255, 255, 255)
+}
 
-    merged
+/// Apply inline styles on top of stylesheet-resolved styles.
+///
+/// Like an HTML `style` attribute, inline styles win property by property: every property set
+/// inline replaces the stylesheet value, and every property left unset keeps the value the
+/// stylesheet resolved. An inline `width`/`height` of `Size::Auto` counts as unset so that
+/// widget defaults don't mask sizes coming from the stylesheet. `resolved` should hold only
+/// normal declarations; `!important` ones are merged afterwards so that they keep winning.
+pub fn merge_inline_styles(resolved: &mut ComputedStyles, inline: &ComputedStyles) {
+    let mut inline = inline.clone();
+    if inline.width.as_ref().is_some_and(|w| matches!(w.0, rvue_style::Size::Auto)) {
+        inline.width = None;
+    }
+    if inline.height.as_ref().is_some_and(|h| matches!(h.0, rvue_style::Size::Auto)) {
+        inline.height = None;
+    }
+    resolved.merge_with_computed(&inline);
 }
 
 pub fn get_inline_styles(component: &Component) -> Option<ComputedStyles> {
@@ -334,4 +329,143 @@ mod tests {
         assert_eq!(stylesheet.len(), 1);
         assert!(!stylesheet.is_empty());
     }
+
+    #[test]
+    fn test_inline_styles_override_stylesheet_per_property() {
+        let mut stylesheet = Stylesheet::new();
+
+        let mut props = rvue_style::Properties::new();
+        props.insert(BackgroundColor(rvue_style::properties::Color::rgb(0, 0, 255)));
+        props.insert(rvue_style::BorderColor(rvue_style::properties::Color::rgb(0, 128, 0)));
+        stylesheet.add_rule("button", props);
+
+        let component = Component::with_properties(
+            1,
+            ComponentType::Button,
+            crate::properties::PropertyMap::new(),
+        );
+        component.set_widget_styles(ComputedStyles {
+            background_color: Some(BackgroundColor(rvue_style::properties::Color::rgb(255, 0, 0))),
+            ..ComputedStyles::default()
+        });
+
+        let resolved = resolve_styles_for_component(&component, &stylesheet);
+
+        assert_eq!(
+            resolved.background_color,
+            Some(BackgroundColor(rvue_style::properties::Color::rgb(255, 0, 0)))
+        );
+        assert_eq!(
+            resolved.border_color,
+            Some(rvue_style::BorderColor(rvue_style::properties::Color::rgb(0, 128, 0)))
+        );
+    }
+
+    #[test]
+    fn test_important_stylesheet_declarations_beat_inline_styles() {
+        let stylesheet = Stylesheet::new();
+
+        let mut props = rvue_style::Properties::new();
+        props.insert(rvue_style::BorderColor(rvue_style::properties::Color::rgb(0, 128, 0)));
+        let mut important = rvue_style::Properties::new();
+        important.insert(BackgroundColor(rvue_style::properties::Color::rgb(0, 0, 255)));
+        stylesheet.inner.borrow_mut().add_rule(
+            rvue_style::StyleRule::new("button".to_string(), props).with_important(important),
+        );
+
+        let component = Component::with_properties(
+            1,
+            ComponentType::Button,
+            crate::properties::PropertyMap::new(),
+        );
+        component.set_widget_styles(ComputedStyles {
+            background_color: Some(BackgroundColor(rvue_style::properties::Color::rgb(255, 0, 0))),
+            border_color: Some(rvue_style::BorderColor(rvue_style::properties::Color::rgb(
+                255, 255, 0,
+            ))),
+            ..ComputedStyles::default()
+        });
+
+        let resolved = resolve_styles_for_component(&component, &stylesheet);
+
+        assert_eq!(
+            resolved.background_color,
+            Some(BackgroundColor(rvue_style::properties::Color::rgb(0, 0, 255)))
+        );
+        assert_eq!(
+            resolved.border_color,
+            Some(rvue_style::BorderColor(rvue_style::properties::Color::rgb(255, 255, 0)))
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_adjusts_resolved_colors() {
+        use rvue_style::properties::Color;
+
+        let mut stylesheet = Stylesheet::new();
+        let mut props = rvue_style::Properties::new();
+        props.insert(BackgroundColor(Color::rgb(119, 119, 119)));
+        props.insert(rvue_style::TextColor(Color::rgb(136, 136, 136)));
+        stylesheet.add_rule("button", props);
+
+        let component = Component::with_properties(
+            1,
+            ComponentType::Button,
+            crate::properties::PropertyMap::new(),
+        );
+
+        crate::app::set_high_contrast(Some(true));
+        let resolved = resolve_styles_for_component(&component, &stylesheet);
+        crate::app::set_high_contrast(None);
+
+        let text = resolved.text_color.unwrap().0;
+        let background = resolved.background_color.unwrap().0;
+        assert!(text.contrast_ratio(&background) >= rvue_style::HIGH_CONTRAST_MIN_RATIO);
+    }
+
+    #[test]
+    fn test_inline_auto_size_keeps_stylesheet_size() {
+        let mut resolved = ComputedStyles {
+            width: Some(rvue_style::Width(rvue_style::Size::Pixels(120.0))),
+            ..ComputedStyles::default()
+        };
+        let inline = ComputedStyles {
+            width: Some(rvue_style::Width(rvue_style::Size::Auto)),
+            min_width: Some(rvue_style::properties::MinWidth(rvue_style::Size::Pixels(80.0))),
+            ..ComputedStyles::default()
+        };
+
+        merge_inline_styles(&mut resolved, &inline);
+
+        assert_eq!(resolved.width, Some(rvue_style::Width(rvue_style::Size::Pixels(120.0))));
+        assert_eq!(
+            resolved.min_width,
+            Some(rvue_style::properties::MinWidth(rvue_style::Size::Pixels(80.0)))
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_text_reads_against_ancestor_background() {
+        use rudo_gc::Gc;
+
+        let card =
+            Component::with_global_id(ComponentType::Flex, crate::properties::PropertyMap::new());
+        card.set_widget_styles(ComputedStyles {
+            background_color: Some(BackgroundColor(Color::rgb(20, 20, 40))),
+            ..Default::default()
+        });
+        let label =
+            Component::with_global_id(ComponentType::Text, crate::properties::PropertyMap::new());
+        card.append_child(Gc::clone(&label));
+
+        crate::app::set_high_contrast(Some(true));
+        let styles = resolve_styles_for_component(&label, &Stylesheet::new());
+        crate::app::set_high_contrast(None);
+
+        let text = styles.text_color.map(|rvue_style::TextColor(color)| color).unwrap();
+        assert!(
+            text.contrast_ratio(&Color::rgb(20, 20, 40)) >= rvue_style::HIGH_CONTRAST_MIN_RATIO
+        );
+        assert_eq!(styles.background_color, None);
+    }
 }
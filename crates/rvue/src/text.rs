@@ -1,9 +1,21 @@
 use parley::fontique::{Collection, CollectionOptions, SourceCache};
-use parley::{FontContext, Layout, LayoutContext};
+use parley::style::{FontStack, StyleProperty};
+use parley::{FontContext, Layout, LayoutContext, RangedBuilder};
+use std::borrow::Cow;
 use vello::peniko::{Color, Fill};
 
 pub mod cursor;
 pub mod editor;
+pub mod span;
+
+/// Font families for all text, tried in order for each character
+///
+/// The generic `sans-serif` font covers Latin and most other scripts. The named families pick
+/// up CJK and color emoji where it has no glyph, and characters none of them cover go through
+/// fontique's per-script system fallback before a missing-glyph box is drawn.
+pub const DEFAULT_FONT_STACK: &str = "sans-serif, Noto Sans CJK SC, Noto Sans CJK JP, \
+     PingFang SC, Hiragino Sans, Microsoft YaHei, Noto Color Emoji, Apple Color Emoji, \
+     Segoe UI Emoji, emoji";
 
 #[derive(Clone, PartialEq, Default, Debug)]
 pub struct BrushIndex(pub usize);
@@ -34,6 +46,20 @@ impl TextContext {
     }
 }
 
+/// Start laying out `text` with the default font stack at `font_size`
+pub fn text_layout_builder<'a>(
+    text_context: &'a mut TextContext,
+    text: &'a str,
+    font_size: f32,
+) -> RangedBuilder<'a, BrushIndex> {
+    let mut builder =
+        text_context.layout_ctx.ranged_builder(&mut text_context.font_ctx, text, 1.0, true);
+    builder.push_default(StyleProperty::FontSize(font_size));
+    builder.push_default(StyleProperty::Brush(BrushIndex(0)));
+    builder.push_default(FontStack::Source(Cow::Borrowed(DEFAULT_FONT_STACK)));
+    builder
+}
+
 pub fn render_text(
     layout: &Layout<BrushIndex>,
     scene: &mut vello::Scene,
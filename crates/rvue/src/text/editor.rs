@@ -5,10 +5,12 @@
 //! - Selection/cursor position tracking
 //! - IME composition support
 //! - Basic text editing operations
+//! - Undo/redo history
 
 use rudo_gc::handles::HandleScope;
 use rudo_gc::heap::current_thread_control_block;
 use rudo_gc::{Gc, GcCell, Trace};
+use std::collections::VecDeque;
 use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -66,10 +68,43 @@ impl ImeComposition {
     }
 }
 
+/// Most undo steps a text editor keeps; older ones are dropped first
+pub const MAX_UNDO_STEPS: usize = 100;
+
+/// Kind of edit recorded in the undo history
+///
+/// Consecutive edits of the same kind made without moving the cursor in between are undone
+/// together, so typing a word is one undo step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct EditSnapshot {
+    content: String,
+    selection: Selection,
+}
+
+#[derive(Default)]
+struct EditHistory {
+    undo: VecDeque<EditSnapshot>,
+    redo: Vec<EditSnapshot>,
+    /// Kind of the last edit and the selection it left, used to coalesce the next one
+    last_edit: Option<(EditKind, Selection)>,
+}
+
+unsafe impl Trace for EditHistory {
+    fn trace(&self, _visitor: &mut impl rudo_gc::Visitor) {}
+}
+
 pub struct TextEditor {
     pub content: GcCell<String>,
     pub selection: GcCell<Selection>,
     pub composition: GcCell<ImeComposition>,
+    history: GcCell<EditHistory>,
 }
 
 unsafe impl Trace for TextEditor {
@@ -77,6 +112,7 @@ unsafe impl Trace for TextEditor {
         self.content.trace(visitor);
         self.selection.trace(visitor);
         self.composition.trace(visitor);
+        self.history.trace(visitor);
     }
 }
 
@@ -92,6 +128,7 @@ impl TextEditor {
             content: GcCell::new(String::new()),
             selection: GcCell::new(Selection::default()),
             composition: GcCell::new(ImeComposition::default()),
+            history: GcCell::new(EditHistory::default()),
         }
     }
 
@@ -100,6 +137,7 @@ impl TextEditor {
             content: GcCell::new(text.to_string()),
             selection: GcCell::new(Selection::default()),
             composition: GcCell::new(ImeComposition::default()),
+            history: GcCell::new(EditHistory::default()),
         }
     }
 
@@ -150,9 +188,81 @@ impl TextEditor {
         self.composition_cursor_offset()
     }
 
+    /// Replace the whole text, moving the cursor to the start and clearing the undo history
     pub fn set_content(&self, text: String) {
         *self.content.borrow_mut_gen_only() = text;
         *self.selection.borrow_mut_gen_only() = Selection::default();
+        *self.history.borrow_mut_gen_only() = EditHistory::default();
+    }
+
+    fn snapshot(&self) -> EditSnapshot {
+        EditSnapshot { content: self.content(), selection: self.selection() }
+    }
+
+    fn restore(&self, snapshot: EditSnapshot) {
+        *self.content.borrow_mut_gen_only() = snapshot.content;
+        *self.selection.borrow_mut_gen_only() = snapshot.selection;
+    }
+
+    /// Apply `edit` as one undoable step
+    ///
+    /// The step merges into the previous one when both have the same `kind` and the cursor has
+    /// not moved since. Edits that leave the text unchanged are not recorded.
+    pub fn edit(&self, kind: EditKind, edit: impl FnOnce(&Self)) {
+        let before = self.snapshot();
+        edit(self);
+        if self.content.borrow().as_str() == before.content {
+            return;
+        }
+
+        let mut history = self.history.borrow_mut_gen_only();
+        let coalesce = kind != EditKind::Other
+            && history.last_edit == Some((kind, before.selection))
+            && !history.undo.is_empty();
+        if !coalesce {
+            history.undo.push_back(before);
+            if history.undo.len() > MAX_UNDO_STEPS {
+                history.undo.pop_front();
+            }
+        }
+        history.redo.clear();
+        history.last_edit = Some((kind, self.selection()));
+    }
+
+    /// Revert the last undo step, returning whether there was one
+    pub fn undo(&self) -> bool {
+        let current = self.snapshot();
+        let mut history = self.history.borrow_mut_gen_only();
+        let Some(previous) = history.undo.pop_back() else {
+            return false;
+        };
+        history.redo.push(current);
+        history.last_edit = None;
+        drop(history);
+        self.restore(previous);
+        true
+    }
+
+    /// Reapply the last undone step, returning whether there was one
+    pub fn redo(&self) -> bool {
+        let current = self.snapshot();
+        let mut history = self.history.borrow_mut_gen_only();
+        let Some(next) = history.redo.pop() else {
+            return false;
+        };
+        history.undo.push_back(current);
+        history.last_edit = None;
+        drop(history);
+        self.restore(next);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.borrow().undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.history.borrow().redo.is_empty()
     }
 
     pub fn selection(&self) -> Selection {
@@ -383,6 +493,41 @@ impl TextEditor {
         *self.selection.borrow_mut_gen_only() = Selection::from_cursor(len);
     }
 
+    /// Move the cursor by `delta` lines, keeping its column where the target line is long enough.
+    ///
+    /// Lines are split at `\n`. Moving above the first line or below the last one goes to the
+    /// start or end of the text.
+    pub fn move_line(&self, delta: isize) {
+        let cursor = self.selection.borrow().cursor();
+        let (line_starts, char_count) = {
+            let content = self.content.borrow();
+            let mut starts = vec![0];
+            let mut count = 0;
+            for (i, c) in content.chars().enumerate() {
+                if c == '\n' {
+                    starts.push(i + 1);
+                }
+                count += 1;
+            }
+            (starts, count)
+        };
+
+        let line = line_starts.iter().rposition(|&start| start <= cursor).unwrap_or(0);
+        let column = cursor - line_starts[line];
+        let target = line as isize + delta;
+
+        let new_cursor = if target < 0 {
+            0
+        } else if target as usize >= line_starts.len() {
+            char_count
+        } else {
+            let target = target as usize;
+            let line_end = line_starts.get(target + 1).map(|next| next - 1).unwrap_or(char_count);
+            (line_starts[target] + column).min(line_end)
+        };
+        *self.selection.borrow_mut_gen_only() = Selection::from_cursor(new_cursor);
+    }
+
     pub fn move_word_left(&self) {
         let cursor = self.selection.borrow().cursor();
         let content = self.content.borrow();
@@ -0,0 +1,94 @@
+//! Styled runs of text for rich `Text` widgets
+
+use crate::text::BrushIndex;
+use parley::style::{FontStyle, StyleProperty};
+use parley::RangedBuilder;
+use rvue_style::{Color, FontWeight};
+
+/// A run of text with its own inline style inside a rich `Text`
+///
+/// Unset fields fall back to the `Text` widget's own styles.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextSpan {
+    pub text: String,
+    pub font_weight: Option<FontWeight>,
+    pub italic: bool,
+    pub color: Option<Color>,
+}
+
+impl TextSpan {
+    /// Create an unstyled span
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), ..Default::default() }
+    }
+
+    /// Render the span in bold
+    pub fn bold(self) -> Self {
+        self.weight(FontWeight::Bold)
+    }
+
+    /// Set the span's font weight
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.font_weight = Some(weight);
+        self
+    }
+
+    /// Render the span in italics
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Set the span's text color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl From<&str> for TextSpan {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for TextSpan {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+/// The plain text of a list of spans, joined in order
+pub fn spans_text(spans: &[TextSpan]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+/// Apply each span's style to its byte range of the joined text
+///
+/// A colored span at index `i` is drawn with `BrushIndex(i + 1)`; index 0 stays the
+/// widget's text color. Use [`span_brush_color`] to resolve the brushes when rendering.
+pub fn push_span_styles(builder: &mut RangedBuilder<'_, BrushIndex>, spans: &[TextSpan]) {
+    let mut start = 0;
+    for (index, span) in spans.iter().enumerate() {
+        let range = start..start + span.text.len();
+        start = range.end;
+        if range.is_empty() {
+            continue;
+        }
+        if let Some(weight) = span.font_weight {
+            let weight = parley::style::FontWeight::new(weight as u16 as f32);
+            builder.push(StyleProperty::FontWeight(weight), range.clone());
+        }
+        if span.italic {
+            builder.push(StyleProperty::FontStyle(FontStyle::Italic), range.clone());
+        }
+        if span.color.is_some() {
+            builder.push(StyleProperty::Brush(BrushIndex(index + 1)), range);
+        }
+    }
+}
+
+/// Color of a brush set by [`push_span_styles`], or `None` for the widget's text color
+pub fn span_brush_color(spans: &[TextSpan], brush: &BrushIndex) -> Option<Color> {
+    brush.0.checked_sub(1).and_then(|index| spans.get(index)).and_then(|span| span.color)
+}
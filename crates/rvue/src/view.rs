@@ -2,19 +2,32 @@
 
 use crate::component::Component;
 use crate::effect::Effect;
+use crate::widget::{build_widget, IntoWidget, Mountable};
 use rudo_gc::{Gc, Trace};
 
 /// View trait for converting types into components
 pub trait View {
     /// Convert this type into a component
     fn into_component(self) -> Gc<Component>;
+
+    /// Convert this type into a view struct, keeping fragments and effects intact
+    fn into_view_struct(self) -> ViewStruct
+    where
+        Self: Sized,
+    {
+        ViewStruct::new(self.into_component())
+    }
 }
 
 /// View structure representing a declarative UI tree
+///
+/// A fragment view has several roots. They wait in `root_component`, a plain container, until
+/// [`ViewStruct::mount_into`] moves them into the parent without the container.
 #[derive(Clone)]
 pub struct ViewStruct {
     pub root_component: Gc<Component>,
     pub effects: Vec<Gc<Effect>>,
+    is_fragment: bool,
 }
 
 unsafe impl Trace for ViewStruct {
@@ -29,7 +42,46 @@ unsafe impl Trace for ViewStruct {
 impl ViewStruct {
     /// Create a new view with a root component
     pub fn new(root_component: Gc<Component>) -> Self {
-        Self { root_component, effects: Vec::new() }
+        Self { root_component, effects: Vec::new(), is_fragment: false }
+    }
+
+    /// Create a fragment view whose roots are the children of `container`
+    pub fn fragment(container: Gc<Component>) -> Self {
+        Self { root_component: container, effects: Vec::new(), is_fragment: true }
+    }
+
+    /// Whether this view has several roots rather than a single root component
+    pub fn is_fragment(&self) -> bool {
+        self.is_fragment
+    }
+
+    /// The top-level components of this view
+    pub fn roots(&self) -> Vec<Gc<Component>> {
+        if self.is_fragment {
+            self.root_component.children.borrow().clone()
+        } else {
+            vec![Gc::clone(&self.root_component)]
+        }
+    }
+
+    /// Add this view's roots as children of `parent`, handing its effects to `parent`
+    pub fn mount_into(self, parent: &Gc<Component>) {
+        for root in self.take_roots() {
+            root.set_parent(Some(Gc::clone(parent)));
+            parent.add_child(root);
+        }
+        for effect in self.effects {
+            parent.add_effect(effect);
+        }
+    }
+
+    /// The top-level components, detached from the fragment container if there is one
+    fn take_roots(&self) -> Vec<Gc<Component>> {
+        if self.is_fragment {
+            std::mem::take(&mut *self.root_component.children.borrow_mut())
+        } else {
+            vec![Gc::clone(&self.root_component)]
+        }
     }
 
     /// Add a top-level effect to the view
@@ -41,6 +93,42 @@ impl ViewStruct {
     pub fn from_component(root_component: Gc<Component>) -> Self {
         Self::new(root_component)
     }
+
+    /// Build a widget and add it as the last child of the root
+    pub fn with_child(self, child: impl IntoWidget) -> Self {
+        let state = build_widget(child.into_widget());
+        state.mount(Some(Gc::clone(&self.root_component)));
+        self.root_component.mark_dirty();
+        self
+    }
+
+    /// Move another view under this view's root, keeping its effects alive with this view
+    ///
+    /// The roots of a fragment are appended one by one.
+    pub fn append(&mut self, other: ViewStruct) {
+        for root in other.take_roots() {
+            root.set_parent(Some(Gc::clone(&self.root_component)));
+            self.root_component.add_child(root);
+        }
+        self.effects.extend(other.effects);
+        self.root_component.mark_dirty();
+    }
+
+    /// Tear down the view: dispose its top-level effects and its component tree
+    ///
+    /// See [`Component::dispose`]. Use this when swapping out a root view so its components,
+    /// effects and tasks can be reclaimed.
+    pub fn dispose(self) {
+        for effect in &self.effects {
+            effect.dispose();
+        }
+        self.root_component.dispose();
+    }
+
+    /// Iterate over the children of the root component
+    pub fn children(&self) -> std::vec::IntoIter<Gc<Component>> {
+        self.root_component.children.borrow().clone().into_iter()
+    }
 }
 
 impl From<Gc<Component>> for ViewStruct {
@@ -54,6 +142,10 @@ impl View for ViewStruct {
     fn into_component(self) -> Gc<Component> {
         self.root_component
     }
+
+    fn into_view_struct(self) -> ViewStruct {
+        self
+    }
 }
 
 /// Implement View for Component (components are views)
@@ -62,3 +154,52 @@ impl View for Gc<Component> {
         self
     }
 }
+
+/// Type-driven mounting of `{expr}` children in `view!`
+///
+/// The macro only sees tokens, so a child such as `{items}` is mounted through
+/// `(&value).block_child_kind().mount(value, ..)`. Method resolution then picks [`ViewKind`] for
+/// any [`View`], whose roots (all of them for a fragment) go under the parent, and falls back to
+/// [`TextKind`] for any `Display` value, which is shown as text.
+#[doc(hidden)]
+pub mod block_child {
+    use super::View;
+    use crate::component::Component;
+    use crate::widget::{BuildContext, Widget};
+    use crate::widgets::Text;
+    use rudo_gc::Gc;
+    use std::fmt::Display;
+
+    pub struct ViewChild;
+
+    impl ViewChild {
+        pub fn mount(self, view: impl View, parent: &Gc<Component>, _ctx: &mut BuildContext) {
+            view.into_view_struct().mount_into(parent);
+        }
+    }
+
+    pub struct TextChild;
+
+    impl TextChild {
+        pub fn mount(self, value: impl Display, parent: &Gc<Component>, ctx: &mut BuildContext) {
+            let state = Text::new(value.to_string()).build(ctx);
+            parent.add_child(Gc::clone(state.component()));
+        }
+    }
+
+    pub trait ViewKind {
+        fn block_child_kind(&self) -> ViewChild {
+            ViewChild
+        }
+    }
+
+    impl<V: View> ViewKind for V {}
+
+    pub trait TextKind {
+        fn block_child_kind(&self) -> TextChild {
+            TextChild
+        }
+    }
+
+    impl<T: Display + ?Sized> TextKind for &T {}
+}
@@ -81,6 +81,28 @@ pub fn get_current_ctx() -> Option<*mut u64> {
     CURRENT_CTX.lock().unwrap().get(&thread_id).map(|&addr| addr as *mut u64)
 }
 
+/// Build a widget outside of `view!`, reusing the active build context when there is one
+pub(crate) fn build_widget<W: Widget>(widget: W) -> W::State {
+    if let Some(handle) = get_build_context() {
+        let mut ctx = BuildContext::new(
+            unsafe { &mut *handle.taffy },
+            unsafe { &mut *handle.text_context },
+            unsafe { &mut *handle.id_counter },
+        );
+        return widget.build(&mut ctx);
+    }
+
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = get_current_ctx().map(|ptr| unsafe { *ptr }).unwrap_or(0);
+    let state =
+        widget.build(&mut BuildContext::new(&mut taffy, &mut text_context, &mut id_counter));
+    if let Some(ptr) = get_current_ctx() {
+        unsafe { *ptr = id_counter };
+    }
+    state
+}
+
 /// Reactive value that can be either static or derived from a signal
 ///
 /// This enum allows widgets to accept both static values and reactive
@@ -157,6 +179,12 @@ impl<T: Trace + Clone + 'static> IntoReactiveValue<T> for ReadSignal<T> {
     }
 }
 
+impl<T: Trace + Clone + 'static> IntoReactiveValue<Vec<T>> for crate::signal::ListSignal<T> {
+    fn into_reactive(self) -> ReactiveValue<Vec<T>> {
+        ReactiveValue::Signal(self.read())
+    }
+}
+
 // Helper function for static values (avoids trait conflict)
 pub fn static_value<T: Trace + Clone + 'static>(value: T) -> ReactiveValue<T> {
     ReactiveValue::Static(value)
@@ -184,6 +212,12 @@ impl IntoReactiveValue<String> for &str {
     }
 }
 
+impl IntoReactiveValue<Option<String>> for Option<String> {
+    fn into_reactive(self) -> ReactiveValue<Option<String>> {
+        ReactiveValue::Static(self)
+    }
+}
+
 // Implement for style types
 impl crate::widget::IntoReactiveValue<rvue_style::FlexDirection> for rvue_style::FlexDirection {
     fn into_reactive(self) -> ReactiveValue<rvue_style::FlexDirection> {
@@ -284,6 +318,132 @@ pub trait IntoWidget: Sized {
     fn into_widget(self) -> Self::Widget;
 }
 
+impl<W: Widget> IntoWidget for W {
+    type Widget = W;
+
+    fn into_widget(self) -> Self::Widget {
+        self
+    }
+}
+
+impl<T: IntoWidget> IntoWidget for Option<T> {
+    type Widget = OptionWidget<T::Widget>;
+
+    fn into_widget(self) -> Self::Widget {
+        OptionWidget(self.map(IntoWidget::into_widget))
+    }
+}
+
+impl<T: IntoWidget> IntoWidget for Vec<T> {
+    type Widget = FragmentWidget<T::Widget>;
+
+    fn into_widget(self) -> Self::Widget {
+        self.into_iter().collect()
+    }
+}
+
+/// A widget that may be absent, rendering nothing when `None`
+///
+/// Whether the widget is present is fixed at build time; use `Show` when it changes.
+pub struct OptionWidget<W>(pub Option<W>);
+
+unsafe impl<W: Trace> Trace for OptionWidget<W> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.0.trace(visitor);
+    }
+}
+
+impl<W: Widget> Widget for OptionWidget<W> {
+    type State = OptionWidgetState<W::State>;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        OptionWidgetState(self.0.map(|widget| widget.build(ctx)))
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if let (Some(widget), Some(state)) = (self.0, state.0.as_mut()) {
+            widget.rebuild(state);
+        }
+    }
+}
+
+/// State for an [`OptionWidget`]
+pub struct OptionWidgetState<S>(pub Option<S>);
+
+unsafe impl<S: Trace> Trace for OptionWidgetState<S> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.0.trace(visitor);
+    }
+}
+
+impl<S: Mountable> Mountable for OptionWidgetState<S> {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        if let Some(state) = &self.0 {
+            state.mount(parent);
+        }
+    }
+
+    fn unmount(&self) {
+        if let Some(state) = &self.0 {
+            state.unmount();
+        }
+    }
+}
+
+/// A list of widgets mounted as siblings, without a wrapping container
+///
+/// The number of widgets is fixed at build time; use `For` for lists that change.
+pub struct FragmentWidget<W>(pub Vec<W>);
+
+unsafe impl<W: Trace> Trace for FragmentWidget<W> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.0.trace(visitor);
+    }
+}
+
+impl<T: IntoWidget> FromIterator<T> for FragmentWidget<T::Widget> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        FragmentWidget(iter.into_iter().map(IntoWidget::into_widget).collect())
+    }
+}
+
+impl<W: Widget> Widget for FragmentWidget<W> {
+    type State = FragmentWidgetState<W::State>;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        FragmentWidgetState(self.0.into_iter().map(|widget| widget.build(ctx)).collect())
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        for (widget, state) in self.0.into_iter().zip(state.0.iter_mut()) {
+            widget.rebuild(state);
+        }
+    }
+}
+
+/// State for a [`FragmentWidget`]
+pub struct FragmentWidgetState<S>(pub Vec<S>);
+
+unsafe impl<S: Trace> Trace for FragmentWidgetState<S> {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.0.trace(visitor);
+    }
+}
+
+impl<S: Mountable> Mountable for FragmentWidgetState<S> {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        for state in &self.0 {
+            state.mount(parent.clone());
+        }
+    }
+
+    fn unmount(&self) {
+        for state in &self.0 {
+            state.unmount();
+        }
+    }
+}
+
 /// Wrapper for type-erased widgets
 ///
 /// This allows storing different widget types in collections
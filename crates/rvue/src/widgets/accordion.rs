@@ -0,0 +1,255 @@
+//! Accordion widget, a collapsible section whose header toggles its body
+
+use crate::component::Component;
+use crate::effect::create_effect;
+use crate::signal::{create_signal, ReadSignal, WriteSignal};
+use crate::widget::{BuildContext, Mountable, Widget};
+use crate::widgets::show::{Show, ShowState, Transition, TransitionKind};
+use crate::widgets::{Button, Flex, Text};
+use rudo_gc::{Gc, Trace};
+use rvue_style::{FlexDirection, ReactiveStyles};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Group of accordions of which at most one is open at a time
+///
+/// Opening a member of the group closes whichever member was open before.
+#[derive(Clone)]
+pub struct AccordionGroup {
+    open: ReadSignal<Option<u64>>,
+    set_open: WriteSignal<Option<u64>>,
+    next_member: Rc<Cell<u64>>,
+}
+
+unsafe impl Trace for AccordionGroup {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.open.trace(visitor);
+        self.set_open.trace(visitor);
+    }
+}
+
+impl AccordionGroup {
+    /// Create a new group with every member closed
+    pub fn new() -> Self {
+        let (open, set_open) = create_signal(None);
+        Self { open, set_open, next_member: Rc::new(Cell::new(0)) }
+    }
+
+    /// Hand out the id of a new member
+    fn join(&self) -> u64 {
+        let id = self.next_member.get();
+        self.next_member.set(id + 1);
+        id
+    }
+}
+
+impl Default for AccordionGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Accordion widget builder showing a header above a body that can be collapsed
+///
+/// Clicking the header toggles the body. The container has the `accordion` class, and `open`
+/// as well while the body shows; the header has the `accordion-header` class.
+pub struct Accordion {
+    title: String,
+    body: Box<dyn Fn(&mut BuildContext) -> Gc<Component>>,
+    open: bool,
+    group: Option<AccordionGroup>,
+    animated: bool,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Accordion {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.group.trace(visitor);
+        self.styles.trace(visitor);
+    }
+}
+
+impl Accordion {
+    /// Create a new collapsed Accordion from its header title and body builder
+    pub fn new(
+        title: impl Into<String>,
+        body: impl Fn(&mut BuildContext) -> Gc<Component> + 'static,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            body: Box::new(body),
+            open: false,
+            group: None,
+            animated: false,
+            styles: None,
+        }
+    }
+
+    /// Start with the body shown
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Join `group`, so opening this accordion closes the other members
+    pub fn group(mut self, group: &AccordionGroup) -> Self {
+        self.group = Some(group.clone());
+        self
+    }
+
+    /// Animate the body's height when it is shown or hidden
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Set the styles of the container holding the header and body
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// State for a mounted Accordion widget
+pub struct AccordionState {
+    component: Gc<Component>,
+    header: Gc<Component>,
+    body: ShowState,
+    open: ReadSignal<bool>,
+    open_effect: Gc<crate::effect::Effect>,
+    group_effect: Option<Gc<crate::effect::Effect>>,
+}
+
+impl AccordionState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+
+    /// Get the header button that toggles the body
+    pub fn header(&self) -> &Gc<Component> {
+        &self.header
+    }
+
+    /// Get the Show component holding the body
+    pub fn body(&self) -> &Gc<Component> {
+        self.body.component()
+    }
+
+    /// Signal tracking whether the body is open
+    pub fn open_signal(&self) -> ReadSignal<bool> {
+        self.open.clone()
+    }
+
+    /// Whether the body is open
+    pub fn is_open(&self) -> bool {
+        self.open.get_untracked()
+    }
+}
+
+unsafe impl Trace for AccordionState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        self.header.trace(visitor);
+        self.body.trace(visitor);
+        self.open.trace(visitor);
+        self.open_effect.trace(visitor);
+        if let Some(effect) = &self.group_effect {
+            effect.trace(visitor);
+        }
+    }
+}
+
+impl Mountable for AccordionState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Accordion {
+    type State = AccordionState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        let container_styles = ReactiveStyles::new().set_flex_direction(FlexDirection::Column);
+        let container_styles = match self.styles {
+            Some(styles) => container_styles.merge(styles),
+            None => container_styles,
+        };
+        let component = Gc::clone(
+            Flex::new()
+                .direction(FlexDirection::Column)
+                .styles(container_styles)
+                .build(ctx)
+                .component(),
+        );
+        component.add_class("accordion");
+
+        let header = Button::new().class("accordion-header").build(ctx);
+        Text::new(self.title).build(ctx).mount(Some(Gc::clone(header.component())));
+        header.mount(Some(Gc::clone(&component)));
+        let header = Gc::clone(header.component());
+
+        let (open, set_open) = create_signal(self.open);
+
+        // Members of a group follow the group's open member instead of toggling on their own
+        let member = self.group.map(|group| (group.join(), group));
+        let group_effect = member.as_ref().map(|(id, group)| {
+            let id = *id;
+            if self.open {
+                group.set_open.set(Some(id));
+            }
+            let group_open = group.open.clone();
+            let open = open.clone();
+            let set_open = set_open.clone();
+            let effect = create_effect(move || {
+                let is_open = group_open.get() == Some(id);
+                if open.get_untracked() != is_open {
+                    set_open.set(is_open);
+                }
+            });
+            component.add_effect(Gc::clone(&effect));
+            effect
+        });
+
+        let is_open = open.clone();
+        header.on_click_0arg(move || {
+            let was_open = is_open.get_untracked();
+            match &member {
+                Some((id, group)) => group.set_open.set((!was_open).then_some(*id)),
+                None => set_open.set(!was_open),
+            }
+        });
+
+        let comp = Gc::clone(&component);
+        let is_open = open.clone();
+        let open_effect = create_effect(move || {
+            if is_open.get() {
+                comp.add_class("open");
+            } else {
+                comp.remove_class("open");
+            }
+        });
+        component.add_effect(Gc::clone(&open_effect));
+
+        let body_fn = self.body;
+        let mut body = Show::new(open.clone(), move |ctx| body_fn(ctx));
+        if self.animated {
+            body = body.transition(Transition::new(TransitionKind::Collapse));
+        }
+        let body = body.build(ctx);
+        body.mount(Some(Gc::clone(&component)));
+
+        AccordionState { component, header, body, open, open_effect, group_effect }
+    }
+
+    fn rebuild(self, _state: &mut Self::State) {
+        // Open state lives in the accordion's own signal once built
+    }
+}
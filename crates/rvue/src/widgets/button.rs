@@ -11,6 +11,7 @@ pub struct Button {
     styles: Option<ReactiveStyles>,
     class: Option<String>,
     id: Option<String>,
+    submit: bool,
 }
 
 unsafe impl Trace for Button {
@@ -29,7 +30,7 @@ impl Default for Button {
 impl Button {
     /// Create a new Button widget
     pub fn new() -> Self {
-        Self { styles: None, class: None, id: None }
+        Self { styles: None, class: None, id: None, submit: false }
     }
 
     /// Set the styles directly
@@ -49,6 +50,12 @@ impl Button {
         self.id = Some(id.to_string());
         self
     }
+
+    /// Submit the enclosing [`Form`](crate::widgets::Form) when clicked or activated by key
+    pub fn submit(mut self, submit: bool) -> Self {
+        self.submit = submit;
+        self
+    }
 }
 
 /// State for a mounted Button widget
@@ -115,6 +122,10 @@ impl Widget for Button {
             *component.element_id.borrow_mut_gen_only() = Some(eid.clone());
         }
 
+        if self.submit {
+            component.set_button_submit(true);
+        }
+
         ButtonState { component }
     }
 
@@ -0,0 +1,120 @@
+//! Canvas widget for custom Vello drawing
+
+use crate::component::{Component, ComponentType};
+use crate::properties::PropertyMap;
+use crate::widget::{BuildContext, Mountable, Widget};
+use rudo_gc::{Gc, Trace};
+use rvue_style::ReactiveStyles;
+use std::rc::Rc;
+use vello::kurbo::Size;
+
+/// Draw callback of a canvas, kept in the component's `canvas_draw`
+#[derive(Clone)]
+pub struct CanvasDraw(pub Rc<dyn Fn(&mut vello::Scene, Size)>);
+
+// The callback may capture signals and components, which are only reachable through it
+unsafe impl Trace for CanvasDraw {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        crate::gc::trace_opaque(&*self.0, visitor);
+    }
+}
+
+/// Canvas widget builder that lets the app draw arbitrary Vello content
+///
+/// The draw callback works in the canvas's local coordinates, with the origin at its top-left
+/// corner, and everything it draws is clipped to the canvas bounds. It runs again whenever the
+/// component is dirty, so call `mark_dirty` on the component to redraw.
+#[derive(Clone)]
+pub struct Canvas {
+    draw: Option<CanvasDraw>,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Canvas {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.draw.trace(visitor);
+        self.styles.trace(visitor);
+    }
+}
+
+impl Canvas {
+    /// Create a new Canvas widget with nothing to draw
+    pub fn new() -> Self {
+        Self { draw: None, styles: None }
+    }
+
+    /// Set the callback that draws the canvas content at the given size
+    pub fn on_draw(mut self, draw: impl Fn(&mut vello::Scene, Size) + 'static) -> Self {
+        self.draw = Some(CanvasDraw(Rc::new(draw)));
+        self
+    }
+
+    /// Set the styles directly
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+impl Default for Canvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State for a mounted Canvas widget
+pub struct CanvasState {
+    component: Gc<Component>,
+}
+
+impl CanvasState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for CanvasState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+    }
+}
+
+impl Mountable for CanvasState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Canvas {
+    type State = CanvasState;
+
+    fn build(self, _ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let component = Component::with_properties(id, ComponentType::Canvas, PropertyMap::new());
+
+        if let Some(styles) = self.styles.as_ref().map(|s| s.compute()) {
+            component.set_widget_styles(styles);
+        }
+
+        if let Some(draw) = self.draw {
+            *component.canvas_draw.borrow_mut_gen_only() = Some(draw);
+        }
+
+        CanvasState { component }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if let Some(draw) = self.draw {
+            *state.component.canvas_draw.borrow_mut_gen_only() = Some(draw);
+        }
+        state.component.mark_dirty();
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::component::{Component, ComponentType};
 use crate::effect::create_effect;
-use crate::properties::{CheckboxChecked, PropertyMap};
+use crate::properties::{CheckboxChecked, CheckboxIndeterminate, PropertyMap};
 use crate::widget::{BuildContext, Mountable, ReactiveValue, Widget};
 use rudo_gc::{Gc, Trace};
 use rvue_style::ReactiveStyles;
@@ -11,12 +11,14 @@ use rvue_style::ReactiveStyles;
 #[derive(Clone)]
 pub struct Checkbox {
     checked: ReactiveValue<bool>,
+    indeterminate: Option<ReactiveValue<bool>>,
     styles: Option<ReactiveStyles>,
 }
 
 unsafe impl Trace for Checkbox {
     fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
         self.checked.trace(visitor);
+        self.indeterminate.trace(visitor);
         self.styles.trace(visitor);
     }
 }
@@ -24,7 +26,18 @@ unsafe impl Trace for Checkbox {
 impl Checkbox {
     /// Create a new Checkbox widget with checked state
     pub fn new(checked: impl crate::widget::IntoReactiveValue<bool>) -> Self {
-        Self { checked: checked.into_reactive(), styles: None }
+        Self { checked: checked.into_reactive(), indeterminate: None, styles: None }
+    }
+
+    /// Show the mixed state as a dash instead of the checked state
+    ///
+    /// Clicking an indeterminate checkbox clears the mixed state and checks it.
+    pub fn indeterminate(
+        mut self,
+        indeterminate: impl crate::widget::IntoReactiveValue<bool>,
+    ) -> Self {
+        self.indeterminate = Some(indeterminate.into_reactive());
+        self
     }
 
     /// Set the styles directly
@@ -38,6 +51,7 @@ impl Checkbox {
 pub struct CheckboxState {
     component: Gc<Component>,
     checked_effect: Option<Gc<crate::effect::Effect>>,
+    indeterminate_effect: Option<Gc<crate::effect::Effect>>,
 }
 
 impl CheckboxState {
@@ -53,6 +67,9 @@ unsafe impl Trace for CheckboxState {
         if let Some(effect) = &self.checked_effect {
             effect.trace(visitor);
         }
+        if let Some(effect) = &self.indeterminate_effect {
+            effect.trace(visitor);
+        }
     }
 }
 
@@ -78,11 +95,14 @@ impl Widget for Checkbox {
         let is_reactive = self.checked.is_reactive();
         let computed_styles = self.styles.as_ref().map(|s| s.compute());
 
-        let properties = if is_reactive {
+        let mut properties = if is_reactive {
             PropertyMap::new()
         } else {
             PropertyMap::with(CheckboxChecked(initial_checked))
         };
+        if let Some(indeterminate) = self.indeterminate.as_ref().filter(|v| !v.is_reactive()) {
+            properties.insert(CheckboxIndeterminate(indeterminate.get()));
+        }
 
         let component = Component::with_properties(id, ComponentType::Checkbox, properties);
 
@@ -104,7 +124,12 @@ impl Widget for Checkbox {
             None
         };
 
-        CheckboxState { component, checked_effect }
+        let indeterminate_effect = self
+            .indeterminate
+            .filter(|v| v.is_reactive())
+            .map(|indeterminate| create_indeterminate_effect(&component, indeterminate));
+
+        CheckboxState { component, checked_effect, indeterminate_effect }
     }
 
     fn rebuild(self, state: &mut Self::State) {
@@ -123,5 +148,28 @@ impl Widget for Checkbox {
             let new_checked = self.checked.get();
             state.component.set_checkbox_checked(new_checked);
         }
+
+        match self.indeterminate {
+            Some(indeterminate) if indeterminate.is_reactive() => {
+                if state.indeterminate_effect.is_none() {
+                    state.indeterminate_effect =
+                        Some(create_indeterminate_effect(&state.component, indeterminate));
+                }
+            }
+            Some(indeterminate) => state.component.set_checkbox_indeterminate(indeterminate.get()),
+            None => {}
+        }
     }
 }
+
+fn create_indeterminate_effect(
+    component: &Gc<Component>,
+    indeterminate: ReactiveValue<bool>,
+) -> Gc<crate::effect::Effect> {
+    let comp = Gc::clone(component);
+    let effect = create_effect(move || {
+        comp.set_checkbox_indeterminate(indeterminate.get());
+    });
+    component.add_effect(Gc::clone(&effect));
+    effect
+}
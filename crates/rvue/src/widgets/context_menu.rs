@@ -0,0 +1,232 @@
+//! ContextMenu widget for right-click overlay menus
+
+use crate::component::{Component, ComponentType};
+use crate::effect::create_effect;
+use crate::properties::{PropertyMap, TextContent};
+use crate::signal::{create_signal, ReadSignal, WriteSignal};
+use crate::widget::{BuildContext, Mountable, Widget};
+use rudo_gc::{Gc, Trace};
+use rvue_style::{BackgroundColor, Color, ComputedStyles, ReactiveStyles, TextColor};
+use std::rc::Rc;
+use vello::kurbo::Point;
+
+/// Shared open/closed state of a context menu.
+///
+/// The trigger opens the menu from its `on_context_menu` handler and the menu
+/// closes itself when an item is selected or the user clicks outside of it.
+#[derive(Clone)]
+pub struct ContextMenuHandle {
+    position: ReadSignal<Option<(f64, f64)>>,
+    set_position: WriteSignal<Option<(f64, f64)>>,
+}
+
+unsafe impl Trace for ContextMenuHandle {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.position.trace(visitor);
+        self.set_position.trace(visitor);
+    }
+}
+
+impl ContextMenuHandle {
+    /// Create a closed context menu handle
+    pub fn new() -> Self {
+        let (position, set_position) = create_signal(None);
+        Self { position, set_position }
+    }
+
+    /// Open the menu with its top-left corner at `position`
+    pub fn open_at(&self, position: Point) {
+        self.set_position.set(Some((position.x, position.y)));
+    }
+
+    /// Close the menu
+    pub fn close(&self) {
+        self.set_position.set(None);
+    }
+
+    /// Get the position the menu is open at
+    pub fn position(&self) -> Option<(f64, f64)> {
+        self.position.get()
+    }
+
+    /// Check whether the menu is open
+    pub fn is_open(&self) -> bool {
+        self.position.get_untracked().is_some()
+    }
+}
+
+impl Default for ContextMenuHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ContextMenuItem {
+    label: String,
+    on_select: Rc<dyn Fn()>,
+}
+
+/// ContextMenu widget builder
+///
+/// The menu is laid out as an overlay positioned relative to its parent, so it
+/// should be placed in the root container of the view.
+pub struct ContextMenu {
+    handle: ContextMenuHandle,
+    items: Vec<ContextMenuItem>,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for ContextMenu {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.handle.trace(visitor);
+        self.styles.trace(visitor);
+    }
+}
+
+impl ContextMenu {
+    /// Create a new ContextMenu driven by `handle`
+    pub fn new(handle: ContextMenuHandle) -> Self {
+        Self { handle, items: Vec::new(), styles: None }
+    }
+
+    /// Add an item that runs `on_select` and closes the menu when clicked
+    pub fn item(mut self, label: impl Into<String>, on_select: impl Fn() + 'static) -> Self {
+        self.items.push(ContextMenuItem { label: label.into(), on_select: Rc::new(on_select) });
+        self
+    }
+
+    /// Set the menu container styles
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// State for a mounted ContextMenu widget
+pub struct ContextMenuState {
+    component: Gc<Component>,
+    position_effect: Option<Gc<crate::effect::Effect>>,
+}
+
+impl ContextMenuState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for ContextMenuState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        if let Some(effect) = &self.position_effect {
+            effect.trace(visitor);
+        }
+    }
+}
+
+impl Mountable for ContextMenuState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+fn item_styles() -> ComputedStyles {
+    ComputedStyles {
+        background_color: Some(BackgroundColor(Color::rgb(255, 255, 255))),
+        text_color: Some(TextColor(Color::rgb(30, 30, 30))),
+        ..Default::default()
+    }
+}
+
+impl Widget for ContextMenu {
+    type State = ContextMenuState;
+
+    fn build(self, _ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let component =
+            Component::with_properties(id, ComponentType::ContextMenu, PropertyMap::new());
+
+        if let Some(styles) = self.styles.as_ref().map(|s| s.compute()) {
+            component.set_widget_styles(styles);
+        }
+
+        // Keep the handle on the component so outside clicks can dismiss the menu
+        *component.context_menu.borrow_mut_gen_only() = Some(self.handle.clone());
+
+        for item in self.items {
+            let button = Component::with_properties(
+                crate::component::next_component_id(),
+                ComponentType::Button,
+                PropertyMap::new(),
+            );
+            button.set_widget_styles(item_styles());
+
+            let label = Component::with_properties(
+                crate::component::next_component_id(),
+                ComponentType::Text,
+                PropertyMap::with(TextContent(item.label)),
+            );
+            button.add_child(Gc::clone(&label));
+            label.set_parent(Some(Gc::clone(&button)));
+
+            let handle = self.handle.clone();
+            let on_select = item.on_select;
+            button.on_click_0arg(move || {
+                on_select();
+                handle.close();
+            });
+
+            component.add_child(Gc::clone(&button));
+            button.set_parent(Some(Gc::clone(&component)));
+        }
+
+        let comp = Gc::clone(&component);
+        let handle = self.handle.clone();
+        let effect = create_effect(move || {
+            comp.set_context_menu_position(handle.position());
+        });
+        component.add_effect(Gc::clone(&effect));
+
+        ContextMenuState { component, position_effect: Some(effect) }
+    }
+
+    fn rebuild(self, _state: &mut Self::State) {
+        // The menu position is driven by the handle's signal
+    }
+}
+
+/// Close every open context menu under `root` that does not contain `target`.
+pub fn dismiss_context_menus(root: &Gc<Component>, target: Option<&Gc<Component>>) {
+    if matches!(root.component_type, ComponentType::ContextMenu)
+        && root.context_menu_position().is_some()
+        && !target.is_some_and(|t| is_descendant_of(t, root))
+    {
+        let handle = root.context_menu.borrow().clone();
+        match handle {
+            Some(handle) => handle.close(),
+            None => root.set_context_menu_position(None),
+        }
+    }
+
+    for child in root.children.borrow().iter() {
+        dismiss_context_menus(child, target);
+    }
+}
+
+fn is_descendant_of(component: &Gc<Component>, ancestor: &Gc<Component>) -> bool {
+    let mut current = Some(Gc::clone(component));
+    while let Some(c) = current {
+        if Gc::ptr_eq(&c, ancestor) {
+            return true;
+        }
+        current = c.parent.borrow().clone();
+    }
+    false
+}
@@ -0,0 +1,72 @@
+//! Divider widget, a thin line between sections
+
+use crate::widget::{BuildContext, Widget};
+use crate::widgets::flex::{Flex, FlexState};
+use rudo_gc::Trace;
+use rvue_style::{BackgroundColor, Color, FlexShrink, Height, ReactiveStyles, Size, Width};
+
+/// Thickness of a divider unless its styles set one
+pub const DIVIDER_THICKNESS: f32 = 1.0;
+
+/// Divider widget builder drawing a 1px line across its container
+///
+/// A horizontal divider is 1px high and stretches across a column; a vertical one is 1px
+/// wide and stretches down a row.
+#[derive(Clone, Default)]
+pub struct Divider {
+    vertical: bool,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Divider {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.styles.trace(visitor);
+    }
+}
+
+impl Divider {
+    /// Create a new horizontal Divider
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw the line top to bottom, to separate items in a row
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    /// Set the styles directly, over the default thickness and color
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    fn line_styles(&self) -> ReactiveStyles {
+        let thickness = Size::Pixels(DIVIDER_THICKNESS);
+        let line = ReactiveStyles::new()
+            .set_background_color(BackgroundColor(Color::rgb(224, 224, 224)))
+            .set_flex_shrink(FlexShrink(0.0));
+        let line = if self.vertical {
+            line.set_width(Width(thickness))
+        } else {
+            line.set_height(Height(thickness))
+        };
+        match &self.styles {
+            Some(styles) => line.merge(styles.clone()),
+            None => line,
+        }
+    }
+}
+
+impl Widget for Divider {
+    type State = FlexState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        Flex::new().styles(self.line_styles()).build(ctx)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        Flex::new().styles(self.line_styles()).rebuild(state)
+    }
+}
@@ -233,7 +233,7 @@ impl Widget for Flex {
             component.set_widget_styles(styles);
         }
 
-        if overflow_x.should_clip() || overflow_y.should_clip() {
+        if overflow_x.is_scroll_container() || overflow_y.is_scroll_container() {
             component.flags.borrow_mut_gen_only().insert(ComponentFlags::ACCEPTS_POINTER);
         }
 
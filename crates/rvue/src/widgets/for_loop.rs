@@ -8,13 +8,21 @@ use crate::view::View;
 use crate::widget::{
     with_build_context, BuildContext, IntoReactiveValue, Mountable, ReactiveValue, Widget,
 };
-use crate::widgets::keyed_state::KeyedState;
+use crate::widgets::keyed_state::{ItemEntry, KeyedState};
 use indexmap::IndexSet;
 use log::warn;
 use rudo_gc::{Gc, GcCell, Trace};
 use rustc_hash::FxHasher;
+use std::collections::HashMap;
 use std::hash::{BuildHasherDefault, Hash};
 
+/// Renders a view per item, keyed by `key_fn`
+///
+/// When `items` changes, items whose key is still present keep their components and are only
+/// reordered; new keys build a view, and removed keys are disposed. Built with
+/// [`For::with_eq`], an item whose key is unchanged but whose value is not is rebuilt too. Keys
+/// should be unique: a duplicate still renders, but is matched against the previous list as a
+/// new item.
 pub struct For<T, K, KF, VF>
 where
     T: Clone + Trace + Send + Sync + 'static,
@@ -25,6 +33,8 @@ where
     pub items: ReactiveValue<Vec<T>>,
     pub key_fn: KF,
     pub view_fn: VF,
+    /// Compares an item with its previous value under the same key; `None` reuses by key alone
+    pub item_eq: Option<fn(&T, &T) -> bool>,
 }
 
 impl<T, K, KF, VF> Clone for For<T, K, KF, VF>
@@ -39,6 +49,7 @@ where
             items: self.items.clone(),
             key_fn: self.key_fn.clone(),
             view_fn: self.view_fn.clone(),
+            item_eq: self.item_eq,
         }
     }
 }
@@ -63,7 +74,20 @@ where
     VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
 {
     pub fn new(items: impl IntoReactiveValue<Vec<T>>, key_fn: KF, view_fn: VF) -> Self {
-        Self { items: items.into_reactive(), key_fn, view_fn }
+        Self { items: items.into_reactive(), key_fn, view_fn, item_eq: None }
+    }
+}
+
+impl<T, K, KF, VF> For<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + Send + Sync + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    /// Like [`For::new`], but an item whose key is unchanged is rebuilt when its value changed
+    pub fn with_eq(items: impl IntoReactiveValue<Vec<T>>, key_fn: KF, view_fn: VF) -> Self {
+        Self { item_eq: Some(T::eq), ..Self::new(items, key_fn, view_fn) }
     }
 }
 
@@ -117,6 +141,7 @@ where
         new_items: Vec<T>,
         key_fn: &KF,
         view_fn: &VF,
+        item_eq: Option<fn(&T, &T) -> bool>,
         ctx: &mut BuildContext,
     ) -> IndexSet<K, BuildHasherDefault<FxHasher>> {
         let mut new_keys: IndexSet<K, BuildHasherDefault<FxHasher>> =
@@ -126,25 +151,36 @@ where
         for item in &new_items {
             let key = key_fn(item);
             if !seen_keys.insert(key.clone()) {
-                warn!("Duplicate key found in For component - using first occurrence");
+                warn!("Duplicate key found in For component - keys should be unique");
                 continue;
             }
             new_keys.insert(key);
         }
 
         {
-            // Safety-first strategy: rebuild the keyed list each update.
-            // This avoids complex move/remove bookkeeping that can leave stale component refs.
+            // Keep the components of keys still present and rebuild the list order around them;
+            // only new keys and changed values build a view, and removed keys are torn down.
             let mut keyed_state = self.keyed_state.borrow_mut();
             let mut rendered_items_mut = keyed_state.rendered_items.borrow_mut();
 
-            for entry in rendered_items_mut.iter_mut().flatten() {
-                entry.component.unmount();
+            let mut previous: HashMap<K, ItemEntry<K, T>> = HashMap::new();
+            for entry in rendered_items_mut.drain(..).flatten() {
+                // Only one entry per key can be reused; the others of a duplicate key go now
+                if let Some(displaced) = previous.insert(entry.key.clone(), entry) {
+                    displaced.component.dispose();
+                }
             }
-            rendered_items_mut.clear();
 
             for item in &new_items {
                 let key = key_fn(item);
+                match previous.remove(&key) {
+                    Some(entry) if item_eq.is_none_or(|eq| eq(&entry.item, item)) => {
+                        rendered_items_mut.push(Some(entry));
+                        continue;
+                    }
+                    Some(entry) => entry.component.dispose(),
+                    None => {}
+                }
                 let view = with_build_context(ctx, || {
                     with_owner(Gc::clone(&keyed_state.marker), || view_fn(item.clone()))
                 });
@@ -152,7 +188,7 @@ where
                 child_component.set_parent(Some(Gc::clone(&keyed_state.marker)));
                 child_component.mount(None);
 
-                rendered_items_mut.push(Some(crate::widgets::keyed_state::ItemEntry {
+                rendered_items_mut.push(Some(ItemEntry {
                     key,
                     item: item.clone(),
                     component: Gc::clone(&child_component),
@@ -160,6 +196,10 @@ where
                 }));
             }
 
+            for entry in previous.into_values() {
+                entry.component.dispose();
+            }
+
             reorder_children(&keyed_state.marker, &rendered_items_mut);
             drop(rendered_items_mut);
             keyed_state.hashed_items = new_keys.clone();
@@ -267,6 +307,7 @@ where
         let comp_clone = Gc::clone(&component);
         let key_fn_clone = self.key_fn;
         let view_fn_clone = self.view_fn;
+        let item_eq = self.item_eq;
         let items_reactive = self.items.clone();
         let keyed_state_for_effect = Gc::clone(&keyed_state_gc_shared);
 
@@ -293,6 +334,7 @@ where
                         new_items,
                         &key_fn_clone,
                         &view_fn_clone,
+                        item_eq,
                         &mut temp_ctx,
                     );
                 }
@@ -322,9 +364,396 @@ where
             let mut temp_id_counter = crate::component::next_component_id();
             let mut temp_ctx =
                 BuildContext::new(&mut temp_taffy, &mut temp_text_context, &mut temp_id_counter);
-            let _ = state.update_keyed_items(new_items, &self.key_fn, &self.view_fn, &mut temp_ctx);
+            let _ = state.update_keyed_items(
+                new_items,
+                &self.key_fn,
+                &self.view_fn,
+                self.item_eq,
+                &mut temp_ctx,
+            );
             state.component.properties.borrow_mut_gen_only().insert(ForItemCount(new_count));
             state.component.mark_dirty();
         }
     }
 }
+
+/// Rows built beyond each edge of the viewport by default
+const DEFAULT_OVERSCAN: usize = 3;
+
+/// Renders only the items scrolled into view, for long lists of equal-height rows
+///
+/// The widget is a vertical scroll container `height` tall. Each item is shown in a row
+/// `item_height` tall, and only the rows in the viewport, plus `overscan` rows past each edge,
+/// are built; spacers stand in for the rest so the scroll range covers the whole list. As the
+/// list scrolls, rows that stay in the window keep their components by key while their value
+/// is unchanged, rows that leave it are disposed and rows that enter it build their view.
+pub struct VirtualFor<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + Send + Sync + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    pub items: ReactiveValue<Vec<T>>,
+    pub key_fn: KF,
+    pub view_fn: VF,
+    pub item_height: f64,
+    pub height: f64,
+    pub overscan: usize,
+}
+
+impl<T, K, KF, VF> VirtualFor<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + Send + Sync + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    pub fn new(
+        items: impl IntoReactiveValue<Vec<T>>,
+        key_fn: KF,
+        view_fn: VF,
+        item_height: f64,
+        height: f64,
+    ) -> Self {
+        Self {
+            items: items.into_reactive(),
+            key_fn,
+            view_fn,
+            item_height,
+            height,
+            overscan: DEFAULT_OVERSCAN,
+        }
+    }
+
+    /// Build `overscan` rows past each edge of the viewport, so short scrolls show built rows
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+unsafe impl<T, K, KF, VF> Trace for VirtualFor<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + Send + Sync + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.items.trace(visitor);
+    }
+}
+
+/// The built rows of a [`VirtualFor`] and the items they are cut from
+struct VirtualWindow<T, K, KF, VF> {
+    component: Gc<Component>,
+    top_spacer: Gc<Component>,
+    bottom_spacer: Gc<Component>,
+    items: Vec<T>,
+    rows: Vec<(K, T, Gc<Component>)>,
+    range: std::ops::Range<usize>,
+    scroll_offset: f64,
+    key_fn: KF,
+    view_fn: VF,
+    item_height: f64,
+    height: f64,
+    overscan: usize,
+}
+
+unsafe impl<T, K, KF, VF> Trace for VirtualWindow<T, K, KF, VF>
+where
+    T: Trace,
+{
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        self.top_spacer.trace(visitor);
+        self.bottom_spacer.trace(visitor);
+        self.items.trace(visitor);
+        for (_, item, row) in &self.rows {
+            item.trace(visitor);
+            row.trace(visitor);
+        }
+        // The view and key functions may capture signals
+        crate::gc::trace_opaque(&self.key_fn, visitor);
+        crate::gc::trace_opaque(&self.view_fn, visitor);
+    }
+}
+
+impl<T, K, KF, VF> rudo_gc::cell::GcCapture for VirtualWindow<T, K, KF, VF>
+where
+    T: rudo_gc::cell::GcCapture,
+{
+    fn capture_gc_ptrs(&self) -> &[std::ptr::NonNull<rudo_gc::GcBox<()>>] {
+        &[]
+    }
+
+    fn capture_gc_ptrs_into(&self, ptrs: &mut Vec<std::ptr::NonNull<rudo_gc::GcBox<()>>>) {
+        self.component.capture_gc_ptrs_into(ptrs);
+        self.top_spacer.capture_gc_ptrs_into(ptrs);
+        self.bottom_spacer.capture_gc_ptrs_into(ptrs);
+        for item in &self.items {
+            item.capture_gc_ptrs_into(ptrs);
+        }
+        for (_, item, row) in &self.rows {
+            item.capture_gc_ptrs_into(ptrs);
+            row.capture_gc_ptrs_into(ptrs);
+        }
+    }
+}
+
+impl<T, K, KF, VF> VirtualWindow<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    /// Items in the viewport at the current offset, widened by the overscan
+    fn visible_range(&self) -> std::ops::Range<usize> {
+        if self.item_height <= 0.0 {
+            return 0..self.items.len();
+        }
+        let first = (self.scroll_offset / self.item_height).floor() as usize;
+        let last = ((self.scroll_offset + self.height) / self.item_height).ceil() as usize;
+        let start = first.saturating_sub(self.overscan).min(self.items.len());
+        let end = (last + self.overscan).min(self.items.len());
+        start..end
+    }
+
+    fn set_items(&mut self, items: Vec<T>, ctx: &mut BuildContext) {
+        self.items = items;
+        self.render(ctx);
+    }
+
+    fn scroll_to(&mut self, scroll_offset: f64) {
+        self.scroll_offset = scroll_offset;
+        if self.visible_range() != self.range {
+            with_temp_build_context(|ctx| self.render(ctx));
+        }
+    }
+
+    /// Rebuild the row list for the current window, reusing the rows of keys still in it
+    fn render(&mut self, ctx: &mut BuildContext) {
+        let range = self.visible_range();
+        let mut previous: HashMap<K, (T, Gc<Component>)> = HashMap::new();
+        for (key, item, row) in self.rows.drain(..) {
+            // Only one row per key can be reused; the others of a duplicate key go now
+            if let Some((_, displaced)) = previous.insert(key, (item, row)) {
+                displaced.dispose();
+            }
+        }
+
+        for item in &self.items[range.clone()] {
+            let key = (self.key_fn)(item);
+            let row = match previous.remove(&key) {
+                Some((previous_item, row)) if previous_item == *item => row,
+                Some((_, row)) => {
+                    row.dispose();
+                    self.build_row(item, ctx)
+                }
+                None => self.build_row(item, ctx),
+            };
+            self.rows.push((key, item.clone(), row));
+        }
+        for (_, row) in previous.into_values() {
+            row.dispose();
+        }
+
+        set_fixed_height(&self.top_spacer, range.start as f64 * self.item_height);
+        set_fixed_height(
+            &self.bottom_spacer,
+            (self.items.len() - range.end) as f64 * self.item_height,
+        );
+
+        {
+            let mut children = self.component.children.borrow_mut();
+            children.clear();
+            children.push(Gc::clone(&self.top_spacer));
+            children.extend(self.rows.iter().map(|(_, _, row)| Gc::clone(row)));
+            children.push(Gc::clone(&self.bottom_spacer));
+        }
+        self.range = range;
+        self.component.mark_dirty();
+    }
+
+    /// Wrap an item's view in a row of the fixed item height
+    fn build_row(&self, item: &T, ctx: &mut BuildContext) -> Gc<Component> {
+        let row = fixed_height_box(self.item_height);
+        row.set_parent(Some(Gc::clone(&self.component)));
+
+        let view = with_build_context(ctx, || {
+            // The row owns what the view creates, so it goes when the row scrolls out
+            with_owner(Gc::clone(&row), || (self.view_fn)(item.clone()))
+        });
+        let child_component = view.into_component();
+        row.add_child(Gc::clone(&child_component));
+        child_component.set_parent(Some(Gc::clone(&row)));
+        child_component.mount(None);
+        row
+    }
+}
+
+/// A flex box that neither grows nor shrinks from `height`
+fn fixed_height_box(height: f64) -> Gc<Component> {
+    let component = Component::with_properties(
+        crate::component::next_component_id(),
+        ComponentType::Flex,
+        PropertyMap::new(),
+    );
+    set_fixed_height(&component, height);
+    component
+}
+
+fn set_fixed_height(component: &Gc<Component>, height: f64) {
+    use rvue_style::properties::MinHeight;
+    use rvue_style::{Height, Size};
+
+    let size = Size::Pixels(height as f32);
+    component.set_widget_styles(rvue_style::ComputedStyles {
+        flex_direction: Some(rvue_style::FlexDirection::Column),
+        height: Some(Height(size.clone())),
+        min_height: Some(MinHeight(size)),
+        ..component.widget_styles().unwrap_or_default()
+    });
+}
+
+/// Run `f` with a build context of its own, for views built outside of `Widget::build`
+fn with_temp_build_context<R>(f: impl FnOnce(&mut BuildContext) -> R) -> R {
+    let mut taffy = taffy::TaffyTree::new();
+    let mut text_context = crate::text::TextContext::new();
+    let mut id_counter = crate::component::next_component_id();
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    f(&mut ctx)
+}
+
+pub struct VirtualForState<T, K, KF, VF>
+where
+    T: Trace + 'static,
+    K: 'static,
+    KF: 'static,
+    VF: 'static,
+{
+    component: Gc<Component>,
+    window: Gc<GcCell<VirtualWindow<T, K, KF, VF>>>,
+}
+
+unsafe impl<T, K, KF, VF> Trace for VirtualForState<T, K, KF, VF>
+where
+    T: Trace + 'static,
+    K: 'static,
+    KF: 'static,
+    VF: 'static,
+{
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        self.window.trace(visitor);
+    }
+}
+
+impl<T, K, KF, VF> VirtualForState<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+
+    /// Indices of the items that currently have a built row
+    pub fn built_range(&self) -> std::ops::Range<usize> {
+        self.window.borrow().range.clone()
+    }
+}
+
+impl<T, K, KF, VF> Mountable for VirtualForState<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl<T, K, KF, VF> Widget for VirtualFor<T, K, KF, VF>
+where
+    T: Clone + PartialEq + Trace + rudo_gc::cell::GcCapture + Send + Sync + 'static,
+    K: Eq + Hash + Clone + 'static,
+    KF: Fn(&T) -> K + Clone + 'static,
+    VF: Fn(T) -> crate::ViewStruct + Clone + 'static,
+{
+    type State = VirtualForState<T, K, KF, VF>;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        use rvue_style::{Height, Overflow, Size};
+
+        let component = Component::with_properties(
+            crate::component::next_component_id(),
+            ComponentType::Flex,
+            PropertyMap::new(),
+        );
+        component.set_widget_styles(rvue_style::ComputedStyles {
+            flex_direction: Some(rvue_style::FlexDirection::Column),
+            height: Some(Height(Size::Pixels(self.height as f32))),
+            overflow_y: Some(Overflow::Auto),
+            ..Default::default()
+        });
+
+        let top_spacer = fixed_height_box(0.0);
+        let bottom_spacer = fixed_height_box(0.0);
+        top_spacer.set_parent(Some(Gc::clone(&component)));
+        bottom_spacer.set_parent(Some(Gc::clone(&component)));
+
+        let window = Gc::new(GcCell::new(VirtualWindow {
+            component: Gc::clone(&component),
+            top_spacer,
+            bottom_spacer,
+            items: Vec::new(),
+            rows: Vec::new(),
+            range: 0..0,
+            scroll_offset: 0.0,
+            key_fn: self.key_fn,
+            view_fn: self.view_fn,
+            item_height: self.item_height,
+            height: self.height,
+            overscan: self.overscan,
+        }));
+        window.borrow_mut().set_items(self.items.get(), ctx);
+
+        // The window and the component point at each other through traced handles, so the
+        // collector reclaims both together
+        let scrolled = Gc::clone(&window);
+        component.on_scroll_offset_change(move |offset| scrolled.borrow_mut().scroll_to(offset.y));
+
+        if self.items.is_reactive() {
+            let items = self.items.clone();
+            let updated = Gc::clone(&window);
+            let effect = create_effect(move || {
+                let items = items.get();
+                with_temp_build_context(|ctx| updated.borrow_mut().set_items(items, ctx));
+            });
+            component.add_effect(effect);
+        }
+
+        VirtualForState { component, window }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if !self.items.is_reactive() {
+            let items = self.items.get();
+            with_temp_build_context(|ctx| state.window.borrow_mut().set_items(items, ctx));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Form widget, collecting the values of its fields on submit
+
+use crate::component::{Component, ComponentType};
+use crate::context::inject;
+use crate::effect::on_cleanup;
+use crate::runtime::with_owner;
+use crate::widget::{BuildContext, Mountable, Widget};
+use crate::widgets::Flex;
+use rudo_gc::{Gc, Trace};
+use rvue_style::{FlexDirection, ReactiveStyles};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+type ChildBuilder = Box<dyn Fn(&mut BuildContext) -> Gc<Component>>;
+
+/// Values of a form's fields by field name
+pub type FormValues = BTreeMap<String, String>;
+
+/// Context a `Form` provides to its content
+///
+/// Inputs given a name register with the nearest form when built, and unregister when their
+/// owner is cleaned up.
+#[derive(Clone)]
+pub struct FormContext {
+    fields: Rc<RefCell<Vec<(String, Gc<Component>)>>>,
+    on_submit: Option<Rc<dyn Fn(&FormValues)>>,
+}
+
+unsafe impl Trace for FormContext {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        for (_, field) in self.fields.borrow().iter() {
+            field.trace(visitor);
+        }
+    }
+}
+
+impl FormContext {
+    fn new(on_submit: Option<Rc<dyn Fn(&FormValues)>>) -> Self {
+        Self { fields: Rc::new(RefCell::new(Vec::new())), on_submit }
+    }
+
+    /// Collect the value of `component` under `name` on submit
+    pub fn register(&self, name: impl Into<String>, component: &Gc<Component>) {
+        self.fields.borrow_mut().push((name.into(), Gc::clone(component)));
+
+        let fields = Rc::clone(&self.fields);
+        let component = Gc::clone(component);
+        on_cleanup(move || {
+            fields.borrow_mut().retain(|(_, field)| !Gc::ptr_eq(field, &component));
+        });
+    }
+
+    /// Current values of the registered fields
+    pub fn values(&self) -> FormValues {
+        self.fields
+            .borrow()
+            .iter()
+            .map(|(name, field)| (name.clone(), field_value(field)))
+            .collect()
+    }
+
+    /// Whether any registered field has a validation error
+    pub fn is_invalid(&self) -> bool {
+        self.fields.borrow().iter().any(|(_, field)| field.is_invalid())
+    }
+
+    /// Pass the field values to the `on_submit` handler, unless a field is invalid
+    ///
+    /// Returns whether the form was submitted.
+    pub fn submit(&self) -> bool {
+        if self.is_invalid() {
+            return false;
+        }
+        let values = self.values();
+        if let Some(on_submit) = &self.on_submit {
+            on_submit(&values);
+        }
+        true
+    }
+}
+
+/// Value a field contributes to its form
+fn field_value(field: &Gc<Component>) -> String {
+    match field.component_type {
+        ComponentType::NumberInput => field.number_input_value().to_string(),
+        _ => field.text_input_value(),
+    }
+}
+
+/// Register `component` under `name` with the enclosing form, if it is built inside one
+pub(crate) fn register_form_field(name: Option<String>, component: &Gc<Component>) {
+    if let (Some(name), Some(form)) = (name, inject::<FormContext>()) {
+        form.register(name, component);
+    }
+}
+
+/// Submit the form enclosing `component`, if there is one
+pub(crate) fn submit_enclosing_form(component: &Gc<Component>) {
+    if let Some(form) = component.find_context::<FormContext>() {
+        form.submit();
+    }
+}
+
+/// Form widget builder gathering the values of the named inputs inside it
+///
+/// Pressing Enter in a single-line field, or activating a button built with
+/// [`Button::submit`](crate::widgets::Button::submit), submits the form: `on_submit` receives
+/// the value of every named field, unless one of them has a validation error, in which case
+/// nothing is submitted. The container has the `form` class.
+pub struct Form {
+    children_fn: ChildBuilder,
+    on_submit: Option<Rc<dyn Fn(&FormValues)>>,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Form {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.styles.trace(visitor);
+    }
+}
+
+impl Form {
+    /// Create a new Form around the content built by `children_fn`
+    pub fn new(children_fn: impl Fn(&mut BuildContext) -> Gc<Component> + 'static) -> Self {
+        Self { children_fn: Box::new(children_fn), on_submit: None, styles: None }
+    }
+
+    /// Handle a submit with the values of the fields
+    pub fn on_submit(mut self, on_submit: impl Fn(&FormValues) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(on_submit));
+        self
+    }
+
+    /// Set the styles of the container holding the content
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// State for a mounted Form widget
+pub struct FormState {
+    component: Gc<Component>,
+    context: FormContext,
+}
+
+impl FormState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+
+    /// Current values of the form's fields
+    pub fn values(&self) -> FormValues {
+        self.context.values()
+    }
+
+    /// Submit the form as Enter or a submit button would; returns whether it was submitted
+    pub fn submit(&self) -> bool {
+        self.context.submit()
+    }
+}
+
+unsafe impl Trace for FormState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        self.context.trace(visitor);
+    }
+}
+
+impl Mountable for FormState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Form {
+    type State = FormState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        let container_styles = ReactiveStyles::new().set_flex_direction(FlexDirection::Column);
+        let container_styles = match self.styles {
+            Some(styles) => container_styles.merge(styles),
+            None => container_styles,
+        };
+        let component = Gc::clone(
+            Flex::new()
+                .direction(FlexDirection::Column)
+                .styles(container_styles)
+                .build(ctx)
+                .component(),
+        );
+        component.add_class("form");
+
+        let context = FormContext::new(self.on_submit);
+        component.provide_context(context.clone());
+
+        let content = with_owner(Gc::clone(&component), || (self.children_fn)(ctx));
+        content.set_parent(Some(Gc::clone(&component)));
+        component.add_child(content);
+
+        FormState { component, context }
+    }
+
+    fn rebuild(self, _state: &mut Self::State) {
+        // Fields register themselves as they are built; nothing to update from the builder
+    }
+}
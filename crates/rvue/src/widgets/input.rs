@@ -2,10 +2,54 @@
 
 use crate::component::{Component, ComponentType};
 use crate::effect::create_effect;
-use crate::properties::{NumberInputValue, PropertyMap, TextInputValue};
+use crate::properties::{
+    NumberInputRange, NumberInputSteppers, NumberInputValue, PropertyMap, TextInputValue,
+};
 use crate::widget::{BuildContext, Mountable, ReactiveValue, Widget};
+use crate::widgets::form::register_form_field;
 use rudo_gc::{Gc, Trace};
 use rvue_style::ReactiveStyles;
+use vello::kurbo::{Point, Rect, Size};
+
+/// Width of the column of stepper buttons at the right edge of a NumberInput
+pub const NUMBER_INPUT_STEPPER_WIDTH: f64 = 20.0;
+
+/// Font size of the validation error shown below an input
+pub const VALIDATION_MESSAGE_FONT_SIZE: f32 = 12.0;
+
+/// Room left below an invalid input for its validation error
+pub const VALIDATION_MESSAGE_HEIGHT: f32 = 18.0;
+
+/// Keep `component`'s validation error in step with `error`
+///
+/// Returns the effect doing so when `error` is reactive; a static error is applied at once.
+pub(crate) fn bind_error_message(
+    component: &Gc<Component>,
+    error: ReactiveValue<Option<String>>,
+) -> Option<Gc<crate::effect::Effect>> {
+    if !error.is_reactive() {
+        component.set_error_message(error.get());
+        return None;
+    }
+    let comp = Gc::clone(component);
+    let effect = create_effect(move || {
+        comp.set_error_message(error.get());
+    });
+    component.add_effect(Gc::clone(&effect));
+    Some(effect)
+}
+
+/// Text as a single-line input takes it in, with line breaks stripped
+///
+/// Pasting or committing `"a\nb"` into a TextInput inserts `"ab"`, the way HTML text inputs
+/// sanitize their value.
+pub(crate) fn single_line_text(text: &str) -> std::borrow::Cow<'_, str> {
+    if text.contains(['\n', '\r']) {
+        text.replace(['\n', '\r'], "").into()
+    } else {
+        text.into()
+    }
+}
 
 /// TextInput widget builder for text input
 #[derive(Clone)]
@@ -13,6 +57,8 @@ pub struct TextInput {
     value: ReactiveValue<String>,
     styles: Option<ReactiveStyles>,
     clip: ReactiveValue<bool>,
+    error: Option<ReactiveValue<Option<String>>>,
+    name: Option<String>,
 }
 
 unsafe impl Trace for TextInput {
@@ -20,13 +66,20 @@ unsafe impl Trace for TextInput {
         self.value.trace(visitor);
         self.styles.trace(visitor);
         self.clip.trace(visitor);
+        self.error.trace(visitor);
     }
 }
 
 impl TextInput {
     /// Create a new TextInput widget with a value
     pub fn new(value: impl crate::widget::IntoReactiveValue<String>) -> Self {
-        Self { value: value.into_reactive(), styles: None, clip: ReactiveValue::Static(true) }
+        Self {
+            value: value.into_reactive(),
+            styles: None,
+            clip: ReactiveValue::Static(true),
+            error: None,
+            name: None,
+        }
     }
 
     /// Set the styles directly
@@ -43,6 +96,21 @@ impl TextInput {
         self.clip = clip.into();
         self
     }
+
+    /// Name the input's value is collected under by an enclosing [`Form`](crate::widgets::Form)
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Show a validation error below the input, or none for `None`
+    ///
+    /// While it has an error the input matches the `:invalid` pseudo-class, which the default
+    /// stylesheet gives a red border.
+    pub fn error(mut self, error: impl crate::widget::IntoReactiveValue<Option<String>>) -> Self {
+        self.error = Some(error.into_reactive());
+        self
+    }
 }
 
 /// State for a mounted TextInput widget
@@ -50,6 +118,7 @@ pub struct TextInputState {
     component: Gc<Component>,
     value_effect: Option<Gc<crate::effect::Effect>>,
     clip_effect: Option<Gc<crate::effect::Effect>>,
+    error_effect: Option<Gc<crate::effect::Effect>>,
 }
 
 impl TextInputState {
@@ -68,6 +137,9 @@ unsafe impl Trace for TextInputState {
         if let Some(effect) = &self.clip_effect {
             effect.trace(visitor);
         }
+        if let Some(effect) = &self.error_effect {
+            effect.trace(visitor);
+        }
     }
 }
 
@@ -87,6 +159,9 @@ impl Mountable for TextInputState {
         if let Some(ref effect) = self.clip_effect {
             self.component.remove_effect(effect);
         }
+        if let Some(ref effect) = self.error_effect {
+            self.component.remove_effect(effect);
+        }
     }
 }
 
@@ -140,10 +215,15 @@ impl Widget for TextInput {
             None
         };
 
-        TextInputState { component, value_effect, clip_effect: None }
+        let error_effect = self.error.and_then(|error| bind_error_message(&component, error));
+        register_form_field(self.name, &component);
+
+        TextInputState { component, value_effect, clip_effect: None, error_effect }
     }
 
     fn rebuild(self, state: &mut Self::State) {
+        rebuild_error(self.error, &state.component, &mut state.error_effect);
+
         let clip = self.clip.get();
         state.component.set_clip(clip);
 
@@ -185,20 +265,73 @@ impl Widget for TextInput {
 #[derive(Clone)]
 pub struct NumberInput {
     value: ReactiveValue<f64>,
+    range: NumberInputRange,
+    steppers: bool,
     styles: Option<ReactiveStyles>,
+    error: Option<ReactiveValue<Option<String>>>,
+    name: Option<String>,
 }
 
 unsafe impl Trace for NumberInput {
     fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
         self.value.trace(visitor);
         self.styles.trace(visitor);
+        self.error.trace(visitor);
     }
 }
 
 impl NumberInput {
     /// Create a new NumberInput widget with a value
     pub fn new(value: impl crate::widget::IntoReactiveValue<f64>) -> Self {
-        Self { value: value.into_reactive(), styles: None }
+        Self {
+            value: value.into_reactive(),
+            range: NumberInputRange { min: f64::NEG_INFINITY, max: f64::INFINITY, step: 1.0 },
+            steppers: false,
+            styles: None,
+            error: None,
+            name: None,
+        }
+    }
+
+    /// Lowest value the steppers and mouse wheel go down to
+    pub fn min(mut self, min: f64) -> Self {
+        self.range.min = min;
+        self
+    }
+
+    /// Highest value the steppers and mouse wheel go up to
+    pub fn max(mut self, max: f64) -> Self {
+        self.range.max = max;
+        self
+    }
+
+    /// Amount each stepper click or wheel notch changes the value by
+    pub fn step(mut self, step: f64) -> Self {
+        self.range.step = step;
+        self
+    }
+
+    /// Show up/down stepper buttons beside the field
+    ///
+    /// Either way, turning the mouse wheel over the field steps the value.
+    pub fn steppers(mut self, steppers: bool) -> Self {
+        self.steppers = steppers;
+        self
+    }
+
+    /// Name the input's value is collected under by an enclosing [`Form`](crate::widgets::Form)
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Show a validation error below the input, or none for `None`
+    ///
+    /// While it has an error the input matches the `:invalid` pseudo-class, which the default
+    /// stylesheet gives a red border.
+    pub fn error(mut self, error: impl crate::widget::IntoReactiveValue<Option<String>>) -> Self {
+        self.error = Some(error.into_reactive());
+        self
     }
 
     /// Set the styles directly
@@ -212,6 +345,7 @@ impl NumberInput {
 pub struct NumberInputState {
     component: Gc<Component>,
     value_effect: Option<Gc<crate::effect::Effect>>,
+    error_effect: Option<Gc<crate::effect::Effect>>,
 }
 
 impl NumberInputState {
@@ -227,6 +361,9 @@ unsafe impl Trace for NumberInputState {
         if let Some(effect) = &self.value_effect {
             effect.trace(visitor);
         }
+        if let Some(effect) = &self.error_effect {
+            effect.trace(visitor);
+        }
     }
 }
 
@@ -252,11 +389,13 @@ impl Widget for NumberInput {
         let is_reactive = self.value.is_reactive();
         let computed_styles = self.styles.as_ref().map(|s| s.compute());
 
-        let properties = if is_reactive {
+        let mut properties = if is_reactive {
             PropertyMap::new()
         } else {
             PropertyMap::with(NumberInputValue(initial_value))
         };
+        properties.insert(self.range.clone());
+        properties.insert(NumberInputSteppers(self.steppers));
 
         let component = Component::with_properties(id, ComponentType::NumberInput, properties);
 
@@ -283,10 +422,14 @@ impl Widget for NumberInput {
             None
         };
 
-        NumberInputState { component, value_effect }
+        let error_effect = self.error.and_then(|error| bind_error_message(&component, error));
+        register_form_field(self.name, &component);
+
+        NumberInputState { component, value_effect, error_effect }
     }
 
     fn rebuild(self, state: &mut Self::State) {
+        rebuild_error(self.error, &state.component, &mut state.error_effect);
         if self.value.is_reactive() {
             if state.value_effect.is_none() {
                 let comp = Gc::clone(&state.component);
@@ -302,5 +445,52 @@ impl Widget for NumberInput {
             let new_value = self.value.get();
             state.component.set_number_input_value(new_value);
         }
+        state.component.set_number_input_range(self.range.min, self.range.max, self.range.step);
+        if state.component.number_input_steppers() != self.steppers {
+            state.component.set_number_input_steppers(self.steppers);
+        }
+    }
+}
+
+/// Follow a rebuilt widget's `error`: a static error is applied, a reactive one gets an effect
+/// the first time
+pub(crate) fn rebuild_error(
+    error: Option<ReactiveValue<Option<String>>>,
+    component: &Gc<Component>,
+    error_effect: &mut Option<Gc<crate::effect::Effect>>,
+) {
+    match error {
+        Some(error) if error.is_reactive() => {
+            if error_effect.is_none() {
+                *error_effect = bind_error_message(component, error);
+            }
+        }
+        Some(error) => component.set_error_message(error.get()),
+        None => {}
+    }
+}
+
+/// `value` moved by `steps` times the step of `range`, clamped to its bounds
+pub(crate) fn step_number(value: f64, steps: f64, range: &NumberInputRange) -> f64 {
+    (value + range.step * steps).max(range.min).min(range.max)
+}
+
+/// Up and down stepper buttons of a NumberInput of `size`, stacked at its right edge
+pub fn stepper_rects(size: Size) -> (Rect, Rect) {
+    let x0 = (size.width - NUMBER_INPUT_STEPPER_WIDTH).max(0.0);
+    let middle = size.height / 2.0;
+    (Rect::new(x0, 0.0, size.width, middle), Rect::new(x0, middle, size.width, size.height))
+}
+
+/// Steps taken by pressing at `position` in a NumberInput of `size`: 1 on the up stepper,
+/// -1 on the down one, `None` elsewhere
+pub fn stepper_at(size: Size, position: Point) -> Option<f64> {
+    let (up, down) = stepper_rects(size);
+    if up.contains(position) {
+        Some(1.0)
+    } else if down.contains(position) {
+        Some(-1.0)
+    } else {
+        None
     }
 }
@@ -0,0 +1,156 @@
+//! Link widget, clickable text pointing at a URL
+
+use crate::component::Component;
+use crate::widget::{BuildContext, IntoReactiveValue, Mountable, Widget};
+use crate::widgets::text::{Text, TextState};
+use rudo_gc::{Gc, Trace};
+use rvue_style::properties::color::RgbColor;
+use rvue_style::{Color, Cursor, ReactiveStyles, TextColor, TextDecoration};
+use std::rc::Rc;
+
+/// Text color of a link unless its styles set one
+pub const LINK_COLOR: Color = Color(RgbColor::rgb(37, 99, 235));
+
+/// Text color of a visited link
+pub const VISITED_LINK_COLOR: Color = Color(RgbColor::rgb(124, 58, 237));
+
+/// Link widget builder, text that hands its URL to `on_click` when clicked
+///
+/// Links show the pointer cursor and are underlined while hovered. The text has the `link`
+/// class, and also the `visited` class once clicked if [`track_visited`](Self::track_visited)
+/// is on. Opening the URL, e.g. in the default browser, is left to the `on_click` handler.
+pub struct Link {
+    text: Text,
+    href: String,
+    on_click: Option<Rc<dyn Fn(&str)>>,
+    track_visited: bool,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Link {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.text.trace(visitor);
+        self.styles.trace(visitor);
+    }
+}
+
+impl Link {
+    /// Create a new Link showing `content` and pointing at `href`
+    pub fn new(content: impl IntoReactiveValue<String>, href: impl Into<String>) -> Self {
+        Self {
+            text: Text::new(content),
+            href: href.into(),
+            on_click: None,
+            track_visited: false,
+            styles: None,
+        }
+    }
+
+    /// Handle a click with the link's URL
+    pub fn on_click(mut self, on_click: impl Fn(&str) + 'static) -> Self {
+        self.on_click = Some(Rc::new(on_click));
+        self
+    }
+
+    /// Show the link in the visited color after it has been clicked
+    pub fn track_visited(mut self, track_visited: bool) -> Self {
+        self.track_visited = track_visited;
+        self
+    }
+
+    /// Set the styles directly, over the default link color and cursor
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    fn text_styles(&self) -> ReactiveStyles {
+        let link =
+            ReactiveStyles::new().set_text_color(TextColor(LINK_COLOR)).set_cursor(Cursor::Pointer);
+        match &self.styles {
+            Some(styles) => link.merge(styles.clone()),
+            None => link,
+        }
+    }
+}
+
+/// State for a mounted Link widget
+pub struct LinkState {
+    text: TextState,
+}
+
+impl LinkState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        self.text.component()
+    }
+
+    /// URL the link points at
+    pub fn href(&self) -> String {
+        self.component().link_href().unwrap_or_default()
+    }
+
+    /// Whether the link has been clicked while tracking visits
+    pub fn is_visited(&self) -> bool {
+        self.component().has_class("visited")
+    }
+}
+
+unsafe impl Trace for LinkState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.text.trace(visitor);
+    }
+}
+
+impl Mountable for LinkState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.text.mount(parent);
+    }
+
+    fn unmount(&self) {
+        self.text.unmount();
+    }
+}
+
+/// Underline a link while it is hovered, keeping the rest of its styles as built
+pub(crate) fn link_hovered(component: &Component, hovered: bool) {
+    let mut styles = component.widget_styles().unwrap_or_default();
+    styles.text_decoration =
+        Some(if hovered { TextDecoration::Underline } else { TextDecoration::None });
+    component.set_widget_styles(styles);
+}
+
+impl Widget for Link {
+    type State = LinkState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        let styles = self.text_styles();
+        let text = self.text.styles(styles).build(ctx);
+        let component = Gc::clone(text.component());
+        component.add_class("link");
+        component.set_link_href(self.href);
+
+        let clicked = Gc::clone(&component);
+        let on_click = self.on_click;
+        let track_visited = self.track_visited;
+        component.on_click_0arg(move || {
+            if track_visited && !clicked.has_class("visited") {
+                clicked.add_class("visited");
+                let mut styles = clicked.widget_styles().unwrap_or_default();
+                styles.text_color = Some(TextColor(VISITED_LINK_COLOR));
+                clicked.set_widget_styles(styles);
+            }
+            if let Some(on_click) = &on_click {
+                on_click(&clicked.link_href().unwrap_or_default());
+            }
+        });
+
+        LinkState { text }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        state.component().set_link_href(self.href.clone());
+        let styles = self.text_styles();
+        self.text.styles(styles).rebuild(&mut state.text);
+    }
+}
@@ -1,26 +1,56 @@
 //! Built-in widget components
 
+pub mod accordion;
 pub mod button;
+pub mod canvas;
 pub mod checkbox;
+pub mod context_menu;
+pub mod divider;
 pub mod flex;
 pub mod for_loop;
+pub mod form;
 pub mod input;
 pub mod keyed_state;
+pub mod link;
+pub mod once;
+pub mod progress;
 pub mod radio;
 pub mod scroll_bar;
 pub mod show;
+pub mod spacer;
+pub mod spinner;
+pub mod suspense;
+pub mod switch;
+pub mod tabs;
 pub mod text;
+pub mod text_area;
+pub mod tooltip;
 
 // New widget builders
+pub use accordion::{Accordion, AccordionGroup};
 pub use button::Button;
+pub use canvas::{Canvas, CanvasDraw};
 pub use checkbox::Checkbox;
+pub use context_menu::{ContextMenu, ContextMenuHandle};
+pub use divider::Divider;
 pub use flex::Flex;
-pub use for_loop::For;
+pub use for_loop::{For, VirtualFor};
+pub use form::{Form, FormContext, FormValues};
 pub use input::{NumberInput, TextInput};
 pub use keyed_state::KeyedState;
+pub use link::Link;
+pub use once::Once;
+pub use progress::ProgressBar;
 pub use radio::Radio;
 pub use scroll_bar::{
     render_horizontal_scrollbar, render_vertical_scrollbar, ScrollAxis, ScrollBar,
 };
-pub use show::Show;
+pub use show::{Show, Transition, TransitionKind, TransitionPhase};
+pub use spacer::Spacer;
+pub use spinner::Spinner;
+pub use suspense::{Suspense, SuspenseContext};
+pub use switch::{Match, Switch};
+pub use tabs::{Tab, Tabs};
 pub use text::Text;
+pub use text_area::TextArea;
+pub use tooltip::{Tooltip, DEFAULT_TOOLTIP_DELAY};
@@ -0,0 +1,113 @@
+//! Once widget, a view built a single time and reused wherever it is placed
+
+use crate::component::{Component, ComponentType};
+use crate::effect::untracked;
+use crate::properties::PropertyMap;
+use crate::runtime::with_owner;
+use crate::view::{View, ViewStruct};
+use rudo_gc::cell::GcCapture;
+use rudo_gc::{Gc, GcBox, GcCell, Trace};
+use std::ptr::NonNull;
+
+type OnceBuilder = Box<dyn FnOnce() -> ViewStruct>;
+
+/// Builds a view the first time it is placed and hands out the same components afterwards
+///
+/// Content under a reactive parent is rebuilt whenever the parent rebuilds, such as a `Switch`
+/// arm that is left and entered again. Create the `Once` outside that parent and place it with
+/// `{header.view()}`: the subtree is moved from where it was last placed instead of being
+/// rebuilt. Only one placement shows it at a time, so it suits static content. Signals and
+/// effects inside the view belong to the `Once`, so they outlive the placement that built them.
+#[derive(Clone)]
+pub struct Once {
+    state: Gc<GcCell<OnceState>>,
+}
+
+struct OnceState {
+    build: Option<OnceBuilder>,
+    view: Option<ViewStruct>,
+    /// Owner of what the view creates, outside of any placement
+    owner: Gc<Component>,
+}
+
+unsafe impl Trace for OnceState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        if let Some(build) = &self.build {
+            crate::gc::trace_opaque(&**build, visitor);
+        }
+        self.view.trace(visitor);
+        self.owner.trace(visitor);
+    }
+}
+
+impl GcCapture for OnceState {
+    fn capture_gc_ptrs(&self) -> &[NonNull<GcBox<()>>] {
+        &[]
+    }
+
+    fn capture_gc_ptrs_into(&self, ptrs: &mut Vec<NonNull<GcBox<()>>>) {
+        if let Some(view) = &self.view {
+            view.root_component.capture_gc_ptrs_into(ptrs);
+        }
+        self.owner.capture_gc_ptrs_into(ptrs);
+    }
+}
+
+impl Once {
+    pub fn new(build: impl FnOnce() -> ViewStruct + 'static) -> Self {
+        let owner = Component::with_properties(
+            crate::component::next_component_id(),
+            ComponentType::Custom("Once".to_string()),
+            PropertyMap::new(),
+        );
+        Self {
+            state: Gc::new(GcCell::new(OnceState {
+                build: Some(Box::new(build)),
+                view: None,
+                owner,
+            })),
+        }
+    }
+
+    /// Whether the view has been built yet
+    pub fn is_built(&self) -> bool {
+        self.state.borrow().view.is_some()
+    }
+
+    /// The view, built on the first call and detached from its previous parent afterwards
+    pub fn view(&self) -> ViewStruct {
+        if let Some(view) = self.state.borrow().view.as_ref() {
+            for root in view.roots() {
+                detach(&root);
+            }
+            return view.clone();
+        }
+
+        let (build, owner) = {
+            let mut state = self.state.borrow_mut();
+            let build = state.build.take().expect("Once view is built only once");
+            (build, Gc::clone(&state.owner))
+        };
+        let view = with_owner(owner, || untracked(build));
+        self.state.borrow_mut().view = Some(view.clone());
+        view
+    }
+}
+
+fn detach(component: &Gc<Component>) {
+    let parent = component.parent.borrow().clone();
+    if let Some(parent) = parent {
+        parent.remove_child(component);
+        component.set_parent(None);
+    }
+}
+
+impl View for Once {
+    fn into_component(self) -> Gc<Component> {
+        self.view().into_component()
+    }
+
+    fn into_view_struct(self) -> ViewStruct {
+        self.view()
+    }
+}
@@ -0,0 +1,208 @@
+//! ProgressBar widget component
+
+use crate::component::{Component, ComponentType};
+use crate::effect::create_effect;
+use crate::properties::{ProgressIndeterminate, ProgressValue, PropertyMap};
+use crate::widget::{BuildContext, IntoReactiveValue, Mountable, ReactiveValue, Widget};
+use rudo_gc::{Gc, Trace};
+use rvue_style::ReactiveStyles;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use vello::kurbo::{Rect, Size};
+
+/// Share of the track covered by the sliding segment of an indeterminate bar
+pub const INDETERMINATE_SEGMENT: f64 = 0.3;
+
+/// Time the sliding segment of an indeterminate bar takes to cross the track
+pub const INDETERMINATE_PERIOD: Duration = Duration::from_millis(1500);
+
+/// ProgressBar widget builder showing how far along a task is
+#[derive(Clone)]
+pub struct ProgressBar {
+    value: ReactiveValue<f64>,
+    indeterminate: Option<ReactiveValue<bool>>,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for ProgressBar {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.value.trace(visitor);
+        self.indeterminate.trace(visitor);
+        self.styles.trace(visitor);
+    }
+}
+
+impl ProgressBar {
+    /// Create a new ProgressBar filled to `value`, from 0.0 to 1.0
+    pub fn new(value: impl IntoReactiveValue<f64>) -> Self {
+        Self { value: value.into_reactive(), indeterminate: None, styles: None }
+    }
+
+    /// Animate a sliding segment instead of the value, for tasks of unknown length
+    pub fn indeterminate(mut self, indeterminate: impl IntoReactiveValue<bool>) -> Self {
+        self.indeterminate = Some(indeterminate.into_reactive());
+        self
+    }
+
+    /// Set the styles directly
+    ///
+    /// `background_color` paints the track and `color` the fill.
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// State for a mounted ProgressBar widget
+pub struct ProgressBarState {
+    component: Gc<Component>,
+    value_effect: Option<Gc<crate::effect::Effect>>,
+    indeterminate_effect: Option<Gc<crate::effect::Effect>>,
+}
+
+impl ProgressBarState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for ProgressBarState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        if let Some(effect) = &self.value_effect {
+            effect.trace(visitor);
+        }
+        if let Some(effect) = &self.indeterminate_effect {
+            effect.trace(visitor);
+        }
+    }
+}
+
+impl Mountable for ProgressBarState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for ProgressBar {
+    type State = ProgressBarState;
+
+    fn build(self, _ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let computed_styles = self.styles.as_ref().map(|s| s.compute());
+
+        let mut properties = if self.value.is_reactive() {
+            PropertyMap::new()
+        } else {
+            PropertyMap::with(ProgressValue(clamp_progress(self.value.get())))
+        };
+        if let Some(indeterminate) = self.indeterminate.as_ref().filter(|v| !v.is_reactive()) {
+            properties.insert(ProgressIndeterminate(indeterminate.get()));
+        }
+
+        let component = Component::with_properties(id, ComponentType::ProgressBar, properties);
+
+        // Initialize WidgetStyles in PropertyMap for layout calculations
+        if let Some(styles) = computed_styles {
+            component.set_widget_styles(styles);
+        }
+
+        let value_effect =
+            self.value.is_reactive().then(|| create_value_effect(&component, self.value.clone()));
+        let indeterminate_effect = self
+            .indeterminate
+            .filter(|v| v.is_reactive())
+            .map(|indeterminate| create_indeterminate_effect(&component, indeterminate));
+
+        ProgressBarState { component, value_effect, indeterminate_effect }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if self.value.is_reactive() {
+            if state.value_effect.is_none() {
+                state.value_effect = Some(create_value_effect(&state.component, self.value));
+            }
+        } else {
+            state.component.set_progress_value(self.value.get());
+        }
+
+        match self.indeterminate {
+            Some(indeterminate) if indeterminate.is_reactive() => {
+                if state.indeterminate_effect.is_none() {
+                    state.indeterminate_effect =
+                        Some(create_indeterminate_effect(&state.component, indeterminate));
+                }
+            }
+            Some(indeterminate) => state.component.set_progress_indeterminate(indeterminate.get()),
+            None => {}
+        }
+    }
+}
+
+fn create_value_effect(
+    component: &Gc<Component>,
+    value: ReactiveValue<f64>,
+) -> Gc<crate::effect::Effect> {
+    let comp = Gc::clone(component);
+    let effect = create_effect(move || {
+        comp.set_progress_value(value.get());
+    });
+    component.add_effect(Gc::clone(&effect));
+    effect
+}
+
+fn create_indeterminate_effect(
+    component: &Gc<Component>,
+    indeterminate: ReactiveValue<bool>,
+) -> Gc<crate::effect::Effect> {
+    let comp = Gc::clone(component);
+    let effect = create_effect(move || {
+        comp.set_progress_indeterminate(indeterminate.get());
+    });
+    component.add_effect(Gc::clone(&effect));
+    effect
+}
+
+/// Clamp a progress value to `0.0..=1.0`, treating NaN as no progress
+pub(crate) fn clamp_progress(value: f64) -> f64 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Area of a `track` covered by the fill of a determinate bar at `value`
+pub fn progress_fill_rect(track: Size, value: f64) -> Rect {
+    Rect::new(0.0, 0.0, track.width * clamp_progress(value), track.height)
+}
+
+/// Area of a `track` covered by the sliding segment `elapsed` into the indeterminate animation
+///
+/// The segment enters past the left edge and leaves past the right one, so it is clipped to
+/// the track at both ends.
+pub fn indeterminate_fill_rect(track: Size, elapsed: Duration) -> Rect {
+    let phase = (elapsed.as_secs_f64() / INDETERMINATE_PERIOD.as_secs_f64()).fract();
+    let segment = track.width * INDETERMINATE_SEGMENT;
+    let x0 = -segment + phase * (track.width + segment);
+    Rect::new(x0.max(0.0), 0.0, (x0 + segment).min(track.width), track.height)
+}
+
+/// Time into the indeterminate animation shared by every bar, so they slide in step
+///
+/// With reduced motion the segment rests in the middle of the track.
+pub(crate) fn indeterminate_elapsed(now: Instant) -> Duration {
+    if crate::app::prefers_reduced_motion() {
+        return INDETERMINATE_PERIOD / 2;
+    }
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    now.saturating_duration_since(*EPOCH.get_or_init(|| now))
+}
@@ -3,6 +3,7 @@
 use crate::component::{Component, ComponentType};
 use crate::widget::{BuildContext, Mountable, Widget};
 use rudo_gc::{Gc, Trace};
+use std::time::Instant;
 use vello::kurbo::{Affine, Rect, RoundedRect};
 use vello::peniko::{Color, Fill};
 
@@ -35,12 +36,11 @@ impl ScrollBar {
 
     /// Calculate the thumb (handle) length based on portal/content ratio
     pub fn thumb_length(&self, track_length: f64) -> f64 {
-        const MIN_THUMB_LENGTH: f64 = 20.0;
         if self.content_size <= self.portal_size {
             return track_length;
         }
         let ratio = self.portal_size / self.content_size;
-        (ratio * track_length).max(MIN_THUMB_LENGTH)
+        clamp_thumb_length(ratio * track_length, track_length)
     }
 
     /// Calculate the thumb position based on scroll offset
@@ -77,7 +77,6 @@ impl ScrollBar {
         scroll_height: f64,
     ) -> Option<Rect> {
         let scrollbar_width = default_scrollbar::WIDTH;
-        let thumb_min_length = default_scrollbar::THUMB_MIN_LENGTH;
 
         let container_height = height;
         let content_height = container_height + scroll_height;
@@ -87,8 +86,7 @@ impl ScrollBar {
         }
 
         let track_x = x + width - scrollbar_width;
-        let thumb_ratio = container_height / content_height;
-        let thumb_height = (thumb_ratio * container_height).max(thumb_min_length);
+        let thumb_height = thumb_length_for(container_height, scroll_height);
         let thumb_y =
             y + (scroll_offset_y / scroll_height.max(1.0)) * (container_height - thumb_height);
 
@@ -110,7 +108,6 @@ impl ScrollBar {
         scroll_width: f64,
     ) -> Option<Rect> {
         let scrollbar_height = default_scrollbar::WIDTH;
-        let thumb_min_length = default_scrollbar::THUMB_MIN_LENGTH;
 
         let container_width = width;
         let content_width = container_width + scroll_width;
@@ -120,8 +117,7 @@ impl ScrollBar {
         }
 
         let track_y = y + height - scrollbar_height;
-        let thumb_ratio = container_width / content_width;
-        let thumb_width = (thumb_ratio * container_width).max(thumb_min_length);
+        let thumb_width = thumb_length_for(container_width, scroll_width);
         let thumb_x =
             x + (scroll_offset_x / scroll_width.max(1.0)) * (container_width - thumb_width);
 
@@ -222,10 +218,63 @@ impl Widget for ScrollBar {
 
 /// Default scrollbar constants
 pub mod default_scrollbar {
+    use std::time::Duration;
+
     pub const WIDTH: f64 = 10.0;
     pub const THUMB_MIN_LENGTH: f64 = 20.0;
     pub const BORDER_RADIUS: f64 = 4.0;
     pub const TRACK_MARGIN: f64 = 2.0;
+    /// How long an `Overflow::Auto` scrollbar stays visible after scrolling stops
+    pub const AUTO_HIDE_DELAY: Duration = Duration::from_millis(1000);
+}
+
+/// Keep a thumb grabbable for huge content without overflowing a tiny track
+fn clamp_thumb_length(length: f64, track_length: f64) -> f64 {
+    length.max(default_scrollbar::THUMB_MIN_LENGTH).min(track_length)
+}
+
+/// Length of the thumb along a track of `container_length` when the content can scroll by
+/// `scrollable_length`
+///
+/// Proportional to the visible share of the content, but never shorter than
+/// [`default_scrollbar::THUMB_MIN_LENGTH`].
+pub fn thumb_length_for(container_length: f64, scrollable_length: f64) -> f64 {
+    let content_length = container_length + scrollable_length.max(0.0);
+    if content_length <= 0.0 {
+        return container_length;
+    }
+    clamp_thumb_length(container_length / content_length * container_length, container_length)
+}
+
+/// Whether an `Overflow::Auto` scrollbar is shown
+///
+/// It appears while the container is hovered and for [`default_scrollbar::AUTO_HIDE_DELAY`]
+/// after it last scrolled.
+pub fn auto_scrollbar_visible(hovered: bool, last_activity: Option<Instant>, now: Instant) -> bool {
+    hovered
+        || last_activity.is_some_and(|at| {
+            now.saturating_duration_since(at) < default_scrollbar::AUTO_HIDE_DELAY
+        })
+}
+
+/// Hide the `Overflow::Auto` scrollbars whose delay has passed since they last scrolled.
+///
+/// Returns true while some scrollbar is still waiting to hide, so the caller keeps frames
+/// coming.
+pub fn expire_scrollbar_activity(root: &Gc<Component>, now: Instant) -> bool {
+    let mut pending = false;
+    if let Some(at) = root.scroll_activity() {
+        if now.saturating_duration_since(at) >= default_scrollbar::AUTO_HIDE_DELAY {
+            root.clear_scroll_activity();
+            root.mark_dirty();
+        } else {
+            pending = true;
+        }
+    }
+    for child in root.children.borrow().iter() {
+        pending |= expire_scrollbar_activity(child, now);
+    }
+    pending
 }
 
 /// Get the scrollbar track color
@@ -249,7 +298,6 @@ pub fn render_vertical_scrollbar(
     scroll_height: f64,
 ) {
     let scrollbar_width = default_scrollbar::WIDTH;
-    let thumb_min_length = default_scrollbar::THUMB_MIN_LENGTH;
 
     let container_height = height;
     let content_height = container_height + scroll_height;
@@ -264,8 +312,7 @@ pub fn render_vertical_scrollbar(
 
     // Calculate and render thumb
     if content_height > container_height {
-        let thumb_ratio = container_height / content_height;
-        let thumb_height = (thumb_ratio * container_height).max(thumb_min_length);
+        let thumb_height = thumb_length_for(container_height, scroll_height);
         let thumb_y =
             y + (scroll_offset_y / scroll_height.max(1.0)) * (container_height - thumb_height);
 
@@ -291,7 +338,6 @@ pub fn render_horizontal_scrollbar(
     scroll_width: f64,
 ) {
     let scrollbar_height = default_scrollbar::WIDTH;
-    let thumb_min_length = default_scrollbar::THUMB_MIN_LENGTH;
 
     let container_width = width;
     let content_width = container_width + scroll_width;
@@ -306,8 +352,7 @@ pub fn render_horizontal_scrollbar(
 
     // Calculate and render thumb
     if content_width > container_width {
-        let thumb_ratio = container_width / content_width;
-        let thumb_width = (thumb_ratio * container_width).max(thumb_min_length);
+        let thumb_width = thumb_length_for(container_width, scroll_width);
         let thumb_x =
             x + (scroll_offset_x / scroll_width.max(1.0)) * (container_width - thumb_width);
 
@@ -5,11 +5,90 @@ use crate::effect::create_effect;
 use crate::properties::{PropertyMap, ShowCondition};
 use crate::widget::{BuildContext, Mountable, ReactiveValue, Widget};
 use rudo_gc::{Gc, Trace};
+use std::time::Duration;
+
+/// Animation played when `Show` content enters or leaves
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionKind {
+    /// Fade the content in and out
+    Fade,
+    /// Slide the content in from below and out downwards while fading
+    Slide,
+    /// Reveal the content from the top down and hide it from the bottom up
+    Collapse,
+}
+
+impl TransitionKind {
+    /// Parse a transition name such as `"fade"` or `"slide"`
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "fade" => Some(Self::Fade),
+            "slide" => Some(Self::Slide),
+            "collapse" => Some(Self::Collapse),
+            _ => None,
+        }
+    }
+}
+
+/// Enter/exit transition for `Show` content
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    pub kind: TransitionKind,
+    pub duration: Duration,
+}
+
+impl Transition {
+    /// Duration used when none is given
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    pub fn new(kind: TransitionKind) -> Self {
+        Self { kind, duration: Self::DEFAULT_DURATION }
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// How long the transition actually plays, which is zero under reduced motion
+    pub fn playing_duration(&self) -> Duration {
+        if crate::app::prefers_reduced_motion() {
+            Duration::ZERO
+        } else {
+            self.duration
+        }
+    }
+}
+
+impl From<TransitionKind> for Transition {
+    fn from(kind: TransitionKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+impl From<&str> for Transition {
+    /// Unknown names fall back to a fade
+    fn from(name: &str) -> Self {
+        let kind = TransitionKind::parse(name).unwrap_or_else(|| {
+            log::warn!("Unknown Show transition {name:?}, using fade");
+            TransitionKind::Fade
+        });
+        Self::new(kind)
+    }
+}
+
+/// Which way a transition is playing
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionPhase {
+    Entering,
+    Exiting,
+}
 
 /// Show widget builder for conditionally rendering content
 pub struct Show {
     when: ReactiveValue<bool>,
     children_fn: Box<dyn Fn(&mut BuildContext) -> Gc<Component>>,
+    transition: Option<Transition>,
 }
 
 unsafe impl Trace for Show {
@@ -24,7 +103,15 @@ impl Show {
         when: impl crate::widget::IntoReactiveValue<bool>,
         children_fn: impl Fn(&mut BuildContext) -> Gc<Component> + 'static,
     ) -> Self {
-        Self { when: when.into_reactive(), children_fn: Box::new(children_fn) }
+        Self { when: when.into_reactive(), children_fn: Box::new(children_fn), transition: None }
+    }
+
+    /// Play `transition` when the content is shown or hidden
+    ///
+    /// Hidden content stays mounted until its exit transition finishes.
+    pub fn transition(mut self, transition: impl Into<Transition>) -> Self {
+        self.transition = Some(transition.into());
+        self
     }
 }
 
@@ -99,6 +186,10 @@ impl Widget for Show {
             None
         };
 
+        if self.transition.is_some() {
+            component.set_show_transition(self.transition);
+        }
+
         ShowState { component, when_effect }
     }
 
@@ -120,6 +211,9 @@ impl Widget for Show {
             let new_when = self.when.get();
             state.component.set_show_when(new_when);
         }
+        if state.component.show_transition() != self.transition {
+            state.component.set_show_transition(self.transition);
+        }
         // Mark component dirty so children are re-rendered
         state.component.mark_dirty();
     }
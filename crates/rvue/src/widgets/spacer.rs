@@ -0,0 +1,53 @@
+//! Spacer widget, filling the free space of a flex container
+
+use crate::widget::{BuildContext, Widget};
+use crate::widgets::flex::{Flex, FlexState};
+use rudo_gc::Trace;
+use rvue_style::{FlexGrow, ReactiveStyles};
+
+/// Spacer widget builder that grows to take up the free space along its container
+///
+/// Placed between two items of a row, it pushes them to opposite ends.
+#[derive(Clone, Default)]
+pub struct Spacer {
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Spacer {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.styles.trace(visitor);
+    }
+}
+
+impl Spacer {
+    /// Create a new Spacer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the styles directly, e.g. a `flex_grow` other than 1 to share space unevenly
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    fn filler_styles(&self) -> ReactiveStyles {
+        let filler = ReactiveStyles::new().set_flex_grow(FlexGrow(1.0));
+        match &self.styles {
+            Some(styles) => filler.merge(styles.clone()),
+            None => filler,
+        }
+    }
+}
+
+impl Widget for Spacer {
+    type State = FlexState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        Flex::new().styles(self.filler_styles()).build(ctx)
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        Flex::new().styles(self.filler_styles()).rebuild(state)
+    }
+}
@@ -0,0 +1,94 @@
+//! Spinner widget component
+
+use crate::component::{Component, ComponentType};
+use crate::properties::PropertyMap;
+use crate::widget::{BuildContext, Mountable, Widget};
+use rudo_gc::{Gc, Trace};
+use rvue_style::ReactiveStyles;
+use std::time::Duration;
+
+/// Time a spinner's arc takes to make one full turn
+pub const SPINNER_PERIOD: Duration = Duration::from_secs(1);
+
+/// Spinner widget builder showing a rotating arc while something loads
+///
+/// The arc turns once per frame while the spinner is mounted and stops, along with the
+/// repaints it requests, once it is unmounted, e.g. when a `Suspense` fallback is replaced.
+#[derive(Clone, Default)]
+pub struct Spinner {
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Spinner {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.styles.trace(visitor);
+    }
+}
+
+impl Spinner {
+    /// Create a new Spinner widget
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the styles directly
+    ///
+    /// `width` and `height` size the spinner and `color` paints the arc.
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// State for a mounted Spinner widget
+pub struct SpinnerState {
+    component: Gc<Component>,
+}
+
+impl SpinnerState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for SpinnerState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+    }
+}
+
+impl Mountable for SpinnerState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Spinner {
+    type State = SpinnerState;
+
+    fn build(self, _ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let component = Component::with_properties(id, ComponentType::Spinner, PropertyMap::new());
+
+        // Initialize WidgetStyles in PropertyMap for layout calculations
+        if let Some(styles) = self.styles.as_ref().map(|s| s.compute()) {
+            component.set_widget_styles(styles);
+        }
+
+        SpinnerState { component }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        if let Some(styles) = self.styles.as_ref().map(|s| s.compute()) {
+            state.component.set_widget_styles(styles);
+        }
+    }
+}
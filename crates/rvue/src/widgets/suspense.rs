@@ -0,0 +1,180 @@
+//! Suspense widget for coordinated loading states
+
+use crate::component::{Component, ComponentType};
+use crate::effect::{create_effect, on_cleanup};
+use crate::properties::PropertyMap;
+use crate::runtime::{current_owner, with_owner};
+use crate::signal::{create_signal, leak_effect, ReadSignal, WriteSignal};
+use crate::widget::{BuildContext, Mountable, Widget};
+use rudo_gc::{Gc, Trace};
+use std::cell::Cell;
+use std::rc::Rc;
+
+type ChildBuilder = Box<dyn Fn(&mut BuildContext) -> Gc<Component>>;
+
+/// Context a `Suspense` boundary provides to its content
+///
+/// Anything that loads asynchronously registers with the nearest boundary through
+/// [`SuspenseContext::track`]. `create_resource` does this for every resource created inside a
+/// boundary.
+#[derive(Clone)]
+pub struct SuspenseContext {
+    pending: ReadSignal<usize>,
+    set_pending: WriteSignal<usize>,
+}
+
+unsafe impl Trace for SuspenseContext {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.pending.trace(visitor);
+        self.set_pending.trace(visitor);
+    }
+}
+
+impl SuspenseContext {
+    fn new() -> Self {
+        let (pending, set_pending) = create_signal(0usize);
+        Self { pending, set_pending }
+    }
+
+    /// Number of registered sources that are still loading
+    pub fn pending(&self) -> ReadSignal<usize> {
+        self.pending.clone()
+    }
+
+    /// Hold the boundary in its fallback while `is_loading` returns true
+    ///
+    /// `is_loading` is re-run whenever the signals it reads change. The registration is dropped
+    /// when the current owner is cleaned up.
+    pub fn track(&self, is_loading: impl Fn() -> bool + 'static) {
+        let loading = Rc::new(Cell::new(false));
+
+        let set_pending = self.set_pending.clone();
+        let effect_loading = Rc::clone(&loading);
+        let effect = create_effect(move || {
+            let now_loading = is_loading();
+            if effect_loading.replace(now_loading) != now_loading {
+                set_pending.update(|count| {
+                    if now_loading {
+                        *count += 1;
+                    } else {
+                        *count = count.saturating_sub(1);
+                    }
+                });
+            }
+        });
+
+        match current_owner() {
+            Some(owner) => owner.add_effect(effect),
+            None => leak_effect(effect),
+        }
+
+        let set_pending = self.set_pending.clone();
+        on_cleanup(move || {
+            if loading.replace(false) {
+                set_pending.update(|count| *count = count.saturating_sub(1));
+            }
+        });
+    }
+}
+
+/// Suspense widget builder that shows a fallback while its content is loading
+///
+/// The content is built right away, so resources inside it start loading, but it is only
+/// shown once every source registered with the boundary has finished.
+pub struct Suspense {
+    children_fn: ChildBuilder,
+    fallback_fn: Option<ChildBuilder>,
+}
+
+unsafe impl Trace for Suspense {
+    fn trace(&self, _visitor: &mut impl rudo_gc::Visitor) {}
+}
+
+impl Suspense {
+    /// Create a new Suspense boundary around the content built by `children_fn`
+    pub fn new(children_fn: impl Fn(&mut BuildContext) -> Gc<Component> + 'static) -> Self {
+        Self { children_fn: Box::new(children_fn), fallback_fn: None }
+    }
+
+    /// Show the content built by `fallback_fn` while loading
+    pub fn fallback(
+        mut self,
+        fallback_fn: impl Fn(&mut BuildContext) -> Gc<Component> + 'static,
+    ) -> Self {
+        self.fallback_fn = Some(Box::new(fallback_fn));
+        self
+    }
+}
+
+/// State for a mounted Suspense widget
+pub struct SuspenseState {
+    component: Gc<Component>,
+    pending_effect: Gc<crate::effect::Effect>,
+}
+
+impl SuspenseState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for SuspenseState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        self.pending_effect.trace(visitor);
+    }
+}
+
+impl Mountable for SuspenseState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Suspense {
+    type State = SuspenseState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let component = Component::with_properties(id, ComponentType::Suspense, PropertyMap::new());
+
+        let context = SuspenseContext::new();
+        let pending = context.pending();
+        component.provide_context(context);
+
+        // The content comes first, the fallback second; see `Component::suspense_visible_child`
+        let content = with_owner(Gc::clone(&component), || (self.children_fn)(ctx));
+        content.set_parent(Some(Gc::clone(&component)));
+        component.add_child(content);
+
+        if let Some(fallback_fn) = &self.fallback_fn {
+            let fallback = fallback_fn(ctx);
+            fallback.set_parent(Some(Gc::clone(&component)));
+            component.add_child(fallback);
+        }
+
+        let comp = Gc::clone(&component);
+        let pending_effect = create_effect(move || {
+            let is_pending = pending.get() > 0;
+            if comp.suspense_pending() != is_pending {
+                comp.set_suspense_pending(is_pending);
+            }
+        });
+        component.add_effect(Gc::clone(&pending_effect));
+
+        SuspenseState { component, pending_effect }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        // The content tracks its own resources; nothing to update from the builder
+        state.component.mark_dirty();
+    }
+}
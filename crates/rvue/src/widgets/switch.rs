@@ -0,0 +1,251 @@
+//! Switch widget for multi-branch conditional rendering
+
+use crate::component::{Component, ComponentLifecycle, ComponentType};
+use crate::effect::{create_effect, untracked};
+use crate::properties::PropertyMap;
+use crate::runtime::with_owner;
+use crate::widget::{BuildContext, IntoReactiveValue, Mountable, ReactiveValue, Widget};
+use rudo_gc::{Gc, GcCell, Trace};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+type ArmBuilder = Rc<dyn Fn(&mut BuildContext) -> Gc<Component>>;
+
+/// Content of keyed arms that were left, by key
+///
+/// Detached arms are out of the component tree, so the cache is their only owner and lives on
+/// the GC heap to keep them traced.
+type ArmCache = Gc<GcCell<HashMap<String, Gc<Component>>>>;
+
+/// One arm of a [`Switch`], rendered when its condition is the first one that holds
+pub struct Match {
+    when: ReactiveValue<bool>,
+    children_fn: ArmBuilder,
+    key: Option<String>,
+}
+
+impl Match {
+    /// Create an arm with a boolean condition and children builder
+    pub fn new(
+        when: impl IntoReactiveValue<bool>,
+        children_fn: impl Fn(&mut BuildContext) -> Gc<Component> + 'static,
+    ) -> Self {
+        Self { when: when.into_reactive(), children_fn: Rc::new(children_fn), key: None }
+    }
+
+    /// Keep this arm's content when another arm becomes active
+    ///
+    /// Coming back to an arm with the same key restores the cached content, including its
+    /// signals and effects, instead of building it again.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+}
+
+unsafe impl Trace for Match {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.when.trace(visitor);
+    }
+}
+
+/// Switch widget builder that renders the first [`Match`] arm whose condition holds
+///
+/// Only the active arm is built. When another arm becomes active, the old arm's content is
+/// unmounted and the new arm's content is built in its place, unless the arms are keyed with
+/// [`Match::key`].
+pub struct Switch {
+    arms: Vec<Match>,
+}
+
+unsafe impl Trace for Switch {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.arms.trace(visitor);
+    }
+}
+
+impl Switch {
+    /// Create a new Switch widget from its arms, checked in order
+    pub fn new(arms: Vec<Match>) -> Self {
+        Self { arms }
+    }
+
+    /// Add an arm after the existing ones
+    pub fn arm(mut self, arm: Match) -> Self {
+        self.arms.push(arm);
+        self
+    }
+}
+
+/// State for a mounted Switch widget
+pub struct SwitchState {
+    component: Gc<Component>,
+    arms_effect: Option<Gc<crate::effect::Effect>>,
+    cache: ArmCache,
+}
+
+impl SwitchState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for SwitchState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        if let Some(effect) = &self.arms_effect {
+            effect.trace(visitor);
+        }
+        self.cache.trace(visitor);
+    }
+}
+
+impl Mountable for SwitchState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+/// Index of the first arm whose condition holds
+///
+/// Conditions after the first match are not read, so they are not tracked either.
+fn active_arm(conditions: &[ReactiveValue<bool>]) -> Option<usize> {
+    conditions.iter().position(|when| when.get())
+}
+
+/// Builders and keys of the arms, in order
+struct Arms {
+    builders: Vec<ArmBuilder>,
+    keys: Vec<Option<String>>,
+}
+
+/// Split arms into their conditions and the rest
+fn split_arms(arms: Vec<Match>) -> (Vec<ReactiveValue<bool>>, Arms) {
+    let mut conditions = Vec::with_capacity(arms.len());
+    let mut builders = Vec::with_capacity(arms.len());
+    let mut keys = Vec::with_capacity(arms.len());
+    for arm in arms {
+        conditions.push(arm.when);
+        builders.push(arm.children_fn);
+        keys.push(arm.key);
+    }
+    (conditions, Arms { builders, keys })
+}
+
+/// Replace the Switch content with the arm at `index`, if it isn't already active
+///
+/// The content of a keyed arm is moved into `cache` rather than unmounted, and reused the next
+/// time an arm with that key becomes active.
+fn activate_arm(
+    component: &Gc<Component>,
+    arms: &Arms,
+    cache: &ArmCache,
+    index: Option<usize>,
+    ctx: &mut BuildContext,
+) {
+    let old_index = component.switch_active_arm();
+    if old_index == index {
+        return;
+    }
+
+    let old_key = old_index.and_then(|old| arms.keys.get(old).cloned().flatten());
+    let old_children = std::mem::take(&mut *component.children.borrow_mut_gen_only());
+    for child in old_children {
+        child.set_parent(None);
+        match &old_key {
+            Some(key) => {
+                cache.borrow_mut().insert(key.clone(), child);
+            }
+            None => child.unmount(),
+        }
+    }
+
+    if let Some(index) = index {
+        let cached = arms.keys[index].as_ref().and_then(|key| cache.borrow_mut().remove(key));
+        let is_cached = cached.is_some();
+        let child = cached.unwrap_or_else(|| {
+            let builder = Rc::clone(&arms.builders[index]);
+            untracked(|| with_owner(Gc::clone(component), || builder(ctx)))
+        });
+        child.set_parent(Some(Gc::clone(component)));
+        component.add_child(Gc::clone(&child));
+        if !is_cached {
+            child.mount(None);
+        }
+    }
+
+    component.set_switch_active_arm(index);
+}
+
+impl Widget for Switch {
+    type State = SwitchState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let component = Component::with_properties(id, ComponentType::Switch, PropertyMap::new());
+
+        let is_reactive = self.arms.iter().any(|arm| arm.when.is_reactive());
+        let (conditions, arms) = split_arms(self.arms);
+        let cache: ArmCache = Gc::new(GcCell::new(HashMap::new()));
+
+        // Cached arms are out of the tree, so unmount them along with the Switch
+        let cached = Gc::clone(&cache);
+        component.cleanups.borrow_mut_gen_only().push(Box::new(move || {
+            for (_, content) in cached.borrow_mut().drain() {
+                content.unmount();
+            }
+        }));
+
+        activate_arm(&component, &arms, &cache, untracked(|| active_arm(&conditions)), ctx);
+
+        let arms_effect = if is_reactive {
+            let comp = Gc::clone(&component);
+            let cache = Gc::clone(&cache);
+            let effect = create_effect(move || {
+                let index = active_arm(&conditions);
+                if comp.switch_active_arm() == index {
+                    return;
+                }
+                let mut temp_taffy = taffy::TaffyTree::new();
+                let mut temp_text_context = crate::text::TextContext::new();
+                let mut temp_id_counter = crate::component::next_component_id();
+                let mut temp_ctx = BuildContext::new(
+                    &mut temp_taffy,
+                    &mut temp_text_context,
+                    &mut temp_id_counter,
+                );
+                activate_arm(&comp, &arms, &cache, index, &mut temp_ctx);
+                comp.mark_dirty();
+            });
+            component.add_effect(Gc::clone(&effect));
+            Some(effect)
+        } else {
+            None
+        };
+
+        SwitchState { component, arms_effect, cache }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        // Static arms are re-evaluated; reactive arms are kept up to date by the effect
+        if state.arms_effect.is_none() {
+            let (conditions, arms) = split_arms(self.arms);
+            let mut temp_taffy = taffy::TaffyTree::new();
+            let mut temp_text_context = crate::text::TextContext::new();
+            let mut temp_id_counter = crate::component::next_component_id();
+            let mut temp_ctx =
+                BuildContext::new(&mut temp_taffy, &mut temp_text_context, &mut temp_id_counter);
+            let index = active_arm(&conditions);
+            activate_arm(&state.component, &arms, &state.cache, index, &mut temp_ctx);
+            state.component.mark_dirty();
+        }
+    }
+}
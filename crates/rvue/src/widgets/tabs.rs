@@ -0,0 +1,222 @@
+//! Tabs widget, a tab bar switching between panels
+
+use crate::component::Component;
+use crate::effect::create_effect;
+use crate::event::types::KeyboardEvent;
+use crate::signal::{create_signal, ReadSignal, WriteSignal};
+use crate::widget::{BuildContext, Mountable, Widget};
+use crate::widgets::switch::{Match, Switch, SwitchState};
+use crate::widgets::{Button, Flex, Text};
+use rudo_gc::{Gc, Trace};
+use rvue_style::{FlexDirection, ReactiveStyles};
+use std::rc::Rc;
+use winit::keyboard::{Key, NamedKey};
+
+type PanelBuilder = Rc<dyn Fn(&mut BuildContext) -> Gc<Component>>;
+
+/// One tab of a [`Tabs`], its label in the tab bar and the panel it shows
+pub struct Tab {
+    label: String,
+    panel: PanelBuilder,
+}
+
+impl Tab {
+    /// Create a tab from its label and panel builder
+    pub fn new(
+        label: impl Into<String>,
+        panel: impl Fn(&mut BuildContext) -> Gc<Component> + 'static,
+    ) -> Self {
+        Self { label: label.into(), panel: Rc::new(panel) }
+    }
+}
+
+unsafe impl Trace for Tab {
+    fn trace(&self, _visitor: &mut impl rudo_gc::Visitor) {}
+}
+
+/// Tabs widget builder showing a tab bar above the panel of the active tab
+///
+/// Clicking a tab, or pressing the arrow keys, Home or End while a tab is focused, writes its
+/// index to the active-index signal. Panels are built the first time their tab is shown and
+/// kept while other tabs are active, like keyed [`Match`] arms.
+///
+/// The tab bar has the `tab-bar` class, each tab the `tab` class and the active tab `active`
+/// as well, for stylesheets to pick up.
+pub struct Tabs {
+    tabs: Vec<Tab>,
+    active: ReadSignal<usize>,
+    set_active: WriteSignal<usize>,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Tabs {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.active.trace(visitor);
+        self.set_active.trace(visitor);
+        self.styles.trace(visitor);
+    }
+}
+
+impl Tabs {
+    /// Create a new Tabs widget showing the tab at index `active`
+    pub fn new(active: ReadSignal<usize>, set_active: WriteSignal<usize>) -> Self {
+        Self { tabs: Vec::new(), active, set_active, styles: None }
+    }
+
+    /// Add a tab after the existing ones
+    pub fn tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Set the styles of the container holding the tab bar and panel
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// State for a mounted Tabs widget
+pub struct TabsState {
+    component: Gc<Component>,
+    tab_bar: Gc<Component>,
+    panels: SwitchState,
+    active_effect: Gc<crate::effect::Effect>,
+}
+
+impl TabsState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+
+    /// Get the tab bar holding one button per tab
+    pub fn tab_bar(&self) -> &Gc<Component> {
+        &self.tab_bar
+    }
+}
+
+unsafe impl Trace for TabsState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        self.tab_bar.trace(visitor);
+        self.panels.trace(visitor);
+        self.active_effect.trace(visitor);
+    }
+}
+
+impl Mountable for TabsState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Tabs {
+    type State = TabsState;
+
+    fn build(self, ctx: &mut BuildContext) -> Self::State {
+        let container_styles = ReactiveStyles::new().set_flex_direction(FlexDirection::Column);
+        let container_styles = match self.styles {
+            Some(styles) => container_styles.merge(styles),
+            None => container_styles,
+        };
+        let component = Gc::clone(
+            Flex::new()
+                .direction(FlexDirection::Column)
+                .styles(container_styles)
+                .build(ctx)
+                .component(),
+        );
+        component.add_class("tabs");
+
+        let tab_bar = Gc::clone(Flex::new().build(ctx).component());
+        tab_bar.add_class("tab-bar");
+        tab_bar.set_parent(Some(Gc::clone(&component)));
+        component.add_child(Gc::clone(&tab_bar));
+
+        let mut buttons = Vec::with_capacity(self.tabs.len());
+        let mut arms = Vec::with_capacity(self.tabs.len());
+        let mut shown_signals = Vec::with_capacity(self.tabs.len());
+        for (index, tab) in self.tabs.into_iter().enumerate() {
+            let button = Button::new().class("tab").build(ctx);
+            Text::new(tab.label).build(ctx).mount(Some(Gc::clone(button.component())));
+            button.mount(Some(Gc::clone(&tab_bar)));
+
+            let set_active = self.set_active.clone();
+            button.component().on_click_0arg(move || set_active.set(index));
+            buttons.push(Gc::clone(button.component()));
+
+            let (shown, set_shown) = create_signal(index == self.active.get_untracked());
+            shown_signals.push((shown.clone(), set_shown));
+            let panel = Rc::clone(&tab.panel);
+            arms.push(Match::new(shown, move |ctx| panel(ctx)).key(index.to_string()));
+        }
+
+        let buttons: Rc<[Gc<Component>]> = buttons.into();
+        let active = self.active.clone();
+        let set_active = self.set_active.clone();
+        let tabs = Rc::clone(&buttons);
+        tab_bar.on_key_down_1arg(move |event| {
+            if let Some(index) = arrow_target(event, active.get_untracked(), tabs.len()) {
+                set_active.set(index);
+                tabs[index].focus();
+            }
+        });
+
+        // The new arm is raised before the old one is lowered, so the Switch never sees a
+        // moment without an active arm and does not drop the panel in between
+        let active = self.active.clone();
+        let tabs = Rc::clone(&buttons);
+        let active_effect = create_effect(move || {
+            let active = active.get();
+            if let (Some(tab), Some((shown, set_shown))) =
+                (tabs.get(active), shown_signals.get(active))
+            {
+                tab.add_class("active");
+                if !shown.get_untracked() {
+                    set_shown.set(true);
+                }
+            }
+            for (index, (tab, (shown, set_shown))) in tabs.iter().zip(&shown_signals).enumerate() {
+                if index != active {
+                    tab.remove_class("active");
+                    if shown.get_untracked() {
+                        set_shown.set(false);
+                    }
+                }
+            }
+        });
+        component.add_effect(Gc::clone(&active_effect));
+
+        let panels = Switch::new(arms).build(ctx);
+        panels.mount(Some(Gc::clone(&component)));
+
+        TabsState { component, tab_bar, panels, active_effect }
+    }
+
+    fn rebuild(self, _state: &mut Self::State) {
+        // The active tab follows its signal; the set of tabs is fixed once built
+    }
+}
+
+/// Index of the tab an arrow, Home or End key moves to from `active`, wrapping at the ends
+fn arrow_target(event: &KeyboardEvent, active: usize, count: usize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let active = active.min(count - 1);
+    match &event.key {
+        Key::Named(NamedKey::ArrowRight) => Some((active + 1) % count),
+        Key::Named(NamedKey::ArrowLeft) => Some((active + count - 1) % count),
+        Key::Named(NamedKey::Home) => Some(0),
+        Key::Named(NamedKey::End) => Some(count - 1),
+        _ => None,
+    }
+}
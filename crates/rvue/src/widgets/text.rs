@@ -3,6 +3,7 @@
 use crate::component::{Component, ComponentType};
 use crate::effect::create_effect;
 use crate::properties::{PropertyMap, TextContent};
+use crate::text::span::{spans_text, TextSpan};
 use crate::widget::{BuildContext, Mountable, ReactiveValue, Widget};
 use rudo_gc::{Gc, Trace};
 use rvue_style::ReactiveStyles;
@@ -11,6 +12,8 @@ use rvue_style::ReactiveStyles;
 #[derive(Clone)]
 pub struct Text {
     content: ReactiveValue<String>,
+    spans: Option<Vec<TextSpan>>,
+    selectable: bool,
     styles: Option<ReactiveStyles>,
 }
 
@@ -24,7 +27,28 @@ unsafe impl Trace for Text {
 impl Text {
     /// Create a new Text widget with content
     pub fn new(content: impl crate::widget::IntoReactiveValue<String>) -> Self {
-        Self { content: content.into_reactive(), styles: None }
+        Self { content: content.into_reactive(), spans: None, selectable: false, styles: None }
+    }
+
+    /// Create a rich Text widget from styled runs, e.g. a bold word inside plain text
+    pub fn rich<S: Into<TextSpan>>(spans: impl IntoIterator<Item = S>) -> Self {
+        let spans: Vec<TextSpan> = spans.into_iter().map(Into::into).collect();
+        Self {
+            content: ReactiveValue::Static(spans_text(&spans)),
+            spans: Some(spans),
+            selectable: false,
+            styles: None,
+        }
+    }
+
+    /// Let the user drag to select the text and copy the selection with Ctrl+C
+    ///
+    /// Copied text goes to the app's copy buffer, read with
+    /// [`EventContext::copied_text`](crate::event::context::EventContext::copied_text), not to
+    /// the system clipboard.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
     }
 
     /// Set the styles directly
@@ -91,6 +115,12 @@ impl Widget for Text {
         if let Some(styles) = computed_styles {
             component.set_widget_styles(styles);
         }
+        if let Some(spans) = self.spans {
+            component.set_text_spans(spans);
+        }
+        if self.selectable {
+            component.set_text_selectable(true);
+        }
 
         let content_effect = if is_reactive {
             let comp = Gc::clone(&component);
@@ -109,7 +139,15 @@ impl Widget for Text {
     }
 
     fn rebuild(self, state: &mut Self::State) {
-        if self.content.is_reactive() {
+        if state.component.is_text_selectable() != self.selectable {
+            state.component.set_text_selectable(self.selectable);
+        }
+
+        if let Some(spans) = self.spans {
+            if state.component.text_spans() != spans {
+                state.component.set_text_spans(spans);
+            }
+        } else if self.content.is_reactive() {
             if state.content_effect.is_none() {
                 let comp = Gc::clone(&state.component);
                 let content = self.content.clone();
@@ -0,0 +1,243 @@
+//! TextArea widget for multi-line text editing
+
+use crate::component::{Component, ComponentType};
+use crate::effect::create_effect;
+use crate::properties::{PropertyMap, TextInputValue};
+use crate::signal::WriteSignal;
+use crate::text::{text_layout_builder, BrushIndex, TextContext};
+use crate::widget::{BuildContext, Mountable, ReactiveValue, Widget};
+use crate::widgets::form::register_form_field;
+use crate::widgets::input::{bind_error_message, rebuild_error};
+use parley::Layout;
+use rudo_gc::{Gc, Trace};
+use rvue_style::ReactiveStyles;
+
+/// Height of one visible row, used to size a text area from its `rows`
+pub const TEXT_AREA_ROW_HEIGHT: f32 = 20.0;
+
+/// Text a text area shows, with any in-progress IME composition spliced in at the cursor
+pub fn text_area_display_text(component: &Component) -> String {
+    let Some(editor) = component.text_editor() else {
+        return component.text_input_value();
+    };
+    let editor = editor.editor();
+    let content = editor.content();
+    let composition = editor.composition();
+    if composition.is_empty() {
+        return content;
+    }
+    let cursor = editor.selection().cursor();
+    let left: String = content.chars().take(cursor).collect();
+    let right: String = content.chars().skip(cursor).collect();
+    format!("{}{}{}", left, composition.text, right)
+}
+
+/// Lay out a text area's text, wrapping lines at `max_width` when given
+pub fn text_area_layout(
+    text: &str,
+    font_size: f32,
+    max_width: Option<f32>,
+    text_context: &mut TextContext,
+) -> Layout<BrushIndex> {
+    let builder = text_layout_builder(text_context, text, font_size);
+    let mut layout: Layout<BrushIndex> = builder.build(text);
+    layout.break_all_lines(max_width);
+    layout
+}
+
+/// TextArea widget builder for multi-line text input
+///
+/// Enter inserts a newline, Up and Down move the cursor between lines, and long lines wrap to
+/// the component width. When the text is taller than the component it scrolls to keep the
+/// cursor in view.
+#[derive(Clone)]
+pub struct TextArea {
+    value: ReactiveValue<String>,
+    rows: u32,
+    binding: Option<WriteSignal<String>>,
+    styles: Option<ReactiveStyles>,
+    error: Option<ReactiveValue<Option<String>>>,
+    name: Option<String>,
+}
+
+unsafe impl Trace for TextArea {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.value.trace(visitor);
+        self.binding.trace(visitor);
+        self.styles.trace(visitor);
+        self.error.trace(visitor);
+    }
+}
+
+impl TextArea {
+    /// Create a new TextArea widget with a value
+    pub fn new(value: impl crate::widget::IntoReactiveValue<String>) -> Self {
+        Self {
+            value: value.into_reactive(),
+            rows: 3,
+            binding: None,
+            styles: None,
+            error: None,
+            name: None,
+        }
+    }
+
+    /// Set the number of visible rows, which sets the default height
+    pub fn rows(mut self, rows: u32) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Write the content back to `signal` after every edit
+    pub fn bind(mut self, signal: WriteSignal<String>) -> Self {
+        self.binding = Some(signal);
+        self
+    }
+
+    /// Set the styles directly
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+
+    /// Name the text area's value is collected under by an enclosing [`Form`](crate::widgets::Form)
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Show a validation error below the text area, or none for `None`
+    ///
+    /// While it has an error the text area matches the `:invalid` pseudo-class, which the default
+    /// stylesheet gives a red border.
+    pub fn error(mut self, error: impl crate::widget::IntoReactiveValue<Option<String>>) -> Self {
+        self.error = Some(error.into_reactive());
+        self
+    }
+}
+
+/// State for a mounted TextArea widget
+pub struct TextAreaState {
+    component: Gc<Component>,
+    value_effect: Option<Gc<crate::effect::Effect>>,
+    error_effect: Option<Gc<crate::effect::Effect>>,
+}
+
+impl TextAreaState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for TextAreaState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+        if let Some(effect) = &self.value_effect {
+            effect.trace(visitor);
+        }
+        if let Some(effect) = &self.error_effect {
+            effect.trace(visitor);
+        }
+    }
+}
+
+impl Mountable for TextAreaState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+        if let Some(ref effect) = self.value_effect {
+            self.component.remove_effect(effect);
+        }
+        if let Some(ref effect) = self.error_effect {
+            self.component.remove_effect(effect);
+        }
+    }
+}
+
+/// Push a new value into the component, leaving the cursor alone if the editor already holds it
+fn apply_value(component: &Gc<Component>, value: String) {
+    component.set_text_input_value(value.clone());
+    if let Some(editor) = component.text_editor() {
+        if editor.editor().content() != value {
+            editor.editor().set_content(value);
+        }
+    }
+}
+
+fn create_value_effect(
+    component: &Gc<Component>,
+    value: ReactiveValue<String>,
+) -> Gc<crate::effect::Effect> {
+    let comp = Gc::clone(component);
+    let effect = create_effect(move || {
+        apply_value(&comp, value.get());
+    });
+    component.add_effect(Gc::clone(&effect));
+    effect
+}
+
+impl Widget for TextArea {
+    type State = TextAreaState;
+
+    fn build(self, _ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let initial_value = self.value.get();
+        let is_reactive = self.value.is_reactive();
+        let computed_styles = self.styles.as_ref().map(|s| s.compute());
+
+        let properties = if is_reactive {
+            PropertyMap::new()
+        } else {
+            PropertyMap::with(TextInputValue(initial_value.clone()))
+        };
+
+        let component = Component::with_properties(id, ComponentType::TextArea, properties);
+        component.init_text_editor(&initial_value);
+
+        let mut widget_styles = computed_styles.unwrap_or_default();
+        if widget_styles.width.is_none() {
+            widget_styles.width = Some(rvue_style::Width(rvue_style::Size::Pixels(200.0)));
+        }
+        if widget_styles.height.is_none() {
+            let height = self.rows.max(1) as f32 * TEXT_AREA_ROW_HEIGHT;
+            widget_styles.height = Some(rvue_style::Height(rvue_style::Size::Pixels(height)));
+        }
+        component.set_widget_styles(widget_styles);
+        component.set_clip(true);
+
+        if let Some(binding) = self.binding {
+            component.set_text_binding(binding);
+        }
+
+        let value_effect =
+            if is_reactive { Some(create_value_effect(&component, self.value)) } else { None };
+
+        let error_effect = self.error.and_then(|error| bind_error_message(&component, error));
+        register_form_field(self.name, &component);
+
+        TextAreaState { component, value_effect, error_effect }
+    }
+
+    fn rebuild(self, state: &mut Self::State) {
+        rebuild_error(self.error, &state.component, &mut state.error_effect);
+
+        if let Some(binding) = self.binding {
+            state.component.set_text_binding(binding);
+        }
+
+        if self.value.is_reactive() {
+            if state.value_effect.is_none() {
+                state.value_effect = Some(create_value_effect(&state.component, self.value));
+            }
+        } else {
+            apply_value(&state.component, self.value.get());
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Tooltip widget that appears after hovering its anchor for a delay
+
+use crate::component::{Component, ComponentType};
+use crate::properties::{PropertyMap, TextContent};
+use crate::widget::{BuildContext, Mountable, Widget};
+use rudo_gc::{Gc, Trace};
+use rvue_style::{Color, ComputedStyles, ReactiveStyles, TextColor};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// How long the anchor must be hovered before a tooltip shows by default
+pub const DEFAULT_TOOLTIP_DELAY: Duration = Duration::from_millis(500);
+
+/// Tooltip widget builder
+///
+/// Mount it as a child of the component it describes. It shows below that anchor once the
+/// pointer has rested on it for the delay, flipping above or shifting left to stay inside the
+/// window, and hides as soon as the pointer leaves.
+pub struct Tooltip {
+    text: String,
+    delay: Duration,
+    styles: Option<ReactiveStyles>,
+}
+
+unsafe impl Trace for Tooltip {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.styles.trace(visitor);
+    }
+}
+
+impl Tooltip {
+    /// Create a tooltip showing `text`
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), delay: DEFAULT_TOOLTIP_DELAY, styles: None }
+    }
+
+    /// Set how long the anchor must be hovered before the tooltip shows
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set the tooltip container styles
+    pub fn styles(mut self, styles: ReactiveStyles) -> Self {
+        self.styles = Some(styles);
+        self
+    }
+}
+
+/// Hover timer of a mounted tooltip, kept in the component's user data
+///
+/// The delay runs on the frame clock: it starts at the first frame after the anchor becomes
+/// hovered, and each frame until it elapses keeps the next one coming.
+struct TooltipTimer {
+    delay: Duration,
+    hovered: Cell<bool>,
+    hovered_since: Cell<Option<Instant>>,
+}
+
+/// State for a mounted Tooltip widget
+pub struct TooltipState {
+    component: Gc<Component>,
+}
+
+impl TooltipState {
+    /// Get the underlying component
+    pub fn component(&self) -> &Gc<Component> {
+        &self.component
+    }
+}
+
+unsafe impl Trace for TooltipState {
+    fn trace(&self, visitor: &mut impl rudo_gc::Visitor) {
+        self.component.trace(visitor);
+    }
+}
+
+impl Mountable for TooltipState {
+    fn mount(&self, parent: Option<Gc<Component>>) {
+        self.component.set_parent(parent.clone());
+        if let Some(parent) = parent {
+            parent.add_child(Gc::clone(&self.component));
+        }
+    }
+
+    fn unmount(&self) {
+        self.component.set_parent(None);
+    }
+}
+
+impl Widget for Tooltip {
+    type State = TooltipState;
+
+    fn build(self, _ctx: &mut BuildContext) -> Self::State {
+        let id = crate::component::next_component_id();
+        let component = Component::with_properties(id, ComponentType::Tooltip, PropertyMap::new());
+
+        if let Some(styles) = self.styles.as_ref().map(|s| s.compute()) {
+            component.set_widget_styles(styles);
+        }
+
+        let label = Component::with_properties(
+            crate::component::next_component_id(),
+            ComponentType::Text,
+            PropertyMap::with(TextContent(self.text)),
+        );
+        label.set_widget_styles(ComputedStyles {
+            text_color: Some(TextColor(Color::rgb(255, 255, 255))),
+            ..Default::default()
+        });
+        component.add_child(Gc::clone(&label));
+        label.set_parent(Some(Gc::clone(&component)));
+
+        *component.user_data.borrow_mut_gen_only() = Some(Box::new(TooltipTimer {
+            delay: self.delay,
+            hovered: Cell::new(false),
+            hovered_since: Cell::new(None),
+        }));
+
+        TooltipState { component }
+    }
+
+    fn rebuild(self, _state: &mut Self::State) {
+        // Visibility is driven by the anchor's hover state
+    }
+}
+
+/// Start the show delay when the anchor of `tooltip` becomes hovered, or hide it when it is left
+pub(crate) fn anchor_hovered(tooltip: &Gc<Component>, hovered: bool) {
+    {
+        let user_data = tooltip.user_data.borrow();
+        let Some(timer) = user_data.as_ref().and_then(|d| d.downcast_ref::<TooltipTimer>()) else {
+            return;
+        };
+        if timer.hovered.replace(hovered) == hovered {
+            return;
+        }
+        // A leave resets a pending show, so a quick pass over the anchor shows nothing
+        timer.hovered_since.set(None);
+    }
+    if hovered {
+        tooltip.mark_paint_dirty();
+    } else {
+        tooltip.set_tooltip_open(false);
+    }
+}
+
+/// Show `tooltip` once its anchor has been hovered for the delay as of `now`
+pub(crate) fn update_tooltip(tooltip: &Component, now: Instant) {
+    let elapsed = {
+        let user_data = tooltip.user_data.borrow();
+        let Some(timer) = user_data.as_ref().and_then(|d| d.downcast_ref::<TooltipTimer>()) else {
+            return;
+        };
+        if !timer.hovered.get() || tooltip.tooltip_open() {
+            return;
+        }
+        let since = timer.hovered_since.get().unwrap_or(now);
+        timer.hovered_since.set(Some(since));
+        now.saturating_duration_since(since) >= timer.delay
+    };
+    if elapsed {
+        tooltip.set_tooltip_open(true);
+    } else {
+        tooltip.mark_paint_dirty();
+    }
+}
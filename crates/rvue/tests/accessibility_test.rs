@@ -0,0 +1,129 @@
+//! Tests for building the AccessKit accessibility tree from components
+
+use accesskit::{NodeId, Role, Toggled, TreeUpdate};
+use rudo_gc::Gc;
+use rvue::accessibility::build_tree_update;
+use rvue::component::{Component, ComponentType};
+use rvue::properties::{
+    CheckboxChecked, CheckboxIndeterminate, LinkHref, PropertyMap, TextContent,
+};
+
+fn add_child(parent: &Gc<Component>, child: &Gc<Component>) {
+    parent.add_child(Gc::clone(child));
+    child.set_parent(Some(Gc::clone(parent)));
+}
+
+fn node(update: &TreeUpdate, id: u64) -> &accesskit::Node {
+    &update.nodes.iter().find(|(node_id, _)| *node_id == NodeId(id)).expect("missing node").1
+}
+
+#[test]
+fn test_accessibility_tree_roles_and_labels() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let title = Component::with_properties(
+        2,
+        ComponentType::Text,
+        PropertyMap::with(TextContent("Settings".into())),
+    );
+    let button = Component::with_properties(3, ComponentType::Button, PropertyMap::new());
+    let label = Component::with_properties(
+        4,
+        ComponentType::Text,
+        PropertyMap::with(TextContent("Save".into())),
+    );
+    let input = Component::with_properties(5, ComponentType::TextInput, PropertyMap::new());
+    let checkbox = Component::with_properties(
+        6,
+        ComponentType::Checkbox,
+        PropertyMap::with(CheckboxChecked(true)),
+    );
+    add_child(&root, &title);
+    add_child(&root, &button);
+    add_child(&button, &label);
+    add_child(&root, &input);
+    add_child(&root, &checkbox);
+
+    let update = build_tree_update(&root, Some(&input));
+
+    assert_eq!(update.tree.as_ref().unwrap().root, NodeId(1));
+    assert_eq!(update.focus, NodeId(5));
+    assert_eq!(update.nodes.len(), 6);
+
+    let root_node = node(&update, 1);
+    assert_eq!(root_node.role(), Role::GenericContainer);
+    assert_eq!(root_node.children(), &[NodeId(2), NodeId(3), NodeId(5), NodeId(6)]);
+
+    assert_eq!(node(&update, 2).role(), Role::Label);
+    assert_eq!(node(&update, 2).label(), Some("Settings"));
+
+    assert_eq!(node(&update, 3).role(), Role::Button);
+    assert_eq!(node(&update, 3).label(), Some("Save"));
+
+    assert_eq!(node(&update, 5).role(), Role::TextInput);
+    assert_eq!(node(&update, 6).role(), Role::CheckBox);
+    assert_eq!(node(&update, 6).toggled(), Some(Toggled::True));
+}
+
+#[test]
+fn test_accessibility_tree_flattens_show() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let show = Component::with_properties(2, ComponentType::Show, PropertyMap::new());
+    show.set_show_when(true);
+    let button = Component::with_properties(3, ComponentType::Button, PropertyMap::new());
+    add_child(&root, &show);
+    add_child(&show, &button);
+
+    let update = build_tree_update(&root, None);
+
+    assert_eq!(update.focus, NodeId(1));
+    assert_eq!(node(&update, 1).children(), &[NodeId(3)]);
+    assert!(update.nodes.iter().all(|(id, _)| *id != NodeId(2)));
+}
+
+#[test]
+fn test_accessibility_aria_label_names_icon_button() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let button = Component::with_properties(2, ComponentType::Button, PropertyMap::new());
+    add_child(&root, &button);
+
+    assert_eq!(node(&build_tree_update(&root, None), 2).label(), None);
+
+    button.set_aria_label("Close");
+    let update = build_tree_update(&root, None);
+    assert_eq!(node(&update, 2).role(), Role::Button);
+    assert_eq!(node(&update, 2).label(), Some("Close"));
+}
+
+#[test]
+fn test_indeterminate_checkbox_reports_mixed() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let checkbox = Component::with_properties(
+        2,
+        ComponentType::Checkbox,
+        PropertyMap::with(CheckboxIndeterminate(true)),
+    );
+    checkbox.set_checkbox_checked(true);
+    add_child(&root, &checkbox);
+
+    let update = build_tree_update(&root, None);
+    assert_eq!(node(&update, 2).toggled(), Some(Toggled::Mixed));
+
+    checkbox.set_checkbox_indeterminate(false);
+    let update = build_tree_update(&root, None);
+    assert_eq!(node(&update, 2).toggled(), Some(Toggled::True));
+}
+
+#[test]
+fn test_link_reports_role_and_url() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let link = Component::with_properties(
+        2,
+        ComponentType::Text,
+        PropertyMap::with(LinkHref("https://example.com".into())),
+    );
+    add_child(&root, &link);
+
+    let update = build_tree_update(&root, None);
+    assert_eq!(node(&update, 2).role(), Role::Link);
+    assert_eq!(node(&update, 2).url(), Some("https://example.com"));
+}
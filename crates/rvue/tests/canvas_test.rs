@@ -0,0 +1,174 @@
+//! Integration tests for the Canvas widget
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::Canvas;
+use rvue::{Gc, Scene};
+use rvue_style::{ComputedStyles, Height, Size, Width};
+use vello::kurbo::{Affine, Circle, Point, Rect};
+use vello::peniko::{Color, Fill};
+
+fn sized(width: f32, height: f32) -> ComputedStyles {
+    ComputedStyles {
+        width: Some(Width(Size::Pixels(width))),
+        height: Some(Height(Size::Pixels(height))),
+        ..Default::default()
+    }
+}
+
+fn build_canvas(canvas: Canvas) -> Gc<Component> {
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = canvas.build(&mut ctx);
+    let component = Gc::clone(state.component());
+    component.set_widget_styles(sized(60.0, 40.0));
+    component
+}
+
+fn mount(canvas: &Gc<Component>) -> Scene {
+    let root = Component::with_properties(1_000, ComponentType::Flex, PropertyMap::new());
+    root.set_widget_styles(sized(200.0, 100.0));
+    root.add_child(Gc::clone(canvas));
+    canvas.set_parent(Some(Gc::clone(&root)));
+
+    let mut scene = Scene::new();
+    scene.add_fragment(root);
+    scene
+}
+
+#[test]
+fn test_canvas_draws_at_its_layout_size() {
+    let sizes = Rc::new(RefCell::new(Vec::new()));
+    let draw_sizes = Rc::clone(&sizes);
+    let canvas = build_canvas(Canvas::new().on_draw(move |scene, size| {
+        draw_sizes.borrow_mut().push(size);
+        let center = Point::new(size.width / 2.0, size.height / 2.0);
+        let circle = Circle::new(center, size.height / 2.0);
+        scene.fill(Fill::NonZero, Affine::IDENTITY, Color::from_rgb8(255, 0, 0), None, &circle);
+    }));
+    assert_eq!(canvas.component_type, ComponentType::Canvas);
+
+    let mut scene = mount(&canvas);
+    scene.update();
+
+    assert_eq!(*sizes.borrow(), vec![vello::kurbo::Size::new(60.0, 40.0)]);
+    let cache = canvas.vello_cache.borrow();
+    assert!(!cache.as_ref().expect("canvas was rendered").0.encoding().is_empty());
+}
+
+#[test]
+fn test_canvas_redraws_only_when_dirty() {
+    let draws = Rc::new(Cell::new(0));
+    let draw_count = Rc::clone(&draws);
+    let canvas = build_canvas(Canvas::new().on_draw(move |_, _| {
+        draw_count.set(draw_count.get() + 1);
+    }));
+
+    let mut scene = mount(&canvas);
+    scene.update();
+    assert_eq!(draws.get(), 1);
+
+    scene.update();
+    assert_eq!(draws.get(), 1);
+
+    canvas.mark_dirty();
+    scene.update();
+    assert_eq!(draws.get(), 2);
+}
+
+#[test]
+fn test_canvas_keeps_drawing_after_scroll_state_is_set() {
+    let draws = Rc::new(Cell::new(0));
+    let draw_count = Rc::clone(&draws);
+    let canvas = build_canvas(Canvas::new().on_draw(move |_, _| {
+        draw_count.set(draw_count.get() + 1);
+    }));
+    canvas.set_scroll_state(rvue::FlexScrollState::default());
+
+    let mut scene = mount(&canvas);
+    scene.update();
+    assert_eq!(draws.get(), 1);
+}
+
+#[test]
+fn test_canvas_without_callback_draws_nothing() {
+    let canvas = build_canvas(Canvas::new());
+    let mut scene = mount(&canvas);
+    scene.update();
+
+    let cache = canvas.vello_cache.borrow();
+    assert!(cache.as_ref().expect("canvas was rendered").0.encoding().is_empty());
+}
+
+#[test]
+fn test_dirty_row_redraws_without_touching_other_rows() {
+    let list = Component::with_properties(2_000, ComponentType::Flex, PropertyMap::new());
+    list.set_widget_styles(sized(200.0, 400.0));
+
+    let mut rows = Vec::new();
+    let mut draws = Vec::new();
+    for _ in 0..5 {
+        let count = Rc::new(Cell::new(0));
+        let draw_count = Rc::clone(&count);
+        let canvas = build_canvas(Canvas::new().on_draw(move |_, _| {
+            draw_count.set(draw_count.get() + 1);
+        }));
+
+        // Each row wraps its canvas, so reusing a row means not walking into it
+        let row = Component::with_global_id(ComponentType::Flex, PropertyMap::new());
+        row.add_child(Gc::clone(&canvas));
+        canvas.set_parent(Some(Gc::clone(&row)));
+        list.add_child(Gc::clone(&row));
+        row.set_parent(Some(Gc::clone(&list)));
+
+        rows.push((row, canvas));
+        draws.push(count);
+    }
+
+    let mut scene = Scene::new();
+    scene.add_fragment(Gc::clone(&list));
+    scene.update();
+    assert!(draws.iter().all(|count| count.get() == 1));
+
+    rows[2].1.mark_paint_dirty();
+    assert!(!rows[0].0.is_dirty());
+    // Walking into a clean row would redraw this canvas, since its own scene is gone
+    *rows[0].1.vello_cache.borrow_mut_gen_only() = None;
+    scene.update();
+
+    let counts: Vec<u32> = draws.iter().map(|count| count.get()).collect();
+    assert_eq!(counts, [1, 1, 2, 1, 1]);
+    assert!(rows.iter().all(|(row, _)| row.subtree_cache.borrow().is_some()));
+    assert!(!scene.vello_scene.as_ref().unwrap().encoding().is_empty());
+}
+
+#[test]
+fn test_dirty_rect_is_the_changed_component_bounds() {
+    let list = Component::with_properties(3_000, ComponentType::Flex, PropertyMap::new());
+    list.set_widget_styles(sized(200.0, 300.0));
+    let canvases: Vec<Gc<Component>> = (0..3).map(|_| build_canvas(Canvas::new())).collect();
+    for canvas in &canvases {
+        list.add_child(Gc::clone(canvas));
+        canvas.set_parent(Some(Gc::clone(&list)));
+    }
+
+    let mut scene = Scene::new();
+    scene.add_fragment(Gc::clone(&list));
+    scene.update();
+    assert_eq!(scene.dirty_rect(), Some(Rect::new(0.0, 0.0, 200.0, 300.0)));
+
+    scene.update();
+    assert_eq!(scene.dirty_rect(), None);
+
+    canvases[1].mark_paint_dirty();
+    scene.update();
+    let (x, y) = canvases[1].layout_position().unwrap();
+    assert_eq!(scene.dirty_rect(), Some(Rect::new(x, y, x + 60.0, y + 40.0)));
+    assert!(x > 0.0, "the second canvas is laid out after the first");
+}
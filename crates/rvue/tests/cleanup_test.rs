@@ -58,3 +58,63 @@ fn test_component_cleanup() {
     // Component cleanup should be called
     assert_eq!(*cleanup_called.lock().unwrap(), 1);
 }
+
+/// Builds a two-level view with cleanups and an effect, and returns weak handles to its components.
+#[inline(never)]
+fn build_disposable_view(
+    cleanups_run: &Arc<Mutex<Vec<&'static str>>>,
+    effect_runs: &Arc<Mutex<i32>>,
+    count: ReadSignal<i32>,
+) -> (ViewStruct, Vec<rudo_gc::Weak<Component>>) {
+    let root =
+        Component::with_properties(1, ComponentType::Flex, rvue::properties::PropertyMap::new());
+    let child = Component::with_properties(
+        2,
+        ComponentType::Custom("Child".to_string()),
+        rvue::properties::PropertyMap::new(),
+    );
+    child.set_parent(Some(root.clone()));
+    root.add_child(child.clone());
+
+    let log = Arc::clone(cleanups_run);
+    rvue::runtime::with_owner(root.clone(), move || {
+        on_cleanup(move || log.lock().unwrap().push("root"));
+    });
+    let log = Arc::clone(cleanups_run);
+    let runs = Arc::clone(effect_runs);
+    let handle = child.clone();
+    rvue::runtime::with_owner(child.clone(), move || {
+        // The effect keeps its owner alive, forming a cycle dispose has to break
+        create_effect(move || {
+            let _ = count.get();
+            let _ = &handle;
+            *runs.lock().unwrap() += 1;
+        });
+        on_cleanup(move || log.lock().unwrap().push("child"));
+    });
+
+    let weaks = vec![rudo_gc::Gc::downgrade(&root), rudo_gc::Gc::downgrade(&child)];
+    (ViewStruct::new(root), weaks)
+}
+
+#[test]
+fn test_dispose_runs_cleanups_and_releases_components() {
+    let (count, set_count) = create_signal(0);
+    let cleanups_run = Arc::new(Mutex::new(Vec::new()));
+    let effect_runs = Arc::new(Mutex::new(0));
+
+    let (view, weaks) = build_disposable_view(&cleanups_run, &effect_runs, count);
+    assert!(weaks.iter().all(|weak| weak.upgrade().is_some()));
+    set_count.set(1);
+    assert_eq!(*effect_runs.lock().unwrap(), 2);
+
+    view.dispose();
+    rudo_gc::collect();
+
+    assert_eq!(*cleanups_run.lock().unwrap(), vec!["child", "root"]);
+    assert!(weaks.iter().all(|weak| weak.upgrade().is_none()));
+
+    set_count.set(2);
+    assert_eq!(*effect_runs.lock().unwrap(), 2, "a disposed effect never runs again");
+    rvue::signal::__test_clear_signal_subscriptions();
+}
@@ -0,0 +1,49 @@
+//! Tests for cancelling component-owned async tasks on unmount
+
+#[cfg(feature = "async")]
+#[cfg(test)]
+mod tests {
+    use rudo_gc::Gc;
+    use rvue::async_runtime::registry::TaskRegistry;
+    use rvue::async_runtime::ComponentScope;
+    use rvue::headless::{init_runtime, settle};
+    use rvue::properties::{PropertyMap, ShowCondition};
+    use rvue::{Component, ComponentLifecycle, ComponentType};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_hiding_show_cancels_child_task() {
+        init_runtime();
+
+        let show =
+            Component::with_global_id(ComponentType::Show, PropertyMap::with(ShowCondition(true)));
+        let child = Component::with_global_id(ComponentType::Text, PropertyMap::new());
+        show.add_child(Gc::clone(&child));
+        child.set_parent(Some(Gc::clone(&show)));
+
+        let (resume, resumed) = tokio::sync::oneshot::channel::<()>();
+        let continued = Arc::new(AtomicBool::new(false));
+        let continued_clone = Arc::clone(&continued);
+
+        let scope = ComponentScope::for_component(&child);
+        let handle = scope.spawn_task(async move {
+            let _ = resumed.await;
+            continued_clone.store(true, Ordering::SeqCst);
+        });
+        assert_eq!(TaskRegistry::task_count(child.id), 1);
+        assert!(handle.is_running());
+
+        show.set_show_when(false);
+        show.update();
+
+        assert!(handle.is_completed());
+        assert_eq!(TaskRegistry::task_count(child.id), 0);
+
+        let _ = resume.send(());
+        std::thread::sleep(Duration::from_millis(50));
+        settle();
+        assert!(!continued.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,80 @@
+//! Tests for the ContextMenu widget
+
+use rvue::prelude::*;
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::context_menu::dismiss_context_menus;
+use rvue::{text::TextContext, ContextMenu, ContextMenuHandle, TaffyTree};
+use vello::kurbo::Point;
+
+#[test]
+fn test_context_menu_opens_at_handle_position() {
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+
+    let handle = ContextMenuHandle::new();
+    let state = ContextMenu::new(handle.clone()).item("Copy", || {}).build(&mut ctx);
+    let menu = state.component();
+
+    assert_eq!(menu.component_type, ComponentType::ContextMenu);
+    assert_eq!(menu.children.borrow().len(), 1);
+    assert_eq!(menu.context_menu_position(), None);
+
+    handle.open_at(Point::new(120.0, 80.0));
+    assert_eq!(menu.context_menu_position(), Some((120.0, 80.0)));
+
+    handle.close();
+    assert_eq!(menu.context_menu_position(), None);
+}
+
+#[test]
+fn test_context_menu_dismissed_by_outside_press() {
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+
+    let root = ctx.create_component(ComponentType::Flex, rvue::properties::PropertyMap::new());
+    let outside = ctx.create_component(ComponentType::Flex, rvue::properties::PropertyMap::new());
+    root.add_child(outside.clone());
+    outside.set_parent(Some(root.clone()));
+
+    let handle = ContextMenuHandle::new();
+    let state = ContextMenu::new(handle.clone()).item("Copy", || {}).build(&mut ctx);
+    let menu = state.component().clone();
+    root.add_child(menu.clone());
+    menu.set_parent(Some(root.clone()));
+
+    handle.open_at(Point::new(10.0, 10.0));
+
+    // Pressing on the menu's own item keeps it open
+    let item = menu.children.borrow()[0].clone();
+    dismiss_context_menus(&root, Some(&item));
+    assert!(handle.is_open());
+
+    dismiss_context_menus(&root, Some(&outside));
+    assert!(!handle.is_open());
+    assert_eq!(menu.context_menu_position(), None);
+}
+
+#[test]
+fn test_context_menu_dismissed_after_scroll_state_is_set() {
+    let mut taffy = TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0u64;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+
+    let root = ctx.create_component(ComponentType::Flex, rvue::properties::PropertyMap::new());
+    let handle = ContextMenuHandle::new();
+    let state = ContextMenu::new(handle.clone()).item("Copy", || {}).build(&mut ctx);
+    let menu = state.component().clone();
+    root.add_child(menu.clone());
+    menu.set_parent(Some(root.clone()));
+
+    handle.open_at(Point::new(10.0, 10.0));
+    menu.set_scroll_state(rvue::FlexScrollState::default());
+
+    dismiss_context_menus(&root, None);
+    assert!(!handle.is_open());
+}
@@ -0,0 +1,74 @@
+//! Tests for mapping the `cursor` style of hovered components to the OS cursor
+
+use rudo_gc::Gc;
+use rvue::component::{Component, ComponentType};
+use rvue::event::cursor::{resolve_cursor, CursorState};
+use rvue_style::properties::Cursor;
+
+fn create_component(id: u64, component_type: ComponentType) -> Gc<Component> {
+    Component::with_properties(id, component_type, rvue::properties::PropertyMap::new())
+}
+
+#[test]
+fn test_cursor_button_uses_pointer() {
+    let button = create_component(1, ComponentType::Button);
+    assert_eq!(resolve_cursor(Some(&button), None), Cursor::Pointer);
+}
+
+#[test]
+fn test_cursor_text_input_uses_text() {
+    let input = create_component(1, ComponentType::TextInput);
+    assert_eq!(resolve_cursor(Some(&input), None), Cursor::Text);
+}
+
+#[test]
+fn test_cursor_explicit_style_overrides_widget_default() {
+    let button = create_component(1, ComponentType::Button);
+    button.set_widget_styles(rvue_style::ComputedStyles {
+        cursor: Some(Cursor::NotAllowed),
+        ..Default::default()
+    });
+    assert_eq!(resolve_cursor(Some(&button), None), Cursor::NotAllowed);
+}
+
+#[test]
+fn test_cursor_inherited_from_parent() {
+    let parent = create_component(1, ComponentType::Button);
+    let child = create_component(2, ComponentType::Text);
+    child.set_parent(Some(Gc::clone(&parent)));
+    assert_eq!(resolve_cursor(Some(&child), None), Cursor::Pointer);
+}
+
+#[test]
+fn test_cursor_state_hover_button_then_leave() {
+    let button = create_component(1, ComponentType::Button);
+    let mut state = CursorState::new();
+
+    assert_eq!(state.update(Some(&button), None), Some(Cursor::Pointer));
+    assert_eq!(state.current, Cursor::Pointer);
+
+    // Hovering the same component again does not request a cursor change
+    assert_eq!(state.update(Some(&button), None), None);
+
+    // Leaving the component reverts to the default cursor
+    assert_eq!(state.update(None, None), Some(Cursor::Default));
+    assert_eq!(state.current, Cursor::Default);
+}
+
+#[test]
+fn test_cursor_state_resolves_only_when_hovered_component_changes() {
+    let button = create_component(1, ComponentType::Button);
+    let mut state = CursorState::new();
+    assert_eq!(state.update(Some(&button), None), Some(Cursor::Pointer));
+
+    // Moving within the same component keeps the resolved cursor
+    button.set_widget_styles(rvue_style::ComputedStyles {
+        cursor: Some(Cursor::NotAllowed),
+        ..Default::default()
+    });
+    assert_eq!(state.update(Some(&button), None), None);
+
+    // Once invalidated, the next update resolves the new style
+    state.invalidate();
+    assert_eq!(state.update(Some(&button), None), Some(Cursor::NotAllowed));
+}
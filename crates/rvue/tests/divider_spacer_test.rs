@@ -0,0 +1,99 @@
+//! Integration tests for the Divider and Spacer layout helpers
+
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::widget::{BuildContext, Mountable, Widget};
+use rvue::widgets::flex::FlexState;
+use rvue::widgets::{Divider, Spacer};
+use rvue::{Gc, Scene};
+use rvue_style::{BackgroundColor, Color, ComputedStyles, FlexDirection, Height, Size, Width};
+use vello::kurbo::Rect;
+
+fn sized(width: f32, height: f32) -> ComputedStyles {
+    ComputedStyles {
+        width: Some(Width(Size::Pixels(width))),
+        height: Some(Height(Size::Pixels(height))),
+        ..Default::default()
+    }
+}
+
+fn container(direction: FlexDirection) -> Gc<Component> {
+    let root = Component::with_properties(1_000, ComponentType::Flex, PropertyMap::new());
+    root.set_widget_styles(ComputedStyles {
+        flex_direction: Some(direction),
+        ..sized(300.0, 100.0)
+    });
+    root
+}
+
+fn mount(widget: impl Widget<State = FlexState>, parent: &Gc<Component>) -> Gc<Component> {
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = widget.build(&mut ctx);
+    state.mount(Some(Gc::clone(parent)));
+    Gc::clone(state.component())
+}
+
+fn item(parent: &Gc<Component>) -> Gc<Component> {
+    let item = Component::with_global_id(ComponentType::Flex, PropertyMap::new());
+    item.set_widget_styles(sized(40.0, 20.0));
+    parent.add_child(Gc::clone(&item));
+    item.set_parent(Some(Gc::clone(parent)));
+    item
+}
+
+fn lay_out(root: &Gc<Component>) {
+    let mut scene = Scene::new();
+    scene.add_fragment(Gc::clone(root));
+    scene.update();
+}
+
+fn rect(component: &Gc<Component>) -> Rect {
+    let layout = component.layout_node().and_then(|node| node.layout_result).expect("laid out");
+    Rect::new(
+        layout.location.x as f64,
+        layout.location.y as f64,
+        (layout.location.x + layout.size.width) as f64,
+        (layout.location.y + layout.size.height) as f64,
+    )
+}
+
+#[test]
+fn test_divider_is_a_1px_line_across_a_column() {
+    let column = container(FlexDirection::Column);
+    let above = item(&column);
+    let divider = mount(Divider::new(), &column);
+    let below = item(&column);
+    lay_out(&column);
+
+    assert_eq!(rect(&divider), Rect::new(0.0, 20.0, 300.0, 21.0));
+    assert_eq!(rect(&above).y1, rect(&divider).y0);
+    assert_eq!(rect(&below).y0, rect(&divider).y1);
+    let background = divider.widget_styles().and_then(|s| s.background_color);
+    assert_eq!(background, Some(BackgroundColor(Color::rgb(224, 224, 224))));
+}
+
+#[test]
+fn test_vertical_divider_runs_down_a_row() {
+    let row = container(FlexDirection::Row);
+    item(&row);
+    let divider = mount(Divider::new().vertical(true), &row);
+    lay_out(&row);
+
+    assert_eq!(rect(&divider), Rect::new(40.0, 0.0, 41.0, 100.0));
+}
+
+#[test]
+fn test_spacer_pushes_row_items_apart() {
+    let row = container(FlexDirection::Row);
+    let start = item(&row);
+    let spacer = mount(Spacer::new(), &row);
+    let end = item(&row);
+    lay_out(&row);
+
+    assert_eq!(rect(&start).x0, 0.0);
+    assert_eq!(rect(&end).x1, 300.0);
+    assert_eq!(rect(&spacer), Rect::new(40.0, 0.0, 260.0, 100.0));
+}
@@ -76,3 +76,344 @@ fn test_for_component_reactive_updates() {
     assert_eq!(call_count.get(), 2);
     assert_eq!(items_signal.get().len(), 3);
 }
+
+mod list_signal {
+    use rudo_gc::Gc;
+    use rvue::prelude::*;
+    use rvue::{create_list, Component};
+    use rvue_macro::view;
+
+    fn item_components(view: &ViewStruct) -> Vec<Gc<Component>> {
+        view.root_component.children.borrow().clone()
+    }
+
+    #[test]
+    fn test_list_operations_produce_expected_items() {
+        let list = create_list(vec![1, 2, 3]);
+        let items = || list.read().get_untracked();
+
+        list.push(4);
+        assert_eq!(items(), vec![1, 2, 3, 4]);
+
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(list.remove(10), None);
+        assert_eq!(items(), vec![1, 3, 4]);
+
+        assert!(list.update_at(0, |item| *item *= 10));
+        assert!(!list.update_at(3, |item| *item = 0));
+        assert_eq!(items(), vec![10, 3, 4]);
+        assert_eq!(list.len(), 3);
+
+        list.clear();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_each_list_change_notifies_once_and_no_ops_notify_nothing() {
+        let list = create_list(vec!["a".to_string()]);
+        let runs = std::rc::Rc::new(std::cell::Cell::new(0));
+        let _effect = create_effect({
+            let list = list.clone();
+            let runs = runs.clone();
+            move || {
+                list.len();
+                runs.set(runs.get() + 1);
+            }
+        });
+
+        list.push("b".to_string());
+        list.update_at(0, |item| item.push('!'));
+        list.remove(0);
+        assert_eq!(runs.get(), 4);
+
+        list.remove(5);
+        list.update_at(5, |item| item.clear());
+        list.clear();
+        list.clear();
+        assert_eq!(runs.get(), 5);
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+
+    #[test]
+    fn test_for_reuses_components_of_unchanged_keys() {
+        let list = create_list(vec![1u32, 2, 3]);
+        let items = list.clone();
+        let view: ViewStruct = view! {
+            <For each=items key=|n: &u32| *n view={|n: u32| view! {
+                <Text content={n.to_string()} />
+            }}/>
+        };
+        let before = item_components(&view);
+        assert_eq!(before.len(), 3);
+
+        list.push(4);
+        let after_push = item_components(&view);
+        assert_eq!(after_push.len(), 4);
+        assert!(before.iter().zip(&after_push).all(|(a, b)| Gc::ptr_eq(a, b)));
+
+        list.remove(0);
+        let after_remove = item_components(&view);
+        let texts: Vec<String> = after_remove.iter().map(|c| c.text_content()).collect();
+        assert_eq!(texts, ["2", "3", "4"]);
+        assert!(Gc::ptr_eq(&after_remove[0], &before[1]));
+        assert!(Gc::ptr_eq(&after_remove[2], &after_push[3]));
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+
+    /// `"key:label"` items keyed by the part before the colon
+    #[allow(clippy::ptr_arg)]
+    fn labelled_key(item: &String) -> String {
+        item.split(':').next().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    fn test_for_reuses_items_by_key_alone() {
+        let list = create_list(vec!["a:one".to_string(), "b:two".to_string()]);
+        let items = list.clone();
+        let view: ViewStruct = view! {
+            <For each=items key=labelled_key view={|item: String| view! {
+                <Text content={item} />
+            }}/>
+        };
+        let before = item_components(&view);
+
+        list.update_at(0, |item| *item = "a:uno".to_string());
+        let after = item_components(&view);
+        assert!(Gc::ptr_eq(&after[0], &before[0]));
+        assert_eq!(after[0].text_content(), "a:one");
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+
+    #[test]
+    fn test_for_with_eq_rebuilds_items_whose_value_changed() {
+        use rvue::text::TextContext;
+        use rvue::widget::{BuildContext, Widget};
+        use rvue::For;
+        use taffy::TaffyTree;
+
+        let list = create_list(vec!["a:one".to_string(), "b:two".to_string()]);
+        let for_widget = For::with_eq(list.clone(), labelled_key, |item: String| {
+            view! { <Text content={item} /> }
+        });
+
+        let mut taffy = TaffyTree::new();
+        let mut text_context = TextContext::new();
+        let mut id_counter = 0;
+        let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+        let state = for_widget.build(&mut ctx);
+        let component = Gc::clone(state.component());
+        let before = component.children.borrow().clone();
+
+        list.update_at(0, |item| *item = "a:uno".to_string());
+        let after = component.children.borrow().clone();
+        assert_eq!(after[0].text_content(), "a:uno");
+        assert!(!Gc::ptr_eq(&after[0], &before[0]));
+        assert!(Gc::ptr_eq(&after[1], &before[1]));
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+
+    #[test]
+    fn test_for_disposes_every_item_of_a_duplicate_key() {
+        let list = create_list(vec!["a:1".to_string(), "a:2".to_string(), "b:3".to_string()]);
+        let items = list.clone();
+        let view: ViewStruct = view! {
+            <For each=items key=labelled_key view={|item: String| view! {
+                <Text content={item} />
+            }}/>
+        };
+        let mut built = item_components(&view);
+        assert_eq!(built.len(), 3);
+
+        list.remove(0);
+        built.extend(item_components(&view));
+        list.remove(0);
+        let remaining = item_components(&view);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].text_content(), "b:3");
+
+        // Disposed components are detached from the list
+        let detached = |c: &Gc<Component>| c.parent.borrow().is_none();
+        assert!(built.iter().filter(|c| !Gc::ptr_eq(c, &remaining[0])).all(detached));
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+}
+
+mod virtual_for {
+    use rudo_gc::Gc;
+    use rvue::prelude::*;
+    use rvue::render::FlexScrollState;
+    use rvue::text::TextContext;
+    use rvue::widget::{BuildContext, Widget};
+    use rvue::{Component, Scene, VirtualFor};
+    use rvue_macro::view;
+    use taffy::TaffyTree;
+
+    /// The item text shown in each built row
+    fn shown_items(list: &Gc<Component>) -> Vec<String> {
+        let children = list.children.borrow();
+        // The first and last children are the spacers
+        children[1..children.len() - 1]
+            .iter()
+            .map(|row| row.children.borrow()[0].text_content())
+            .collect()
+    }
+
+    #[test]
+    fn test_only_rows_in_view_are_built_and_follow_scrolling() {
+        let (items, _set_items) = create_signal((0..1000).collect::<Vec<u32>>());
+        let list = VirtualFor::new(
+            items,
+            |n: &u32| *n,
+            |n: u32| view! { <Text content={n.to_string()} /> },
+            30.0,
+            300.0,
+        )
+        .overscan(2);
+
+        let mut taffy = TaffyTree::new();
+        let mut text_context = TextContext::new();
+        let mut id_counter = 0;
+        let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+        let state = list.build(&mut ctx);
+        let component = Gc::clone(state.component());
+
+        // Ten rows fill the viewport, plus the overscan below it
+        assert_eq!(state.built_range(), 0..12);
+        assert_eq!(shown_items(&component).len(), 12);
+        assert_eq!(shown_items(&component)[0], "0");
+
+        let mut scene = Scene::new();
+        scene.add_fragment(Gc::clone(&component));
+        scene.update();
+        assert_eq!(component.scroll_state().scroll_height, 1000.0 * 30.0 - 300.0);
+
+        let kept = Gc::clone(&component.children.borrow()[11]);
+        component.set_scroll_state(FlexScrollState {
+            scroll_offset_y: 150.0,
+            ..component.scroll_state()
+        });
+        assert_eq!(state.built_range(), 3..17);
+        assert!(component.children.borrow().iter().any(|row| Gc::ptr_eq(row, &kept)));
+
+        component.set_scroll_state(FlexScrollState {
+            scroll_offset_y: 3000.0,
+            ..component.scroll_state()
+        });
+        assert_eq!(state.built_range(), 98..112);
+        let shown = shown_items(&component);
+        assert_eq!(shown.first().map(String::as_str), Some("98"));
+        assert_eq!(shown.last().map(String::as_str), Some("111"));
+
+        // Item 100 sits at the top of the viewport
+        scene.update();
+        let row = Gc::clone(&component.children.borrow()[3]);
+        assert_eq!(row.children.borrow()[0].text_content(), "100");
+        let top = row.layout_node().and_then(|node| node.layout().map(|l| l.location.y));
+        assert_eq!(top, Some(3000.0));
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+
+    #[test]
+    fn test_rows_scrolled_out_dispose_what_their_view_created() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let (items, _set_items) = create_signal((0..100).collect::<Vec<u32>>());
+        let (tick, set_tick) = create_signal(0);
+        let runs = Rc::new(RefCell::new(Vec::new()));
+        let cleaned = Rc::new(RefCell::new(Vec::new()));
+        let (runs_in_view, cleaned_in_view) = (Rc::clone(&runs), Rc::clone(&cleaned));
+        let list = VirtualFor::new(
+            items,
+            |n: &u32| *n,
+            move |n: u32| {
+                let runs = Rc::clone(&runs_in_view);
+                let tick = tick.clone();
+                create_effect(move || {
+                    tick.get();
+                    runs.borrow_mut().push(n);
+                });
+                let cleaned = Rc::clone(&cleaned_in_view);
+                on_cleanup(move || cleaned.borrow_mut().push(n));
+                view! { <Text content={n.to_string()} /> }
+            },
+            30.0,
+            90.0,
+        )
+        .overscan(0);
+
+        let mut taffy = TaffyTree::new();
+        let mut text_context = TextContext::new();
+        let mut id_counter = 0;
+        let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+        let state = list.build(&mut ctx);
+        let component = Gc::clone(state.component());
+        assert_eq!(state.built_range(), 0..3);
+
+        component.set_scroll_state(FlexScrollState {
+            scroll_offset_y: 900.0,
+            ..component.scroll_state()
+        });
+        assert_eq!(state.built_range(), 30..33);
+        let mut cleaned_rows = cleaned.borrow().clone();
+        cleaned_rows.sort();
+        assert_eq!(cleaned_rows, [0, 1, 2]);
+
+        // Only the rows still built react to the signal their views read
+        runs.borrow_mut().clear();
+        set_tick.set(1);
+        rvue::effect::flush_pending_effects();
+        let mut reran = runs.borrow().clone();
+        reran.sort();
+        assert_eq!(reran, [30, 31, 32]);
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+
+    #[test]
+    fn test_rows_sharing_a_key_are_all_disposed() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let (items, _set_items) = create_signal((0..100).collect::<Vec<u32>>());
+        let cleaned = Rc::new(RefCell::new(Vec::new()));
+        let cleaned_in_view = Rc::clone(&cleaned);
+        let list = VirtualFor::new(
+            items,
+            // Every key is shared by two items
+            |n: &u32| *n / 2,
+            move |n: u32| {
+                let cleaned = Rc::clone(&cleaned_in_view);
+                on_cleanup(move || cleaned.borrow_mut().push(n));
+                view! { <Text content={n.to_string()} /> }
+            },
+            30.0,
+            90.0,
+        )
+        .overscan(0);
+
+        let mut taffy = TaffyTree::new();
+        let mut text_context = TextContext::new();
+        let mut id_counter = 0;
+        let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+        let state = list.build(&mut ctx);
+        let component = Gc::clone(state.component());
+        assert_eq!(shown_items(&component), ["0", "1", "2"]);
+
+        component.set_scroll_state(FlexScrollState {
+            scroll_offset_y: 900.0,
+            ..component.scroll_state()
+        });
+        let mut cleaned_rows = cleaned.borrow().clone();
+        cleaned_rows.sort();
+        assert_eq!(cleaned_rows, [0, 1, 2]);
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+}
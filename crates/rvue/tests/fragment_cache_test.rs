@@ -4,6 +4,7 @@ use rudo_gc::Gc;
 use rvue::prelude::*;
 use rvue::Scene;
 use rvue_macro::view;
+use rvue_style::BackgroundColor;
 
 #[test]
 fn test_nested_child_transform_update() {
@@ -229,6 +230,105 @@ fn test_clean_component_skips_recursion() {
     }
 }
 
+#[test]
+fn test_same_size_text_change_is_paint_only() {
+    let view = view! {
+        <Flex direction="column">
+            <Text content="1234" />
+            <Text content="Static sibling" />
+        </Flex>
+    };
+
+    let root_component = view.root_component;
+    let mut scene = Scene::new();
+    scene.add_fragment(root_component.clone());
+    scene.update();
+
+    let (text, sibling) = {
+        let children = root_component.children.borrow();
+        (children[0].clone(), children[1].clone())
+    };
+    let layout_before = text.layout_node().and_then(|node| node.layout().copied());
+
+    text.set_text_content("4321".to_string());
+
+    assert!(text.is_paint_dirty());
+    assert!(!text.is_layout_dirty());
+    assert!(root_component.is_paint_dirty());
+    assert!(!root_component.is_layout_dirty());
+    assert!(!sibling.is_dirty());
+    assert!(sibling.vello_cache.borrow().is_some());
+
+    scene.update();
+
+    assert!(!text.is_dirty());
+    assert!(text.vello_cache.borrow().is_some());
+    assert_eq!(text.layout_node().and_then(|node| node.layout().copied()), layout_before);
+}
+
+#[test]
+fn test_text_size_change_escalates_to_relayout() {
+    let view = view! {
+        <Flex direction="column" align_items="start">
+            <Text content="ab" />
+        </Flex>
+    };
+
+    let root_component = view.root_component;
+    let mut scene = Scene::new();
+    scene.add_fragment(root_component.clone());
+    scene.update();
+
+    let text = root_component.children.borrow()[0].clone();
+    let width = |text: &Gc<rvue::Component>| {
+        text.layout_node().and_then(|node| node.layout().map(|layout| layout.size.width))
+    };
+    let width_before = width(&text).unwrap();
+
+    text.set_text_content("a much longer line of text".to_string());
+    assert!(!text.is_layout_dirty());
+
+    scene.update();
+
+    assert!(!text.is_dirty());
+    assert!(width(&text).unwrap() > width_before);
+}
+
+#[test]
+fn test_paint_only_change_reuses_layout() {
+    let view = view! {
+        <Flex direction="column">
+            <Text content="Static" />
+            <Flex background_color=Color::rgb(255, 0, 0) />
+        </Flex>
+    };
+
+    let root_component = view.root_component;
+    let mut scene = Scene::new();
+    scene.add_fragment(root_component.clone());
+    scene.update();
+    let passes = scene.layout_passes();
+    assert!(passes > 0);
+    assert!(!scene.needs_layout());
+
+    // An animating color only repaints
+    let swatch = root_component.children.borrow()[1].clone();
+    for blue in [64, 128, 255] {
+        let mut styles = swatch.widget_styles().unwrap();
+        styles.background_color = Some(BackgroundColor(Color::rgb(0, 0, blue)));
+        swatch.set_widget_styles(styles);
+
+        assert!(root_component.is_paint_dirty());
+        assert!(!scene.needs_layout());
+        scene.update();
+    }
+    assert_eq!(scene.layout_passes(), passes);
+
+    root_component.children.borrow()[0].set_text_content("Static, now longer".to_string());
+    scene.update();
+    assert_eq!(scene.layout_passes(), passes + 1);
+}
+
 #[ignore]
 #[test]
 fn test_stress_1000_components() {
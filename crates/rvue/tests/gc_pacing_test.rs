@@ -0,0 +1,63 @@
+//! Tests for pacing garbage collections into idle frame time
+
+use rvue::properties::PropertyMap;
+use rvue::{Component, ComponentLifecycle, ComponentType, GcPacer};
+use std::time::Duration;
+
+const TREE_SIZE: u64 = 200;
+
+/// Builds a tree, mounts and unmounts it, and drops every handle.
+#[inline(never)]
+fn mount_and_unmount_tree() {
+    let root = Component::with_properties(0, ComponentType::Flex, PropertyMap::new());
+    for id in 1..TREE_SIZE {
+        let child = Component::with_properties(id, ComponentType::Text, PropertyMap::new());
+        root.add_child(child);
+    }
+    root.mount(None);
+    root.unmount();
+}
+
+/// Automatic collection is off, as in a running app.
+fn app_gc_settings() {
+    rudo_gc::set_gc_enabled(false);
+    rudo_gc::set_collect_condition(|_| false);
+}
+
+#[test]
+fn test_unmounted_components_are_reclaimed_only_when_idle() {
+    app_gc_settings();
+    let mut pacer = GcPacer::new().with_growth_threshold(1).with_min_idle(Duration::from_millis(4));
+
+    mount_and_unmount_tree();
+    let grown = rudo_gc::current_heap_size();
+    let collections = rudo_gc::last_gc_metrics().total_collections;
+
+    assert!(!pacer.collect_if_idle(Some(Duration::ZERO)), "a frame is due");
+    assert!(!pacer.collect_if_idle(Some(Duration::from_millis(1))), "idle window too short");
+    assert_eq!(rudo_gc::last_gc_metrics().total_collections, collections);
+    assert_eq!(rudo_gc::current_heap_size(), grown);
+
+    assert!(pacer.collect_if_idle(Some(Duration::from_millis(8))));
+    let metrics = rudo_gc::last_gc_metrics();
+    assert_eq!(metrics.total_collections, collections + 1);
+    assert!(metrics.objects_reclaimed >= TREE_SIZE as usize);
+    assert!(rudo_gc::current_heap_size() < grown, "unmounted tree should be reclaimed");
+
+    // Nothing new to reclaim, so the next idle period stays free
+    assert!(!pacer.collect_if_idle(None));
+
+    // Allocation-triggered and explicit collections stay off outside paced ones
+    rudo_gc::collect();
+    assert_eq!(rudo_gc::last_gc_metrics().total_collections, collections + 1);
+}
+
+#[test]
+fn test_pacer_waits_for_heap_growth_unless_over_hard_limit() {
+    let pacer = GcPacer::new().with_growth_threshold(1024).with_hard_limit(4096);
+
+    assert!(!pacer.should_collect(512, None));
+    assert!(pacer.should_collect(2048, None));
+    assert!(!pacer.should_collect(2048, Some(Duration::ZERO)));
+    assert!(pacer.should_collect(4096, Some(Duration::ZERO)));
+}
@@ -3,7 +3,9 @@
 #[cfg(feature = "async")]
 #[cfg(test)]
 mod tests {
-    use rvue::async_runtime::create_resource;
+    use rvue::async_runtime::{
+        create_reload_trigger, create_resource, create_resource_with_trigger,
+    };
     use rvue::headless::{advance, advance_tokio, init_runtime};
     use rvue::{create_memo, create_signal};
     use std::sync::atomic::{AtomicU32, Ordering};
@@ -143,4 +145,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_trigger_signal_refetches_with_same_source() {
+        init_runtime();
+
+        let (source, _set_source) = create_signal(7i32);
+        let (trigger, reload) = create_reload_trigger();
+        let fetch_count = Arc::new(AtomicU32::new(0));
+        let fetch_for_check = Arc::clone(&fetch_count);
+
+        let resource = create_resource_with_trigger(source, trigger, move |s| {
+            let c = Arc::clone(&fetch_count);
+            async move {
+                let n = c.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(format!("data-{s}-{n}"))
+            }
+        });
+
+        let ready = pump_until(|| resource.get().data().is_some_and(|d| d == "data-7-1"), 200);
+        assert!(ready, "Initial fetch should complete, got {:?}", resource.get());
+
+        reload.reload();
+
+        let reloaded = pump_until(|| resource.get().data().is_some_and(|d| d == "data-7-2"), 200);
+        assert!(reloaded, "Bumping the trigger should refetch, got {:?}", resource.get());
+        assert_eq!(fetch_for_check.load(Ordering::SeqCst), 2);
+    }
 }
@@ -0,0 +1,92 @@
+//! Tests for the inspector overlay
+
+#[cfg(feature = "inspector")]
+mod tests {
+    use rvue::component::{Component, ComponentType};
+    use rvue::properties::PropertyMap;
+    use rvue::render::{Inspector, OverlayKind};
+    use rvue::{Gc, Scene};
+    use rvue_style::properties::FlexDirection;
+    use rvue_style::{ComputedStyles, Height, Size, Width};
+    use vello::kurbo::Rect;
+
+    fn sized(id: u64, width: f32, height: f32) -> Gc<Component> {
+        let component = Component::with_properties(id, ComponentType::Flex, PropertyMap::new());
+        component.set_widget_styles(ComputedStyles {
+            width: Some(Width(Size::Pixels(width))),
+            height: Some(Height(Size::Pixels(height))),
+            flex_direction: Some(FlexDirection::Column),
+            ..Default::default()
+        });
+        component
+    }
+
+    /// A 200x100 column holding a 30px tall header above a 50x20 box
+    fn laid_out_tree() -> (Gc<Component>, Gc<Component>, Scene) {
+        let root = sized(1, 200.0, 100.0);
+        for child in [sized(2, 200.0, 30.0), sized(3, 50.0, 20.0)] {
+            root.add_child(Gc::clone(&child));
+            child.set_parent(Some(Gc::clone(&root)));
+        }
+        let target = Gc::clone(&root.children.borrow()[1]);
+
+        let mut scene = Scene::new();
+        scene.add_fragment(Gc::clone(&root));
+        scene.update();
+        (root, target, scene)
+    }
+
+    #[test]
+    fn test_hovered_component_gets_box_model_overlay() {
+        let (root, target, mut scene) = laid_out_tree();
+        let mut inspector = Inspector::new();
+        assert!(inspector.toggle());
+
+        let items = inspector.overlay_items(&root, Some(&target));
+        let target_rect = Rect::new(0.0, 30.0, 50.0, 50.0);
+
+        let bounds: Vec<_> = items
+            .iter()
+            .filter(|item| item.kind == OverlayKind::Bounds)
+            .map(|item| (item.component_id, item.rect))
+            .collect();
+        assert_eq!(
+            bounds,
+            vec![
+                (1, Rect::new(0.0, 0.0, 200.0, 100.0)),
+                (2, Rect::new(0.0, 0.0, 200.0, 30.0)),
+                (3, target_rect),
+            ]
+        );
+
+        let box_model: Vec<_> = items
+            .iter()
+            .filter(|item| item.kind != OverlayKind::Bounds)
+            .map(|item| (item.component_id, item.kind, item.rect))
+            .collect();
+        assert_eq!(
+            box_model,
+            vec![
+                (3, OverlayKind::Margin, target_rect),
+                (3, OverlayKind::Border, target_rect),
+                (3, OverlayKind::Padding, target_rect),
+                (3, OverlayKind::Content, target_rect),
+            ]
+        );
+
+        let mut overlay = vello::Scene::new();
+        inspector.paint(&mut overlay, &root, Some(&target), &mut scene.text_context);
+        assert!(!overlay.encoding().is_empty());
+    }
+
+    #[test]
+    fn test_disabled_inspector_draws_nothing() {
+        let (root, target, mut scene) = laid_out_tree();
+        let inspector = Inspector::new();
+
+        assert!(inspector.overlay_items(&root, Some(&target)).is_empty());
+        let mut overlay = vello::Scene::new();
+        inspector.paint(&mut overlay, &root, Some(&target), &mut scene.text_context);
+        assert!(overlay.encoding().is_empty());
+    }
+}
@@ -0,0 +1,49 @@
+//! Tests for serializing the component tree to JSON
+
+use rvue::prelude::*;
+use rvue::Scene;
+use rvue_macro::view;
+use serde_json::json;
+
+#[test]
+fn test_to_json_describes_the_tree() {
+    let view: ViewStruct = view! {
+        <Flex>
+            <Text content="Hello" />
+            <Checkbox checked=true />
+            <Button class="primary" id="save" />
+        </Flex>
+    };
+    let root = view.into_component();
+
+    let mut scene = Scene::new();
+    scene.add_fragment(rvue::Gc::clone(&root));
+    scene.update();
+
+    let tree = root.to_json();
+    assert_eq!(tree["type"], "Flex");
+    assert_eq!(tree["id"], root.id);
+    assert_eq!(tree["element_id"], serde_json::Value::Null);
+    assert_eq!(tree["layout"]["x"], 0.0);
+    assert!(tree["layout"]["width"].as_f64().unwrap() > 0.0);
+
+    let children = tree["children"].as_array().unwrap();
+    assert_eq!(children.len(), 3);
+    assert_eq!(children[0]["type"], "Text");
+    assert_eq!(children[0]["props"], json!({ "text": "Hello" }));
+    assert_eq!(children[1]["type"], "Checkbox");
+    assert_eq!(children[1]["props"], json!({ "checked": true }));
+    assert_eq!(children[2]["type"], "Button");
+    assert_eq!(children[2]["element_id"], "save");
+    assert_eq!(children[2]["classes"], json!(["primary"]));
+    assert_eq!(children[2]["children"], json!([]));
+}
+
+#[test]
+fn test_to_json_has_null_layout_before_layout() {
+    let view: ViewStruct = view! { <Text content="unlaid" /> };
+    let tree = view.into_component().to_json();
+
+    assert_eq!(tree["layout"], serde_json::Value::Null);
+    assert_eq!(tree["props"]["text"], "unlaid");
+}
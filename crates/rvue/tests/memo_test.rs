@@ -130,3 +130,48 @@ fn test_memo_nested() {
     write.set(20);
     assert_eq!(memo2.get(), 42);
 }
+
+#[test]
+fn test_derived_reads_from_source_and_writes_back() {
+    let (celsius, set_celsius) = create_signal(100.0_f64);
+    let source = celsius.clone();
+    let (fahrenheit, set_fahrenheit) = rvue::create_derived(
+        move || source.get() * 9.0 / 5.0 + 32.0,
+        move |f: f64| set_celsius.set((f - 32.0) * 5.0 / 9.0),
+    );
+    assert_eq!(fahrenheit.get(), 212.0);
+
+    set_fahrenheit.set(32.0);
+    assert_eq!(celsius.get(), 0.0);
+    assert_eq!(fahrenheit.get(), 32.0);
+
+    set_fahrenheit.update(|f| *f += 18.0);
+    assert_eq!(celsius.get(), 10.0);
+    assert_eq!(fahrenheit.get(), 50.0);
+}
+
+#[test]
+fn test_mapped_signal_follows_source() {
+    let (count, set_count) = create_signal(1);
+    let label = count.map(|n| format!("Count: {n}"));
+    assert_eq!(label.get(), "Count: 1");
+
+    set_count.set(2);
+    assert_eq!(label.get(), "Count: 2");
+}
+
+#[test]
+fn test_filter_mapped_signal_keeps_last_passing_value() {
+    let (input, set_input) = create_signal("x".to_string());
+    let parsed = input.filter_map(|text| text.parse::<u32>().ok());
+    assert_eq!(parsed.get(), None);
+
+    set_input.set("42".to_string());
+    assert_eq!(parsed.get(), Some(42));
+
+    set_input.set("4x".to_string());
+    assert_eq!(parsed.get(), Some(42));
+
+    set_input.set("7".to_string());
+    assert_eq!(parsed.get(), Some(7));
+}
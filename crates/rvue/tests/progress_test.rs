@@ -0,0 +1,99 @@
+//! Integration tests for the ProgressBar widget
+
+use std::time::Duration;
+
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::progress::{
+    indeterminate_fill_rect, progress_fill_rect, INDETERMINATE_PERIOD, INDETERMINATE_SEGMENT,
+};
+use rvue::widgets::ProgressBar;
+use rvue::{create_signal, Gc, Scene};
+use rvue_style::{ComputedStyles, Height, Size, Width};
+
+fn sized(width: f32, height: f32) -> ComputedStyles {
+    ComputedStyles {
+        width: Some(Width(Size::Pixels(width))),
+        height: Some(Height(Size::Pixels(height))),
+        ..Default::default()
+    }
+}
+
+fn build_bar(bar: ProgressBar) -> Gc<Component> {
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = bar.build(&mut ctx);
+    let component = Gc::clone(state.component());
+    component.set_widget_styles(sized(200.0, 20.0));
+    component
+}
+
+/// Lay `bar` out inside a root and return the size of its track
+fn track_size(bar: &Gc<Component>) -> vello::kurbo::Size {
+    let root = Component::with_properties(1_000, ComponentType::Flex, PropertyMap::new());
+    root.set_widget_styles(sized(400.0, 100.0));
+    root.add_child(Gc::clone(bar));
+    bar.set_parent(Some(Gc::clone(&root)));
+
+    let mut scene = Scene::new();
+    scene.add_fragment(root);
+    scene.update();
+
+    let layout = bar.layout_node().and_then(|node| node.layout_result).expect("bar laid out");
+    vello::kurbo::Size::new(layout.size.width as f64, layout.size.height as f64)
+}
+
+#[test]
+fn test_half_progress_fills_half_the_track() {
+    let bar = build_bar(ProgressBar::new(0.5));
+    assert_eq!(bar.component_type, ComponentType::ProgressBar);
+
+    let track = track_size(&bar);
+    let fill = progress_fill_rect(track, bar.progress_value());
+
+    assert_eq!(track.width, 200.0);
+    assert_eq!(fill.x0, 0.0);
+    assert_eq!(fill.width(), track.width / 2.0);
+    assert_eq!(fill.height(), track.height);
+}
+
+#[test]
+fn test_progress_value_is_clamped() {
+    let (progress, set_progress) = create_signal(1.5);
+    let bar = build_bar(ProgressBar::new(progress));
+    assert_eq!(bar.progress_value(), 1.0);
+
+    set_progress.set(-0.25);
+    rvue::effect::flush_pending_effects();
+    assert_eq!(bar.progress_value(), 0.0);
+
+    set_progress.set(f64::NAN);
+    rvue::effect::flush_pending_effects();
+    assert_eq!(bar.progress_value(), 0.0);
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_indeterminate_segment_slides_across_the_track() {
+    let bar = build_bar(ProgressBar::new(0.0).indeterminate(true));
+    assert!(bar.progress_indeterminate());
+
+    let track = track_size(&bar);
+    let segment = track.width * INDETERMINATE_SEGMENT;
+
+    // Starts hidden past the left edge and comes back there after a full period
+    assert_eq!(indeterminate_fill_rect(track, Duration::ZERO).width(), 0.0);
+    assert_eq!(indeterminate_fill_rect(track, INDETERMINATE_PERIOD).width(), 0.0);
+
+    let middle = indeterminate_fill_rect(track, INDETERMINATE_PERIOD / 2);
+    assert_eq!(middle.width(), segment);
+    assert_eq!(middle.center().x, track.width / 2.0);
+
+    let leaving = indeterminate_fill_rect(track, INDETERMINATE_PERIOD * 19 / 20);
+    assert_eq!(leaving.x1, track.width);
+    assert!(leaving.width() < segment);
+}
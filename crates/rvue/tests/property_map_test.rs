@@ -0,0 +1,76 @@
+//! Tests for the typed property map API
+
+use rudo_gc::{collect_full, Gc};
+use rvue::properties::{
+    CheckboxChecked, FlexGap, GcPropertyMap, PropertyMap, TextContent, WidgetProperty,
+};
+
+/// A property a custom widget might define
+#[derive(Clone, Debug, PartialEq)]
+struct Rating(u8);
+
+impl WidgetProperty for Rating {
+    fn static_default() -> &'static Self {
+        static DEFAULT: Rating = Rating(3);
+        &DEFAULT
+    }
+}
+
+#[test]
+fn test_insert_and_overwrite_return_previous_value() {
+    let mut map = PropertyMap::new();
+
+    assert_eq!(map.insert(Rating(4)), None);
+    assert_eq!(map.insert(TextContent("a".into())), None);
+    assert_eq!(map.insert(Rating(5)), Some(Rating(4)));
+
+    assert_eq!(map.get::<Rating>(), Some(&Rating(5)));
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn test_remove_returns_value_and_clears_property() {
+    let mut map = PropertyMap::with(CheckboxChecked(true)).and(Rating(1));
+
+    assert_eq!(map.remove::<CheckboxChecked>(), Some(CheckboxChecked(true)));
+    assert_eq!(map.remove::<CheckboxChecked>(), None);
+    assert!(!map.contains::<CheckboxChecked>());
+    assert!(map.contains::<Rating>());
+}
+
+#[test]
+fn test_get_or_falls_back_only_when_missing() {
+    let map = PropertyMap::with(FlexGap(8.0));
+
+    assert_eq!(map.get_or(FlexGap(0.0)), FlexGap(8.0));
+    assert_eq!(map.get_or(Rating(0)), Rating(0));
+    assert_eq!(map.get_or_default::<Rating>(), &Rating(3));
+}
+
+#[test]
+fn test_iteration_visits_each_property() {
+    let map = PropertyMap::with(Rating(2)).and(TextContent("b".into())).and(FlexGap(1.0));
+
+    let names: Vec<&str> = map.values().map(|prop| prop.type_name()).collect();
+    assert_eq!(names.len(), 3);
+    assert!(names.iter().any(|name| name.ends_with("Rating")));
+
+    let rating = map.values().find(|prop| prop.is::<Rating>()).unwrap();
+    assert_eq!(rating.downcast::<Rating>(), Some(&Rating(2)));
+    assert_eq!(rating.downcast::<FlexGap>(), None);
+}
+
+#[test]
+fn test_gc_property_map_survives_collection() {
+    let map = Gc::new(GcPropertyMap::with(Rating(4)));
+    assert_eq!(map.insert(TextContent("kept".into())), None);
+    assert_eq!(map.insert(Rating(5)), Some(Rating(4)));
+
+    collect_full();
+
+    assert_eq!(map.get_or(Rating(0)), Rating(5));
+    assert_eq!(map.get::<TextContent>().unwrap().0, "kept");
+    assert_eq!(map.remove::<Rating>(), Some(Rating(5)));
+    assert_eq!(map.get_or(Rating(0)), Rating(0));
+    assert_eq!(map.type_names().len(), 1);
+}
@@ -0,0 +1,50 @@
+//! Tests for finding components with `query_selector`
+
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::Gc;
+
+fn append(parent: &Gc<Component>, id: u64, component_type: ComponentType) -> Gc<Component> {
+    let child = Component::with_properties(id, component_type, PropertyMap::new());
+    parent.append_child(Gc::clone(&child));
+    child
+}
+
+/// A form holding three rows, the second of which is nested, and a submit button
+fn form() -> Gc<Component> {
+    let form = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    append(&form, 2, ComponentType::Flex).add_class("row");
+    let group = append(&form, 3, ComponentType::Flex);
+    append(&group, 4, ComponentType::Flex).add_class("row");
+    let last = append(&form, 5, ComponentType::Flex);
+    last.add_class("row");
+    last.add_class("last");
+    append(&form, 6, ComponentType::Button).set_id("submit");
+    form
+}
+
+fn ids(components: &[Gc<Component>]) -> Vec<u64> {
+    components.iter().map(|c| c.id).collect()
+}
+
+#[test]
+fn test_query_selector_finds_by_id() {
+    let form = form();
+    assert_eq!(form.query_selector("#submit").map(|c| c.id), Some(6));
+    assert!(form.query_selector("#cancel").is_none());
+}
+
+#[test]
+fn test_query_selector_all_finds_classes_in_tree_order() {
+    let form = form();
+    assert_eq!(ids(&form.query_selector_all(".row")), vec![2, 4, 5]);
+    assert_eq!(form.query_selector(".row").map(|c| c.id), Some(2));
+    assert_eq!(ids(&form.query_selector_all("flex.row.last")), vec![5]);
+}
+
+#[test]
+fn test_query_selector_matches_type_and_skips_self() {
+    let form = form();
+    assert_eq!(ids(&form.query_selector_all("Button")), vec![6]);
+    assert_eq!(ids(&form.query_selector_all("flex")), vec![2, 3, 4, 5]);
+}
@@ -1,6 +1,9 @@
 //! Unit tests for scroll offset rendering logic
 
+use rvue::render::widget::overflow_clip_rect;
 use rvue::render::FlexScrollState;
+use rvue_style::properties::Overflow;
+use vello::kurbo::{Rect, Size};
 
 #[test]
 fn test_no_scroll_offset_when_zero() {
@@ -79,3 +82,27 @@ fn test_scroll_state_with_both_offsets() {
     assert!(should_apply_x);
     assert!(should_apply_y);
 }
+
+#[test]
+fn test_mixed_axis_overflow_clips_horizontally_only() {
+    let clip = overflow_clip_rect(Overflow::Hidden, Overflow::Visible, Size::new(100.0, 100.0))
+        .expect("overflow_x clips");
+
+    let wide_child = Rect::new(0.0, 0.0, 300.0, 20.0);
+    assert_eq!(wide_child.intersect(clip), Rect::new(0.0, 0.0, 100.0, 20.0));
+
+    let tall_child = Rect::new(0.0, 0.0, 20.0, 300.0);
+    assert_eq!(tall_child.intersect(clip), tall_child);
+}
+
+#[test]
+fn test_visible_overflow_has_no_clip() {
+    assert_eq!(
+        overflow_clip_rect(Overflow::Visible, Overflow::Visible, Size::new(50.0, 50.0)),
+        None
+    );
+    assert_eq!(
+        overflow_clip_rect(Overflow::Clip, Overflow::Clip, Size::new(50.0, 50.0)),
+        Some(Rect::new(0.0, 0.0, 50.0, 50.0))
+    );
+}
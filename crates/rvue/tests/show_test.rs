@@ -79,3 +79,46 @@ fn test_show_component_mounting_unmounting() {
     // Unmount should not panic
     show.unmount();
 }
+
+#[test]
+fn test_show_keeps_child_state_when_toggled() {
+    use rvue::widget::{BuildContext, Widget};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let (visible, set_visible) = create_signal(true);
+    let setter = Rc::new(RefCell::new(None));
+
+    let child_setter = Rc::clone(&setter);
+    let show = rvue::widgets::Show::new(visible, move |ctx| {
+        let (count, set_count) = create_signal(0);
+        *child_setter.borrow_mut() = Some(set_count);
+        let state = rvue::widgets::Text::new("0").build(ctx);
+        let text = rudo_gc::Gc::clone(state.component());
+        let comp = rudo_gc::Gc::clone(&text);
+        text.add_effect(rvue::create_effect(move || {
+            comp.set_text_content(count.get().to_string())
+        }));
+        text
+    });
+
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = show.build(&mut ctx);
+    let child = rudo_gc::Gc::clone(&state.component().children.borrow()[0]);
+
+    setter.borrow().as_ref().unwrap().set(3);
+    set_visible.set(false);
+    assert!(!state.component().show_when());
+    set_visible.set(true);
+
+    // Hiding keeps the content mounted, so the counter survives without a key
+    assert!(rudo_gc::Gc::ptr_eq(&state.component().children.borrow()[0], &child));
+    assert_eq!(child.text_content(), "3");
+    setter.borrow().as_ref().unwrap().set(4);
+    assert_eq!(child.text_content(), "4");
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
@@ -62,3 +62,81 @@ fn test_show_widget_lifecycle() {
     show.update();
     show.unmount();
 }
+
+#[test]
+fn test_show_exit_transition_delays_unmount() {
+    use rvue::widgets::{Transition, TransitionKind};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    let show = Component::with_properties(
+        1,
+        ComponentType::Show,
+        rvue::properties::PropertyMap::with(rvue::properties::ShowCondition(true)),
+    );
+    let child =
+        Component::with_properties(2, ComponentType::Text, rvue::properties::PropertyMap::new());
+    show.add_child(rudo_gc::Gc::clone(&child));
+    child.set_parent(Some(rudo_gc::Gc::clone(&show)));
+
+    let unmounted = Rc::new(Cell::new(false));
+    let unmounted_clone = Rc::clone(&unmounted);
+    child.cleanups.borrow_mut_gen_only().push(Box::new(move || unmounted_clone.set(true)));
+
+    let duration = Duration::from_millis(200);
+    show.set_show_transition(Some(Transition::new(TransitionKind::Fade).with_duration(duration)));
+
+    let start = Instant::now();
+    show.set_show_when(false);
+    show.update_show_transition(start);
+    assert!(show.show_content_visible());
+    assert!(!unmounted.get());
+
+    show.update_show_transition(start + duration / 2);
+    assert!(show.show_content_visible());
+    assert!(!unmounted.get());
+
+    show.update_show_transition(start + duration);
+    assert!(!show.show_content_visible());
+    assert!(unmounted.get());
+}
+
+#[test]
+fn test_reduced_motion_skips_show_transition() {
+    use rvue::widgets::{Transition, TransitionKind, TransitionPhase};
+    use std::time::{Duration, Instant};
+
+    let show = Component::with_properties(
+        1,
+        ComponentType::Show,
+        rvue::properties::PropertyMap::with(rvue::properties::ShowCondition(false)),
+    );
+    let child =
+        Component::with_properties(2, ComponentType::Text, rvue::properties::PropertyMap::new());
+    show.add_child(rudo_gc::Gc::clone(&child));
+    child.set_parent(Some(rudo_gc::Gc::clone(&show)));
+    let transition = Transition::new(TransitionKind::Fade).with_duration(Duration::from_secs(1));
+    show.set_show_transition(Some(transition));
+
+    rvue::set_reduced_motion(Some(true));
+    let start = Instant::now();
+
+    // Entering starts at its end state
+    show.set_show_when(true);
+    show.update_show_transition(start);
+    assert_eq!(show.show_transition_progress(start), None);
+    assert!(show.show_content_visible());
+
+    // Exiting hides the content at once
+    show.set_show_when(false);
+    show.update_show_transition(start);
+    assert!(!show.show_content_visible());
+
+    rvue::set_reduced_motion(Some(false));
+    show.set_show_when(true);
+    show.update_show_transition(start);
+    assert_eq!(show.show_transition_progress(start), Some((TransitionPhase::Entering, 0.0)));
+
+    rvue::set_reduced_motion(None);
+}
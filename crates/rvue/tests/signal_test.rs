@@ -95,3 +95,47 @@ fn test_signal_independent_signals() {
     assert_eq!(read1.get(), 11); // Should be unchanged
     assert_eq!(read2.get(), 22);
 }
+
+#[test]
+fn test_weak_signal_handles_no_op_once_signal_is_collected() {
+    let (read, write) = create_signal(0);
+    let weak_read = read.downgrade();
+    let weak_write = write.downgrade();
+
+    assert!(weak_write.set(5));
+    assert_eq!(weak_read.upgrade().map(|signal| signal.get()), Some(5));
+
+    drop(read);
+    drop(write);
+    rudo_gc::collect_full();
+
+    assert!(weak_read.upgrade().is_none());
+    assert!(weak_write.upgrade().is_none());
+    assert!(!weak_write.set(6));
+}
+
+#[test]
+fn test_signal_with_equality_skips_unchanged_writes() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let (read, write) = rvue::create_signal_with_equality(0, |a: &i32, b: &i32| a == b);
+    let runs = Rc::new(Cell::new(0));
+    let runs_in_effect = Rc::clone(&runs);
+    let _effect = rvue::create_effect(move || {
+        read.get();
+        runs_in_effect.set(runs_in_effect.get() + 1);
+    });
+    assert_eq!(runs.get(), 1);
+
+    write.set(1);
+    write.set(1);
+    assert_eq!(runs.get(), 2);
+
+    write.update(|value| *value *= 1);
+    assert_eq!(runs.get(), 2);
+    write.update(|value| *value += 1);
+    assert_eq!(runs.get(), 3);
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
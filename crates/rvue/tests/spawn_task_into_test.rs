@@ -0,0 +1,35 @@
+//! Tests for delivering async task results into signals
+
+#[cfg(feature = "async")]
+#[cfg(test)]
+mod tests {
+    use rvue::async_runtime::spawn_task_into;
+    use rvue::create_signal;
+    use rvue::headless::{init_runtime, settle};
+    use std::time::Duration;
+
+    #[test]
+    fn test_spawn_task_into_sets_signal_after_settle() {
+        init_runtime();
+
+        let (result, set_result) = create_signal(String::new());
+        let handle = spawn_task_into(
+            async {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+                "fetched".to_string()
+            },
+            &set_result,
+        );
+
+        for _ in 0..200 {
+            if handle.is_completed() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(handle.is_completed());
+
+        settle();
+        assert_eq!(result.get(), "fetched");
+    }
+}
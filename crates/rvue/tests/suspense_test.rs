@@ -0,0 +1,59 @@
+//! Integration tests for the Suspense widget
+
+#[cfg(feature = "async")]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use rvue::async_runtime::create_resource;
+    use rvue::headless::{init_runtime, settle, UiDispatchQueue};
+    use rvue::widget::{BuildContext, Widget};
+    use rvue::widgets::{Suspense, Text};
+    use rvue::{create_signal, ComponentType, Gc};
+
+    fn text_widget(ctx: &mut BuildContext, content: &str) -> Gc<rvue::Component> {
+        let state = Text::new(content).build(ctx);
+        Gc::clone(state.component())
+    }
+
+    /// Wait until the resource's task has handed its result to the UI queue
+    fn wait_for_dispatch() {
+        let start = Instant::now();
+        while UiDispatchQueue::len() == 0 {
+            assert!(start.elapsed() < Duration::from_secs(2), "resource never finished");
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_suspense_shows_fallback_until_resource_resolves() {
+        init_runtime();
+
+        let (source, _set_source) = create_signal(1i32);
+        let suspense = Suspense::new(move |ctx| {
+            let _resource = create_resource(source.clone(), |id| async move {
+                Ok::<_, String>(format!("user {id}"))
+            });
+            text_widget(ctx, "content")
+        })
+        .fallback(|ctx| text_widget(ctx, "loading"));
+
+        let mut taffy = rvue::TaffyTree::new();
+        let mut text_context = rvue::text::TextContext::new();
+        let mut id_counter = 0;
+        let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+        let state = suspense.build(&mut ctx);
+        let boundary = state.component();
+
+        assert_eq!(boundary.component_type, ComponentType::Suspense);
+        assert!(boundary.suspense_pending());
+        assert_eq!(boundary.suspense_visible_child().unwrap().text_content(), "loading");
+
+        wait_for_dispatch();
+        settle();
+
+        assert!(!boundary.suspense_pending());
+        assert_eq!(boundary.suspense_visible_child().unwrap().text_content(), "content");
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+}
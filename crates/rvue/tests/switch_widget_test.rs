@@ -0,0 +1,308 @@
+//! Integration tests for the Switch widget
+
+use rudo_gc::Trace;
+use rvue::prelude::*;
+use rvue::widget::{BuildContext, Widget};
+use rvue::{Component, ComponentType};
+use rvue_macro::view;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Idle,
+    Loading,
+    Done,
+}
+
+unsafe impl Trace for Mode {
+    fn trace(&self, _visitor: &mut impl rudo_gc::Visitor) {}
+}
+
+fn rendered_texts(switch: &Component) -> Vec<String> {
+    switch.children.borrow().iter().map(|child| child.text_content()).collect()
+}
+
+#[test]
+fn test_switch_mounts_only_the_matching_arm() {
+    let (mode, set_mode) = create_signal(Mode::Idle);
+    let is_mode = |expected: Mode| {
+        let mode = mode.clone();
+        create_memo(move || mode.get() == expected)
+    };
+    let is_idle = is_mode(Mode::Idle);
+    let is_loading = is_mode(Mode::Loading);
+    let is_done = is_mode(Mode::Done);
+
+    let view: ViewStruct = view! {
+        <Switch>
+            <Match when=is_idle>
+                <Text content="idle" />
+            </Match>
+            <Match when=is_loading>
+                <Text content="loading" />
+            </Match>
+            <Match when=is_done>
+                <Text content="done" />
+            </Match>
+        </Switch>
+    };
+    let switch = view.into_component();
+    assert_eq!(switch.component_type, ComponentType::Switch);
+    assert_eq!(rendered_texts(&switch), vec!["idle"]);
+    assert_eq!(switch.switch_active_arm(), Some(0));
+
+    let idle_child = rvue::Gc::clone(&switch.children.borrow()[0]);
+
+    set_mode.set(Mode::Loading);
+    assert_eq!(rendered_texts(&switch), vec!["loading"]);
+    assert_eq!(switch.switch_active_arm(), Some(1));
+    assert!(idle_child.parent.borrow().is_none());
+
+    set_mode.set(Mode::Done);
+    assert_eq!(rendered_texts(&switch), vec!["done"]);
+    assert_eq!(switch.switch_active_arm(), Some(2));
+
+    set_mode.set(Mode::Idle);
+    assert_eq!(rendered_texts(&switch), vec!["idle"]);
+    assert_eq!(switch.switch_active_arm(), Some(0));
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_switch_builds_only_the_active_arm() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let builds = Rc::new(Cell::new(0));
+    let (flag, set_flag) = create_signal(false);
+
+    let arm_builds = Rc::clone(&builds);
+    let switch = rvue::widgets::Switch::new(vec![
+        rvue::widgets::Match::new(flag, move |ctx| {
+            arm_builds.set(arm_builds.get() + 1);
+            let state = rvue::widgets::Text::new("on").build(ctx);
+            rvue::Gc::clone(state.component())
+        }),
+        rvue::widgets::Match::new(true, |ctx| {
+            let state = rvue::widgets::Text::new("fallback").build(ctx);
+            rvue::Gc::clone(state.component())
+        }),
+    ]);
+
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = switch.build(&mut ctx);
+    let component = state.component();
+
+    assert_eq!(builds.get(), 0);
+    assert_eq!(rendered_texts(component), vec!["fallback"]);
+
+    set_flag.set(true);
+    assert_eq!(builds.get(), 1);
+    assert_eq!(rendered_texts(component), vec!["on"]);
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_switch_renders_nothing_without_a_match() {
+    let switch = rvue::widgets::Switch::new(vec![rvue::widgets::Match::new(false, |ctx| {
+        let state = rvue::widgets::Text::new("never").build(ctx);
+        rvue::Gc::clone(state.component())
+    })]);
+
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = switch.build(&mut ctx);
+
+    assert!(state.component().children.borrow().is_empty());
+    assert_eq!(state.component().switch_active_arm(), None);
+}
+
+#[test]
+fn test_once_view_keeps_its_components_when_arm_is_rebuilt() {
+    let (mode, set_mode) = create_signal(Mode::Idle);
+    let is_idle = {
+        let mode = mode.clone();
+        create_memo(move || mode.get() == Mode::Idle)
+    };
+    let is_loading = {
+        let mode = mode.clone();
+        create_memo(move || mode.get() == Mode::Loading)
+    };
+    let is_done = create_memo(move || mode.get() == Mode::Done);
+    let header = rvue::Once::new(|| {
+        view! {
+            <Flex>
+                <Text content="header" />
+            </Flex>
+        }
+    });
+
+    let view: ViewStruct = view! {
+        <Switch>
+            <Match when=is_idle>
+                {header.view()}
+            </Match>
+            <Match when=is_loading>
+                <Text content="loading" />
+            </Match>
+            <Match when=is_done>
+                <Text content="done" />
+            </Match>
+        </Switch>
+    };
+    let switch = view.into_component();
+    let active = |switch: &Component| rvue::Gc::clone(&switch.children.borrow()[0]);
+    let cached = active(&switch);
+    let cached_text_id = cached.children.borrow()[0].id;
+
+    set_mode.set(Mode::Loading);
+    let loading = active(&switch);
+    set_mode.set(Mode::Done);
+    set_mode.set(Mode::Loading);
+    // Arms are rebuilt on every activation
+    assert_ne!(active(&switch).id, loading.id);
+
+    set_mode.set(Mode::Idle);
+    // The Once subtree is reattached as it was
+    let reattached = active(&switch);
+    assert_eq!(reattached.id, cached.id);
+    assert_eq!(reattached.children.borrow()[0].id, cached_text_id);
+    assert_eq!(rendered_texts(&reattached), vec!["header"]);
+    assert!(rvue::Gc::ptr_eq(reattached.parent.borrow().as_ref().unwrap(), &switch));
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_once_view_effects_outlive_the_placement_that_built_them() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let (label, set_label) = create_signal("a".to_string());
+    let seen = Rc::new(RefCell::new(String::new()));
+    let seen_clone = Rc::clone(&seen);
+    let header = rvue::Once::new(move || {
+        create_effect(move || *seen_clone.borrow_mut() = label.get());
+        view! { <Text content="header" /> }
+    });
+
+    let placed = header.clone();
+    let first: ViewStruct = view! {
+        <Switch>
+            <Match when=true>
+                {placed.view()}
+            </Match>
+        </Switch>
+    };
+    let first = first.into_component();
+    assert_eq!(*seen.borrow(), "a");
+
+    // Disposing the first placement leaves the Once content working
+    first.dispose();
+    let second = header.view().into_component();
+    set_label.set("b".to_string());
+    assert_eq!(*seen.borrow(), "b");
+    assert_eq!(second.text_content(), "header");
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+/// A Text showing a counter that lives inside the arm
+///
+/// The counter's setter is pushed to `setters`.
+fn counter_arm(
+    setters: &std::rc::Rc<std::cell::RefCell<Vec<WriteSignal<i32>>>>,
+    ctx: &mut BuildContext,
+) -> rvue::Gc<Component> {
+    let (count, set_count) = create_signal(0);
+    setters.borrow_mut().push(set_count);
+    let state = rvue::widgets::Text::new("0").build(ctx);
+    let text = rvue::Gc::clone(state.component());
+    let comp = rvue::Gc::clone(&text);
+    let effect = create_effect(move || comp.set_text_content(count.get().to_string()));
+    text.add_effect(effect);
+    text
+}
+
+#[test]
+fn test_keyed_arm_keeps_its_state_when_switched_back() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let setters = Rc::new(RefCell::new(Vec::new()));
+    let (editing, set_editing) = create_signal(true);
+
+    let arm_setters = Rc::clone(&setters);
+    let switch = rvue::widgets::Switch::new(vec![
+        rvue::widgets::Match::new(editing, move |ctx| counter_arm(&arm_setters, ctx)).key("editor"),
+        rvue::widgets::Match::new(true, |ctx| {
+            let state = rvue::widgets::Text::new("preview").build(ctx);
+            rvue::Gc::clone(state.component())
+        }),
+    ]);
+
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = rvue::text::TextContext::new();
+    let mut id_counter = 0;
+    let mut ctx = BuildContext::new(&mut taffy, &mut text_context, &mut id_counter);
+    let state = switch.build(&mut ctx);
+    let component = state.component();
+    let editor = rvue::Gc::clone(&component.children.borrow()[0]);
+
+    setters.borrow()[0].set(1);
+    assert_eq!(rendered_texts(component), vec!["1"]);
+
+    set_editing.set(false);
+    assert_eq!(rendered_texts(component), vec!["preview"]);
+    assert!(editor.parent.borrow().is_none());
+
+    set_editing.set(true);
+    assert_eq!(setters.borrow().len(), 1);
+    assert!(rvue::Gc::ptr_eq(&component.children.borrow()[0], &editor));
+    assert_eq!(rendered_texts(component), vec!["1"]);
+
+    // The restored counter is still live
+    setters.borrow()[0].set(2);
+    assert_eq!(rendered_texts(component), vec!["2"]);
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
+#[test]
+fn test_match_key_attribute_reuses_arm_content() {
+    let (on, set_on) = create_signal(true);
+    let is_off = {
+        let on = on.clone();
+        create_memo(move || !on.get())
+    };
+
+    let view: ViewStruct = view! {
+        <Switch>
+            <Match when=on key="on">
+                <Text content="on" />
+            </Match>
+            <Match when=is_off>
+                <Text content="off" />
+            </Match>
+        </Switch>
+    };
+    let switch = view.into_component();
+    let on_child = rvue::Gc::clone(&switch.children.borrow()[0]);
+
+    set_on.set(false);
+    let off_child = rvue::Gc::clone(&switch.children.borrow()[0]);
+    set_on.set(true);
+    assert!(rvue::Gc::ptr_eq(&switch.children.borrow()[0], &on_child));
+
+    // Unkeyed arms are still built again
+    set_on.set(false);
+    assert!(!rvue::Gc::ptr_eq(&switch.children.borrow()[0], &off_child));
+
+    rvue::signal::__test_clear_signal_subscriptions();
+}
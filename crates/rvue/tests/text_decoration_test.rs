@@ -0,0 +1,71 @@
+//! Tests for the text_decoration property on Text rendering
+
+use rvue::layout::LayoutNode;
+use rvue::render::widget::text_decoration_rects;
+use rvue::text::{ParleyLayoutWrapper, TextContext};
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::Text;
+use rvue::Scene;
+use rvue_style::{ReactiveStyles, TextDecoration};
+
+/// Build and lay out a Text, returning its component
+fn build_text(text: Text) -> rvue::Gc<rvue::Component> {
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0;
+    let state = text.build(&mut BuildContext::new(&mut taffy, &mut text_context, &mut id_counter));
+    let component = rvue::Gc::clone(state.component());
+    LayoutNode::build_in_tree(&mut taffy, &component, &[], &mut text_context, None);
+    component
+}
+
+#[test]
+fn test_underline_spans_text_below_baseline() {
+    let text = build_text(Text::new("underlined link"));
+    let user_data = text.user_data.borrow();
+    let ParleyLayoutWrapper(layout) = user_data.as_ref().unwrap().downcast_ref().unwrap();
+
+    let rects = text_decoration_rects(layout, TextDecoration::Underline);
+    assert!(!rects.is_empty());
+
+    let line = layout.lines().next().unwrap();
+    let baseline = line.metrics().baseline as f64;
+    let left = rects.iter().map(|(rect, _)| rect.x0).fold(f64::INFINITY, f64::min);
+    let right = rects.iter().map(|(rect, _)| rect.x1).fold(f64::NEG_INFINITY, f64::max);
+    assert!(left.abs() < 0.5, "underline starts at {left}");
+    assert!((right - layout.width() as f64).abs() < 0.5, "underline ends at {right}");
+    for (rect, _) in &rects {
+        assert!(rect.y0 >= baseline, "underline at {} is above the baseline {baseline}", rect.y0);
+        assert!(rect.y1 <= layout.height() as f64, "underline at {} is below the text", rect.y1);
+        assert!(rect.height() > 0.0);
+    }
+}
+
+#[test]
+fn test_line_through_sits_above_baseline() {
+    let text = build_text(Text::new("removed"));
+    let user_data = text.user_data.borrow();
+    let ParleyLayoutWrapper(layout) = user_data.as_ref().unwrap().downcast_ref().unwrap();
+
+    let baseline = layout.lines().next().unwrap().metrics().baseline as f64;
+    let rects = text_decoration_rects(layout, TextDecoration::LineThrough);
+    assert!(!rects.is_empty());
+    assert!(rects.iter().all(|(rect, _)| rect.y1 < baseline));
+    assert!(text_decoration_rects(layout, TextDecoration::None).is_empty());
+}
+
+#[test]
+fn test_underlined_text_renders() {
+    let text = build_text(
+        Text::new("link")
+            .styles(ReactiveStyles::new().set_text_decoration(TextDecoration::Underline)),
+    );
+    assert_eq!(
+        text.widget_styles().and_then(|s| s.text_decoration),
+        Some(TextDecoration::Underline)
+    );
+
+    let mut scene = Scene::new();
+    scene.add_fragment(text);
+    scene.update();
+}
@@ -0,0 +1,88 @@
+//! Font fallback for text mixing Latin, CJK and emoji
+
+use parley::swash::FontRef;
+use parley::{Layout, Run};
+use rvue::text::{text_layout_builder, BrushIndex, TextContext};
+
+const MIXED: &str = "Hello 世界 😀";
+
+fn layout_mixed(text_context: &mut TextContext) -> Layout<BrushIndex> {
+    let mut layout = text_layout_builder(text_context, MIXED, 16.0).build(MIXED);
+    layout.break_all_lines(None);
+    layout
+}
+
+/// Whether any installed font has a glyph for `ch`
+fn installed_font_covers(text_context: &mut TextContext, ch: char) -> bool {
+    let collection = &mut text_context.font_ctx.collection;
+    let names: Vec<String> = collection.family_names().map(str::to_string).collect();
+    names.iter().any(|name| {
+        let Some(family) = collection.family_by_name(name) else {
+            return false;
+        };
+        family.fonts().iter().any(|font| {
+            font.load(Some(&mut text_context.font_ctx.source_cache)).is_some_and(|data| {
+                FontRef::from_index(data.as_ref(), font.index() as usize)
+                    .is_some_and(|font_ref| font_ref.charmap().map(ch) != 0)
+            })
+        })
+    })
+}
+
+/// The run laying out byte `index` and the glyph drawn for it
+fn run_at<'a>(layout: &'a Layout<BrushIndex>, index: usize) -> (Run<'a, BrushIndex>, u32) {
+    for line in layout.lines() {
+        for run in line.runs() {
+            let glyph = run
+                .clusters()
+                .find(|cluster| cluster.text_range().contains(&index))
+                .and_then(|cluster| cluster.glyphs().next())
+                .map(|glyph| glyph.id);
+            if let Some(glyph) = glyph {
+                return (run, glyph);
+            }
+        }
+    }
+    panic!("no run covers byte {index}");
+}
+
+fn font_key(run: &Run<'_, BrushIndex>) -> (u64, u32) {
+    (run.font().data.id(), run.font().index)
+}
+
+#[test]
+fn test_mixed_scripts_lay_out_in_contiguous_runs() {
+    let mut text_context = TextContext::new();
+    let layout = layout_mixed(&mut text_context);
+
+    let mut next = 0;
+    for line in layout.lines() {
+        for run in line.runs() {
+            assert_eq!(run.text_range().start, next);
+            next = run.text_range().end;
+        }
+    }
+    assert_eq!(next, MIXED.len());
+}
+
+#[test]
+fn test_fallback_fonts_get_their_own_runs() {
+    let mut text_context = TextContext::new();
+    let layout = layout_mixed(&mut text_context);
+    let (latin_run, latin_glyph) = run_at(&layout, 0);
+    assert_ne!(latin_glyph, 0, "Latin text should never draw a missing-glyph box");
+
+    for ch in ['世', '😀'] {
+        let index = MIXED.find(ch).unwrap();
+        let (run, glyph) = run_at(&layout, index);
+
+        // Without any font for the script a missing-glyph box is all that can be drawn
+        if installed_font_covers(&mut text_context, ch) {
+            assert_ne!(glyph, 0, "{ch:?} should fall back to an installed font that has it");
+        }
+        if font_key(&run) != font_key(&latin_run) {
+            assert!(run.text_range().start > 0, "{ch:?} uses another font, so another run");
+            assert!(run.text_range().start <= index);
+        }
+    }
+}
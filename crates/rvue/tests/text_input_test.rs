@@ -3,7 +3,7 @@
 //! These tests verify that keyboard input works correctly on TextInput
 //! without requiring a GUI window.
 
-use rvue::text::editor::SharedTextEditor;
+use rvue::text::editor::{EditKind, SharedTextEditor, MAX_UNDO_STEPS};
 
 #[test]
 fn test_text_input_editor_initialization() {
@@ -397,3 +397,78 @@ fn test_chinese_text_delete() {
     editor.editor().delete();
     assert_eq!(editor.editor().content(), "文");
 }
+
+#[test]
+fn test_move_line_keeps_column() {
+    let editor = SharedTextEditor::new();
+
+    editor.editor().insert_text("first line\nab\nthird");
+    editor.editor().move_to(8);
+
+    // The short middle line clamps the column to its end
+    editor.editor().move_line(1);
+    assert_eq!(editor.editor().selection().cursor(), 13);
+
+    editor.editor().move_line(1);
+    assert_eq!(editor.editor().selection().cursor(), 16);
+
+    editor.editor().move_line(-2);
+    assert_eq!(editor.editor().selection().cursor(), 2);
+
+    // Moving past the first or last line goes to the start or end
+    editor.editor().move_line(-1);
+    assert_eq!(editor.editor().selection().cursor(), 0);
+    editor.editor().move_line(5);
+    assert_eq!(editor.editor().selection().cursor(), 19);
+}
+
+#[test]
+fn test_undo_coalesces_same_kind_edits() {
+    let editor = SharedTextEditor::new();
+    let editor = editor.editor();
+
+    for ch in ["a", "b", "c"] {
+        editor.edit(EditKind::Typing, |e| e.insert_text(ch));
+    }
+    editor.edit(EditKind::Deleting, |e| e.backspace());
+    assert_eq!(editor.content(), "ab");
+
+    assert!(editor.undo());
+    assert_eq!(editor.content(), "abc");
+    assert!(editor.undo());
+    assert_eq!(editor.content(), "");
+    assert!(!editor.undo());
+
+    assert!(editor.redo());
+    assert_eq!(editor.content(), "abc");
+    assert_eq!(editor.selection().cursor(), 3);
+
+    // A new edit drops the redo steps
+    editor.edit(EditKind::Other, |e| e.insert_text("!"));
+    assert!(!editor.can_redo());
+}
+
+#[test]
+fn test_undo_history_is_bounded() {
+    let editor = SharedTextEditor::new();
+    let editor = editor.editor();
+
+    for _ in 0..MAX_UNDO_STEPS + 20 {
+        editor.edit(EditKind::Other, |e| e.insert_text("x"));
+    }
+    let mut undone = 0;
+    while editor.undo() {
+        undone += 1;
+    }
+    assert_eq!(undone, MAX_UNDO_STEPS);
+    assert_eq!(editor.content().len(), 20);
+}
+
+#[test]
+fn test_unchanged_edit_is_not_recorded() {
+    let editor = SharedTextEditor::new();
+    let editor = editor.editor();
+
+    editor.edit(EditKind::Deleting, |e| e.backspace());
+    assert!(!editor.can_undo());
+}
@@ -0,0 +1,64 @@
+//! Tests for the white_space property on Text layout
+
+use rvue::layout::LayoutNode;
+use rvue::text::{ParleyLayoutWrapper, TextContext};
+use rvue::widget::{BuildContext, Widget};
+use rvue::widgets::Text;
+use rvue_style::{ReactiveStyles, Size, WhiteSpace, Width};
+
+const LONG_TEXT: &str = "the quick brown fox jumps over the lazy dog";
+
+/// Shape `text` and return its line count and laid-out width
+fn shape(text: Text) -> (usize, f32) {
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0;
+    let state = text.build(&mut BuildContext::new(&mut taffy, &mut text_context, &mut id_counter));
+    let component = state.component();
+
+    LayoutNode::build_in_tree(&mut taffy, component, &[], &mut text_context, None);
+
+    let user_data = component.user_data.borrow();
+    let ParleyLayoutWrapper(layout) = user_data.as_ref().unwrap().downcast_ref().unwrap();
+    (layout.lines().count(), layout.width())
+}
+
+#[test]
+fn test_normal_text_wraps_at_its_width() {
+    let (lines, width) = shape(
+        Text::new(LONG_TEXT).styles(ReactiveStyles::new().set_width(Width(Size::Pixels(50.0)))),
+    );
+
+    assert!(lines > 1, "expected wrapped lines, got {lines}");
+    assert!(width <= 50.0, "wrapped text is {width}px wide");
+}
+
+#[test]
+fn test_nowrap_keeps_long_text_on_one_line() {
+    let (lines, width) = shape(
+        Text::new(LONG_TEXT).styles(
+            ReactiveStyles::new()
+                .set_width(Width(Size::Pixels(50.0)))
+                .set_white_space(WhiteSpace::NoWrap),
+        ),
+    );
+
+    assert_eq!(lines, 1);
+    assert!(width > 50.0, "unwrapped text should overflow, is {width}px wide");
+}
+
+#[test]
+fn test_pre_preserves_embedded_newline() {
+    let (lines, _) = shape(
+        Text::new("first\nsecond").styles(ReactiveStyles::new().set_white_space(WhiteSpace::Pre)),
+    );
+
+    assert_eq!(lines, 2);
+}
+
+#[test]
+fn test_normal_text_turns_newline_into_space() {
+    let (lines, _) = shape(Text::new("first\nsecond"));
+
+    assert_eq!(lines, 1);
+}
@@ -41,3 +41,40 @@ fn test_text_widget_rendering_properties() {
         assert_eq!(text.component_type, ComponentType::Text);
     }
 }
+
+#[test]
+fn test_rich_text_applies_bold_weight_to_its_run() {
+    use rvue::layout::LayoutNode;
+    use rvue::text::{ParleyLayoutWrapper, TextContext};
+    use rvue::widget::{BuildContext, Widget};
+    use rvue::widgets::Text;
+    use rvue::TextSpan;
+
+    let mut taffy = rvue::TaffyTree::new();
+    let mut text_context = TextContext::new();
+    let mut id_counter = 0;
+    let widget = Text::rich([TextSpan::new("normal "), TextSpan::new("bold").bold()]);
+    let state =
+        widget.build(&mut BuildContext::new(&mut taffy, &mut text_context, &mut id_counter));
+    let text = state.component();
+    assert_eq!(text.text_content(), "normal bold");
+
+    LayoutNode::build_in_tree(&mut taffy, text, &[], &mut text_context, None);
+
+    let user_data = text.user_data.borrow();
+    let ParleyLayoutWrapper(layout) = user_data.as_ref().unwrap().downcast_ref().unwrap();
+    let mut weights = Vec::new();
+    for line in layout.lines() {
+        for run in line.runs() {
+            let font = run.font();
+            let font_ref =
+                parley::swash::FontRef::from_index(font.data.as_ref(), font.index as usize)
+                    .unwrap();
+            let weight = font_ref.attributes().weight().0;
+            let embolden = run.synthesis().embolden();
+            weights.push((run.text_range(), weight >= 700 || embolden));
+        }
+    }
+
+    assert_eq!(weights, vec![(0..7, false), (7..11, true)]);
+}
@@ -0,0 +1,69 @@
+//! Tests for debounced and throttled signals driven by the virtual clock
+
+#[cfg(all(feature = "async", feature = "testing"))]
+#[cfg(test)]
+mod tests {
+    use rvue::async_runtime::clock::enable_virtual_clock;
+    use rvue::async_runtime::{create_debounced_signal, create_throttled_signal};
+    use rvue::headless::{advance_time, init_runtime};
+    use rvue::{create_effect, create_signal};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    /// The virtual clock is process-wide, so tests that advance it run one at a time.
+    static CLOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_debounced_signal_updates_once_after_delay() {
+        let _clock = CLOCK.lock().unwrap();
+        enable_virtual_clock();
+        init_runtime();
+
+        let (query, set_query) = create_signal(String::new());
+        let debounced = create_debounced_signal(query, Duration::from_millis(300));
+
+        let runs = Rc::new(Cell::new(0));
+        let runs_clone = Rc::clone(&runs);
+        let debounced_clone = debounced.clone();
+        let _effect = create_effect(move || {
+            let _ = debounced_clone.get();
+            runs_clone.set(runs_clone.get() + 1);
+        });
+        assert_eq!(runs.get(), 1);
+
+        for text in ["r", "ru", "rus", "rust"] {
+            set_query.set(text.to_string());
+            advance_time(Duration::from_millis(100));
+        }
+        assert_eq!(debounced.get(), "");
+        assert_eq!(runs.get(), 1);
+
+        advance_time(Duration::from_millis(300));
+        assert_eq!(debounced.get(), "rust");
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn test_throttled_signal_applies_first_and_latest_change() {
+        let _clock = CLOCK.lock().unwrap();
+        enable_virtual_clock();
+        init_runtime();
+
+        let (value, set_value) = create_signal(0);
+        let throttled = create_throttled_signal(value, Duration::from_millis(100));
+
+        set_value.set(1);
+        advance_time(Duration::ZERO);
+        assert_eq!(throttled.get(), 1);
+
+        set_value.set(2);
+        set_value.set(3);
+        advance_time(Duration::from_millis(50));
+        assert_eq!(throttled.get(), 1);
+
+        advance_time(Duration::from_millis(50));
+        assert_eq!(throttled.get(), 3);
+    }
+}
@@ -0,0 +1,48 @@
+//! Tests for the tracing spans emitted while updating a scene
+
+use std::sync::{Arc, Mutex};
+
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::Scene;
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+/// Records the name of every span opened
+#[derive(Clone, Default)]
+struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+impl<S: Subscriber> Layer<S> for SpanNames {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        self.0.lock().unwrap().push(attrs.metadata().name());
+    }
+}
+
+#[test]
+fn test_scene_update_records_layout_and_paint_spans() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let mut scene = Scene::new();
+    scene.add_fragment(root);
+
+    let names = SpanNames::default();
+    let subscriber = tracing_subscriber::registry().with(names.clone());
+    tracing::subscriber::with_default(subscriber, || scene.update());
+
+    let names = names.0.lock().unwrap();
+    assert_eq!(*names, vec!["scene_update", "layout", "paint"]);
+}
+
+#[test]
+fn test_clean_scene_update_records_no_spans() {
+    let root = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    let mut scene = Scene::new();
+    scene.add_fragment(root);
+    scene.update();
+
+    let names = SpanNames::default();
+    let subscriber = tracing_subscriber::registry().with(names.clone());
+    tracing::subscriber::with_default(subscriber, || scene.update());
+
+    assert!(names.0.lock().unwrap().is_empty());
+}
@@ -0,0 +1,52 @@
+//! Tests for composing `ViewStruct`s imperatively
+
+use rudo_gc::Gc;
+use rvue::widgets::{Button, Text};
+use rvue::{Component, ComponentType};
+use rvue_macro::view;
+
+fn parent_of(component: &Gc<Component>) -> Option<Gc<Component>> {
+    component.parent.borrow().clone()
+}
+
+#[test]
+fn test_with_child_builds_and_links_widgets() {
+    let view = view! { <Flex /> }.with_child(Text::new("one")).with_child(Button::new());
+
+    let children: Vec<_> = view.children().collect();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].component_type, ComponentType::Text);
+    assert_eq!(children[0].text_content(), "one");
+    assert_eq!(children[1].component_type, ComponentType::Button);
+    for child in &children {
+        assert!(Gc::ptr_eq(&parent_of(child).unwrap(), &view.root_component));
+    }
+}
+
+#[test]
+fn test_append_puts_both_subtrees_under_one_root() {
+    let header = view! {
+        <Flex>
+            <Text content="Title" />
+        </Flex>
+    };
+    let body = view! { <Flex /> }.with_child(Text::new("first")).with_child(Text::new("second"));
+    let header_root = Gc::clone(&header.root_component);
+    let body_root = Gc::clone(&body.root_component);
+
+    let mut page = view! { <Flex /> };
+    page.root_component.clear_dirty();
+    page.append(header);
+    page.append(body);
+
+    let roots: Vec<_> = page.children().collect();
+    assert_eq!(roots.len(), 2);
+    assert!(Gc::ptr_eq(&roots[0], &header_root));
+    assert!(Gc::ptr_eq(&roots[1], &body_root));
+    assert!(Gc::ptr_eq(&parent_of(&body_root).unwrap(), &page.root_component));
+    assert!(page.root_component.is_dirty());
+
+    let body_texts: Vec<_> = body_root.children.borrow().iter().map(|c| c.text_content()).collect();
+    assert_eq!(body_texts, ["first", "second"]);
+    assert_eq!(header_root.children.borrow()[0].text_content(), "Title");
+}
@@ -0,0 +1,87 @@
+//! Tests for `on_visibility_change` on the children of a scroll container
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rvue::component::{Component, ComponentType};
+use rvue::properties::PropertyMap;
+use rvue::render::FlexScrollState;
+use rvue::{Gc, Scene};
+use rvue_style::properties::{FlexDirection, MinHeight, Overflow};
+use rvue_style::{ComputedStyles, Height, Size, Width};
+
+fn sized(width: f32, height: f32) -> ComputedStyles {
+    ComputedStyles {
+        width: Some(Width(Size::Pixels(width))),
+        height: Some(Height(Size::Pixels(height))),
+        ..Default::default()
+    }
+}
+
+/// A 100px tall scroll container holding five 50px rows
+fn scroll_list() -> (Gc<Component>, Vec<Gc<Component>>) {
+    let list = Component::with_properties(1, ComponentType::Flex, PropertyMap::new());
+    list.set_widget_styles(ComputedStyles {
+        flex_direction: Some(FlexDirection::Column),
+        overflow_y: Some(Overflow::Auto),
+        ..sized(200.0, 100.0)
+    });
+
+    let rows: Vec<_> = (0..5)
+        .map(|i| {
+            let row = Component::with_properties(10 + i, ComponentType::Flex, PropertyMap::new());
+            row.set_widget_styles(ComputedStyles {
+                min_height: Some(MinHeight(Size::Pixels(50.0))),
+                ..sized(200.0, 50.0)
+            });
+            list.add_child(Gc::clone(&row));
+            row.set_parent(Some(Gc::clone(&list)));
+            row
+        })
+        .collect();
+    (list, rows)
+}
+
+fn scroll_to(list: &Gc<Component>, offset_y: f32) {
+    let state = list.scroll_state();
+    list.set_scroll_state(FlexScrollState { scroll_offset_y: offset_y, ..state });
+    list.mark_dirty();
+}
+
+#[test]
+fn test_scrolling_hidden_row_into_view_reports_visible() {
+    let (list, rows) = scroll_list();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let seen = Rc::clone(&changes);
+    rows[4].on_visibility_change(move |visible| seen.borrow_mut().push(visible));
+
+    let mut scene = Scene::new();
+    scene.add_fragment(Gc::clone(&list));
+    scene.update();
+    assert_eq!(*changes.borrow(), vec![false]);
+
+    scroll_to(&list, 150.0);
+    scene.update();
+    assert_eq!(*changes.borrow(), vec![false, true]);
+}
+
+#[test]
+fn test_row_reports_only_boundary_crossings() {
+    let (list, rows) = scroll_list();
+    let changes = Rc::new(RefCell::new(Vec::new()));
+    let seen = Rc::clone(&changes);
+    rows[0].on_visibility_change(move |visible| seen.borrow_mut().push(visible));
+
+    let mut scene = Scene::new();
+    scene.add_fragment(Gc::clone(&list));
+    scene.update();
+
+    // Still partly visible
+    scroll_to(&list, 30.0);
+    scene.update();
+    // Scrolled exactly past its bottom edge
+    scroll_to(&list, 50.0);
+    scene.update();
+
+    assert_eq!(*changes.borrow(), vec![true, false]);
+}
@@ -75,3 +75,32 @@ mod tests {
         });
     }
 }
+
+#[cfg(feature = "async")]
+mod watch_tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use rvue::async_runtime::watch;
+    use rvue::create_signal;
+
+    #[test]
+    fn test_watch_passes_new_and_previous_value() {
+        let (flag, set_flag) = create_signal(false);
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let calls_clone = Rc::clone(&calls);
+
+        let watcher = watch(flag, move |new, old| calls_clone.borrow_mut().push((new, old)));
+        assert!(calls.borrow().is_empty());
+
+        set_flag.set(true);
+        set_flag.set(false);
+        assert_eq!(*calls.borrow(), vec![(true, false), (false, true)]);
+
+        watcher.stop();
+        set_flag.set(true);
+        assert_eq!(calls.borrow().len(), 2);
+
+        rvue::signal::__test_clear_signal_subscriptions();
+    }
+}
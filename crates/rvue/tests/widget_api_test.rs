@@ -80,6 +80,21 @@ fn test_checkbox_widget_builder() {
     });
 }
 
+#[test]
+fn test_checkbox_indeterminate_follows_signal() {
+    let (indeterminate, set_indeterminate) = create_signal(true);
+
+    with_build_context(|ctx| {
+        let widget = Checkbox::new(false).indeterminate(indeterminate);
+        let state = widget.build(ctx);
+        assert!(state.component().checkbox_indeterminate());
+
+        set_indeterminate.set(false);
+        assert!(!state.component().checkbox_indeterminate());
+    });
+    rvue::signal::__test_clear_signal_subscriptions();
+}
+
 #[test]
 fn test_text_input_widget_builder() {
     with_build_context(|ctx| {
@@ -189,3 +204,37 @@ fn test_into_reactive_value_trait() {
     assert_eq!(value.get(), 42);
     assert!(value.is_reactive());
 }
+
+/// Build `child` and mount it into a fresh Flex, returning the Flex's children
+fn mount_into_flex(child: impl IntoWidget) -> Vec<rvue::Gc<Component>> {
+    with_build_context(|ctx| {
+        let parent = Flex::new().build(ctx);
+        child.into_widget().build(ctx).mount(Some(rvue::Gc::clone(parent.component())));
+        let children = parent.component().children.borrow().clone();
+        children
+    })
+}
+
+#[test]
+fn test_option_into_widget() {
+    let some = mount_into_flex(Some(Text::new("shown")));
+    assert_eq!(some.len(), 1);
+    assert_eq!(some[0].text_content(), "shown");
+
+    assert!(mount_into_flex(None::<Text>).is_empty());
+}
+
+#[test]
+fn test_vec_into_widget_mounts_siblings() {
+    let children = mount_into_flex(vec![Text::new("a"), Text::new("b"), Text::new("c")]);
+
+    assert_eq!(children.len(), 3);
+    let texts: Vec<_> = children.iter().map(|child| child.text_content()).collect();
+    assert_eq!(texts, ["a", "b", "c"]);
+}
+
+#[test]
+fn test_iterator_collects_into_fragment() {
+    let fragment: FragmentWidget<Text> = (1..=2).map(|i| Text::new(format!("item {i}"))).collect();
+    assert_eq!(mount_into_flex(fragment).len(), 2);
+}
@@ -0,0 +1,42 @@
+//! Tests for window configuration passed to `run_app_with_config`
+
+use rvue::WindowConfig;
+use winit::dpi::{LogicalSize, Size};
+
+#[test]
+fn test_window_config_default_matches_run_app() {
+    let attributes = WindowConfig::default().window_attributes();
+
+    assert_eq!(attributes.title, "Rvue Application");
+    assert_eq!(attributes.inner_size, Some(Size::Logical(LogicalSize::new(800.0, 600.0))));
+    assert_eq!(attributes.min_inner_size, None);
+    assert_eq!(attributes.max_inner_size, None);
+    assert!(attributes.resizable);
+    assert!(attributes.decorations);
+    assert!(!attributes.transparent);
+    // The system accessibility settings are only read when asked for
+    assert!(!WindowConfig::default().system_preferences);
+    assert!(WindowConfig::new().with_system_preferences(true).system_preferences);
+}
+
+#[test]
+fn test_window_config_builder_applies_attributes() {
+    let config = WindowConfig::new()
+        .with_title("Settings")
+        .with_size(1024.0, 768.0)
+        .with_min_size(400.0, 300.0)
+        .with_max_size(1920.0, 1080.0)
+        .with_resizable(false)
+        .with_decorations(false)
+        .with_transparent(true);
+
+    let attributes = config.window_attributes();
+
+    assert_eq!(attributes.title, "Settings");
+    assert_eq!(attributes.inner_size, Some(Size::Logical(LogicalSize::new(1024.0, 768.0))));
+    assert_eq!(attributes.min_inner_size, Some(Size::Logical(LogicalSize::new(400.0, 300.0))));
+    assert_eq!(attributes.max_inner_size, Some(Size::Logical(LogicalSize::new(1920.0, 1080.0))));
+    assert!(!attributes.resizable);
+    assert!(!attributes.decorations);
+    assert!(attributes.transparent);
+}